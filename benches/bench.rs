@@ -9,6 +9,53 @@ fn readme(c: &mut Criterion) {
     });
 }
 
+fn links(c: &mut Criterion) {
+    let doc = "a [b] [c](d) ![e] [f][g] *h [i* j]\n\n[g]: k\n\n".repeat(1000);
+
+    c.bench_with_input(BenchmarkId::new("links", "links"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
+fn long_line(c: &mut Criterion) {
+    let doc = "a *b* c* **d [e *f] g* h](i) _j ".repeat(10000);
+
+    c.bench_with_input(BenchmarkId::new("long line", "long line"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
+fn max_attention_sequences(c: &mut Criterion) {
+    let doc = "a**b ".repeat(20000);
+    let options = markdown::Options {
+        parse: markdown::ParseOptions {
+            max_attention_sequences: Some(100),
+            ..markdown::ParseOptions::default()
+        },
+        ..markdown::Options::default()
+    };
+
+    c.bench_with_input(
+        BenchmarkId::new("max attention sequences", "max attention sequences"),
+        &doc,
+        |b, s| {
+            b.iter(|| markdown::to_html_with_options(s, &options));
+        },
+    );
+}
+
+fn punctuation(c: &mut Criterion) {
+    let doc = "a*「b」*c _“d”_ e**‘f’**g ~—h—~ ".repeat(10000);
+
+    c.bench_with_input(
+        BenchmarkId::new("punctuation", "punctuation"),
+        &doc,
+        |b, s| {
+            b.iter(|| markdown::to_html(s));
+        },
+    );
+}
+
 // fn one_and_a_half_mb(c: &mut Criterion) {
 //     let doc = fs::read_to_string("../a-dump-of-markdown/markdown.md").unwrap();
 //     let mut group = c.benchmark_group("giant");
@@ -20,5 +67,12 @@ fn readme(c: &mut Criterion) {
 // }
 // , one_and_a_half_mb
 
-criterion_group!(benches, readme);
+criterion_group!(
+    benches,
+    readme,
+    links,
+    long_line,
+    max_attention_sequences,
+    punctuation
+);
 criterion_main!(benches);
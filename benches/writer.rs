@@ -0,0 +1,35 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::fs;
+use std::io::Write;
+
+fn writer(c: &mut Criterion) {
+    let doc = fs::read_to_string("readme.md").unwrap().repeat(100);
+    let options = markdown::Options::default();
+    let mut group = c.benchmark_group("writer");
+
+    group.bench_with_input(BenchmarkId::new("to_html", "large"), &doc, |b, s| {
+        b.iter(|| {
+            let mut out = Vec::new();
+            let html = markdown::to_html_with_options(s, &options).unwrap();
+            out.write_all(html.as_bytes()).unwrap();
+            out
+        });
+    });
+
+    group.bench_with_input(
+        BenchmarkId::new("to_html_into_writer", "large"),
+        &doc,
+        |b, s| {
+            b.iter(|| {
+                let mut out = Vec::new();
+                markdown::to_html_into_writer(s, &mut out, &options).unwrap();
+                out
+            });
+        },
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, writer);
+criterion_main!(benches);
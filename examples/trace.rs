@@ -0,0 +1,30 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() -> Result<(), String> {
+    // Trace how an ambiguous input is tokenized.
+    // Run with `cargo run --features trace --example trace`.
+    let trace = Rc::new(RefCell::new(vec![]));
+    let sink = Rc::clone(&trace);
+
+    let options = markdown::ParseOptions {
+        trace: Some(Box::new(move |name, point| {
+            sink.borrow_mut().push(format!(
+                "{}:{} ({}): {:?}",
+                point.line, point.column, point.index, name
+            ));
+        })),
+        ..markdown::ParseOptions::default()
+    };
+
+    // Is this a link, emphasis, or both?
+    let tree = markdown::to_mdast("*[a*](b)", &options)?;
+
+    for line in trace.borrow().iter() {
+        println!("{}", line);
+    }
+
+    println!("{:?}", tree);
+
+    Ok(())
+}
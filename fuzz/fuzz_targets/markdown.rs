@@ -2,11 +2,35 @@
 use libfuzzer_sys::fuzz_target;
 
 fuzz_target!(|data: &[u8]| {
-    if let Ok(s) = std::str::from_utf8(data) {
-        let _ = markdown::to_html(s);
-        let _ = markdown::to_html_with_options(s, &markdown::Options::gfm());
-        let _ = markdown::to_mdast(s, &markdown::ParseOptions::default());
-        let _ = markdown::to_mdast(s, &markdown::ParseOptions::gfm());
-        let _ = markdown::to_mdast(s, &markdown::ParseOptions::mdx());
+    let everything = markdown::ParseOptions {
+        constructs: markdown::Constructs {
+            frontmatter: true,
+            math_flow: true,
+            math_text: true,
+            ..markdown::Constructs::gfm()
+        },
+        ..markdown::ParseOptions::gfm()
+    };
+    let parse = vec![
+        markdown::ParseOptions::default(),
+        markdown::ParseOptions::gfm(),
+        markdown::ParseOptions::mdx(),
+        everything,
+    ];
+
+    // Invalid UTF-8 is replaced, so raw bytes can be used.
+    for options in parse {
+        let _ = markdown::to_mdast_lossy(data, &options);
+        let _ = markdown::to_html_with_options_lossy(
+            data,
+            &markdown::Options {
+                parse: options,
+                compile: markdown::CompileOptions {
+                    allow_dangerous_html: true,
+                    allow_dangerous_protocol: true,
+                    ..markdown::CompileOptions::gfm()
+                },
+            },
+        );
     }
 });
@@ -10,7 +10,7 @@ use std::fs;
 #[tokio::main]
 async fn main() {
     commonmark().await;
-    punctuation().await;
+    unicode().await;
 }
 
 async fn commonmark() {
@@ -78,6 +78,7 @@ use pretty_assertions::assert_eq;
 #[rustfmt::skip]
 #[test]
 fn commonmark() -> Result<(), String> {{
+    // Strict `CommonMark` defaults, except that the spec includes raw HTML.
     let danger = Options {{
         compile: CompileOptions {{
             allow_dangerous_html: true,
@@ -98,7 +99,7 @@ fn commonmark() -> Result<(), String> {{
     fs::write(code_url, doc).unwrap();
 }
 
-async fn punctuation() {
+async fn unicode() {
     let url = "https://www.unicode.org/Public/UCD/latest/ucd/UnicodeData.txt";
     let data_url = "unicode-data.txt";
     let code_url = "src/util/unicode.rs";
@@ -113,7 +114,7 @@ async fn punctuation() {
         value
     };
 
-    let search = [
+    let punctuation = [
         "Pc", // Punctuation, Connector
         "Pd", // Punctuation, Dash
         "Pe", // Punctuation, Close
@@ -123,11 +124,25 @@ async fn punctuation() {
         "Ps", // Punctuation, Open
     ];
 
-    let found = value
+    let mark = [
+        "Mc", // Mark, Spacing Combining
+        "Me", // Mark, Enclosing
+        "Mn", // Mark, Nonspacing
+    ];
+
+    let cells = value
         .lines()
         .map(|line| line.split(';').collect::<Vec<_>>())
         .map(|cells| (cells[0], cells[2]))
-        .filter(|c| search.contains(&c.1))
+        .collect::<Vec<_>>();
+    let punctuation_found = cells
+        .iter()
+        .filter(|c| punctuation.contains(&c.1))
+        .map(|c| c.0)
+        .collect::<Vec<_>>();
+    let mark_found = cells
+        .iter()
+        .filter(|c| mark.contains(&c.1))
         .map(|c| c.0)
         .collect::<Vec<_>>();
 
@@ -146,15 +161,32 @@ async fn punctuation() {
 /// comes before or after them.
 /// One such difference is if those characters are Unicode punctuation.
 ///
+/// Sorted, so it can be searched with `binary_search`.
+///
 /// ## References
 ///
 /// *   [*§ 2.1 Characters and lines* in `CommonMark`](https://spec.commonmark.org/0.30/#unicode-punctuation-character)
 pub const PUNCTUATION: [char; {}] = [
 {}
 ];
+
+/// List of characters that are considered marks.
+///
+/// > 👉 **Important**: this module is generated by `generate/src/main.rs`.
+/// > It is generate from the latest Unicode data.
+///
+/// Marks (such as combining accents) combine with the character before them.
+/// They are used when counting columns in graphemes.
+///
+/// Sorted, so it can be searched with `binary_search`.
+pub const MARK: [char; {}] = [
+{}
+];
 ",
-    found.len(),
-    found.iter().map(|d| format!("    '\\u{{{}}}',", d)).collect::<Vec<_>>().join("\n")
+    punctuation_found.len(),
+    punctuation_found.iter().map(|d| format!("    '\\u{{{}}}',", d)).collect::<Vec<_>>().join("\n"),
+    mark_found.len(),
+    mark_found.iter().map(|d| format!("    '\\u{{{}}}',", d)).collect::<Vec<_>>().join("\n")
     );
 
     fs::write(code_url, doc).unwrap();
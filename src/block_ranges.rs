@@ -0,0 +1,221 @@
+//! Turn events into the kinds and positions of top-level blocks.
+
+use crate::event::{Event, Kind, Name};
+use crate::unist::{Point, Position};
+use alloc::{vec, vec::Vec};
+
+/// Kind of a top-level block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlockKind {
+    /// Block quote.
+    ///
+    /// ```markdown
+    /// > | > a
+    ///     ^^^
+    /// ```
+    BlockQuote,
+    /// Code (fenced or indented).
+    ///
+    /// ```markdown
+    /// > | ```
+    ///     ^^^
+    /// > | a
+    ///     ^
+    /// > | ```
+    ///     ^^^
+    /// ```
+    Code,
+    /// Definition.
+    ///
+    /// ```markdown
+    /// > | [a]: b
+    ///     ^^^^^^
+    /// ```
+    Definition,
+    /// Definition list.
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | : b
+    ///     ^^^
+    /// ```
+    DescriptionList,
+    /// Frontmatter.
+    ///
+    /// ```markdown
+    /// > | ---
+    ///     ^^^
+    /// > | a: b
+    ///     ^^^^
+    /// > | ---
+    ///     ^^^
+    /// ```
+    Frontmatter,
+    /// GFM: footnote definition.
+    ///
+    /// ```markdown
+    /// > | [^a]: b
+    ///     ^^^^^^^
+    /// ```
+    GfmFootnoteDefinition,
+    /// GFM: table.
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///     ^^^^^
+    /// > | | - |
+    ///     ^^^^^
+    /// ```
+    GfmTable,
+    /// Heading (ATX or setext).
+    ///
+    /// ```markdown
+    /// > | # a
+    ///     ^^^
+    /// ```
+    Heading,
+    /// HTML (flow).
+    ///
+    /// ```markdown
+    /// > | <div>
+    ///     ^^^^^
+    /// ```
+    Html,
+    /// List (ordered or unordered).
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^^^
+    /// > | * b
+    ///     ^^^
+    /// ```
+    List,
+    /// Math (flow).
+    ///
+    /// ```markdown
+    /// > | $$
+    ///     ^^
+    /// > | a
+    ///     ^
+    /// > | $$
+    ///     ^^
+    /// ```
+    Math,
+    /// MDX: ESM.
+    ///
+    /// ```markdown
+    /// > | import a from 'b'
+    ///     ^^^^^^^^^^^^^^^^^
+    /// ```
+    MdxEsm,
+    /// MDX: expression (flow).
+    ///
+    /// ```markdown
+    /// > | {a}
+    ///     ^^^
+    /// ```
+    MdxFlowExpression,
+    /// MDX: JSX tag (flow).
+    ///
+    /// Opening and closing tags are separate blocks: the blocks between them
+    /// are not inside them.
+    ///
+    /// ```markdown
+    /// > | <A>
+    ///     ^^^
+    ///   | b
+    /// > | </A>
+    ///     ^^^^
+    /// ```
+    MdxJsxFlowTag,
+    /// Paragraph.
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// ```
+    Paragraph,
+    /// Thematic break.
+    ///
+    /// ```markdown
+    /// > | ***
+    ///     ^^^
+    /// ```
+    ThematicBreak,
+}
+
+/// Top-level block and where it occurs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockRange {
+    /// Kind of block.
+    pub kind: BlockKind,
+    /// Place of the block in the source.
+    pub position: Position,
+}
+
+/// Turn events into top-level blocks.
+pub fn compile(events: &[Event]) -> Vec<BlockRange> {
+    let mut ranges = vec![];
+    let mut depth = 0;
+    let mut index = 0;
+    let mut start = None;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if depth == 0 {
+                start = kind(&event.name).map(|kind| (kind, point(event)));
+            }
+
+            depth += 1;
+        } else {
+            depth -= 1;
+
+            if depth == 0 {
+                if let Some((kind, start)) = start.take() {
+                    ranges.push(BlockRange {
+                        kind,
+                        position: Position {
+                            start,
+                            end: point(event),
+                        },
+                    });
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    ranges
+}
+
+/// Get the kind of block of a top-level event, if it is one.
+pub fn kind(name: &Name) -> Option<BlockKind> {
+    match name {
+        Name::BlockQuote => Some(BlockKind::BlockQuote),
+        Name::CodeFenced | Name::CodeIndented => Some(BlockKind::Code),
+        Name::Definition => Some(BlockKind::Definition),
+        Name::DescriptionList => Some(BlockKind::DescriptionList),
+        Name::Frontmatter => Some(BlockKind::Frontmatter),
+        Name::GfmFootnoteDefinition => Some(BlockKind::GfmFootnoteDefinition),
+        Name::GfmTable => Some(BlockKind::GfmTable),
+        Name::HeadingAtx | Name::HeadingSetext => Some(BlockKind::Heading),
+        Name::HtmlFlow => Some(BlockKind::Html),
+        Name::ListOrdered | Name::ListUnordered => Some(BlockKind::List),
+        Name::MathFlow => Some(BlockKind::Math),
+        Name::MdxEsm => Some(BlockKind::MdxEsm),
+        Name::MdxFlowExpression => Some(BlockKind::MdxFlowExpression),
+        Name::MdxJsxFlowTag => Some(BlockKind::MdxJsxFlowTag),
+        Name::Paragraph => Some(BlockKind::Paragraph),
+        Name::ThematicBreak => Some(BlockKind::ThematicBreak),
+        _ => None,
+    }
+}
+
+/// Create a point from an event.
+fn point(event: &Event) -> Point {
+    Point::new(event.point.line, event.point.column, event.point.index)
+}
@@ -0,0 +1,197 @@
+//! Remember what documents compile to.
+
+use crate::event::Event;
+use crate::mdast::Node;
+use crate::{parser, to_html, to_mdast, Options, ParseOptions};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+/// Cache of compiled documents.
+///
+/// This is useful when the same documents are compiled over and over, such
+/// as comments on a server.
+/// Each document is parsed once: its events are kept, so that turning it
+/// into HTML or into a syntax tree again does not parse it again, and HTML
+/// is kept too.
+/// Documents are keyed by their value; the options are the same for every
+/// document in a cache.
+///
+/// When the cache is full, the least recently used document is dropped.
+/// Errors (such as for invalid MDX) are not cached.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{HtmlCache, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let mut cache = HtmlCache::new(Options::default(), 100);
+///
+/// assert_eq!(cache.get_or_render("*a*")?, "<p><em>a</em></p>");
+/// // This time, it is not parsed or compiled:
+/// assert_eq!(cache.get_or_render("*a*")?, "<p><em>a</em></p>");
+/// assert_eq!(cache.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HtmlCache {
+    /// Configuration, used for all documents.
+    options: Options,
+    /// Documents.
+    entries: Entries,
+}
+
+/// Documents in a cache, and when they were used.
+#[derive(Debug)]
+struct Entries {
+    /// Maximum number of documents.
+    capacity: usize,
+    /// Documents, by value.
+    documents: BTreeMap<String, Entry>,
+    /// Values of documents, by when they were last used, so that the least
+    /// recently used one is first.
+    order: BTreeMap<usize, String>,
+    /// Counter, increased each time a document is used.
+    clock: usize,
+}
+
+/// Document in a cache.
+#[derive(Debug)]
+struct Entry {
+    /// Events of the document.
+    events: Vec<Event>,
+    /// Definitions from the reference resolver, found while parsing.
+    resolved_definitions: Vec<(String, String, Option<String>)>,
+    /// HTML of the document, once compiled.
+    html: Option<String>,
+    /// Value of the clock when the document was last used.
+    used: usize,
+}
+
+impl HtmlCache {
+    /// Create a new cache, which uses `options` for every document, and
+    /// keeps at most `capacity` documents.
+    ///
+    /// A capacity of `0` keeps nothing.
+    pub fn new(options: Options, capacity: usize) -> Self {
+        Self {
+            options,
+            entries: Entries {
+                capacity,
+                documents: BTreeMap::new(),
+                order: BTreeMap::new(),
+                clock: 0,
+            },
+        }
+    }
+
+    /// Turn a document into HTML, or get the HTML from the cache.
+    ///
+    /// ## Errors
+    ///
+    /// Same as [`to_html_with_options()`][crate::to_html_with_options()]:
+    /// only MDX has syntax errors.
+    pub fn get_or_render(&mut self, value: &str) -> Result<String, String> {
+        let options = &self.options;
+
+        if let Some(entry) = self.entries.touch(value, &options.parse)? {
+            let Entry {
+                events,
+                resolved_definitions,
+                html,
+                ..
+            } = entry;
+            let html = html.get_or_insert_with(|| {
+                to_html::compile(
+                    events,
+                    value.as_bytes(),
+                    resolved_definitions,
+                    &options.parse,
+                    &options.compile,
+                )
+            });
+            Ok(html.clone())
+        } else {
+            crate::to_html_with_options(value, options)
+        }
+    }
+
+    /// Turn a document into a syntax tree, from the events in the cache if
+    /// possible.
+    ///
+    /// ## Errors
+    ///
+    /// Same as [`to_mdast()`][crate::to_mdast()]: only MDX has syntax
+    /// errors.
+    pub fn get_or_mdast(&mut self, value: &str) -> Result<Node, String> {
+        if let Some(entry) = self.entries.touch(value, &self.options.parse)? {
+            to_mdast::compile(&entry.events, value.as_bytes())
+        } else {
+            crate::to_mdast(value, &self.options.parse)
+        }
+    }
+
+    /// Get the number of documents in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.documents.len()
+    }
+
+    /// Check whether there are no documents in the cache.
+    pub fn is_empty(&self) -> bool {
+        self.entries.documents.is_empty()
+    }
+
+    /// Remove all documents from the cache.
+    pub fn clear(&mut self) {
+        self.entries.documents.clear();
+        self.entries.order.clear();
+    }
+}
+
+impl Entries {
+    /// Make sure a document is in the cache, parsing it if needed, and
+    /// mark it as used.
+    ///
+    /// Returns the document, or `None` if nothing is kept (with a capacity
+    /// of `0`).
+    fn touch(&mut self, value: &str, options: &ParseOptions) -> Result<Option<&mut Entry>, String> {
+        if self.capacity == 0 {
+            return Ok(None);
+        }
+
+        if let Some(entry) = self.documents.get(value) {
+            self.order.remove(&entry.used);
+        } else {
+            let (events, parse_state) = parser::parse(value, options)?;
+
+            if self.documents.len() >= self.capacity {
+                if let Some(used) = self.order.keys().next().copied() {
+                    if let Some(oldest) = self.order.remove(&used) {
+                        self.documents.remove(&oldest);
+                    }
+                }
+            }
+
+            self.documents.insert(
+                value.into(),
+                Entry {
+                    events,
+                    resolved_definitions: parse_state.resolved_definitions,
+                    html: None,
+                    used: 0,
+                },
+            );
+        }
+
+        self.clock += 1;
+        self.order.insert(self.clock, value.into());
+
+        let mut entry = self.documents.get_mut(value);
+
+        if let Some(entry) = entry.as_mut() {
+            entry.used = self.clock;
+        }
+
+        Ok(entry)
+    }
+}
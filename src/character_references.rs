@@ -0,0 +1,113 @@
+//! Turn events into the character references in them.
+
+use crate::event::{Event, Kind, Name};
+use crate::unist::{Point, Position};
+use crate::util::{
+    character_reference::decode as decode_character_reference,
+    slice::{Position as SlicePosition, Slice},
+};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Kind of a character reference.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CharacterReferenceKind {
+    /// Named.
+    ///
+    /// ```markdown
+    /// > | a &amp; b
+    ///       ^^^^^
+    /// ```
+    Named,
+    /// Decimal (numeric).
+    ///
+    /// ```markdown
+    /// > | a &#123; b
+    ///       ^^^^^^
+    /// ```
+    Decimal,
+    /// Hexadecimal (numeric).
+    ///
+    /// ```markdown
+    /// > | a &#x7b; b
+    ///       ^^^^^^
+    /// ```
+    Hexadecimal,
+}
+
+/// Character reference, where it occurs, and what it decodes to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharacterReference {
+    /// Kind of reference.
+    pub kind: CharacterReferenceKind,
+    /// Place of the reference in the source.
+    pub position: Position,
+    /// Source of the reference, such as `&amp;`.
+    pub source: String,
+    /// Decoded value, such as `&`.
+    ///
+    /// Numeric references to invalid code points (such as `&#0;`) decode to
+    /// U+FFFD REPLACEMENT CHARACTER (`�`).
+    /// This is a string, not a `char`, because some named references (such
+    /// as `&NotEqualTilde;`) decode to two characters.
+    pub value: String,
+}
+
+/// Turn events into character references.
+pub fn compile(events: &[Event], bytes: &[u8]) -> Vec<CharacterReference> {
+    let mut references = vec![];
+    let mut marker = b'&';
+    let mut start = None;
+    let mut value = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match (&event.kind, &event.name) {
+            (Kind::Enter, Name::CharacterReference) => {
+                marker = b'&';
+                start = Some(index);
+            }
+            (Kind::Exit, Name::CharacterReferenceMarkerNumeric) => marker = b'#',
+            (Kind::Exit, Name::CharacterReferenceMarkerHexadecimal) => marker = b'x',
+            (Kind::Exit, Name::CharacterReferenceValue) => {
+                let slice =
+                    Slice::from_position(bytes, &SlicePosition::from_exit_event(events, index));
+                value = decode_character_reference(slice.as_str(), marker, true);
+            }
+            (Kind::Exit, Name::CharacterReference) => {
+                if let (Some(start), Some(value)) = (start.take(), value.take()) {
+                    references.push(CharacterReference {
+                        kind: match marker {
+                            b'#' => CharacterReferenceKind::Decimal,
+                            b'x' => CharacterReferenceKind::Hexadecimal,
+                            _ => CharacterReferenceKind::Named,
+                        },
+                        position: Position {
+                            start: point(&events[start]),
+                            end: point(event),
+                        },
+                        source: Slice::from_indices(
+                            bytes,
+                            events[start].point.index,
+                            event.point.index,
+                        )
+                        .as_str()
+                        .into(),
+                        value,
+                    });
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    references
+}
+
+/// Create a point from an event.
+fn point(event: &Event) -> Point {
+    Point::new(event.point.line, event.point.column, event.point.index)
+}
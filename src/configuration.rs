@@ -1,8 +1,17 @@
+use crate::block_ranges::BlockKind;
+use crate::event::Event;
+#[cfg(feature = "trace")]
+use crate::event::Point;
+#[cfg(feature = "trace")]
+use crate::state::Name as StateName;
 use crate::util::{
+    constant::HARD_BREAK_PREFIX_SIZE_MIN,
     line_ending::LineEnding,
     mdx::{EsmParse as MdxEsmParse, ExpressionParse as MdxExpressionParse},
 };
-use alloc::{boxed::Box, fmt, string::String};
+use alloc::{
+    boxed::Box, collections::BTreeMap, fmt, format, string::String, sync::Arc, vec, vec::Vec,
+};
 
 /// Control which constructs are enabled.
 ///
@@ -32,6 +41,15 @@ use alloc::{boxed::Box, fmt, string::String};
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Constructs {
+    /// Abbreviation.
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// > | a HTML b
+    ///       ^^^^
+    /// ```
+    pub abbreviation: bool,
     /// Attention.
     ///
     /// ```markdown
@@ -39,13 +57,43 @@ pub struct Constructs {
     ///       ^^^   ^^^^^
     /// ```
     pub attention: bool,
+    /// Attributes.
+    ///
+    /// ```markdown
+    /// > | # a {#b .c}
+    ///         ^^^^^^^
+    /// > | d *e*{.f}
+    ///          ^^^^
+    /// ```
+    pub attributes: bool,
     /// Autolink.
     ///
     /// ```markdown
     /// > | a <https://example.com> b <user@example.org>.
     ///       ^^^^^^^^^^^^^^^^^^^^^   ^^^^^^^^^^^^^^^^^^
     /// ```
+    ///
+    /// This turns both forms on or off; see `autolink_email` and
+    /// `autolink_protocol` to turn on one of them.
     pub autolink: bool,
+    /// Autolink: email form.
+    ///
+    /// Only used when `autolink` is on.
+    ///
+    /// ```markdown
+    /// > | a <user@example.org> b
+    ///       ^^^^^^^^^^^^^^^^^^
+    /// ```
+    pub autolink_email: bool,
+    /// Autolink: protocol (URL) form.
+    ///
+    /// Only used when `autolink` is on.
+    ///
+    /// ```markdown
+    /// > | a <https://example.com> b
+    ///       ^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    pub autolink_protocol: bool,
     /// Block quote.
     ///
     /// ```markdown
@@ -92,6 +140,17 @@ pub struct Constructs {
     ///       ^^^
     /// ```
     pub code_text: bool,
+    /// Language hint in code (text).
+    ///
+    /// Turns a known language name followed by a colon, at the very start of
+    /// code (text), into a `language-*` class.
+    /// Requires `code_text`.
+    ///
+    /// ```markdown
+    /// > | a `rust:b` c
+    ///        ^^^^^
+    /// ```
+    pub code_span_lang: bool,
     /// Definition.
     ///
     /// ```markdown
@@ -99,6 +158,15 @@ pub struct Constructs {
     ///     ^^^^^^^^^^
     /// ```
     pub definition: bool,
+    /// Definition list.
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | : b
+    ///     ^^^
+    /// ```
+    pub definition_list: bool,
     /// Frontmatter.
     ///
     /// ````markdown
@@ -336,19 +404,30 @@ impl Default for Constructs {
     /// works.
     /// Most markdown parsers try to follow it.
     ///
+    /// Exactly the constructs of `CommonMark` 0.30 are on: no GFM, math,
+    /// frontmatter, or MDX.
+    /// New extensions are always added turned off, so that the default
+    /// stays strict `CommonMark`.
+    ///
     /// For more information, see the `CommonMark` specification:
     /// <https://spec.commonmark.org>.
     fn default() -> Self {
         Self {
+            abbreviation: false,
             attention: true,
+            attributes: false,
             autolink: true,
+            autolink_email: true,
+            autolink_protocol: true,
             block_quote: true,
             character_escape: true,
             character_reference: true,
             code_indented: true,
             code_fenced: true,
             code_text: true,
+            code_span_lang: false,
             definition: true,
+            definition_list: false,
             frontmatter: false,
             gfm_autolink_literal: false,
             gfm_label_start_footnote: false,
@@ -432,6 +511,95 @@ impl Constructs {
             ..Self::default()
         }
     }
+
+    /// Turn a construct on or off by its name.
+    ///
+    /// Names are the names of the fields, such as `gfm_table` or
+    /// `math_flow`.
+    /// This is useful for CLIs that take names of constructs as flags.
+    ///
+    /// Setting `autolink` also sets `autolink_email` and `autolink_protocol`.
+    ///
+    /// ## Errors
+    ///
+    /// There are errors for unknown names.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::Constructs;
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let mut constructs = Constructs::default();
+    /// constructs.set("gfm_table", true)?;
+    /// constructs.set("code_indented", false)?;
+    ///
+    /// assert!(constructs.gfm_table);
+    /// assert!(!constructs.code_indented);
+    /// assert_eq!(
+    ///     constructs.set("tables", true),
+    ///     Err("Unknown construct `tables`, expected a field name of `Constructs`, such as `gfm_table`".into())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set(&mut self, name: &str, value: bool) -> Result<(), String> {
+        let field = match name {
+            "abbreviation" => &mut self.abbreviation,
+            "attention" => &mut self.attention,
+            "attributes" => &mut self.attributes,
+            "autolink" => {
+                self.autolink = value;
+                self.autolink_email = value;
+                &mut self.autolink_protocol
+            }
+            "autolink_email" => &mut self.autolink_email,
+            "autolink_protocol" => &mut self.autolink_protocol,
+            "block_quote" => &mut self.block_quote,
+            "character_escape" => &mut self.character_escape,
+            "character_reference" => &mut self.character_reference,
+            "code_indented" => &mut self.code_indented,
+            "code_fenced" => &mut self.code_fenced,
+            "code_text" => &mut self.code_text,
+            "code_span_lang" => &mut self.code_span_lang,
+            "definition" => &mut self.definition,
+            "definition_list" => &mut self.definition_list,
+            "frontmatter" => &mut self.frontmatter,
+            "gfm_autolink_literal" => &mut self.gfm_autolink_literal,
+            "gfm_footnote_definition" => &mut self.gfm_footnote_definition,
+            "gfm_label_start_footnote" => &mut self.gfm_label_start_footnote,
+            "gfm_strikethrough" => &mut self.gfm_strikethrough,
+            "gfm_table" => &mut self.gfm_table,
+            "gfm_task_list_item" => &mut self.gfm_task_list_item,
+            "hard_break_escape" => &mut self.hard_break_escape,
+            "hard_break_trailing" => &mut self.hard_break_trailing,
+            "heading_atx" => &mut self.heading_atx,
+            "heading_setext" => &mut self.heading_setext,
+            "html_flow" => &mut self.html_flow,
+            "html_text" => &mut self.html_text,
+            "label_start_image" => &mut self.label_start_image,
+            "label_start_link" => &mut self.label_start_link,
+            "label_end" => &mut self.label_end,
+            "list_item" => &mut self.list_item,
+            "math_flow" => &mut self.math_flow,
+            "math_text" => &mut self.math_text,
+            "mdx_esm" => &mut self.mdx_esm,
+            "mdx_expression_flow" => &mut self.mdx_expression_flow,
+            "mdx_expression_text" => &mut self.mdx_expression_text,
+            "mdx_jsx_flow" => &mut self.mdx_jsx_flow,
+            "mdx_jsx_text" => &mut self.mdx_jsx_text,
+            "thematic_break" => &mut self.thematic_break,
+            _ => {
+                return Err(format!(
+                    "Unknown construct `{}`, expected a field name of `Constructs`, such as `gfm_table`",
+                    name
+                ))
+            }
+        };
+
+        *field = value;
+        Ok(())
+    }
 }
 
 /// Configuration that describes how to compile to HTML.
@@ -466,8 +634,9 @@ impl Constructs {
 /// # }
 /// ```
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone)]
 pub struct CompileOptions {
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
     /// Whether to allow (dangerous) HTML.
     ///
     /// The default is `false`, which still parses the HTML according to
@@ -831,6 +1000,52 @@ pub struct CompileOptions {
     /// ```
     pub gfm_footnote_clobber_prefix: Option<String>,
 
+    /// Markup to use for footnote calls (references).
+    ///
+    /// The default is the markup that GitHub uses: a link in a `sup`
+    /// element, without a class.
+    /// Pass a different tag name (or `None`, to not wrap the link) and class
+    /// to use something else.
+    /// The `href` and `id` of the link are the same either way, so they
+    /// match the footnote definitions and their backreferences.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, GfmFootnoteRefTemplate, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // A link in a `sup` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_ref_template` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_ref_template: GfmFootnoteRefTemplate {
+    ///                 tag_name: None,
+    ///                 class: Some("footnote-ref".into()),
+    ///               },
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\" class=\"footnote-ref\">1</a></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_ref_template: GfmFootnoteRefTemplate,
+
     /// Whether to support the GFM tagfilter.
     ///
     /// This option does nothing if `allow_dangerous_html` is not turned on.
@@ -886,240 +1101,2198 @@ pub struct CompileOptions {
     /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
     /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
     pub gfm_tagfilter: bool,
-}
 
-impl CompileOptions {
-    /// GFM.
+    /// Whether to remove trailing whitespace from lines in the output.
     ///
-    /// GFM stands for **GitHub flavored markdown**.
-    /// On the compilation side, GFM turns on the GFM tag filter.
-    /// The tagfilter is useless, but it’s included here for consistency, and
-    /// this method exists for parity to parse options.
-    ///
-    /// For more information, see the GFM specification:
-    /// <https://github.github.com/gfm/>.
-    pub fn gfm() -> Self {
-        Self {
-            gfm_tagfilter: true,
-            ..Self::default()
-        }
-    }
-}
-
-/// Configuration that describes how to parse from markdown.
-///
-/// You can use this:
-///
-/// *   To control what markdown constructs are turned on and off
-/// *   To control some of those constructs
-/// *   To add support for certain programming languages when parsing MDX
-///
-/// In most cases, you will want to use the default trait or `gfm` method.
-///
-/// ## Examples
-///
-/// ```
-/// use markdown::ParseOptions;
-/// # fn main() {
-///
-/// // Use the default trait to parse markdown according to `CommonMark`:
-/// let commonmark = ParseOptions::default();
-///
-/// // Use the `gfm` method to parse markdown according to GFM:
-/// let gfm = ParseOptions::gfm();
-/// # }
-/// ```
-#[allow(clippy::struct_excessive_bools)]
-pub struct ParseOptions {
-    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
-    /// Which constructs to enable and disable.
+    /// The default is `false`, which keeps spaces and tabs that occur right
+    /// before line endings, for example from character references or
+    /// dangerous HTML.
+    /// Pass `true` for HTML that is nicer to diff.
     ///
-    /// The default is to follow `CommonMark`.
+    /// Code (indented, fenced) and math (flow) are never changed, nor is HTML
+    /// when `allow_dangerous_html` is on, as it could include a `<pre>`.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` follows CommonMark by default:
+    /// // `markdown-rs` keeps trailing whitespace by default:
     /// assert_eq!(
-    ///     to_html("    indented code?"),
-    ///     "<pre><code>indented code?\n</code></pre>"
+    ///     to_html("a&#32;\nb"),
+    ///     "<p>a \nb</p>"
     /// );
     ///
-    /// // Pass `constructs` to choose what to enable and disable:
+    /// // Pass `trim_trailing_whitespace: true` to remove it:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "    indented code?",
+    ///         "a&#32;\nb",
     ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 code_indented: false,
-    ///                 ..Constructs::default()
-    ///               },
-    ///               ..ParseOptions::default()
+    ///             compile: CompileOptions {
+    ///               trim_trailing_whitespace: true,
+    ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>indented code?</p>"
+    ///     "<p>a\nb</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub constructs: Constructs,
+    pub trim_trailing_whitespace: bool,
 
-    /// Whether to support GFM strikethrough with a single tilde
+    /// Whether to remove a line ending from the start and end of the output.
     ///
-    /// This option does nothing if `gfm_strikethrough` is not turned on in
-    /// `constructs`.
-    /// This option does not affect strikethrough with double tildes.
+    /// The default is `false`, which keeps the line ending that follows the
+    /// last block when there is one in the input.
+    /// Pass `true` to get snippets that can be concatenated or embedded
+    /// without extra whitespace.
     ///
-    /// The default is `true`, which follows how markdown on `github.com`
-    /// works, as strikethrough with single tildes is supported.
-    /// Pass `false`, to follow the GFM spec more strictly, by not allowing
-    /// strikethrough with single tildes.
+    /// Only a single line ending is removed from either side, so the content
+    /// of code (indented, fenced) and math (flow) is kept.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` supports single tildes by default:
+    /// // `markdown-rs` keeps the final line ending by default:
+    /// assert_eq!(
+    ///     to_html("a\n"),
+    ///     "<p>a</p>\n"
+    /// );
+    ///
+    /// // Pass `trim_output: true` to remove it:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "~a~",
+    ///         "a\n",
     ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
-    ///               ..ParseOptions::default()
+    ///             compile: CompileOptions {
+    ///               trim_output: true,
+    ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><del>a</del></p>"
+    ///     "<p>a</p>"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub trim_output: bool,
+
+    /// Function to get extra attributes for images.
     ///
-    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// The default is `None`, which adds no extra attributes.
+    /// Pass a function to add attributes such as `loading="lazy"` or
+    /// dimensions to each image.
+    ///
+    /// The function receives info on the image (see [`ImageInfo`][]), and
+    /// returns a list of attribute names and values.
+    /// Values are encoded, names are not: make sure they are valid attribute
+    /// names.
+    /// The `src`, `alt`, and `title` attributes cannot be overwritten: when
+    /// returned, they are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// use std::sync::Arc;
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no extra attributes by default:
+    /// assert_eq!(
+    ///     to_html("![a](b.png)"),
+    ///     "<p><img src=\"b.png\" alt=\"a\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_attributes` to add some:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "~a~",
+    ///         "![a](b.png)",
     ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
-    ///               gfm_strikethrough_single_tilde: false,
-    ///               ..ParseOptions::default()
+    ///             compile: CompileOptions {
+    ///               image_attributes: Some(Arc::new(|_image| {
+    ///                   vec![("loading".into(), "lazy".into())]
+    ///               })),
+    ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>~a~</p>"
+    ///     "<p><img src=\"b.png\" alt=\"a\" loading=\"lazy\" /></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_strikethrough_single_tilde: bool,
+    pub image_attributes: Option<Arc<ImageAttributes>>,
 
-    /// Whether to support math (text) with a single dollar
-    ///
-    /// This option does nothing if `math_text` is not turned on in
-    /// `constructs`.
-    /// This option does not affect math (text) with two or more dollars.
+    /// Context the resulting HTML will be placed in.
     ///
-    /// The default is `true`, which is more close to how code (text) and
-    /// Pandoc work, as it allows math with a single dollar to form.
-    /// However, single dollars can interfere with “normal” dollars in text.
-    /// Pass `false`, to only allow math (text) to form when two or more
-    /// dollars are used.
+    /// The default is `EscapeContext::Content`, which escapes text as needed
+    /// for HTML content.
+    /// Pass `EscapeContext::Attribute` to also escape apostrophes (`'`) in
+    /// text, for when text in the resulting HTML ends up in attribute values.
+    /// Quotes (`"`) in text are always escaped.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, EscapeContext, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` supports single dollars by default:
+    /// // `markdown-rs` escapes for content by default:
+    /// assert_eq!(
+    ///     to_html("a \"b\" 'c'"),
+    ///     "<p>a &quot;b&quot; 'c'</p>"
+    /// );
+    ///
+    /// // Pass `escape_context: EscapeContext::Attribute` to escape for attributes:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "$a$",
+    ///         "a \"b\" 'c'",
     ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 math_text: true,
-    ///                 ..Constructs::default()
-    ///               },
-    ///               ..ParseOptions::default()
+    ///             compile: CompileOptions {
+    ///               escape_context: EscapeContext::Attribute,
+    ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><code class=\"language-math math-inline\">a</code></p>"
+    ///     "<p>a &quot;b&quot; &#x27;c&#x27;</p>"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub escape_context: EscapeContext,
+
+    /// Whether to escape greater than signs (`>`) in text.
     ///
-    /// // Pass `math_text_single_dollar: false` to turn that off:
+    /// The default is `true`, which turns `>` into `&gt;` in text, like
+    /// `CommonMark` does.
+    /// Pass `false` to keep `>` as is in text, which is safe in HTML content
+    /// and matches what some other tools do.
+    /// Less than signs (`<`) and ampersands (`&`) are always escaped, and
+    /// `>` is always escaped in code.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` escapes `>` by default:
+    /// assert_eq!(
+    ///     to_html("a > b"),
+    ///     "<p>a &gt; b</p>"
+    /// );
+    ///
+    /// // Pass `escape_gt: false` to not escape it:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "$a$",
+    ///         "a > b",
     ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 math_text: true,
-    ///                 ..Constructs::default()
-    ///               },
-    ///               math_text_single_dollar: false,
-    ///               ..ParseOptions::default()
+    ///             compile: CompileOptions {
+    ///               escape_gt: false,
+    ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>$a$</p>"
+    ///     "<p>a > b</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub math_text_single_dollar: bool,
+    pub escape_gt: bool,
 
-    /// Function to parse expressions with.
-    ///
-    /// This function can be used to add support for arbitrary programming
-    /// languages within expressions.
+    /// Whether to wrap each line of code in an element.
     ///
-    /// It only makes sense to pass this when compiling to a syntax tree
-    /// with [`to_mdast()`][crate::to_mdast()].
+    /// The default is `false`, which does not wrap lines.
+    /// Pass `true` to wrap each line of code (indented, fenced) in a
+    /// `<span class="line">`, which can be styled, for example to show line
+    /// numbers with CSS counters.
+    /// Line endings are kept after each element.
     ///
-    /// For an example that adds support for JavaScript with SWC, see
-    /// `tests/test_utils/mod.rs`.
-    pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
-
-    /// Function to parse ESM with.
+    /// ## Examples
     ///
-    /// This function can be used to add support for arbitrary programming
-    /// languages within ESM blocks, however, the keywords (`export`,
-    /// `import`) are currently hardcoded JavaScript-specific.
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not wrap lines by default:
+    /// assert_eq!(
+    ///     to_html("```js\na\nb\n```"),
+    ///     "<pre><code class=\"language-js\">a\nb\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `code_line_numbers: true` to wrap them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```js\na\nb\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               code_line_numbers: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-js\"><span class=\"line\">a</span>\n<span class=\"line\">b</span>\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_line_numbers: bool,
+
+    /// Whether to turn comma-separated items in the info string of fenced
+    /// code into `data-*` attributes.
+    ///
+    /// The default is `false`, which uses the whole info string (the first
+    /// word after the opening fence) as the language.
+    /// Pass `true` to split the info string at commas (`,`): the first item
+    /// is the language, which becomes the class as normal, and each further
+    /// item becomes an empty `data-*` attribute on the `<code>` element.
+    /// Empty items and items with characters other than ASCII alphanumerics,
+    /// dashes (`-`), and underscores (`_`) are ignored.
+    /// The meta (after whitespace, such as `title="a"` in
+    /// `` ```rust,no_run title="a" ``) is still ignored.
+    ///
+    /// Code highlighters and code block renderers get the language without
+    /// the further items.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses the whole info string as the language by default:
+    /// assert_eq!(
+    ///     to_html("```rust,no_run\na\n```"),
+    ///     "<pre><code class=\"language-rust,no_run\">a\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `code_info_as_data_attrs: true` to split it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```rust,no_run\na\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               code_info_as_data_attrs: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-rust\" data-no_run=\"\">a\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_info_as_data_attrs: bool,
+
+    /// Whether to keep the closing sequence of ATX (hash) headings.
+    ///
+    /// The default is `false`, which drops the optional closing sequence of
+    /// hashes (`#`), as in `CommonMark`.
+    /// Pass `true` to keep it, and the whitespace before it, in the content of
+    /// the heading, which is useful for formatters that round-trip.
+    /// Hashes that are not a closing sequence (such as in `# a#`, where there
+    /// is no whitespace before them) are always kept.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` drops the closing sequence by default:
+    /// assert_eq!(to_html("# a ##"), "<h1>a</h1>");
+    ///
+    /// // Pass `keep_atx_trailing_hashes: true` to keep it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a ##",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               keep_atx_trailing_hashes: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1>a ##</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub keep_atx_trailing_hashes: bool,
+
+    /// Whether to keep empty paragraphs for extra blank lines.
+    ///
+    /// The default is `false`, which drops blank lines: any number of them
+    /// between two blocks is the same as one.
+    /// Pass `true` to emit an empty paragraph (`<p></p>`) for each blank line
+    /// after the first, which is useful in editors that let users place their
+    /// cursor on empty lines.
+    /// Lines with only whitespace are blank lines too.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` drops extra blank lines by default:
+    /// assert_eq!(
+    ///     to_html("a\n\n\n\nb"),
+    ///     "<p>a</p>\n<p>b</p>"
+    /// );
+    ///
+    /// // Pass `preserve_empty_paragraphs: true` to keep them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\n\n\n\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               preserve_empty_paragraphs: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a</p>\n<p></p>\n<p></p>\n<p>b</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub preserve_empty_paragraphs: bool,
+
+    /// Protocols to allow in links.
+    ///
+    /// The default is `None`, which allows `http`, `https`, `irc`, `ircs`,
+    /// `mailto`, and `xmpp`.
+    /// Pass a list of (lowercase) protocols to allow only those in links
+    /// (`a[href]`); links with other protocols get an empty destination.
+    /// Relative links are always allowed.
+    ///
+    /// This is ignored when
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// is on.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` allows several safe protocols by default:
+    /// assert_eq!(
+    ///     to_html("<irc://a.b>"),
+    ///     "<p><a href=\"irc://a.b\">irc://a.b</a></p>"
+    /// );
+    ///
+    /// // Pass `link_protocols` to allow fewer:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<irc://a.b>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_protocols: Some(vec!["http".into(), "https".into()]),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"\">irc://a.b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub link_protocols: Option<Vec<String>>,
+
+    /// Value to use for the `rel` attribute of external links.
+    ///
+    /// The default is `None`, which does not add `rel` attributes.
+    /// Pass a value, such as `nofollow noopener`, to add it to links with a
+    /// protocol (such as `https:` or `mailto:`).
+    /// Relative links are not external and get no `rel`.
+    /// When a link has a `rel` from [`attributes`][Constructs::attributes]
+    /// (such as `[a](b){rel=me}`), the tokens are merged into it.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add `rel` by default:
+    /// assert_eq!(
+    ///     to_html("[a](https://b.c) [d](/e)"),
+    ///     "<p><a href=\"https://b.c\">a</a> <a href=\"/e\">d</a></p>"
+    /// );
+    ///
+    /// // Pass `link_rel` to add it to external links:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://b.c) [d](/e)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_rel: Some("nofollow".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://b.c\" rel=\"nofollow\">a</a> <a href=\"/e\">d</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub link_rel: Option<String>,
+
+    /// Whether to compile paragraphs with only an image as figures.
+    ///
+    /// The default is `false`, which compiles such paragraphs as `<p>`.
+    /// Pass `true` to compile them as `<figure>`, with the title of the image,
+    /// if any, as a `<figcaption>`.
+    /// Paragraphs with other content next to the image are not figures.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` compiles images in paragraphs by default:
+    /// assert_eq!(
+    ///     to_html("![a](b.png \"c\")"),
+    ///     "<p><img src=\"b.png\" alt=\"a\" title=\"c\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_figure: true` to compile figures instead:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](b.png \"c\")",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_figure: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<figure><img src=\"b.png\" alt=\"a\" title=\"c\" /><figcaption>c</figcaption></figure>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_figure: bool,
+
+    /// Whether to collapse adjacent thematic breaks into one.
+    ///
+    /// The default is `false`, which compiles each thematic break.
+    /// Pass `true` to compile several thematic breaks, separated by nothing
+    /// but blank lines, as one `<hr />`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` compiles each thematic break by default:
+    /// assert_eq!(
+    ///     to_html("a\n\n***\n***\n\n***\n\nb"),
+    ///     "<p>a</p>\n<hr />\n<hr />\n<hr />\n<p>b</p>"
+    /// );
+    ///
+    /// // Pass `collapse_thematic_breaks: true` to collapse them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\n\n***\n***\n\n***\n\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               collapse_thematic_breaks: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a</p>\n<hr />\n<p>b</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub collapse_thematic_breaks: bool,
+
+    /// Whether to add `id`s to headings.
+    ///
+    /// The default is `false`, which does not add `id`s.
+    /// Pass `true` to add an `id` to each heading, made from its text in the
+    /// same way as GitHub does it: the text is lowercased, punctuation is
+    /// removed, spaces become dashes, and when a value was already used, a
+    /// dash and a number is added (`a`, `a-1`, `a-2`).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add `id`s to headings by default:
+    /// assert_eq!(
+    ///     to_html("# Hello, *world*!\n\n## Hello, world"),
+    ///     "<h1>Hello, <em>world</em>!</h1>\n<h2>Hello, world</h2>"
+    /// );
+    ///
+    /// // Pass `heading_ids: true` to add them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello, *world*!\n\n## Hello, world",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello-world\">Hello, <em>world</em>!</h1>\n<h2 id=\"hello-world-1\">Hello, world</h2>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_ids: bool,
+
+    /// Whether to add permalinks to headings.
+    ///
+    /// The default is `false`, which does not add permalinks.
+    /// Pass `true`, together with [`heading_ids`][CompileOptions::heading_ids],
+    /// to add an anchor at the start of each heading, linking to its `id`.
+    /// Use [`heading_anchor`][CompileOptions::heading_anchor] to configure
+    /// the anchor.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // Pass `heading_anchor_links: true` to add permalinks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello, world!",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               heading_anchor_links: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello-world\"><a class=\"anchor\" aria-hidden=\"true\" href=\"#hello-world\">#</a>Hello, world!</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_anchor_links: bool,
+
+    /// Anchor to use for permalinks in headings.
+    ///
+    /// Only used when
+    /// [`heading_anchor_links`][CompileOptions::heading_anchor_links] is on.
+    /// The default is a `#` with the class `anchor`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, HeadingAnchor, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // Use a different anchor:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello, world!",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               heading_anchor_links: true,
+    ///               heading_anchor: HeadingAnchor {
+    ///                 class: "permalink".into(),
+    ///                 content: "<span class=\"icon\"></span>".into(),
+    ///               },
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello-world\"><a class=\"permalink\" aria-hidden=\"true\" href=\"#hello-world\"><span class=\"icon\"></span></a>Hello, world!</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_anchor: HeadingAnchor,
+
+    /// Function to get HTML to add before top-level blocks.
+    ///
+    /// The default is `None`, which adds nothing.
+    /// Pass a function to add things such as ads or annotations between
+    /// blocks.
+    ///
+    /// The function is called before each top-level block, in document
+    /// order, with the kind of block (see [`BlockKind`][]) and its index (as
+    /// in, the index in what [`block_ranges`][crate::block_ranges()]
+    /// returns).
+    /// When it returns `Some(html)`, that HTML is added before the block.
+    ///
+    /// > 👉 **Note**: the returned HTML is not encoded or sanitized.
+    /// > It is added as is.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, BlockKind, CompileOptions, Options};
+    /// use std::sync::Arc;
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds nothing between blocks by default:
+    /// assert_eq!(
+    ///     to_html("# a\n\nb"),
+    ///     "<h1>a</h1>\n<p>b</p>"
+    /// );
+    ///
+    /// // Pass `on_block` to add things:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a\n\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               on_block: Some(Arc::new(|kind, index| {
+    ///                   if kind == BlockKind::Paragraph {
+    ///                       Some(format!("<!-- {} -->", index))
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1>a</h1>\n<!-- 1 -->\n<p>b</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub on_block: Option<Arc<OnBlock>>,
+
+    /// Function to render code (fenced) with.
+    ///
+    /// This is useful for code that is not shown as code, such as diagrams
+    /// (for example `mermaid`), which are rendered by something else.
+    /// The function gets the language (the first word of the info string,
+    /// or an empty string if there is none) and the content of the code
+    /// (without the final line ending), both not encoded.
+    /// It returns the HTML to use instead of `<pre><code>`, or `None` to
+    /// compile the code as normal.
+    ///
+    /// > 👉 **Note**: the returned HTML is not encoded or sanitized.
+    /// > It is added as is.
+    ///
+    /// The default is `None`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// use std::sync::Arc;
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` compiles code to `<pre><code>` by default:
+    /// assert_eq!(
+    ///     to_html("```mermaid\na --> b\n```"),
+    ///     "<pre><code class=\"language-mermaid\">a --&gt; b\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `code_block_renderer` to render some code differently:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```mermaid\na --> b\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               code_block_renderer: Some(Arc::new(|language, content| {
+    ///                   if language == "mermaid" {
+    ///                       Some(format!("<div class=\"mermaid\">{}</div>", content))
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<div class=\"mermaid\">a --> b</div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_block_renderer: Option<Arc<CodeBlockRenderer>>,
+
+    /// Function to highlight code (fenced) with.
+    ///
+    /// This is useful for syntax highlighting on the server, for example
+    /// with `syntect`.
+    /// The function gets the language (the first word of the info string,
+    /// or an empty string if there is none) and the content of the code
+    /// (without the final line ending), both not encoded.
+    /// It returns the HTML to use inside `<code>`, or `None` to encode the
+    /// content as normal.
+    /// Unlike [`code_block_renderer`][CompileOptions::code_block_renderer],
+    /// the `<pre><code>` around the content (and its `class`) is kept.
+    ///
+    /// > 👉 **Important**: the returned HTML is trusted: it is not encoded or
+    /// > sanitized, but added as is.
+    /// > Make sure that the highlighter encodes the content it gets.
+    ///
+    /// The default is `None`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// use std::sync::Arc;
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` encodes code by default:
+    /// assert_eq!(
+    ///     to_html("```rs\nfn a() {}\n```"),
+    ///     "<pre><code class=\"language-rs\">fn a() {}\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `code_highlighter` to highlight some code:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```rs\nfn a() {}\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               code_highlighter: Some(Arc::new(|language, content| {
+    ///                   if language == "rs" {
+    ///                       Some(content.replace("fn", "<b>fn</b>"))
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-rs\"><b>fn</b> a() {}\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_highlighter: Option<Arc<CodeHighlighter>>,
+
+    /// How to turn line endings between CJK characters into HTML.
+    ///
+    /// The default is [`CjkSoftBreak::LineEnding`][], which keeps line
+    /// endings as they are, like for other text.
+    /// Browsers show line endings as spaces, but Chinese, Japanese, and
+    /// Korean (when written without spaces) text does not use spaces between
+    /// characters.
+    /// Pass a different value to turn line endings (soft breaks) that are
+    /// directly between two CJK characters into a `<wbr />` or nothing.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CjkSoftBreak, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps line endings by default:
+    /// assert_eq!(
+    ///     to_html("中文\n文本"),
+    ///     "<p>中文\n文本</p>"
+    /// );
+    ///
+    /// // Pass `cjk_soft_break` to turn them into something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "中文\n文本",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               cjk_soft_break: CjkSoftBreak::Wbr,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>中文<wbr />文本</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub cjk_soft_break: CjkSoftBreak,
+
+    /// Whether to turn line endings in text (soft breaks) into hard breaks.
+    ///
+    /// The default is `false`, which keeps line endings as they are, so that
+    /// browsers show them as spaces.
+    /// Pass `true` to add a `<br />` before each line ending in a paragraph
+    /// or heading, as is done on GitHub in comments and issues.
+    /// Line endings in code, HTML, titles, and alt text are not changed.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps line endings by default:
+    /// assert_eq!(
+    ///     to_html("a\nb"),
+    ///     "<p>a\nb</p>"
+    /// );
+    ///
+    /// // Pass `soft_break_as_hard_break` to turn them into hard breaks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               soft_break_as_hard_break: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br />\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub soft_break_as_hard_break: bool,
+
+    /// Whether to add line break opportunities to the text of autolinks.
+    ///
+    /// The default is `false`, which keeps the text of autolinks as it is.
+    /// Pass `true` to add `<wbr />` after each `/`, `?`, `&`, and `.` in the
+    /// visible text of autolinks and GFM autolink literals, so that browsers
+    /// can wrap long URLs there.
+    /// The `href` is not changed.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps the text of autolinks by default:
+    /// assert_eq!(
+    ///     to_html("<https://a.b/c?d>"),
+    ///     "<p><a href=\"https://a.b/c?d\">https://a.b/c?d</a></p>"
+    /// );
+    ///
+    /// // Pass `autolink_break_opportunities` to add break opportunities:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<https://a.b/c?d>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               autolink_break_opportunities: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://a.b/c?d\">https:/<wbr />/<wbr />a.<wbr />b/<wbr />c?<wbr />d</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub autolink_break_opportunities: bool,
+
+    /// Function to encode the values of attributes with.
+    ///
+    /// This is useful in environments with unusual needs, such as also
+    /// escaping backticks.
+    /// The function gets a value that is not encoded, and must return a
+    /// value that is safe inside a double-quoted attribute.
+    /// It is used for `href` and `src` (after sanitizing, when they are
+    /// kept; with unsafe characters percent-encoded), `title`, `rel`, and
+    /// the values from [`image_attributes`][CompileOptions::image_attributes].
+    /// Text content (including the `alt` of images and captions of figures)
+    /// is not affected.
+    ///
+    /// The default is `None`, which encodes `&`, `"`, `<`, and `>` as
+    /// character references.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// use std::sync::Arc;
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not encode backticks by default:
+    /// assert_eq!(
+    ///     to_html("[a](b \"`c`\")"),
+    ///     "<p><a href=\"b\" title=\"`c`\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `attribute_escaper` to encode more:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](b \"`c`\")",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               attribute_escaper: Some(Arc::new(|value| {
+    ///                   value
+    ///                       .replace('&', "&amp;")
+    ///                       .replace('"', "&quot;")
+    ///                       .replace('<', "&lt;")
+    ///                       .replace('>', "&gt;")
+    ///                       .replace('`', "&#x60;")
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"b\" title=\"&#x60;c&#x60;\">a</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub attribute_escaper: Option<Arc<AttributeEscaper>>,
+
+    /// Whether to remove (dangerous) HTML.
+    ///
+    /// The default is `false`, which shows HTML as text (unless
+    /// [`allow_dangerous_html`][CompileOptions::allow_dangerous_html] is on).
+    ///
+    /// Pass `true` to instead replace each piece of HTML (a whole HTML
+    /// (flow) block, or a single HTML (text) tag, comment, etc.) with the
+    /// comment `<!-- raw html removed -->`.
+    /// This is useful when embedding untrusted content where even escaped
+    /// HTML is not wanted.
+    /// Has no effect when `allow_dangerous_html` is on.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` shows HTML as text by default:
+    /// assert_eq!(
+    ///     to_html("Hi, <i>venus</i>!"),
+    ///     "<p>Hi, &lt;i&gt;venus&lt;/i&gt;!</p>"
+    /// );
+    ///
+    /// // Pass `remove_dangerous_html` to remove it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "Hi, <i>venus</i>!",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               remove_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>Hi, <!-- raw html removed -->venus<!-- raw html removed -->!</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub remove_dangerous_html: bool,
+
+    /// Ranks to use for setext (underline) headings, as `[equals, dash]`.
+    ///
+    /// The default is `[1, 2]`, which turns headings with an equals (`=`)
+    /// underline into `<h1>`, and headings with a dash (`-`) underline into
+    /// `<h2>`, as in `CommonMark`.
+    /// Pass other ranks to use other elements, such as when the document is
+    /// embedded in a page that already has a main heading.
+    /// Ranks below 1 are treated as 1, and ranks above 6 as 6.
+    ///
+    /// This does not affect ATX (hash) headings.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `<h1>` and `<h2>` for setext headings by default:
+    /// assert_eq!(to_html("a\n=\n\nb\n-"), "<h1>a</h1>\n<h2>b</h2>");
+    ///
+    /// // Pass `setext_as_atx_level` to use other ranks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\n=\n\nb\n-",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               setext_as_atx_level: [2, 3],
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h2>a</h2>\n<h3>b</h3>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub setext_as_atx_level: [u8; 2],
+
+    /// Whether to expand tabs in text to spaces, and to which tab size.
+    ///
+    /// The default is `None`, which keeps tabs as they are.
+    /// Pass a size to turn each tab in text into spaces up to the next tab
+    /// stop, which is useful when the output is not shown in a `<pre>` (or
+    /// with `white-space: pre`) and tabs would otherwise collapse.
+    /// Tab stops are counted in characters from the start of the line of
+    /// text, as displayed: markdown syntax (such as `>` or `*`) before a tab
+    /// is not counted.
+    /// A size of `0` removes tabs.
+    ///
+    /// This does not affect code (flow and text) and math, which keep tabs.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps tabs by default:
+    /// assert_eq!(to_html("a\tb\n\n\tc\td"), "<p>a\tb</p>\n<pre><code>c\td\n</code></pre>");
+    ///
+    /// // Pass `expand_tabs` to turn tabs in text into spaces:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\tb\n\n\tc\td",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               expand_tabs: Some(4),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a   b</p>\n<pre><code>c\td\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub expand_tabs: Option<usize>,
+
+    /// Whether to add `rel="noopener noreferrer"` to links that open in a new
+    /// window.
+    ///
+    /// The default is `false`, which leaves links with `target="_blank"` as
+    /// they are.
+    /// Pass `true` to make sure such links have `noopener` and `noreferrer`
+    /// in their `rel`, so that the page they open cannot reach back into
+    /// this one.
+    /// Links get `target="_blank"` from [`attributes`][Constructs::attributes]
+    /// (such as `[a](b){target=_blank}`).
+    /// The tokens are merged into the `rel` of the link (such as
+    /// `[a](b){target=_blank rel=me}`) and the one from
+    /// [`link_rel`][CompileOptions::link_rel], if any: tokens that are
+    /// already there are not added again.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // Pass `auto_noopener` to protect links that open in a new window:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://b.c){target=_blank}",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 attributes: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               auto_noopener: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://b.c\" rel=\"noopener noreferrer\" target=\"_blank\">a</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub auto_noopener: bool,
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+}
+
+impl fmt::Debug for CompileOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompileOptions")
+            .field("allow_dangerous_html", &self.allow_dangerous_html)
+            .field("allow_dangerous_protocol", &self.allow_dangerous_protocol)
+            .field("default_line_ending", &self.default_line_ending)
+            .field("gfm_footnote_label", &self.gfm_footnote_label)
+            .field(
+                "gfm_footnote_label_tag_name",
+                &self.gfm_footnote_label_tag_name,
+            )
+            .field(
+                "gfm_footnote_label_attributes",
+                &self.gfm_footnote_label_attributes,
+            )
+            .field("gfm_footnote_back_label", &self.gfm_footnote_back_label)
+            .field(
+                "gfm_footnote_clobber_prefix",
+                &self.gfm_footnote_clobber_prefix,
+            )
+            .field("gfm_footnote_ref_template", &self.gfm_footnote_ref_template)
+            .field("gfm_tagfilter", &self.gfm_tagfilter)
+            .field("trim_trailing_whitespace", &self.trim_trailing_whitespace)
+            .field("trim_output", &self.trim_output)
+            .field(
+                "image_attributes",
+                &self.image_attributes.as_ref().map(|_d| "[Function]"),
+            )
+            .field("escape_context", &self.escape_context)
+            .field("escape_gt", &self.escape_gt)
+            .field("code_line_numbers", &self.code_line_numbers)
+            .field("code_info_as_data_attrs", &self.code_info_as_data_attrs)
+            .field("keep_atx_trailing_hashes", &self.keep_atx_trailing_hashes)
+            .field("preserve_empty_paragraphs", &self.preserve_empty_paragraphs)
+            .field("link_protocols", &self.link_protocols)
+            .field("link_rel", &self.link_rel)
+            .field("image_figure", &self.image_figure)
+            .field("collapse_thematic_breaks", &self.collapse_thematic_breaks)
+            .field("heading_ids", &self.heading_ids)
+            .field("heading_anchor_links", &self.heading_anchor_links)
+            .field("heading_anchor", &self.heading_anchor)
+            .field("on_block", &self.on_block.as_ref().map(|_d| "[Function]"))
+            .field(
+                "code_block_renderer",
+                &self.code_block_renderer.as_ref().map(|_d| "[Function]"),
+            )
+            .field(
+                "code_highlighter",
+                &self.code_highlighter.as_ref().map(|_d| "[Function]"),
+            )
+            .field("cjk_soft_break", &self.cjk_soft_break)
+            .field("soft_break_as_hard_break", &self.soft_break_as_hard_break)
+            .field(
+                "autolink_break_opportunities",
+                &self.autolink_break_opportunities,
+            )
+            .field(
+                "attribute_escaper",
+                &self.attribute_escaper.as_ref().map(|_d| "[Function]"),
+            )
+            .field("remove_dangerous_html", &self.remove_dangerous_html)
+            .field("setext_as_atx_level", &self.setext_as_atx_level)
+            .field("expand_tabs", &self.expand_tabs)
+            .field("auto_noopener", &self.auto_noopener)
+            .finish()
+    }
+}
+
+/// Info on an image, passed to
+/// [`image_attributes`][CompileOptions::image_attributes].
+///
+/// Values are as they occur in the HTML: they are encoded, and the source is
+/// sanitized.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageInfo {
+    /// Source (url) of the image.
+    pub src: String,
+    /// Alternative text of the image.
+    pub alt: String,
+    /// Title of the image, if any.
+    pub title: Option<String>,
+}
+
+/// Signature of a function that returns extra attributes for an image.
+///
+/// Can be passed as `image_attributes` in [`CompileOptions`][].
+pub type ImageAttributes = dyn Fn(&ImageInfo) -> Vec<(String, String)> + Send + Sync;
+
+/// Signature of a function that returns HTML to add before a top-level
+/// block.
+///
+/// Can be passed as `on_block` in [`CompileOptions`][].
+pub type OnBlock = dyn Fn(BlockKind, usize) -> Option<String> + Send + Sync;
+
+/// Signature of a function that renders code (fenced).
+///
+/// Gets the language and the content of the code, and can return HTML.
+///
+/// Can be passed as `code_block_renderer` in [`CompileOptions`][].
+pub type CodeBlockRenderer = dyn Fn(&str, &str) -> Option<String> + Send + Sync;
+
+/// Signature of a function that highlights code (fenced).
+///
+/// Gets the language and the content of the code, and can return HTML to
+/// use inside `<code>`.
+///
+/// Can be passed as `code_highlighter` in [`CompileOptions`][].
+pub type CodeHighlighter = dyn Fn(&str, &str) -> Option<String> + Send + Sync;
+
+/// Signature of a function that encodes the value of an attribute.
+///
+/// Can be passed as `attribute_escaper` in [`CompileOptions`][].
+pub type AttributeEscaper = dyn Fn(&str) -> String + Send + Sync;
+
+/// Context that the resulting HTML is placed in.
+///
+/// Can be passed as `escape_context` in [`CompileOptions`][].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EscapeContext {
+    /// HTML content, such as in the body of an element.
+    #[default]
+    Content,
+    /// Quoted HTML attribute value, such as in `title="…"` or `title='…'`.
+    Attribute,
+}
+
+/// What to turn line endings between CJK characters into.
+///
+/// Can be passed as `cjk_soft_break` in [`CompileOptions`][].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CjkSoftBreak {
+    /// Keep the line ending.
+    #[default]
+    LineEnding,
+    /// Turn the line ending into `<wbr />`, so that browsers can still wrap
+    /// lines there.
+    Wbr,
+    /// Remove the line ending.
+    Remove,
+}
+
+/// Markup of footnote calls (references).
+///
+/// Can be passed as `gfm_footnote_ref_template` in [`CompileOptions`][].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GfmFootnoteRefTemplate {
+    /// Tag name of the element around the link, such as `sup`.
+    ///
+    /// Pass `None` to not wrap the link in an element.
+    pub tag_name: Option<String>,
+    /// Class of the link.
+    pub class: Option<String>,
+}
+
+impl Default for GfmFootnoteRefTemplate {
+    /// The markup that GitHub uses: a link in a `sup`, without a class.
+    fn default() -> Self {
+        Self {
+            tag_name: Some("sup".into()),
+            class: None,
+        }
+    }
+}
+
+/// Anchor added to headings as a permalink.
+///
+/// Can be passed as `heading_anchor` in [`CompileOptions`][].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeadingAnchor {
+    /// Class of the anchor.
+    pub class: String,
+    /// Content of the anchor.
+    ///
+    /// > 👉 **Note**: this is injected as raw HTML, so it can be an icon.
+    pub content: String,
+}
+
+impl Default for HeadingAnchor {
+    /// The default anchor: a `#` with the class `anchor`.
+    fn default() -> Self {
+        Self {
+            class: "anchor".into(),
+            content: "#".into(),
+        }
+    }
+}
+
+impl Default for CompileOptions {
+    /// Safe `CommonMark` defaults.
+    ///
+    /// Dangerous HTML and protocols are not allowed, and everything that is
+    /// not described by `CommonMark` is turned off.
+    fn default() -> Self {
+        Self {
+            allow_dangerous_html: false,
+            allow_dangerous_protocol: false,
+            default_line_ending: LineEnding::default(),
+            gfm_footnote_label: None,
+            gfm_footnote_label_tag_name: None,
+            gfm_footnote_label_attributes: None,
+            gfm_footnote_back_label: None,
+            gfm_footnote_clobber_prefix: None,
+            gfm_footnote_ref_template: GfmFootnoteRefTemplate::default(),
+            gfm_tagfilter: false,
+            trim_trailing_whitespace: false,
+            trim_output: false,
+            image_attributes: None,
+            escape_context: EscapeContext::default(),
+            escape_gt: true,
+            code_line_numbers: false,
+            code_info_as_data_attrs: false,
+            keep_atx_trailing_hashes: false,
+            preserve_empty_paragraphs: false,
+            link_protocols: None,
+            link_rel: None,
+            image_figure: false,
+            collapse_thematic_breaks: false,
+            heading_ids: false,
+            heading_anchor_links: false,
+            heading_anchor: HeadingAnchor::default(),
+            on_block: None,
+            code_block_renderer: None,
+            code_highlighter: None,
+            cjk_soft_break: CjkSoftBreak::default(),
+            soft_break_as_hard_break: false,
+            autolink_break_opportunities: false,
+            attribute_escaper: None,
+            remove_dangerous_html: false,
+            setext_as_atx_level: [1, 2],
+            expand_tabs: None,
+            auto_noopener: false,
+        }
+    }
+}
+
+impl CompileOptions {
+    /// GFM.
+    ///
+    /// GFM stands for **GitHub flavored markdown**.
+    /// On the compilation side, GFM turns on the GFM tag filter.
+    /// The tagfilter is useless, but it’s included here for consistency, and
+    /// this method exists for parity to parse options.
+    ///
+    /// For more information, see the GFM specification:
+    /// <https://github.github.com/gfm/>.
+    pub fn gfm() -> Self {
+        Self {
+            gfm_tagfilter: true,
+            ..Self::default()
+        }
+    }
+
+    /// Safe.
+    ///
+    /// For content from untrusted authors, such as comments.
+    /// HTML is escaped and dangerous protocols are dropped (as by default),
+    /// links are restricted to `http`, `https`, and `mailto`, external links
+    /// get `rel="nofollow noopener"`, and the GFM tag filter is on in case
+    /// HTML is allowed later.
+    pub fn safe() -> Self {
+        Self {
+            allow_dangerous_html: false,
+            allow_dangerous_protocol: false,
+            gfm_tagfilter: true,
+            link_protocols: Some(vec!["http".into(), "https".into(), "mailto".into()]),
+            link_rel: Some("nofollow noopener".into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Configuration that describes how to parse from markdown.
+///
+/// You can use this:
+///
+/// *   To control what markdown constructs are turned on and off
+/// *   To control some of those constructs
+/// *   To add support for certain programming languages when parsing MDX
+///
+/// In most cases, you will want to use the default trait or `gfm` method.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ParseOptions;
+/// # fn main() {
+///
+/// // Use the default trait to parse markdown according to `CommonMark`:
+/// let commonmark = ParseOptions::default();
+///
+/// // Use the `gfm` method to parse markdown according to GFM:
+/// let gfm = ParseOptions::gfm();
+/// # }
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+    /// Which constructs to enable and disable.
+    ///
+    /// The default is to follow `CommonMark`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html("    indented code?"),
+    ///     "<pre><code>indented code?\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `constructs` to choose what to enable and disable:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "    indented code?",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 code_indented: false,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>indented code?</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub constructs: Constructs,
+
+    /// Whether to support GFM strikethrough with a single tilde
+    ///
+    /// This option does nothing if `gfm_strikethrough` is not turned on in
+    /// `constructs`.
+    /// This option does not affect strikethrough with double tildes.
+    ///
+    /// The default is `true`, which follows how markdown on `github.com`
+    /// works, as strikethrough with single tildes is supported.
+    /// Pass `false`, to follow the GFM spec more strictly, by not allowing
+    /// strikethrough with single tildes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports single tildes by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><del>a</del></p>"
+    /// );
+    ///
+    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_strikethrough_single_tilde: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>~a~</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_strikethrough_single_tilde: bool,
+
+    /// Whether to support math (text) with a single dollar
+    ///
+    /// This option does nothing if `math_text` is not turned on in
+    /// `constructs`.
+    /// This option does not affect math (text) with two or more dollars.
+    ///
+    /// The default is `true`, which is more close to how code (text) and
+    /// Pandoc work, as it allows math with a single dollar to form.
+    /// However, single dollars can interfere with “normal” dollars in text.
+    /// Pass `false`, to only allow math (text) to form when two or more
+    /// dollars are used.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports single dollars by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$a$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><code class=\"language-math math-inline\">a</code></p>"
+    /// );
+    ///
+    /// // Pass `math_text_single_dollar: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$a$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               math_text_single_dollar: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>$a$</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub math_text_single_dollar: bool,
+
+    /// Minimum number of trailing spaces that form a hard break.
+    ///
+    /// This option does nothing if `hard_break_trailing` is not turned on
+    /// in `constructs`.
+    ///
+    /// The default is `2`, which is what `CommonMark` defines.
+    /// Some content uses a different convention: pass a different number to
+    /// change how many spaces at the end of a line are needed to form a
+    /// hard break (trailing).
+    /// `0` is treated as `1`: a line ending without spaces is never a hard
+    /// break.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` needs two spaces by default:
+    /// assert_eq!(
+    ///     to_html("a \nb"),
+    ///     "<p>a\nb</p>"
+    /// );
+    ///
+    /// // Pass `hard_break_spaces_min` to change that:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a \nb",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               hard_break_spaces_min: 1,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br />\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub hard_break_spaces_min: usize,
+
+    /// Whether the underline of a heading (setext) must be about as wide as
+    /// its text.
+    ///
+    /// The default is `false`, which is what `CommonMark` defines: any
+    /// number of `=` or `-` forms an underline.
+    /// Pass `true` to only form a heading (setext) when the number of
+    /// markers differs by at most one from the width of the last line of
+    /// text (without surrounding whitespace, where CJK characters count as
+    /// two).
+    /// Otherwise, the line is what it would be without the text before it:
+    /// `-` can form a thematic break, and `=` continues the paragraph.
+    ///
+    /// This option does nothing if `heading_setext` is not turned on in
+    /// `constructs`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` allows underlines of any width by default:
+    /// assert_eq!(
+    ///     to_html("abc\n-"),
+    ///     "<h2>abc</h2>"
+    /// );
+    ///
+    /// // Pass `setext_underline_match_width` to require matching widths:
+    /// let options = Options {
+    ///     parse: ParseOptions {
+    ///       setext_underline_match_width: true,
+    ///       ..ParseOptions::default()
+    ///     },
+    ///     ..Options::default()
+    /// };
+    ///
+    /// assert_eq!(to_html_with_options("abc\n---", &options)?, "<h2>abc</h2>");
+    /// assert_eq!(to_html_with_options("abc\n=", &options)?, "<p>abc\n=</p>");
+    /// assert_eq!(
+    ///     to_html_with_options("abc\n---------", &options)?,
+    ///     "<p>abc</p>\n<hr />"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub setext_underline_match_width: bool,
+
+    /// Whether to continue a list when the marker of an item changes.
+    ///
+    /// The default is `false`, which follows `CommonMark`: a different bullet
+    /// (`*`, `+`, `-`) or delimiter (`.`, `)`) starts a new list.
+    /// Pass `true` to only start a new list when switching between ordered and
+    /// unordered items, as some other markdown parsers do.
+    ///
+    /// This option does nothing if `list_item` is not turned on in
+    /// `constructs`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` starts a new list when the marker changes by default:
+    /// assert_eq!(
+    ///     to_html("- a\n* b"),
+    ///     "<ul>\n<li>a</li>\n</ul>\n<ul>\n<li>b</li>\n</ul>"
+    /// );
+    ///
+    /// // Pass `loose_list_marker_change` to continue the list:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "- a\n* b",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               loose_list_marker_change: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li>a</li>\n<li>b</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub loose_list_marker_change: bool,
+
+    /// Markers that can form thematic breaks.
+    ///
+    /// The default is `vec!['*', '-', '_']`, which follows `CommonMark`.
+    /// Pass fewer markers to not form thematic breaks with the others, such
+    /// as to leave `___` alone when it is used for something else.
+    /// Other characters are ignored.
+    ///
+    /// Dashes that are not a thematic break can still form a heading
+    /// (setext) underline, and asterisks and dashes can still start list
+    /// items.
+    ///
+    /// This option does nothing if `thematic_break` is not turned on in
+    /// `constructs`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports underscores in thematic breaks by default:
+    /// assert_eq!(to_html("***\n\n___"), "<hr />\n<hr />");
+    ///
+    /// // Pass `thematic_break_markers` to allow only some markers:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "***\n\n___",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               thematic_break_markers: vec!['*', '-'],
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<hr />\n<p>___</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub thematic_break_markers: Vec<char>,
+
+    /// Maximum number of attention sequences (`*`, `_`, `~`) to match.
+    ///
+    /// The default is `None`, which matches all sequences.
+    /// Pass a number to bound the time spent on matching sequences in
+    /// pathological input (such as untrusted input with many asterisks).
+    /// Sequences past that number, per text (such as a paragraph), are
+    /// treated as literal text.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` matches all attention sequences by default:
+    /// assert_eq!(
+    ///     to_html("*a* *b*"),
+    ///     "<p><em>a</em> <em>b</em></p>"
+    /// );
+    ///
+    /// // Pass `max_attention_sequences` to limit that:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "*a* *b*",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               max_attention_sequences: Some(2),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><em>a</em> *b*</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub max_attention_sequences: Option<usize>,
+
+    /// Extra characters to treat as word characters next to attention
+    /// sequences (`*`, `_`, `~`).
+    ///
+    /// The default is `vec![]`, which follows `CommonMark`: whether a
+    /// sequence can open or close depends on whether the characters around
+    /// it are whitespace, punctuation, or anything else (word characters).
+    /// Pass characters to treat them as word characters instead.
+    /// This is useful for mixed-script text, such as to allow emphasis
+    /// around CJK brackets (`「`, `」`) when there is no whitespace around
+    /// them, or to prohibit intraword underscores in words with hyphens or
+    /// apostrophes.
+    ///
+    /// This option does nothing if `attention` or `gfm_strikethrough` are
+    /// not turned on in `constructs`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` treats brackets as punctuation by default:
+    /// assert_eq!(to_html("a*「b」*c"), "<p>a*「b」*c</p>");
+    ///
+    /// // Pass `attention_word_characters` to treat them as word characters:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a*「b」*c",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               attention_word_characters: vec!['「', '」'],
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<em>「b」</em>c</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub attention_word_characters: Vec<char>,
+
+    /// Schemes (protocols) to allow in autolinks.
+    ///
+    /// The default is `None`, which allows any scheme.
+    /// Pass a list of (lowercase) schemes to only form autolinks
+    /// (`<scheme:…>`) with those schemes: other autolinks are literal text.
+    /// Email autolinks (`<a@b.c>`) and GFM autolink literals are not affected.
+    ///
+    /// This is about syntax: to not output links with dangerous protocols, see
+    /// [`link_protocols`][CompileOptions::link_protocols] instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports any scheme in autolinks by default (but
+    /// // only outputs destinations with safe protocols):
+    /// assert_eq!(
+    ///     to_html("<ftp://a>"),
+    ///     "<p><a href=\"\">ftp://a</a></p>"
+    /// );
+    ///
+    /// // Pass `autolink_scheme_allowlist` to allow fewer:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<ftp://a>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               autolink_scheme_allowlist: Some(vec!["https".into()]),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>&lt;ftp://a&gt;</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub autolink_scheme_allowlist: Option<Vec<String>>,
+
+    /// Other schemes (protocols) to recognize in GFM autolink literals.
+    ///
+    /// The default is `vec![]`, which only recognizes `http://` and
+    /// `https://` (next to `www.` and email addresses, optionally with
+    /// `mailto:` or `xmpp:`), as in GFM.
+    /// Pass more schemes (such as `ftp`) to also turn `scheme://…` into
+    /// links.
+    /// Schemes are made of ASCII letters and are matched case-insensitively.
+    /// They must be followed by `://`, so things like `foo:bar` are never
+    /// links.
+    ///
+    /// To not output links with dangerous protocols, schemes must also be
+    /// allowed when compiling, see
+    /// [`link_protocols`][CompileOptions::link_protocols].
+    ///
+    /// This option does nothing if `gfm_autolink_literal` is not turned on in
+    /// `constructs`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` only recognizes `http` and `https` by default:
+    /// assert_eq!(
+    ///     to_html_with_options("ftp://a.b", &Options::gfm())?,
+    ///     "<p>ftp://a.b</p>"
+    /// );
+    ///
+    /// // Pass `gfm_autolink_literal_schemes` to recognize more:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "ftp://a.b",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               gfm_autolink_literal_schemes: vec!["ftp".into()],
+    ///               ..ParseOptions::gfm()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_protocol: true,
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"ftp://a.b\">ftp://a.b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_autolink_literal_schemes: Vec<String>,
+
+    /// Unit to count columns in.
+    ///
+    /// The default is [`ColumnMode::Utf8CodeUnit`][], which counts bytes
+    /// (and counts tabs up to the next tab stop).
+    /// Editors typically count differently: for example, VS Code and the
+    /// language server protocol count UTF-16 code units.
+    /// Pass a different mode to have columns in positions (such as in
+    /// [`to_mdast()`][crate::to_mdast()]) line up with them.
+    ///
+    /// This only affects columns: lines and offsets (indices into the bytes
+    /// of the document) are the same for all modes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_mdast, ColumnMode, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` counts columns in bytes by default:
+    /// let tree = to_mdast("😀 *a*", &ParseOptions::default())?;
+    /// let emphasis = &tree.children().unwrap()[0].children().unwrap()[1];
+    /// assert_eq!(emphasis.position().unwrap().start.column, 6);
+    ///
+    /// // Pass `column_mode` to count differently:
+    /// let tree = to_mdast(
+    ///     "😀 *a*",
+    ///     &ParseOptions {
+    ///       column_mode: ColumnMode::Utf16CodeUnit,
+    ///       ..ParseOptions::default()
+    ///     },
+    /// )?;
+    /// let emphasis = &tree.children().unwrap()[0].children().unwrap()[1];
+    /// assert_eq!(emphasis.position().unwrap().start.column, 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub column_mode: ColumnMode,
+
+    /// Function to parse expressions with.
+    ///
+    /// This function can be used to add support for arbitrary programming
+    /// languages within expressions.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// For an example that adds support for JavaScript with SWC, see
+    /// `tests/test_utils/mod.rs`.
+    pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
+
+    /// Function to parse ESM with.
+    ///
+    /// This function can be used to add support for arbitrary programming
+    /// languages within ESM blocks, however, the keywords (`export`,
+    /// `import`) are currently hardcoded JavaScript-specific.
     ///
     /// > 👉 **Note**: please raise an issue if you’re interested in working on
     /// > MDX that is aware of, say, Rust, or other programming languages.
     ///
-    /// It only makes sense to pass this when compiling to a syntax tree
-    /// with [`to_mdast()`][crate::to_mdast()].
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// For an example that adds support for JavaScript with SWC, see
+    /// `tests/test_utils/mod.rs`.
+    pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+
+    /// Which definition to use when several have the same identifier.
+    ///
+    /// `CommonMark` uses the first definition, and ignores later ones.
+    /// Some systems, such as templates that override definitions, want the
+    /// last one instead.
+    /// This applies to definitions in the document; they still take
+    /// precedence over external definitions.
+    ///
+    /// The default is [`DefinitionConflict::KeepFirst`][].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, DefinitionConflict, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // The first definition is used by default:
+    /// assert_eq!(
+    ///     to_html("[a]\n\n[a]: b\n[a]: c"),
+    ///     "<p><a href=\"b\">a</a></p>\n"
+    /// );
+    ///
+    /// // Pass `definition_conflict` to use the last one:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a]\n\n[a]: b\n[a]: c",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///                 definition_conflict: DefinitionConflict::KeepLast,
+    ///                 ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"c\">a</a></p>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub definition_conflict: DefinitionConflict,
+
+    /// Definitions defined outside of the document.
+    ///
+    /// This is useful when several documents share definitions, for example
+    /// when they are stored in a separate file.
+    /// Keys are identifiers (they are normalized, so `A` and `a` are the
+    /// same), values are the destination (url) and an optional title.
     ///
-    /// For an example that adds support for JavaScript with SWC, see
-    /// `tests/test_utils/mod.rs`.
-    pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+    /// Definitions in the document take precedence over external definitions
+    /// with the same identifier.
+    ///
+    /// The default is to have no external definitions.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // References to unknown definitions are not links:
+    /// assert_eq!(to_html("[a]"), "<p>[a]</p>");
+    ///
+    /// // Pass `external_definitions` to define them elsewhere:
+    /// let mut parse = ParseOptions::default();
+    /// parse
+    ///     .external_definitions
+    ///     .insert("a".into(), ("https://example.com".into(), Some("b".into())));
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options("[a]", &Options { parse, ..Options::default() })?,
+    ///     "<p><a href=\"https://example.com\" title=\"b\">a</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub external_definitions: BTreeMap<String, (String, Option<String>)>,
+
+    /// Function to resolve references that are not defined.
+    ///
+    /// This is useful when the set of definitions is not known upfront, for
+    /// example in a wiki, where `[PageName]` could link to a page if it
+    /// exists.
+    /// The function gets the label of a full (`[a][b]`), collapsed
+    /// (`[a][]`), or shortcut (`[a]`) reference as written in the document
+    /// (not normalized), and returns the destination (url) and an optional
+    /// title to use, or `None` to not treat it as a reference.
+    ///
+    /// Definitions in the document and external definitions take precedence
+    /// over the resolver.
+    /// The function may be called with labels that end up not being
+    /// references, so it should be cheap.
+    /// What it returns while parsing is used when compiling to HTML: it is
+    /// not called again.
+    ///
+    /// When compiling to a syntax tree with [`to_mdast()`][crate::to_mdast()],
+    /// resolved references are link or image references without a
+    /// definition.
+    ///
+    /// The default is `None`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // References to unknown definitions are not links:
+    /// assert_eq!(to_html("[PageName]"), "<p>[PageName]</p>");
+    ///
+    /// // Pass `reference_resolver` to resolve them:
+    /// let parse = ParseOptions {
+    ///     reference_resolver: Some(Box::new(|label| {
+    ///         Some((format!("/wiki/{}", label), None))
+    ///     })),
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options("[PageName]", &Options { parse, ..Options::default() })?,
+    ///     "<p><a href=\"/wiki/PageName\">PageName</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub reference_resolver: Option<Box<ReferenceResolver>>,
+
+    /// Functions to resolve events with.
+    ///
+    /// Resolvers are called in order, after parsing, with the final list of
+    /// events (of all content types) and the bytes of the document.
+    /// They can change events, typically with an [`EditMap`][crate::EditMap],
+    /// for example to turn some [`Data`][crate::EventName::Data] into
+    /// [`Custom`][crate::EventName::Custom] spans.
+    ///
+    /// Resolvers must keep events balanced: every enter must have an exit.
+    /// Compilers ignore custom spans, but do compile what is in them.
+    ///
+    /// For an example that wraps a sentinel value in a custom span, see
+    /// `tests/misc_resolve.rs`.
+    pub resolvers: Vec<Box<Resolve>>,
+
+    /// Function to trace the state machine with.
+    ///
+    /// This is useful when debugging why markdown is parsed in a certain
+    /// way.
+    /// The function is called with the name of each state the tokenizer
+    /// moves to or retries in, and the point in the document where that
+    /// happens, in order, across all content types.
+    /// Attempts that fail are traced too, so the same bytes can be seen
+    /// several times.
+    ///
+    /// Only available with the `trace` feature, so there is no overhead
+    /// without it.
+    ///
+    /// For an example that prints a trace, see `examples/trace.rs`.
+    #[cfg(feature = "trace")]
+    pub trace: Option<Box<Trace>>,
     // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
 }
 
 impl fmt::Debug for ParseOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("ParseOptions")
+        let mut debug = f.debug_struct("ParseOptions");
+
+        debug
             .field("constructs", &self.constructs)
             .field(
                 "gfm_strikethrough_single_tilde",
                 &self.gfm_strikethrough_single_tilde,
             )
             .field("math_text_single_dollar", &self.math_text_single_dollar)
+            .field("hard_break_spaces_min", &self.hard_break_spaces_min)
+            .field(
+                "setext_underline_match_width",
+                &self.setext_underline_match_width,
+            )
+            .field("loose_list_marker_change", &self.loose_list_marker_change)
+            .field("thematic_break_markers", &self.thematic_break_markers)
+            .field("max_attention_sequences", &self.max_attention_sequences)
+            .field("attention_word_characters", &self.attention_word_characters)
+            .field("autolink_scheme_allowlist", &self.autolink_scheme_allowlist)
+            .field(
+                "gfm_autolink_literal_schemes",
+                &self.gfm_autolink_literal_schemes,
+            )
+            .field("column_mode", &self.column_mode)
             .field(
                 "mdx_expression_parse",
                 &self.mdx_expression_parse.as_ref().map(|_d| "[Function]"),
@@ -1128,19 +3301,118 @@ impl fmt::Debug for ParseOptions {
                 "mdx_esm_parse",
                 &self.mdx_esm_parse.as_ref().map(|_d| "[Function]"),
             )
-            .finish()
+            .field("definition_conflict", &self.definition_conflict)
+            .field("external_definitions", &self.external_definitions)
+            .field(
+                "reference_resolver",
+                &self.reference_resolver.as_ref().map(|_d| "[Function]"),
+            )
+            .field(
+                "resolvers",
+                &self
+                    .resolvers
+                    .iter()
+                    .map(|_d| "[Function]")
+                    .collect::<Vec<_>>(),
+            );
+
+        #[cfg(feature = "trace")]
+        debug.field("trace", &self.trace.as_ref().map(|_d| "[Function]"));
+
+        debug.finish()
     }
 }
 
+/// Unit to count columns in.
+///
+/// Can be passed as `column_mode` in [`ParseOptions`][].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ColumnMode {
+    /// UTF-8 code units (bytes).
+    ///
+    /// Tabs count up to the next tab stop (of 4).
+    /// This is how `markdown-rs` counts internally.
+    ///
+    /// For example, `😀` is 4 columns and `é` (`e` and a combining acute
+    /// accent) is 3.
+    #[default]
+    Utf8CodeUnit,
+    /// UTF-16 code units.
+    ///
+    /// Tabs count as 1.
+    /// This is how VS Code, the language server protocol, and JavaScript
+    /// count.
+    ///
+    /// For example, `😀` is 2 columns and `é` (`e` and a combining acute
+    /// accent) is 2.
+    Utf16CodeUnit,
+    /// Graphemes (user-perceived characters).
+    ///
+    /// Tabs count as 1.
+    /// Combining marks, emoji modifiers, characters joined by a zero-width
+    /// joiner, and pairs of regional indicators (flags) count as one with
+    /// what comes before them.
+    /// This approximates extended grapheme clusters as defined by Unicode,
+    /// without support for Hangul syllables and other rare cases.
+    ///
+    /// For example, `😀` is 1 column and `é` (`e` and a combining acute
+    /// accent) is 1.
+    Grapheme,
+}
+
+/// Which definition to use when several have the same identifier.
+///
+/// Can be passed as `definition_conflict` in [`ParseOptions`][].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DefinitionConflict {
+    /// Use the first definition (as in `CommonMark`).
+    ///
+    /// ```markdown
+    /// [a]: b
+    /// [a]: c
+    /// ```
+    ///
+    /// Here, `a` is `b`.
+    #[default]
+    KeepFirst,
+    /// Use the last definition.
+    ///
+    /// ```markdown
+    /// [a]: b
+    /// [a]: c
+    /// ```
+    ///
+    /// Here, `a` is `c`.
+    KeepLast,
+}
+
 impl Default for ParseOptions {
     /// `CommonMark` defaults.
+    ///
+    /// Uses [`Constructs::default()`][Constructs::default], so only
+    /// `CommonMark` 0.30 is parsed.
     fn default() -> Self {
         Self {
             constructs: Constructs::default(),
             gfm_strikethrough_single_tilde: true,
             math_text_single_dollar: true,
+            hard_break_spaces_min: HARD_BREAK_PREFIX_SIZE_MIN,
+            setext_underline_match_width: false,
+            loose_list_marker_change: false,
+            thematic_break_markers: vec!['*', '-', '_'],
+            max_attention_sequences: None,
+            attention_word_characters: vec![],
+            autolink_scheme_allowlist: None,
+            gfm_autolink_literal_schemes: vec![],
+            column_mode: ColumnMode::default(),
             mdx_expression_parse: None,
             mdx_esm_parse: None,
+            definition_conflict: DefinitionConflict::default(),
+            external_definitions: BTreeMap::new(),
+            reference_resolver: None,
+            resolvers: vec![],
+            #[cfg(feature = "trace")]
+            trace: None,
         }
     }
 }
@@ -1188,11 +3460,40 @@ impl ParseOptions {
     }
 }
 
+/// Signature of a function that resolves undefined references.
+///
+/// Gets the label of a reference, and can return its destination (url) and
+/// an optional title.
+///
+/// Can be passed as `reference_resolver` in [`ParseOptions`][].
+pub type ReferenceResolver = dyn Fn(&str) -> Option<(String, Option<String>)>;
+
+/// Signature of a function that traces the state machine.
+///
+/// Gets the name of a state and the point where the tokenizer is in it.
+///
+/// Can be passed as `trace` in [`ParseOptions`][].
+#[cfg(feature = "trace")]
+pub type Trace = dyn Fn(StateName, &Point);
+
+/// Signature of a function that resolves events.
+///
+/// Gets the events and the bytes of the document, and can change the events.
+/// Can return an error message to stop parsing.
+///
+/// Can be passed in `resolvers` in [`ParseOptions`][].
+pub type Resolve = dyn Fn(&mut Vec<Event>, &[u8]) -> Result<(), String>;
+
 /// Configuration that describes how to parse from markdown and compile to
 /// HTML.
 ///
 /// In most cases, you will want to use the default trait or `gfm` method.
 ///
+/// The default trait is strict and safe `CommonMark` 0.30: no extensions are
+/// on and no dangerous HTML or protocols are allowed.
+/// The `CommonMark` test suite is run with these defaults, with only
+/// dangerous HTML and protocols allowed, as the spec includes raw HTML.
+///
 /// ## Examples
 ///
 /// ```
@@ -1232,13 +3533,269 @@ impl Options {
             compile: CompileOptions::gfm(),
         }
     }
+
+    /// Safe.
+    ///
+    /// `CommonMark`, compiled with [`CompileOptions::safe()`][], for content
+    /// from untrusted authors, such as comments.
+    pub fn safe() -> Self {
+        Self {
+            parse: ParseOptions::default(),
+            compile: CompileOptions::safe(),
+        }
+    }
+
+    /// GitHub comments.
+    ///
+    /// GFM as rendered in comments, issues, and pull requests on GitHub.
+    /// That is GFM (autolink literals, footnotes, strikethrough, tables,
+    /// tasklists, and the tag filter), where raw HTML is not allowed, and
+    /// where line endings in text are hard breaks.
+    ///
+    /// GitHub does some things after compiling to HTML (such as adding
+    /// `dir="auto"` and linking mentions) that are not done here.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "Thanks!\nSee ~~#1~~ www.example.com.",
+    ///         &Options::gfm_github_comments()
+    ///     )?,
+    ///     "<p>Thanks!<br />\nSee <del>#1</del> <a href=\"http://www.example.com\">www.example.com</a>.</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gfm_github_comments() -> Self {
+        Self {
+            parse: ParseOptions::gfm(),
+            compile: CompileOptions {
+                soft_break_as_hard_break: true,
+                ..CompileOptions::gfm()
+            },
+        }
+    }
+}
+
+/// Configuration that describes how to serialize a syntax tree to markdown.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::SerializeOptions;
+/// # fn main() {
+///
+/// // Use the default trait to serialize with `*` for bullets and attention:
+/// let default = SerializeOptions::default();
+///
+/// // Or, mix and match:
+/// let custom = SerializeOptions {
+///   bullet: '-',
+///   emphasis: '_',
+///   ..SerializeOptions::default()
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SerializeOptions {
+    /// Marker to use for bullets of items in unordered lists.
+    ///
+    /// The default is `'*'`.
+    /// Pass `'+'` or `'-'` to use those instead.
+    /// Other characters are not supported and result in `'*'`.
+    ///
+    /// When two unordered lists are next to each other, the second uses `'-'`
+    /// (or `'*'`, when the bullet is `'-'`), as they would otherwise be seen
+    /// as one list.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let tree = to_mdast("* a", &ParseOptions::default())?;
+    ///
+    /// // `markdown-rs` uses `*` by default:
+    /// assert_eq!(to_markdown(&tree, &SerializeOptions::default()), "* a\n");
+    ///
+    /// // Pass `bullet: '-'` to use `-`:
+    /// assert_eq!(
+    ///     to_markdown(&tree, &SerializeOptions {
+    ///         bullet: '-',
+    ///         ..SerializeOptions::default()
+    ///     }),
+    ///     "- a\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub bullet: char,
+
+    /// Marker to use for emphasis.
+    ///
+    /// The default is `'*'`.
+    /// Pass `'_'` to use underscores instead.
+    /// Other characters are not supported and result in `'*'`.
+    ///
+    /// Underscores cannot form emphasis inside words, so `'*'` is used there.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let tree = to_mdast("*a*", &ParseOptions::default())?;
+    ///
+    /// // `markdown-rs` uses `*` by default:
+    /// assert_eq!(to_markdown(&tree, &SerializeOptions::default()), "*a*\n");
+    ///
+    /// // Pass `emphasis: '_'` to use `_`:
+    /// assert_eq!(
+    ///     to_markdown(&tree, &SerializeOptions {
+    ///         emphasis: '_',
+    ///         ..SerializeOptions::default()
+    ///     }),
+    ///     "_a_\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub emphasis: char,
+
+    /// Marker to use for strong.
+    ///
+    /// The default is `'*'`.
+    /// Pass `'_'` to use underscores instead.
+    /// Other characters are not supported and result in `'*'`.
+    ///
+    /// Underscores cannot form strong inside words, so `'*'` is used there.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let tree = to_mdast("**a**", &ParseOptions::default())?;
+    ///
+    /// // `markdown-rs` uses `*` by default:
+    /// assert_eq!(to_markdown(&tree, &SerializeOptions::default()), "**a**\n");
+    ///
+    /// // Pass `strong: '_'` to use `_`:
+    /// assert_eq!(
+    ///     to_markdown(&tree, &SerializeOptions {
+    ///         strong: '_',
+    ///         ..SerializeOptions::default()
+    ///     }),
+    ///     "__a__\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strong: char,
+
+    /// Marker to use for fenced code.
+    ///
+    /// The default is `` '`' ``.
+    /// Pass `'~'` to use tildes instead.
+    /// Other characters are not supported and result in `` '`' ``.
+    ///
+    /// Info strings cannot contain grave accents when fenced with grave
+    /// accents, so `'~'` is used then.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let tree = to_mdast("~~~js\na\n~~~", &ParseOptions::default())?;
+    ///
+    /// // `markdown-rs` uses grave accents by default:
+    /// assert_eq!(to_markdown(&tree, &SerializeOptions::default()), "```js\na\n```\n");
+    ///
+    /// // Pass `fence: '~'` to use tildes:
+    /// assert_eq!(
+    ///     to_markdown(&tree, &SerializeOptions {
+    ///         fence: '~',
+    ///         ..SerializeOptions::default()
+    ///     }),
+    ///     "~~~js\na\n~~~\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fence: char,
+
+    /// Whether to serialize links as references.
+    ///
+    /// The default is `false`, which serializes links as resources
+    /// (`[a](b)`).
+    /// Pass `true` to serialize them as full references (`[a][1]`), with
+    /// definitions for them at the end of the document.
+    ///
+    /// Autolinks (`<https://example.com>`) are kept as they are.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let tree = to_mdast("[a](b) and [c](b)", &ParseOptions::default())?;
+    ///
+    /// // `markdown-rs` serializes resources by default:
+    /// assert_eq!(
+    ///     to_markdown(&tree, &SerializeOptions::default()),
+    ///     "[a](b) and [c](b)\n"
+    /// );
+    ///
+    /// // Pass `reference_links: true` to serialize references:
+    /// assert_eq!(
+    ///     to_markdown(&tree, &SerializeOptions {
+    ///         reference_links: true,
+    ///         ..SerializeOptions::default()
+    ///     }),
+    ///     "[a][1] and [c][1]\n\n[1]: b\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub reference_links: bool,
+}
+
+impl Default for SerializeOptions {
+    /// Default markers.
+    fn default() -> Self {
+        Self {
+            bullet: '*',
+            emphasis: '*',
+            strong: '*',
+            fence: '`',
+            reference_links: false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::util::mdx::Signal;
-    use alloc::format;
+    use alloc::{format, vec};
+
+    /// Debug representation of the `trace` field, which only exists with
+    /// the `trace` feature.
+    #[cfg(feature = "trace")]
+    const TRACE_DEBUG: &str = ", trace: None";
+    #[cfg(not(feature = "trace"))]
+    const TRACE_DEBUG: &str = "";
 
     #[test]
     fn test_constructs() {
@@ -1283,6 +3840,183 @@ mod tests {
         assert!(!constructs.frontmatter, "should support `mdx` shortcut (4)");
     }
 
+    #[test]
+    fn test_constructs_default() {
+        // No `..` here: adding a construct must fail to compile until it is
+        // listed below, as either `CommonMark` or an extension.
+        let Constructs {
+            abbreviation,
+            attention,
+            attributes,
+            autolink,
+            autolink_email,
+            autolink_protocol,
+            block_quote,
+            character_escape,
+            character_reference,
+            code_indented,
+            code_fenced,
+            code_text,
+            code_span_lang,
+            definition,
+            definition_list,
+            frontmatter,
+            gfm_autolink_literal,
+            gfm_footnote_definition,
+            gfm_label_start_footnote,
+            gfm_strikethrough,
+            gfm_table,
+            gfm_task_list_item,
+            hard_break_escape,
+            hard_break_trailing,
+            heading_atx,
+            heading_setext,
+            html_flow,
+            html_text,
+            label_start_image,
+            label_start_link,
+            label_end,
+            list_item,
+            math_flow,
+            math_text,
+            mdx_esm,
+            mdx_expression_flow,
+            mdx_expression_text,
+            mdx_jsx_flow,
+            mdx_jsx_text,
+            thematic_break,
+        } = Constructs::default();
+
+        let commonmark = [
+            ("attention", attention),
+            ("autolink", autolink),
+            ("autolink_email", autolink_email),
+            ("autolink_protocol", autolink_protocol),
+            ("block_quote", block_quote),
+            ("character_escape", character_escape),
+            ("character_reference", character_reference),
+            ("code_indented", code_indented),
+            ("code_fenced", code_fenced),
+            ("code_text", code_text),
+            ("definition", definition),
+            ("hard_break_escape", hard_break_escape),
+            ("hard_break_trailing", hard_break_trailing),
+            ("heading_atx", heading_atx),
+            ("heading_setext", heading_setext),
+            ("html_flow", html_flow),
+            ("html_text", html_text),
+            ("label_start_image", label_start_image),
+            ("label_start_link", label_start_link),
+            ("label_end", label_end),
+            ("list_item", list_item),
+            ("thematic_break", thematic_break),
+        ];
+        let extensions = [
+            ("abbreviation", abbreviation),
+            ("attributes", attributes),
+            ("code_span_lang", code_span_lang),
+            ("definition_list", definition_list),
+            ("frontmatter", frontmatter),
+            ("gfm_autolink_literal", gfm_autolink_literal),
+            ("gfm_footnote_definition", gfm_footnote_definition),
+            ("gfm_label_start_footnote", gfm_label_start_footnote),
+            ("gfm_strikethrough", gfm_strikethrough),
+            ("gfm_table", gfm_table),
+            ("gfm_task_list_item", gfm_task_list_item),
+            ("math_flow", math_flow),
+            ("math_text", math_text),
+            ("mdx_esm", mdx_esm),
+            ("mdx_expression_flow", mdx_expression_flow),
+            ("mdx_expression_text", mdx_expression_text),
+            ("mdx_jsx_flow", mdx_jsx_flow),
+            ("mdx_jsx_text", mdx_jsx_text),
+        ];
+
+        for (name, on) in commonmark {
+            assert!(on, "should turn `CommonMark` construct `{}` on", name);
+        }
+
+        for (name, on) in extensions {
+            assert!(!on, "should turn extension `{}` off", name);
+        }
+    }
+
+    #[test]
+    fn test_constructs_set() {
+        let names = [
+            "abbreviation",
+            "attention",
+            "attributes",
+            "autolink",
+            "autolink_email",
+            "autolink_protocol",
+            "block_quote",
+            "character_escape",
+            "character_reference",
+            "code_indented",
+            "code_fenced",
+            "code_text",
+            "code_span_lang",
+            "definition",
+            "definition_list",
+            "frontmatter",
+            "gfm_autolink_literal",
+            "gfm_footnote_definition",
+            "gfm_label_start_footnote",
+            "gfm_strikethrough",
+            "gfm_table",
+            "gfm_task_list_item",
+            "hard_break_escape",
+            "hard_break_trailing",
+            "heading_atx",
+            "heading_setext",
+            "html_flow",
+            "html_text",
+            "label_start_image",
+            "label_start_link",
+            "label_end",
+            "list_item",
+            "math_flow",
+            "math_text",
+            "mdx_esm",
+            "mdx_expression_flow",
+            "mdx_expression_text",
+            "mdx_jsx_flow",
+            "mdx_jsx_text",
+            "thematic_break",
+        ];
+
+        let mut all = Constructs::default();
+
+        for name in names {
+            let mut constructs = Constructs::default();
+            constructs.set(name, true).unwrap();
+            assert!(
+                format!("{:?}", constructs).contains(&format!(" {}: true", name)),
+                "should support turning `{}` on",
+                name
+            );
+            constructs.set(name, false).unwrap();
+            assert!(
+                format!("{:?}", constructs).contains(&format!(" {}: false", name)),
+                "should support turning `{}` off",
+                name
+            );
+            all.set(name, true).unwrap();
+        }
+
+        assert!(
+            !format!("{:?}", all).contains("false"),
+            "should support all constructs by name"
+        );
+
+        assert_eq!(
+            Constructs::default().set("gfm-table", true),
+            Err("Unknown construct `gfm-table`, expected a field name of `Constructs`, such as `gfm_table`".into()),
+            "should error on unknown names"
+        );
+    }
+
     #[test]
     fn test_parse_options() {
         ParseOptions::default();
@@ -1333,7 +4067,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", ParseOptions::default()),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
+            format!("ParseOptions {{ constructs: Constructs {{ abbreviation: false, attention: true, attributes: false, autolink: true, autolink_email: true, autolink_protocol: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, code_span_lang: false, definition: true, definition_list: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }}, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, hard_break_spaces_min: 2, setext_underline_match_width: false, loose_list_marker_change: false, thematic_break_markers: ['*', '-', '_'], max_attention_sequences: None, attention_word_characters: [], autolink_scheme_allowlist: None, gfm_autolink_literal_schemes: [], column_mode: Utf8CodeUnit, mdx_expression_parse: None, mdx_esm_parse: None, definition_conflict: KeepFirst, external_definitions: {{}}, reference_resolver: None, resolvers: []{} }}", TRACE_DEBUG),
             "should support `Debug` trait"
         );
         assert_eq!(
@@ -1346,7 +4080,7 @@ mod tests {
                 })),
                 ..Default::default()
             }),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
+            format!("ParseOptions {{ constructs: Constructs {{ abbreviation: false, attention: true, attributes: false, autolink: true, autolink_email: true, autolink_protocol: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, code_span_lang: false, definition: true, definition_list: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }}, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, hard_break_spaces_min: 2, setext_underline_match_width: false, loose_list_marker_change: false, thematic_break_markers: ['*', '-', '_'], max_attention_sequences: None, attention_word_characters: [], autolink_scheme_allowlist: None, gfm_autolink_literal_schemes: [], column_mode: Utf8CodeUnit, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\"), definition_conflict: KeepFirst, external_definitions: {{}}, reference_resolver: None, resolvers: []{} }}", TRACE_DEBUG),
             "should support `Debug` trait on mdx functions"
         );
     }
@@ -1375,6 +4109,51 @@ mod tests {
             options.gfm_tagfilter,
             "should support safe `gfm` shortcut (1)"
         );
+
+        assert_eq!(
+            format!("{:?}", CompileOptions::default()),
+            "CompileOptions { allow_dangerous_html: false, allow_dangerous_protocol: false, default_line_ending: LineFeed, gfm_footnote_label: None, gfm_footnote_label_tag_name: None, gfm_footnote_label_attributes: None, gfm_footnote_back_label: None, gfm_footnote_clobber_prefix: None, gfm_footnote_ref_template: GfmFootnoteRefTemplate { tag_name: Some(\"sup\"), class: None }, gfm_tagfilter: false, trim_trailing_whitespace: false, trim_output: false, image_attributes: None, escape_context: Content, escape_gt: true, code_line_numbers: false, code_info_as_data_attrs: false, keep_atx_trailing_hashes: false, preserve_empty_paragraphs: false, link_protocols: None, link_rel: None, image_figure: false, collapse_thematic_breaks: false, heading_ids: false, heading_anchor_links: false, heading_anchor: HeadingAnchor { class: \"anchor\", content: \"#\" }, on_block: None, code_block_renderer: None, code_highlighter: None, cjk_soft_break: LineEnding, soft_break_as_hard_break: false, autolink_break_opportunities: false, attribute_escaper: None, remove_dangerous_html: false, setext_as_atx_level: [1, 2], expand_tabs: None, auto_noopener: false }",
+            "should support `Debug` trait"
+        );
+        assert_eq!(
+            format!("{:?}", CompileOptions {
+                image_attributes: Some(Arc::new(|_image| {
+                    vec![]
+                })),
+                ..Default::default()
+            }),
+            "CompileOptions { allow_dangerous_html: false, allow_dangerous_protocol: false, default_line_ending: LineFeed, gfm_footnote_label: None, gfm_footnote_label_tag_name: None, gfm_footnote_label_attributes: None, gfm_footnote_back_label: None, gfm_footnote_clobber_prefix: None, gfm_footnote_ref_template: GfmFootnoteRefTemplate { tag_name: Some(\"sup\"), class: None }, gfm_tagfilter: false, trim_trailing_whitespace: false, trim_output: false, image_attributes: Some(\"[Function]\"), escape_context: Content, escape_gt: true, code_line_numbers: false, code_info_as_data_attrs: false, keep_atx_trailing_hashes: false, preserve_empty_paragraphs: false, link_protocols: None, link_rel: None, image_figure: false, collapse_thematic_breaks: false, heading_ids: false, heading_anchor_links: false, heading_anchor: HeadingAnchor { class: \"anchor\", content: \"#\" }, on_block: None, code_block_renderer: None, code_highlighter: None, cjk_soft_break: LineEnding, soft_break_as_hard_break: false, autolink_break_opportunities: false, attribute_escaper: None, remove_dangerous_html: false, setext_as_atx_level: [1, 2], expand_tabs: None, auto_noopener: false }",
+            "should support `Debug` trait on functions"
+        );
+
+        let options = Options::default().compile.clone();
+        assert_eq!(
+            format!("{:?}", options),
+            format!("{:?}", CompileOptions::default()),
+            "should support `Clone` trait"
+        );
+
+        let options = Options::safe().compile.clone();
+        assert_eq!(
+            format!("{:?}", options),
+            format!("{:?}", CompileOptions::safe()),
+            "should support `Clone` trait on the safe preset"
+        );
+
+        fn send_sync<T: Send + Sync>() {}
+        // Fails to compile if `CompileOptions` cannot be shared between threads.
+        send_sync::<CompileOptions>();
+
+        let options = CompileOptions {
+            attribute_escaper: Some(Arc::new(|value| value.into())),
+            ..Default::default()
+        };
+        let clone = options.clone();
+        assert_eq!(
+            (clone.attribute_escaper.unwrap())("a"),
+            "a",
+            "should support `Clone` trait on functions"
+        );
     }
 
     #[test]
@@ -0,0 +1,181 @@
+//! Abbreviation occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Abbreviation forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: must be exactly the label of an abbreviation definition.
+//! ; Restriction: the character before and after must not be `word`.
+//! abbreviation ::= 1*line
+//!
+//! word ::= unicode_alphanumeric
+//! ```
+//!
+//! Abbreviations are not parsed like other constructs.
+//! Instead, after text is parsed, data in it is searched for the labels of
+//! [abbreviation definitions][abbreviation_definition].
+//! Because definitions apply to the whole document, this can only happen
+//! once all flow is parsed.
+//!
+//! Matching is case-sensitive, and only whole words match: `HTML` in
+//! `HTMLs` or `XHTML` is not an abbreviation.
+//! When several labels match at the same place, the longest one is used.
+//! Abbreviations are not found in code, autolinks, or HTML, as those do not
+//! contain data.
+//! They are also not found in the destinations and titles of links and
+//! images, or in the labels of references.
+//!
+//! ## HTML
+//!
+//! Abbreviations relate to the `<abbr>` element in HTML.
+//! See [*§ 4.5.9 The `abbr` element*][html] in the HTML spec for more info.
+//!
+//! ## Recommendation
+//!
+//! Only define abbreviations for terms that are not known to your readers.
+//!
+//! ## Tokens
+//!
+//! *   [`Abbreviation`][Name::Abbreviation]
+//!
+//! ## References
+//!
+//! *   [*§ Abbreviations* in PHP Markdown Extra](https://michelf.ca/projects/php-markdown/extra/#abbr)
+//!
+//! [text]: crate::construct::text
+//! [abbreviation_definition]: crate::construct::abbreviation_definition
+//! [html]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-abbr-element
+
+use crate::event::{Event, Kind, Name};
+use crate::tokenizer::Tokenizer;
+use crate::util::char::{after_index, before_index};
+use alloc::vec::Vec;
+
+/// Find abbreviations in data.
+///
+/// This turns data into abbreviations, and splits data around them.
+pub fn resolve(tokenizer: &mut Tokenizer) {
+    tokenizer.map.consume(&mut tokenizer.events);
+
+    let bytes = tokenizer.parse_state.bytes;
+    let abbreviations = &tokenizer.parse_state.abbreviations;
+    let mut index = 0;
+    // Depth in resources, references, and footnote calls.
+    let mut ignore = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if matches!(
+            event.name,
+            Name::GfmFootnoteCall | Name::Reference | Name::Resource
+        ) {
+            if event.kind == Kind::Enter {
+                ignore += 1;
+            } else {
+                ignore -= 1;
+            }
+        } else if ignore == 0 && event.kind == Kind::Exit && event.name == Name::Data {
+            let end = event.point.index;
+            let mut point = tokenizer.events[index - 1].point.clone();
+            let mut byte_index = point.index;
+            let mut min = byte_index;
+            let mut replace = Vec::new();
+
+            while byte_index < end {
+                // Only look at the start of words.
+                if !is_continuation(bytes[byte_index])
+                    && !before_index(bytes, byte_index).map_or(false, is_word)
+                {
+                    let mut size = 0;
+
+                    for label in abbreviations {
+                        let label_end = byte_index + label.len();
+
+                        if label.len() > size
+                            && label_end <= end
+                            && bytes[byte_index..label_end] == *label.as_bytes()
+                            && !after_index(bytes, label_end).map_or(false, is_word)
+                        {
+                            size = label.len();
+                        }
+                    }
+
+                    if size > 0 {
+                        // If there is something between the last abbreviation
+                        // (or the start) and this one.
+                        if min != byte_index {
+                            replace.push(Event {
+                                kind: Kind::Enter,
+                                name: Name::Data,
+                                point: point.clone(),
+                                link: None,
+                            });
+                            point = point.shift_to(bytes, byte_index);
+                            replace.push(Event {
+                                kind: Kind::Exit,
+                                name: Name::Data,
+                                point: point.clone(),
+                                link: None,
+                            });
+                        }
+
+                        replace.push(Event {
+                            kind: Kind::Enter,
+                            name: Name::Abbreviation,
+                            point: point.clone(),
+                            link: None,
+                        });
+                        point = point.shift_to(bytes, byte_index + size);
+                        replace.push(Event {
+                            kind: Kind::Exit,
+                            name: Name::Abbreviation,
+                            point: point.clone(),
+                            link: None,
+                        });
+
+                        byte_index += size;
+                        min = byte_index;
+                        continue;
+                    }
+                }
+
+                byte_index += 1;
+            }
+
+            // If there was an abbreviation, and we have more bytes left.
+            if !replace.is_empty() && min < end {
+                replace.push(Event {
+                    kind: Kind::Enter,
+                    name: Name::Data,
+                    point,
+                    link: None,
+                });
+                replace.push(Event {
+                    kind: Kind::Exit,
+                    name: Name::Data,
+                    point: tokenizer.events[index].point.clone(),
+                    link: None,
+                });
+            }
+
+            if !replace.is_empty() {
+                tokenizer.map.add(index - 1, 2, replace);
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Check whether a byte continues a multibyte character.
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+/// Check whether a character is part of a word.
+fn is_word(char: char) -> bool {
+    char.is_alphanumeric()
+}
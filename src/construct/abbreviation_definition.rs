@@ -0,0 +1,317 @@
+//! Abbreviation definition occurs in the [flow][] content type.
+//!
+//! ## Grammar
+//!
+//! Abbreviation definition forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! abbreviation_definition ::= *space_or_tab '*' label ':' *space_or_tab [ title ] *space_or_tab
+//!
+//! ; Restriction: cannot start or end with `space_or_tab`.
+//! label ::= '[' 1*( line - '[' - ']' ) ']'
+//! title ::= text *( line ) text | text
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//!
+//! Abbreviation definitions are an extension from
+//! [PHP Markdown Extra][php_markdown_extra].
+//! Definitions can occur anywhere in the document.
+//! They apply to the whole document, including to text before them.
+//! Occurrences of the label in text are matched by the
+//! [abbreviation][crate::construct::abbreviation] resolver.
+//! The title is taken as is: character escapes and character references in
+//! it are not interpreted.
+//!
+//! Abbreviation definitions cannot interrupt paragraphs.
+//! When multiple definitions have the same label, the first one is used.
+//!
+//! ## HTML
+//!
+//! Abbreviation definitions in markdown do not, on their own, relate to
+//! anything in HTML.
+//! When matched with an abbreviation, the title is used for the `title`
+//! attribute of an `<abbr>` element.
+//! See [*§ 4.5.9 The `abbr` element*][html] in the HTML spec for more info.
+//!
+//! ## Recommendation
+//!
+//! Place abbreviation definitions at the end of a document.
+//!
+//! ## Tokens
+//!
+//! *   [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+//! *   [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+//! *   [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker]
+//! *   [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
+//! *   [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker]
+//! *   [`AbbreviationDefinitionTitle`][Name::AbbreviationDefinitionTitle]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*§ Abbreviations* in PHP Markdown Extra][php_markdown_extra]
+//!
+//! [flow]: crate::construct::flow
+//! [php_markdown_extra]: https://michelf.ca/projects/php-markdown/extra/#abbr
+//! [html]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-abbr-element
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    constant::TAB_SIZE,
+    slice::{Position, Slice},
+};
+
+/// Start of abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    // Do not interrupt paragraphs.
+    if tokenizer.parse_state.options.constructs.abbreviation && !tokenizer.interrupt {
+        tokenizer.enter(Name::AbbreviationDefinition);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(
+                State::Next(StateName::AbbreviationDefinitionBefore),
+                State::Nok,
+            );
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::AbbreviationDefinitionBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at `*`.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'*') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationDefinitionLabelBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `*`, at `[`.
+///
+/// ```markdown
+/// > | *[a]: b
+///      ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.enter(Name::AbbreviationDefinitionLabel);
+        tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+        State::Next(StateName::AbbreviationDefinitionLabelAtString)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `[`, at label data.
+///
+/// ```markdown
+/// > | *[a]: b
+///       ^
+/// ```
+pub fn label_at_string(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\t' | b'\n' | b' ' | b'[' | b']') => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::AbbreviationDefinitionLabelString);
+            State::Retry(StateName::AbbreviationDefinitionLabelInside)
+        }
+    }
+}
+
+/// In label data.
+///
+/// ```markdown
+/// > | *[a]: b
+///       ^
+/// ```
+pub fn label_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        // Last byte before `]` must not be whitespace.
+        Some(b']') if tokenizer.tokenize_state.seen => {
+            tokenizer.tokenize_state.seen = false;
+            tokenizer.exit(Name::AbbreviationDefinitionLabelString);
+            // Note: the label is collected when the definition is done.
+            tokenizer.tokenize_state.end = tokenizer.events.len() - 1;
+            tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+            tokenizer.exit(Name::AbbreviationDefinitionLabel);
+            State::Next(StateName::AbbreviationDefinitionLabelAfter)
+        }
+        None | Some(b'\n' | b'[' | b']') => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+        Some(byte) => {
+            tokenizer.tokenize_state.seen = !matches!(byte, b'\t' | b' ');
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationDefinitionLabelInside)
+        }
+    }
+}
+
+/// After label, at `:`.
+///
+/// ```markdown
+/// > | *[a]: b
+///        ^
+/// ```
+pub fn label_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationDefinitionMarkerAfter)
+    } else {
+        tokenizer.tokenize_state.end = 0;
+        State::Nok
+    }
+}
+
+/// After `:`, at optional whitespace.
+///
+/// ```markdown
+/// > | *[a]: b
+///         ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::AbbreviationDefinitionTitleBefore),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::AbbreviationDefinitionTitleBefore)
+    }
+}
+
+/// After whitespace, at optional title.
+///
+/// ```markdown
+/// > | *[a]: b
+///           ^
+/// ```
+pub fn title_before(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        State::Retry(StateName::AbbreviationDefinitionAfter)
+    } else {
+        tokenizer.enter(Name::AbbreviationDefinitionTitle);
+        State::Retry(StateName::AbbreviationDefinitionTitleInside)
+    }
+}
+
+/// In title.
+///
+/// ```markdown
+/// > | *[a]: b
+///           ^
+/// ```
+pub fn title_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::AbbreviationDefinitionTitle);
+            State::Retry(StateName::AbbreviationDefinitionAfter)
+        }
+        // Trailing whitespace is not part of the title.
+        Some(b'\t' | b' ') => {
+            tokenizer.check(
+                State::Next(StateName::AbbreviationDefinitionTitleEnd),
+                State::Next(StateName::AbbreviationDefinitionTitleContinue),
+            );
+            State::Retry(StateName::BlankLineStart)
+        }
+        Some(_) => State::Retry(StateName::AbbreviationDefinitionTitleContinue),
+    }
+}
+
+/// In title, at a byte that belongs to it.
+///
+/// ```markdown
+/// > | *[a]: b c
+///            ^
+/// ```
+pub fn title_continue(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.consume();
+    State::Next(StateName::AbbreviationDefinitionTitleInside)
+}
+
+/// In title, at trailing whitespace.
+///
+/// ```markdown
+/// > | *[a]: b␠␠
+///            ^
+/// ```
+pub fn title_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::AbbreviationDefinitionTitle);
+    tokenizer.attempt(
+        State::Next(StateName::AbbreviationDefinitionAfter),
+        State::Nok,
+    );
+    State::Retry(space_or_tab(tokenizer))
+}
+
+/// After abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///            ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::AbbreviationDefinition);
+
+            // Note: we don’t care about uniqueness, as with definitions.
+            tokenizer.tokenize_state.abbreviations.push(
+                Slice::from_position(
+                    tokenizer.parse_state.bytes,
+                    &Position::from_exit_event(&tokenizer.events, tokenizer.tokenize_state.end),
+                )
+                .serialize(),
+            );
+
+            tokenizer.tokenize_state.end = 0;
+            // Feel free to interrupt.
+            tokenizer.interrupt = false;
+            State::Ok
+        }
+        _ => {
+            tokenizer.tokenize_state.end = 0;
+            State::Nok
+        }
+    }
+}
@@ -16,11 +16,32 @@
 //! each sequence.
 //! Otherwise they are turned into data.
 //!
+//! When either of two sequences can both open and close, they do not match
+//! if the sum of their lengths is a multiple of 3, unless both lengths are.
+//! These are the lengths of the whole sequences in the source, also when some
+//! of their markers were already used by other attention.
+//!
+//! The characters before and after a sequence are those in the source, not
+//! what they represent: as in `CommonMark`, a character reference such as
+//! `&#65;` (`A`) or `&nbsp;` (whitespace) next to a sequence counts as the
+//! punctuation `;` before it or `&` after it.
+//! Characters passed in
+//! [`attention_word_characters`][crate::ParseOptions::attention_word_characters]
+//! count as neither whitespace nor punctuation.
+//!
 //! ## HTML
 //!
 //! When asterisk/underscore sequences match, and two markers can be “taken”
 //! from them, they together relate to the `<strong>` element in HTML.
 //! When one marker can be taken, they relate to the `<em>` element.
+//! Markers are taken from the inside out, two at a time while both sequences
+//! have at least two left, so longer sequences nest like so:
+//!
+//! *   `***a***` is `<em><strong>a</strong></em>`
+//! *   `****a****` is `<strong><strong>a</strong></strong>`
+//! *   `*****a*****` is `<em><strong><strong>a</strong></strong></em>`
+//! *   `******a******` is `<strong><strong><strong>a</strong></strong></strong>`
+//!
 //! See [*§ 4.5.2 The `em` element*][html-em] and
 //! [*§ 4.5.3 The `strong` element*][html-strong] in the HTML spec for more
 //! info.
@@ -29,6 +50,16 @@
 //! HTML.
 //! See [*§ 4.7.2 The `del` element*][html-del] in the HTML spec for more info.
 //!
+//! ## Performance
+//!
+//! Matching sequences is linear in the number of sequences in a paragraph
+//! (well, `O(n log n)`).
+//! Sequences that are fully used, or that are between matched sequences, are
+//! skipped when looking for openers, and for each kind of closer we remember
+//! below which point no opener can be found.
+//! This keeps long paragraphs (such as a very long single line) with lots of
+//! unmatched or misnested sequences fast.
+//!
 //! ## Recommendation
 //!
 //! It is recommended to use asterisks for emphasis/strong attention when
@@ -85,7 +116,7 @@ use crate::util::char::{
     after_index as char_after_index, before_index as char_before_index, classify_opt,
     Kind as CharacterKind,
 };
-use alloc::{vec, vec::Vec};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
 
 /// Attentention sequence that we can take markers from.
 #[derive(Debug)]
@@ -104,6 +135,10 @@ struct Sequence {
     end_point: Point,
     /// The number of markers we can still use.
     size: usize,
+    /// The number of markers in the sequence, before any were used.
+    ///
+    /// The “rule of 3” uses this, not how many markers are left.
+    length: usize,
     /// Whether this sequence can open attention.
     open: bool,
     /// Whether this sequence can close attention.
@@ -154,6 +189,31 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     // Find all sequences, gather info about them.
     let mut sequences = get_sequences(tokenizer);
 
+    // Treat sequences past the configured maximum as data.
+    if let Some(max) = tokenizer.parse_state.options.max_attention_sequences {
+        if sequences.len() > max {
+            for sequence in sequences.split_off(max) {
+                tokenizer.events[sequence.index].name = Name::Data;
+                tokenizer.events[sequence.index + 1].name = Name::Data;
+            }
+        }
+    }
+
+    // For each sequence, the previous sequence that can still open.
+    // This skips over sequences that are fully used, and over sequences that
+    // are between matched sequences, as those can no longer open (that would
+    // misnest: `<em>a <strong>b</em> c</strong>`).
+    // Sequences after the current closer are not yet skipped over.
+    let mut previous = (0..sequences.len())
+        .map(|index| index.checked_sub(1))
+        .collect::<Vec<_>>();
+    // Where to stop looking for openers for a kind of closer: below that, no
+    // opener matched a closer of that kind before.
+    // Closers are grouped by their stack (through its innermost event),
+    // marker, whether they can open, and their length (modulo 3).
+    // This keeps texts without openers for their closers linear.
+    let mut bottoms: BTreeMap<(Option<usize>, u8, bool, usize), usize> = BTreeMap::new();
+
     // Now walk through them and match them.
     let mut close = 0;
 
@@ -162,13 +222,24 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         let mut next_index = close + 1;
 
         // Find a sequence that can close.
-        if sequence_close.close {
-            let mut open = close;
+        if sequence_close.close && sequence_close.size > 0 {
+            let key = (
+                sequence_close.stack.last().copied(),
+                sequence_close.marker,
+                sequence_close.open,
+                sequence_close.length % 3,
+            );
+            let bottom = bottoms.get(&key).copied().unwrap_or(0);
+            let mut open_opt = previous[close];
+            let mut found = false;
 
             // Now walk back to find an opener.
-            while open > 0 {
-                open -= 1;
+            while let Some(open) = open_opt {
+                if open < bottom {
+                    break;
+                }
 
+                open_opt = previous[open];
                 let sequence_open = &sequences[open];
 
                 // An opener matching our closer:
@@ -177,12 +248,14 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                     && sequence_close.stack == sequence_open.stack
                 {
                     // If the opening can close or the closing can open,
-                    // and the close size *is not* a multiple of three,
-                    // but the sum of the opening and closing size *is*
+                    // and the close length *is not* a multiple of three,
+                    // but the sum of the opening and closing length *is*
                     // multiple of three, then **don’t** match.
+                    // This uses the whole sequences, also when some of their
+                    // markers were already used.
                     if (sequence_open.close || sequence_close.open)
-                        && sequence_close.size % 3 != 0
-                        && (sequence_open.size + sequence_close.size) % 3 == 0
+                        && sequence_close.length % 3 != 0
+                        && (sequence_open.length + sequence_close.length) % 3 == 0
                     {
                         continue;
                     }
@@ -201,11 +274,43 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                     }
 
                     // We found a match!
-                    next_index = match_sequences(tokenizer, &mut sequences, open, close);
+                    match_sequences(tokenizer, &mut sequences, open, close);
+                    found = true;
+
+                    // Skip over the sequences between them, and the opener if
+                    // it’s fully used.
+                    previous[close] = if sequences[open].size == 0 {
+                        previous[open]
+                    } else {
+                        Some(open)
+                    };
+
+                    // Skip over the closer if it’s fully used, otherwise stay
+                    // on it: it might close more things.
+                    if sequences[close].size == 0 {
+                        if close + 1 < sequences.len() {
+                            previous[close + 1] = previous[close];
+                        }
+                    } else {
+                        next_index = close;
+                    }
+
+                    // The opener changed, so it might now match closers that
+                    // it did not match before.
+                    let stack = sequences[close].stack.last().copied();
+                    for (_, bottom) in
+                        bottoms.range_mut((stack, 0, false, 0)..=(stack, u8::MAX, true, 2))
+                    {
+                        *bottom = (*bottom).min(open);
+                    }
 
                     break;
                 }
             }
+
+            if !found {
+                bottoms.insert(key, close);
+            }
         }
 
         close = next_index;
@@ -215,8 +320,12 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     let mut index = 0;
     while index < sequences.len() {
         let sequence = &sequences[index];
-        tokenizer.events[sequence.index].name = Name::Data;
-        tokenizer.events[sequence.index + 1].name = Name::Data;
+
+        if sequence.size > 0 {
+            tokenizer.events[sequence.index].name = Name::Data;
+            tokenizer.events[sequence.index + 1].name = Name::Data;
+        }
+
         index += 1;
     }
 
@@ -224,11 +333,31 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     None
 }
 
+/// Get extra characters that sequences can open before and close after, as
+/// if they were not punctuation.
+///
+/// Like `attentionMarkers` in `micromark`: when GFM strikethrough is on,
+/// emphasis and strong can open before `~~` and close after it, so that
+/// `a*~~b~~*c` works.
+fn attention_markers(tokenizer: &Tokenizer) -> &'static [char] {
+    if tokenizer.parse_state.options.constructs.gfm_strikethrough {
+        &['~']
+    } else {
+        &[]
+    }
+}
+
 /// Get sequences.
 fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
     let mut index = 0;
     let mut stack = vec![];
     let mut sequences = vec![];
+    let markers = attention_markers(tokenizer);
+    let word_characters = &tokenizer.parse_state.options.attention_word_characters;
+    let classify = |char: Option<char>| match char {
+        Some(char) if word_characters.contains(&char) => CharacterKind::Other,
+        _ => classify_opt(char),
+    };
 
     while index < tokenizer.events.len() {
         let enter = &tokenizer.events[index];
@@ -239,18 +368,16 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
                 let exit = &tokenizer.events[end];
 
                 let marker = tokenizer.parse_state.bytes[enter.point.index];
-                let before = classify_opt(char_before_index(
-                    tokenizer.parse_state.bytes,
-                    enter.point.index,
-                ));
-                let after = classify_opt(char_after_index(
-                    tokenizer.parse_state.bytes,
-                    exit.point.index,
-                ));
+                let char_before = char_before_index(tokenizer.parse_state.bytes, enter.point.index);
+                let char_after = char_after_index(tokenizer.parse_state.bytes, exit.point.index);
+                let before = classify(char_before);
+                let after = classify(char_after);
                 let open = after == CharacterKind::Other
-                    || (after == CharacterKind::Punctuation && before != CharacterKind::Other);
+                    || (after == CharacterKind::Punctuation && before != CharacterKind::Other)
+                    || matches!(char_after, Some(char) if markers.contains(&char));
                 let close = before == CharacterKind::Other
-                    || (before == CharacterKind::Punctuation && after != CharacterKind::Other);
+                    || (before == CharacterKind::Punctuation && after != CharacterKind::Other)
+                    || matches!(char_before, Some(char) if markers.contains(&char));
 
                 sequences.push(Sequence {
                     index,
@@ -258,6 +385,7 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
                     start_point: enter.point.clone(),
                     end_point: exit.point.clone(),
                     size: exit.point.index - enter.point.index,
+                    length: exit.point.index - enter.point.index,
                     open: if marker == b'_' {
                         open && (before != CharacterKind::Other || !close)
                     } else {
@@ -287,16 +415,10 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
 #[allow(clippy::too_many_lines)]
 fn match_sequences(
     tokenizer: &mut Tokenizer,
-    sequences: &mut Vec<Sequence>,
+    sequences: &mut [Sequence],
     open: usize,
     close: usize,
-) -> usize {
-    // Where to move to next.
-    // Stay on this closing sequence for the next iteration: it
-    // might close more things.
-    // It’s changed if sequences are removed.
-    let mut next = close;
-
+) {
     // Number of markers to use from the sequence.
     let take = if sequences[open].size > 1 && sequences[close].size > 1 {
         2
@@ -304,25 +426,6 @@ fn match_sequences(
         1
     };
 
-    // We’re *on* a closing sequence, with a matching opening
-    // sequence.
-    // Now we make sure that we can’t have misnested attention:
-    //
-    // ```html
-    // <em>a <strong>b</em> c</strong>
-    // ```
-    //
-    // Do that by marking everything between it as no longer
-    // possible to open anything.
-    // Theoretically we should mark as `close: false` too, but
-    // we don’t look for closers backwards, so it’s not needed.
-    let mut between = open + 1;
-
-    while between < close {
-        sequences[between].open = false;
-        between += 1;
-    }
-
     let (group_name, seq_name, text_name) = if sequences[open].marker == b'~' {
         (
             Name::GfmStrikethrough,
@@ -413,7 +516,6 @@ fn match_sequences(
 
     // Remove closing sequence if fully used.
     if sequences[close].size == 0 {
-        sequences.remove(close);
         tokenizer.map.add(close_index, 2, vec![]);
     } else {
         // Shift remaining closing sequence forward.
@@ -424,13 +526,8 @@ fn match_sequences(
     }
 
     if sequences[open].size == 0 {
-        sequences.remove(open);
         tokenizer.map.add(open_index, 2, vec![]);
-        // Everything shifts one to the left, account for it in next iteration.
-        next -= 1;
     } else {
         tokenizer.events[open_index + 1].point = sequences[open].end_point.clone();
     }
-
-    next
 }
@@ -0,0 +1,493 @@
+//! Attributes occur in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Attributes form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! attributes ::= '{' [ ':' ] *space_or_tab attribute *( 1*space_or_tab attribute ) *space_or_tab '}'
+//!
+//! attribute ::= id | class | key_value
+//! id ::= '#' 1*shorthand_byte
+//! class ::= '.' 1*shorthand_byte
+//! key_value ::= name '=' value
+//! name ::= ( ':' | '_' | ascii_alphabetic ) *( '-' | '.' | ':' | '_' | ascii_alphanumeric )
+//! value ::= '"' *( line - '"' ) '"' | "'" *( line - "'" ) "'" | 1*( line - space_or_tab - '"' - "'" - '<' - '=' - '>' - '`' - '{' - '}' )
+//! shorthand_byte ::= '-' | ':' | '_' | ascii_alphanumeric
+//! ```
+//!
+//! Attributes are an extension inspired by kramdown and Pandoc.
+//! They apply to something else, which is decided once the whole document is
+//! parsed.
+//! When attributes directly follow (without whitespace) an inline span, they
+//! apply to that span.
+//! Spans are emphasis, strong, GFM strikethrough, code (text), math (text),
+//! links, and images.
+//! Otherwise, when they are at the end of a paragraph or the text of a
+//! heading, and preceded by whitespace, they apply to that paragraph or
+//! heading.
+//! In all other cases, they are not attributes but plain text.
+//!
+//! Multiple classes are joined together.
+//! When there are multiple ids, or multiple values for another name, the
+//! last one is used.
+//! Values are taken as is: character escapes and character references in
+//! them are not interpreted.
+//!
+//! ## HTML
+//!
+//! Attributes do not, on their own, relate to anything in HTML.
+//! They are added to the element that they apply to.
+//! Attributes whose name starts with `on` are only added when dangerous HTML
+//! is allowed.
+//! Attributes that are already set on an element, such as `href` on links,
+//! are not overwritten.
+//! Paragraphs in tight lists do not have an element, so their attributes are
+//! dropped.
+//!
+//! ## Recommendation
+//!
+//! Use attributes sparingly: they are not portable markdown, and are shown as
+//! text elsewhere.
+//!
+//! ## Tokens
+//!
+//! *   [`Attributes`][Name::Attributes]
+//! *   [`AttributesClass`][Name::AttributesClass]
+//! *   [`AttributesClassMarker`][Name::AttributesClassMarker]
+//! *   [`AttributesId`][Name::AttributesId]
+//! *   [`AttributesIdMarker`][Name::AttributesIdMarker]
+//! *   [`AttributesInitializerMarker`][Name::AttributesInitializerMarker]
+//! *   [`AttributesMarker`][Name::AttributesMarker]
+//! *   [`AttributesName`][Name::AttributesName]
+//! *   [`AttributesValue`][Name::AttributesValue]
+//! *   [`AttributesValueMarker`][Name::AttributesValueMarker]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*§ Inline attribute lists* in kramdown](https://kramdown.gettalong.org/syntax.html#inline-attribute-lists)
+//! *   [*§ Extension: `header_attributes`* in Pandoc](https://pandoc.org/MANUAL.html#extension-header_attributes)
+//!
+//! [text]: crate::construct::text
+
+use crate::construct::partial_space_or_tab::space_or_tab;
+use crate::event::{Event, Kind, Name};
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{edit_map::EditMap, infer::ATTRIBUTES_SPANS, skip};
+use alloc::{vec, vec::Vec};
+
+/// Start of attributes.
+///
+/// ```markdown
+/// > | *a*{.b}
+///        ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.attributes && tokenizer.current == Some(b'{') {
+        tokenizer.enter(Name::Attributes);
+        tokenizer.enter(Name::AttributesMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AttributesMarker);
+        State::Next(StateName::AttributesOpenAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `{`, at optional `:`.
+///
+/// ```markdown
+/// > | *a*{:.b}
+///         ^
+/// ```
+pub fn open_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::AttributesMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AttributesMarker);
+        State::Next(StateName::AttributesColonAfter)
+    } else {
+        State::Retry(StateName::AttributesColonAfter)
+    }
+}
+
+/// After `{` or `{:`, at optional whitespace.
+///
+/// ```markdown
+/// > | *a*{ .b}
+///         ^
+/// ```
+pub fn colon_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::AttributesAttributeBefore),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::AttributesAttributeBefore)
+    }
+}
+
+/// At an attribute.
+///
+/// ```markdown
+/// > | *a*{#b .c d=e}
+///         ^  ^  ^
+/// ```
+pub fn attribute_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte @ (b'#' | b'.')) => {
+            let (marker, name) = if byte == b'#' {
+                (Name::AttributesIdMarker, Name::AttributesId)
+            } else {
+                (Name::AttributesClassMarker, Name::AttributesClass)
+            };
+            tokenizer.enter(marker.clone());
+            tokenizer.consume();
+            tokenizer.exit(marker);
+            tokenizer.tokenize_state.token_1 = name;
+            State::Next(StateName::AttributesShorthandBefore)
+        }
+        Some(b':' | b'_' | b'A'..=b'Z' | b'a'..=b'z') => {
+            tokenizer.enter(Name::AttributesName);
+            tokenizer.consume();
+            State::Next(StateName::AttributesNameInside)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// After `#` or `.`, at id or class.
+///
+/// ```markdown
+/// > | *a*{#b}
+///          ^
+/// ```
+pub fn shorthand_before(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(byte) if is_shorthand(byte)) {
+        tokenizer.enter(tokenizer.tokenize_state.token_1.clone());
+        tokenizer.consume();
+        State::Next(StateName::AttributesShorthandInside)
+    } else {
+        tokenizer.tokenize_state.token_1 = Name::Data;
+        State::Nok
+    }
+}
+
+/// In id or class.
+///
+/// ```markdown
+/// > | *a*{#bc}
+///           ^
+/// ```
+pub fn shorthand_inside(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(byte) if is_shorthand(byte)) {
+        tokenizer.consume();
+        State::Next(StateName::AttributesShorthandInside)
+    } else {
+        tokenizer.exit(tokenizer.tokenize_state.token_1.clone());
+        tokenizer.tokenize_state.token_1 = Name::Data;
+        State::Retry(StateName::AttributesAttributeAfter)
+    }
+}
+
+/// In name.
+///
+/// ```markdown
+/// > | *a*{bc=d}
+///          ^
+/// ```
+pub fn name_inside(tokenizer: &mut Tokenizer) -> State {
+    if matches!(
+        tokenizer.current,
+        Some(b'-' | b'.' | b':' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z')
+    ) {
+        tokenizer.consume();
+        State::Next(StateName::AttributesNameInside)
+    } else {
+        tokenizer.exit(Name::AttributesName);
+        State::Retry(StateName::AttributesNameAfter)
+    }
+}
+
+/// After name, at `=`.
+///
+/// ```markdown
+/// > | *a*{b=c}
+///          ^
+/// ```
+pub fn name_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'=') {
+        tokenizer.enter(Name::AttributesInitializerMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AttributesInitializerMarker);
+        State::Next(StateName::AttributesValueBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `=`, at value.
+///
+/// ```markdown
+/// > | *a*{b=c}
+///           ^
+/// ```
+pub fn value_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte @ (b'"' | b'\'')) => {
+            tokenizer.tokenize_state.marker = byte;
+            tokenizer.enter(Name::AttributesValueMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AttributesValueMarker);
+            State::Next(StateName::AttributesValueQuotedBefore)
+        }
+        Some(byte) if is_unquoted(byte) => {
+            tokenizer.enter(Name::AttributesValue);
+            tokenizer.consume();
+            State::Next(StateName::AttributesValueUnquotedInside)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// After opening quote, at quoted value or closing quote.
+///
+/// ```markdown
+/// > | *a*{b="c"}
+///            ^
+/// ```
+pub fn value_quoted_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.tokenize_state.marker = 0;
+            State::Nok
+        }
+        Some(byte) if byte == tokenizer.tokenize_state.marker => {
+            tokenizer.tokenize_state.marker = 0;
+            tokenizer.enter(Name::AttributesValueMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AttributesValueMarker);
+            State::Next(StateName::AttributesAttributeAfter)
+        }
+        Some(_) => {
+            tokenizer.enter(Name::AttributesValue);
+            State::Retry(StateName::AttributesValueQuotedInside)
+        }
+    }
+}
+
+/// In quoted value.
+///
+/// ```markdown
+/// > | *a*{b="c"}
+///            ^
+/// ```
+pub fn value_quoted_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.tokenize_state.marker = 0;
+            State::Nok
+        }
+        Some(byte) if byte == tokenizer.tokenize_state.marker => {
+            tokenizer.exit(Name::AttributesValue);
+            State::Retry(StateName::AttributesValueQuotedBefore)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::AttributesValueQuotedInside)
+        }
+    }
+}
+
+/// In unquoted value.
+///
+/// ```markdown
+/// > | *a*{b=cd}
+///            ^
+/// ```
+pub fn value_unquoted_inside(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(byte) if is_unquoted(byte)) {
+        tokenizer.consume();
+        State::Next(StateName::AttributesValueUnquotedInside)
+    } else {
+        tokenizer.exit(Name::AttributesValue);
+        State::Retry(StateName::AttributesAttributeAfter)
+    }
+}
+
+/// After an attribute.
+///
+/// ```markdown
+/// > | *a*{#b .c}
+///           ^  ^
+/// ```
+pub fn attribute_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\t' | b' ') => {
+            tokenizer.attempt(
+                State::Next(StateName::AttributesWhitespaceAfter),
+                State::Nok,
+            );
+            State::Retry(space_or_tab(tokenizer))
+        }
+        Some(b'}') => State::Retry(StateName::AttributesEnd),
+        _ => State::Nok,
+    }
+}
+
+/// After whitespace after an attribute, at another attribute or `}`.
+///
+/// ```markdown
+/// > | *a*{#b .c }
+///            ^  ^
+/// ```
+pub fn whitespace_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'}') {
+        State::Retry(StateName::AttributesEnd)
+    } else {
+        State::Retry(StateName::AttributesAttributeBefore)
+    }
+}
+
+/// At `}`.
+///
+/// ```markdown
+/// > | *a*{.b}
+///           ^
+/// ```
+pub fn end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::AttributesMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::AttributesMarker);
+    tokenizer.exit(Name::Attributes);
+    State::Ok
+}
+
+/// Resolve attributes.
+///
+/// This runs on all events, once everything is parsed, as attributes can
+/// only be matched to what they apply to when the surrounding flow and text
+/// are known.
+/// Attributes that do not apply to something are turned into data.
+/// Whitespace before attributes that apply to a paragraph or heading is
+/// turned into [`SpaceOrTab`][Name::SpaceOrTab].
+pub fn resolve(events: &mut Vec<Event>, bytes: &[u8]) {
+    let mut map = EditMap::new();
+    let mut index = 0;
+
+    while index < events.len() {
+        if events[index].kind == Kind::Enter && events[index].name == Name::Attributes {
+            let enter = index;
+            let exit = skip::to(events, enter + 1, &[Name::Attributes]);
+            // Attributes directly after a span apply to that span.
+            let mut keep = enter > 0
+                && events[enter - 1].kind == Kind::Exit
+                && ATTRIBUTES_SPANS.contains(&events[enter - 1].name);
+
+            // Attributes after whitespace at the end of a paragraph or
+            // heading apply to it.
+            if !keep && enter > 0 && events[enter - 1].name == Name::Data {
+                let previous = &events[enter - 1];
+                let after = skip::opt(events, exit + 1, &[Name::SpaceOrTab]);
+                let start = events[enter - 2].point.index;
+                let end = previous.point.index;
+                let mut whitespace_start = end;
+
+                while whitespace_start > start
+                    && matches!(bytes[whitespace_start - 1], b'\t' | b' ')
+                {
+                    whitespace_start -= 1;
+                }
+
+                if whitespace_start != end
+                    && after < events.len()
+                    && events[after].kind == Kind::Exit
+                    && matches!(
+                        events[after].name,
+                        Name::HeadingAtxText | Name::HeadingSetextText | Name::Paragraph
+                    )
+                {
+                    let mut add = vec![];
+                    let mut point = events[enter - 2].point.clone();
+
+                    if whitespace_start != start {
+                        add.push(Event {
+                            kind: Kind::Enter,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                        point = point.shift_to(bytes, whitespace_start);
+                        add.push(Event {
+                            kind: Kind::Exit,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                    }
+
+                    add.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::SpaceOrTab,
+                        point,
+                        link: None,
+                    });
+                    add.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::SpaceOrTab,
+                        point: previous.point.clone(),
+                        link: None,
+                    });
+
+                    map.add(enter - 2, 2, add);
+                    keep = true;
+                }
+            }
+
+            if !keep {
+                map.add(
+                    enter,
+                    exit - enter + 1,
+                    vec![
+                        Event {
+                            kind: Kind::Enter,
+                            name: Name::Data,
+                            point: events[enter].point.clone(),
+                            link: None,
+                        },
+                        Event {
+                            kind: Kind::Exit,
+                            name: Name::Data,
+                            point: events[exit].point.clone(),
+                            link: None,
+                        },
+                    ],
+                );
+            }
+
+            index = exit;
+        }
+
+        index += 1;
+    }
+
+    map.consume(events);
+}
+
+/// Check whether a byte can occur in an id or class.
+fn is_shorthand(byte: u8) -> bool {
+    matches!(byte, b'-' | b':' | b'_') || byte.is_ascii_alphanumeric()
+}
+
+/// Check whether a byte can occur in an unquoted value.
+fn is_unquoted(byte: u8) -> bool {
+    !matches!(
+        byte,
+        b'\t' | b'\n' | b' ' | b'"' | b'\'' | b'<' | b'=' | b'>' | b'`' | b'{' | b'}'
+    )
+}
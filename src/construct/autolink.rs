@@ -25,6 +25,10 @@
 //! The maximum allowed size of a domain is `63` (inclusive), which is defined
 //! in [`AUTOLINK_DOMAIN_SIZE_MAX`][autolink_domain_size_max].
 //!
+//! The email and protocol forms can be turned off separately, with
+//! `autolink_email` and `autolink_protocol` in
+//! [`Constructs`][crate::Constructs].
+//!
 //! The grammar for autolinks is quite strict and prohibits the use of ASCII control
 //! characters or spaces.
 //! To use non-ascii characters and otherwise impossible characters in URLs,
@@ -135,7 +139,12 @@ use crate::util::constant::{AUTOLINK_DOMAIN_SIZE_MAX, AUTOLINK_SCHEME_SIZE_MAX};
 ///      ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.autolink && tokenizer.current == Some(b'<') {
+    let constructs = &tokenizer.parse_state.options.constructs;
+
+    if constructs.autolink
+        && (constructs.autolink_email || constructs.autolink_protocol)
+        && tokenizer.current == Some(b'<')
+    {
         tokenizer.enter(Name::Autolink);
         tokenizer.enter(Name::AutolinkMarker);
         tokenizer.consume();
@@ -197,8 +206,26 @@ pub fn scheme_or_email_atext(tokenizer: &mut Tokenizer) -> State {
 pub fn scheme_inside_or_email_atext(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         Some(b':') => {
-            tokenizer.consume();
+            let size = tokenizer.tokenize_state.size;
             tokenizer.tokenize_state.size = 0;
+
+            if !tokenizer.parse_state.options.constructs.autolink_protocol {
+                return State::Nok;
+            }
+
+            if let Some(allowlist) = &tokenizer.parse_state.options.autolink_scheme_allowlist {
+                let end = tokenizer.point.index;
+                let scheme = &tokenizer.parse_state.bytes[end - size..end];
+
+                if !allowlist
+                    .iter()
+                    .any(|allowed| allowed.as_bytes().eq_ignore_ascii_case(scheme))
+                {
+                    return State::Nok;
+                }
+            }
+
+            tokenizer.consume();
             State::Next(StateName::AutolinkUrlInside)
         }
         // ASCII alphanumeric and `+`, `-`, and `.`.
@@ -249,7 +276,7 @@ pub fn url_inside(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn email_atext(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        Some(b'@') => {
+        Some(b'@') if tokenizer.parse_state.options.constructs.autolink_email => {
             tokenizer.consume();
             State::Next(StateName::AutolinkEmailAtSignOrDot)
         }
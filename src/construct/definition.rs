@@ -38,6 +38,9 @@
 //! [a]
 //! ```
 //!
+//! Pass `definition_conflict` in [`ParseOptions`][crate::ParseOptions] to
+//! prefer the last definition instead.
+//!
 //! Importantly, while labels *can* include [string][] content (character
 //! escapes and character references), these are not considered when matching.
 //! To illustrate, neither definition matches the reference:
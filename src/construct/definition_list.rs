@@ -0,0 +1,343 @@
+//! Definition list occurs in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Definition list forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: must directly follow a paragraph (the term) or other details.
+//! ; Restriction: the line must not be blank after the prefix.
+//! description_details_start ::= ':' 1*space_or_tab
+//!
+//! ; Restriction: blank line allowed.
+//! description_details_cont ::= 4(space_or_tab)
+//! ```
+//!
+//! Further lines that are not prefixed with `description_details_cont` cause
+//! the details to be exited, except when those lines are lazy continuation or
+//! blank.
+//!
+//! Definition lists are an extension from
+//! [PHP Markdown Extra][php_markdown_extra].
+//! A paragraph directly followed by one or more details is a term.
+//! Each line of a term is not a separate term: the whole paragraph is one
+//! term.
+//! There cannot be a blank line between a term and its first details, but
+//! there can be blank lines between details, and between details and a next
+//! term.
+//! As details are containers (like block quotes and list items), they can
+//! contain further blocks, when indented with 4 spaces (or a tab), and their
+//! paragraphs can be lazy:
+//!
+//! ```markdown
+//! Mercury
+//! : The closest planet to the sun.
+//!
+//!     It has no moons.
+//! : A messenger of the
+//! gods.
+//! ```
+//!
+//! Details that do not follow a paragraph or other details are not details:
+//! they are paragraphs that start with `:`.
+//!
+//! ## HTML
+//!
+//! Definition list relates to the `<dl>`, `<dt>`, and `<dd>` elements in
+//! HTML.
+//! See [*§ 4.4.9 The `dl` element*][html_dl],
+//! [*§ 4.4.10 The `dt` element*][html_dt], and
+//! [*§ 4.4.11 The `dd` element*][html_dd] in the HTML spec for more info.
+//!
+//! Like lists, definition lists are either tight or loose.
+//! They are loose when there are blank lines between details, or between
+//! blocks in details, in which case the paragraphs in details are wrapped in
+//! `<p>` elements.
+//!
+//! ## Recommendation
+//!
+//! Use a single space after a marker.
+//! Never use lazy continuation.
+//!
+//! ## Tokens
+//!
+//! *   [`DescriptionDetails`][Name::DescriptionDetails]
+//! *   [`DescriptionDetailsMarker`][Name::DescriptionDetailsMarker]
+//! *   [`DescriptionDetailsPrefix`][Name::DescriptionDetailsPrefix]
+//! *   [`DescriptionList`][Name::DescriptionList]
+//! *   [`DescriptionTerm`][Name::DescriptionTerm]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*§ Definition Lists* in PHP Markdown Extra][php_markdown_extra]
+//! *   [`micromark-extension-definition-list`](https://github.com/wataru-chocola/micromark-extension-definition-list)
+//!
+//! [document]: crate::construct::document
+//! [php_markdown_extra]: https://michelf.ca/projects/php-markdown/extra/#def-list
+//! [html_dl]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-dl-element
+//! [html_dt]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-dt-element
+//! [html_dd]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-dd-element
+
+use crate::construct::partial_space_or_tab::space_or_tab_min_max;
+use crate::event::{Event, Kind, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::{Container, Tokenizer};
+use crate::util::{constant::TAB_SIZE, skip};
+use alloc::{vec, vec::Vec};
+
+/// Start of description details.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.definition_list && allowed(tokenizer) {
+        tokenizer.enter(Name::DescriptionDetails);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DefinitionListBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                1,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::DefinitionListBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// At `:`, after optional whitespace.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DescriptionDetailsPrefix);
+        tokenizer.enter(Name::DescriptionDetailsMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::DescriptionDetailsMarker);
+        State::Next(StateName::DefinitionListMarkerAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `:`, at whitespace.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///      ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::DefinitionListWhitespaceAfter),
+        State::Nok,
+    );
+    // Any whitespace after the marker is eaten, forming indented code is not
+    // possible.
+    State::Retry(space_or_tab_min_max(tokenizer, 1, usize::MAX))
+}
+
+/// After whitespace.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///       ^
+/// ```
+pub fn whitespace_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        State::Nok
+    } else {
+        tokenizer.exit(Name::DescriptionDetailsPrefix);
+        // Register on the flow tokenizer, so that this runs after content is
+        // resolved into paragraphs.
+        tokenizer
+            .tokenize_state
+            .document_child
+            .as_mut()
+            .unwrap()
+            .register_resolver(ResolveName::DefinitionList);
+        State::Ok
+    }
+}
+
+/// Start of description details continuation.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |     c
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.check(
+        State::Next(StateName::DefinitionListContBlank),
+        State::Next(StateName::DefinitionListContFilled),
+    );
+    State::Retry(StateName::BlankLineStart)
+}
+
+/// Start of description details continuation, at a blank line.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > | ␠␠␊
+///     ^
+/// ```
+pub fn cont_blank(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        State::Retry(space_or_tab_min_max(tokenizer, 0, TAB_SIZE))
+    } else {
+        State::Ok
+    }
+}
+
+/// Start of description details continuation, at a filled line.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |     c
+///     ^
+/// ```
+pub fn cont_filled(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        // Consume exactly `TAB_SIZE`.
+        State::Retry(space_or_tab_min_max(tokenizer, TAB_SIZE, TAB_SIZE))
+    } else {
+        State::Nok
+    }
+}
+
+/// Group details, and the paragraphs before them, into lists.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    // Lists, as the index of their first and last event.
+    let mut lists: Vec<(usize, usize)> = vec![];
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if event.kind == Kind::Enter && event.name == Name::DescriptionDetails {
+            // Find the matching exit.
+            let mut end = index + 1;
+            let mut depth = 1;
+            loop {
+                let event = &tokenizer.events[end];
+                if event.name == Name::DescriptionDetails {
+                    if event.kind == Kind::Enter {
+                        depth += 1;
+                    } else {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                }
+                end += 1;
+            }
+
+            let mut start = index;
+            let mut previous = previous(&tokenizer.events, index);
+
+            // A paragraph before: that’s a term.
+            if let Some(exit) = previous {
+                if tokenizer.events[exit].kind == Kind::Exit
+                    && tokenizer.events[exit].name == Name::Paragraph
+                {
+                    start = skip::to_back(&tokenizer.events, exit - 1, &[Name::Paragraph]);
+                    tokenizer.events[start].name = Name::DescriptionTerm;
+                    tokenizer.events[exit].name = Name::DescriptionTerm;
+                    previous = self::previous(&tokenizer.events, start);
+                }
+            }
+
+            // Details before: this belongs to that list.
+            if let Some(list) = lists.iter_mut().rev().find(|list| Some(list.1) == previous) {
+                list.1 = end;
+            } else {
+                lists.push((start, end));
+            }
+        }
+
+        index += 1;
+    }
+
+    // Inject events.
+    for (start, end) in lists {
+        let mut list_start = tokenizer.events[start].clone();
+        let mut list_end = tokenizer.events[end].clone();
+        list_start.name = Name::DescriptionList;
+        list_end.name = Name::DescriptionList;
+        tokenizer.map.add(start, 0, vec![list_start]);
+        tokenizer.map.add(end + 1, 0, vec![list_end]);
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Whether details can start here: after a paragraph (the term), or after
+/// other details.
+fn allowed(tokenizer: &Tokenizer) -> bool {
+    let stack = &tokenizer.tokenize_state.document_container_stack;
+    let continued = tokenizer.tokenize_state.document_continued;
+
+    // The container at this level that was not continued is swapped to the
+    // end of the stack.
+    if continued + 1 < stack.len() {
+        stack[stack.len() - 1].kind == Container::DescriptionDetails
+    }
+    // Otherwise, check whether the flow on the previous line can be a
+    // paragraph.
+    else {
+        let child = tokenizer.tokenize_state.document_child.as_ref().unwrap();
+
+        if child.stack.contains(&Name::GfmTableHead) {
+            return true;
+        }
+
+        !child.events.is_empty() && {
+            let before = skip::opt_back(&child.events, child.events.len() - 1, &[Name::LineEnding]);
+            child.events[before].kind == Kind::Exit && child.events[before].name == Name::Content
+        }
+    }
+}
+
+/// Get the index of the event before `index`, skipping whitespace and line
+/// endings.
+fn previous(events: &[Event], index: usize) -> Option<usize> {
+    if index == 0 {
+        None
+    } else {
+        Some(skip::opt_back(
+            events,
+            index - 1,
+            &[
+                Name::BlankLineEnding,
+                Name::BlockQuotePrefix,
+                Name::LineEnding,
+                Name::SpaceOrTab,
+            ],
+        ))
+    }
+}
@@ -8,6 +8,7 @@
 //! *   [Block quote][crate::construct::block_quote]
 //! *   [List item][crate::construct::list_item]
 //! *   [GFM: Footnote definition][crate::construct::gfm_footnote_definition]
+//! *   [Definition list][crate::construct::definition_list]
 
 use crate::event::{Content, Event, Kind, Link, Name};
 use crate::state::{Name as StateName, State};
@@ -102,6 +103,7 @@ pub fn container_existing_before(tokenizer: &mut Tokenizer) -> State {
             Container::BlockQuote => StateName::BlockQuoteContStart,
             Container::GfmFootnoteDefinition => StateName::GfmFootnoteDefinitionContStart,
             Container::ListItem => StateName::ListItemContStart,
+            Container::DescriptionDetails => StateName::DefinitionListContStart,
         };
 
         tokenizer.attempt(
@@ -233,7 +235,33 @@ pub fn container_new_before_not_list(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -> State {
-    // It wasn’t a new block quote, list item, or footnote definition.
+    // Description details?
+    // We replace the empty footnote definition container for this new
+    // description details one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::DescriptionDetails,
+        blank_initial: false,
+        size: 0,
+    };
+
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotDefinitionList),
+    );
+    State::Retry(StateName::DefinitionListStart)
+}
+
+/// At new container, but not a block quote, list item, footnote definition,
+/// or description details.
+//
+/// ```markdown
+/// > | a
+///     ^
+/// ```
+pub fn container_new_before_not_definition_list(tokenizer: &mut Tokenizer) -> State {
+    // It wasn’t a new block quote, list item, footnote definition, or
+    // description details.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Drop what was in the middle.
     tokenizer
@@ -253,7 +281,8 @@ pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -
 ///       ^
 /// ```
 pub fn container_new_after(tokenizer: &mut Tokenizer) -> State {
-    // It was a new block quote, list item, or footnote definition.
+    // It was a new block quote, list item, footnote definition, or description
+    // details.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Take the new container.
     let container = tokenizer
@@ -486,7 +515,13 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), Strin
             .take()
             .unwrap_or(State::Next(StateName::FlowStart));
 
-        child.flush(state, false)?;
+        let mut result = child.flush(state, false)?;
+        // Abbreviation definitions are collected while parsing flow, not when
+        // resolving, so keep them.
+        tokenizer
+            .tokenize_state
+            .abbreviations
+            .append(&mut result.abbreviations);
     }
 
     if !stack_close.is_empty() {
@@ -500,6 +535,7 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), Strin
                 Container::BlockQuote => Name::BlockQuote,
                 Container::GfmFootnoteDefinition => Name::GfmFootnoteDefinition,
                 Container::ListItem => Name::ListItem,
+                Container::DescriptionDetails => Name::DescriptionDetails,
             };
 
             exits.push(Event {
@@ -628,4 +664,9 @@ fn resolve(tokenizer: &mut Tokenizer) {
         .tokenize_state
         .definitions
         .append(&mut child.tokenize_state.definitions.split_off(0));
+
+    tokenizer
+        .tokenize_state
+        .abbreviations
+        .append(&mut child.tokenize_state.abbreviations.split_off(0));
 }
@@ -10,6 +10,7 @@
 //!
 //! The constructs found in flow are:
 //!
+//! *   [Abbreviation definition][crate::construct::abbreviation_definition]
 //! *   [Blank line][crate::construct::blank_line]
 //! *   [Code (indented)][crate::construct::code_indented]
 //! *   [Heading (atx)][crate::construct::heading_atx]
@@ -56,7 +57,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
         Some(b'*' | b'_') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
+                State::Next(StateName::FlowBeforeAbbreviationDefinition),
             );
             State::Retry(StateName::ThematicBreakStart)
         }
@@ -224,11 +225,25 @@ pub fn before_mdx_expression(tokenizer: &mut Tokenizer) -> State {
 pub fn before_gfm_table(tokenizer: &mut Tokenizer) -> State {
     tokenizer.attempt(
         State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeContent),
+        State::Next(StateName::FlowBeforeAbbreviationDefinition),
     );
     State::Retry(StateName::GfmTableStart)
 }
 
+/// At abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn before_abbreviation_definition(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::FlowAfter),
+        State::Next(StateName::FlowBeforeContent),
+    );
+    State::Retry(StateName::AbbreviationDefinitionStart)
+}
+
 /// At content.
 ///
 /// ```markdown
@@ -15,7 +15,8 @@
 //!
 //! ; Restriction: the code before must be `http_autolink_before`.
 //! ; Restriction: the code after the protocol must be `http_autolink_protocol_after`.
-//! http_autolink ::= ('h' | 'H') 2('t' | 'T') ('p' | 'P') ['s' | 'S'] ':' 2'/' domain [path]
+//! ; Restriction: schemes in `gfm_autolink_literal_schemes` can be used instead of `http` or `https`.
+//! http_autolink ::= ('h' | 'H') 2('t' | 'T') ('p' | 'P') ['s' | 'S'] ':' 2'/' (domain | ipv6_host) [path]
 //! http_autolink_before ::= byte - ascii_alpha
 //! http_autolink_protocol_after ::= byte - eof - eol - ascii_control - unicode_whitespace - unicode_punctuation
 //!
@@ -24,6 +25,9 @@
 //! email_autolink ::= 1*('+' | '-' | '.' | '_' | ascii_alphanumeric) '@' 1*(1*label_segment label_dot_cont) 1*label_segment
 //! email_autolink_before ::= byte - ascii_alpha - '/'
 //!
+//! ; Restriction: at least one `:` must occur.
+//! ipv6_host ::= '[' 1*(ascii_hexdigit | '.' | ':') ']'
+//!
 //! ; Restriction: `_` may not occur in the last two domain parts.
 //! domain ::= 1*(url_ampt_cont | domain_punct_cont | '-' | byte - eof - ascii_control - unicode_whitespace - unicode_punctuation)
 //! ; Restriction: must not be followed by `punct`.
@@ -120,6 +124,11 @@
 //! *   [GFM autolink extension (`www.` part): the word `www` matches](https://github.com/github/cmark-gfm/issues/279)\
 //!     present here for consistency
 //!
+//! Unlike on GitHub, protocol literals with an IPv6 host in brackets (such as
+//! `http://[::1]:8080/`) are recognized here, instead of stopping at the
+//! `[`.
+//! The brackets are percent-encoded in the `href`, as is done for other URLs.
+//!
 //! ## Tokens
 //!
 //! *   [`GfmAutolinkLiteralEmail`][Name::GfmAutolinkLiteralEmail]
@@ -160,14 +169,22 @@ use alloc::vec::Vec;
 ///     ^
 /// ```
 pub fn protocol_start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer
-        .parse_state
-        .options
-        .constructs
-        .gfm_autolink_literal &&
-        matches!(tokenizer.current, Some(b'H' | b'h'))
-            // Source: <https://github.com/github/cmark-gfm/blob/ef1cfcb/extensions/autolink.c#L214>.
-            && !matches!(tokenizer.previous, Some(b'A'..=b'Z' | b'a'..=b'z'))
+    let options = &tokenizer.parse_state.options;
+    let scheme_start = match tokenizer.current {
+        Some(b'H' | b'h') => true,
+        Some(byte) => options.gfm_autolink_literal_schemes.iter().any(|scheme| {
+            scheme
+                .as_bytes()
+                .first()
+                .map_or(false, |first| first.eq_ignore_ascii_case(&byte))
+        }),
+        None => false,
+    };
+
+    if options.constructs.gfm_autolink_literal
+        && scheme_start
+        // Source: <https://github.com/github/cmark-gfm/blob/ef1cfcb/extensions/autolink.c#L214>.
+        && !matches!(tokenizer.previous, Some(b'A'..=b'Z' | b'a'..=b'z'))
     {
         tokenizer.enter(Name::GfmAutolinkLiteralProtocol);
         tokenizer.attempt(
@@ -175,7 +192,7 @@ pub fn protocol_start(tokenizer: &mut Tokenizer) -> State {
             State::Nok,
         );
         tokenizer.attempt(
-            State::Next(StateName::GfmAutolinkLiteralDomainInside),
+            State::Next(StateName::GfmAutolinkLiteralProtocolHostStart),
             State::Nok,
         );
         tokenizer.tokenize_state.start = tokenizer.point.index;
@@ -205,8 +222,14 @@ pub fn protocol_after(tokenizer: &mut Tokenizer) -> State {
 pub fn protocol_prefix_inside(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         Some(b'A'..=b'Z' | b'a'..=b'z')
-            // `5` is size of `https`
-            if tokenizer.point.index - tokenizer.tokenize_state.start < 5 =>
+            // `5` is size of `https`, other schemes can be longer.
+            if tokenizer.point.index - tokenizer.tokenize_state.start
+                < tokenizer
+                    .parse_state
+                    .options
+                    .gfm_autolink_literal_schemes
+                    .iter()
+                    .fold(5, |size, scheme| size.max(scheme.len())) =>
         {
             tokenizer.consume();
             State::Next(StateName::GfmAutolinkLiteralProtocolPrefixInside)
@@ -221,7 +244,15 @@ pub fn protocol_prefix_inside(tokenizer: &mut Tokenizer) -> State {
 
             tokenizer.tokenize_state.start = 0;
 
-            if name == "http" || name == "https" {
+            if name == "http"
+                || name == "https"
+                || tokenizer
+                    .parse_state
+                    .options
+                    .gfm_autolink_literal_schemes
+                    .iter()
+                    .any(|scheme| scheme.eq_ignore_ascii_case(&name))
+            {
                 tokenizer.consume();
                 State::Next(StateName::GfmAutolinkLiteralProtocolSlashesInside)
             } else {
@@ -257,6 +288,53 @@ pub fn protocol_slashes_inside(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// After protocol slashes, at host.
+///
+/// ```markdown
+/// > | https://example.com/a?b#c
+///             ^
+/// > | http://[::1]:8080/a
+///            ^
+/// ```
+pub fn protocol_host_start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.consume();
+        State::Next(StateName::GfmAutolinkLiteralProtocolIpv6Inside)
+    } else {
+        State::Retry(StateName::GfmAutolinkLiteralDomainInside)
+    }
+}
+
+/// In IPv6 host.
+///
+/// ```markdown
+/// > | http://[::1]:8080/a
+///             ^^^^
+/// ```
+pub fn protocol_ipv6_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        // At least one colon must be seen.
+        Some(b']') if tokenizer.tokenize_state.seen => {
+            tokenizer.tokenize_state.seen = false;
+            tokenizer.consume();
+            State::Next(StateName::GfmAutolinkLiteralPathInside)
+        }
+        Some(b':') => {
+            tokenizer.tokenize_state.seen = true;
+            tokenizer.consume();
+            State::Next(StateName::GfmAutolinkLiteralProtocolIpv6Inside)
+        }
+        Some(b'.' | b'0'..=b'9' | b'A'..=b'F' | b'a'..=b'f') => {
+            tokenizer.consume();
+            State::Next(StateName::GfmAutolinkLiteralProtocolIpv6Inside)
+        }
+        _ => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+    }
+}
+
 /// Start of www autolink literal.
 ///
 /// ```markdown
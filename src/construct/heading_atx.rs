@@ -223,8 +223,12 @@ pub fn data(tokenizer: &mut Tokenizer) -> State {
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     let mut index = 0;
     let mut heading_inside = false;
+    let mut opening_seen = false;
     let mut data_start: Option<usize> = None;
     let mut data_end: Option<usize> = None;
+    // End of the content before the last sequence, in case that sequence
+    // turns out to be the closing sequence.
+    let mut data_end_before_sequence: Option<usize> = None;
 
     while index < tokenizer.events.len() {
         let event = &tokenizer.events[index];
@@ -233,9 +237,23 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
             if event.kind == Kind::Enter {
                 heading_inside = true;
             } else {
-                if let Some(start) = data_start {
-                    // If `start` is some, `end` is too.
-                    let end = data_end.unwrap();
+                // The last sequence is the closing sequence.
+                let end = match data_end {
+                    Some(end) if tokenizer.events[end].name == Name::HeadingAtxSequence => {
+                        data_end_before_sequence
+                    }
+                    end => end,
+                };
+
+                if let (Some(start), Some(end)) = (data_start, end) {
+                    // Sequences that are not the closing sequence are text.
+                    tokenizer.events[start].name = Name::Data;
+                    tokenizer.events[start].link = Some(Link {
+                        previous: None,
+                        next: None,
+                        content: Content::Text,
+                    });
+                    tokenizer.events[end].name = Name::Data;
 
                     tokenizer.map.add(
                         start,
@@ -264,14 +282,26 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                 }
 
                 heading_inside = false;
+                opening_seen = false;
                 data_start = None;
                 data_end = None;
+                data_end_before_sequence = None;
             }
-        } else if heading_inside && event.name == Name::Data {
-            if event.kind == Kind::Enter {
+        } else if heading_inside
+            && (event.name == Name::Data || event.name == Name::HeadingAtxSequence)
+        {
+            if event.name == Name::HeadingAtxSequence && !opening_seen {
+                if event.kind == Kind::Exit {
+                    opening_seen = true;
+                }
+            } else if event.kind == Kind::Enter {
                 if data_start.is_none() {
                     data_start = Some(index);
                 }
+
+                if event.name == Name::HeadingAtxSequence {
+                    data_end_before_sequence = data_end;
+                }
             } else {
                 data_end = Some(index);
             }
@@ -30,6 +30,14 @@
 //! Which one forms depends on whether there is text directly in fron of the
 //! sequence.
 //!
+//! Definitions are not text: when only definitions (or another heading) are
+//! directly in front of an underline, there is nothing to turn into a
+//! heading.
+//! An underline of three or more dashes then forms a thematic break, as in
+//! `CommonMark`.
+//! Other underlines, such as ones of equals signs, are text instead, which
+//! is a paragraph (or the start of a paragraph, when text follows).
+//!
 //! > 🏛 **Background**: the word *setext* originates from a small markup
 //! > language by Ian Feldman from 1991.
 //! > See [*§ Setext* on Wikipedia][wiki_setext] for more info.
@@ -76,7 +84,12 @@ use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
-use crate::util::{constant::TAB_SIZE, skip};
+use crate::util::{
+    char::cjk,
+    constant::{TAB_SIZE, THEMATIC_BREAK_MARKER_COUNT_MIN},
+    skip,
+    slice::Slice,
+};
 use alloc::vec;
 
 /// At start of heading (setext) underline.
@@ -171,7 +184,10 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn after(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        None | Some(b'\n') => {
+        None | Some(b'\n')
+            if !tokenizer.parse_state.options.setext_underline_match_width
+                || width_matches(tokenizer) =>
+        {
             // Feel free to interrupt.
             tokenizer.interrupt = false;
             tokenizer.register_resolver(ResolveName::HeadingSetext);
@@ -182,6 +198,49 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// Check whether the sequence of the underline is about as wide as the last
+/// line of text before it.
+///
+/// ```markdown
+///   | aa
+/// > | ==
+///     ^^
+/// ```
+fn width_matches(tokenizer: &Tokenizer) -> bool {
+    let events = &tokenizer.events;
+    let sequence_exit = skip::to_back(
+        events,
+        events.len() - 1,
+        &[Name::HeadingSetextUnderlineSequence],
+    );
+    let underline_enter = skip::to_back(events, sequence_exit, &[Name::HeadingSetextUnderline]);
+    let content_exit = skip::opt_back(
+        events,
+        underline_enter - 1,
+        &[Name::LineEnding, Name::SpaceOrTab],
+    );
+
+    // Only content (the text of a potential heading) has a width.
+    if events[content_exit].name != Name::Content {
+        return true;
+    }
+
+    let size = events[sequence_exit].point.index - events[sequence_exit - 1].point.index;
+    let text = Slice::from_indices(
+        tokenizer.parse_state.bytes,
+        events[content_exit - 1].point.index,
+        events[content_exit].point.index,
+    );
+    let width = text
+        .as_str()
+        .trim()
+        .chars()
+        .map(|char| if cjk(char) { 2 } else { 1 })
+        .sum::<usize>();
+
+    size + 1 >= width && size <= width + 1
+}
+
 /// Resolve heading (setext).
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     let mut enter = skip::to(&tokenizer.events, 0, &[Name::HeadingSetextUnderline]);
@@ -220,10 +279,45 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
             heading_exit.name = Name::HeadingSetext;
             tokenizer.map.add(exit + 1, 0, vec![heading_exit]);
         } else {
-            // There’s a following paragraph, move this underline inside it.
-            if exit + 3 < tokenizer.events.len()
+            let sequence = skip::to(
+                &tokenizer.events,
+                enter + 1,
+                &[Name::HeadingSetextUnderlineSequence],
+            );
+            let start = tokenizer.events[sequence].point.index;
+            let end = tokenizer.events[sequence + 1].point.index;
+            // Find paragraph after, on the next line.
+            let paragraph_enter_after = if exit + 1 < tokenizer.events.len()
                 && tokenizer.events[exit + 1].name == Name::LineEnding
-                && tokenizer.events[exit + 3].name == Name::Paragraph
+            {
+                skip::opt(
+                    &tokenizer.events,
+                    exit + 3,
+                    &[Name::SpaceOrTab, Name::BlockQuotePrefix],
+                )
+            } else {
+                tokenizer.events.len()
+            };
+
+            // There’s no paragraph (such as only definitions or another
+            // heading before): dashes can form a thematic break instead.
+            if tokenizer.parse_state.options.constructs.thematic_break
+                && tokenizer
+                    .parse_state
+                    .options
+                    .thematic_break_markers
+                    .contains(&'-')
+                && tokenizer.parse_state.bytes[start] == b'-'
+                && end - start >= THEMATIC_BREAK_MARKER_COUNT_MIN
+            {
+                tokenizer.events[enter].name = Name::ThematicBreak;
+                tokenizer.events[exit].name = Name::ThematicBreak;
+                tokenizer.events[sequence].name = Name::ThematicBreakSequence;
+                tokenizer.events[sequence + 1].name = Name::ThematicBreakSequence;
+            }
+            // There’s a following paragraph, move this underline inside it.
+            else if paragraph_enter_after < tokenizer.events.len()
+                && tokenizer.events[paragraph_enter_after].name == Name::Paragraph
             {
                 // Swap type, HeadingSetextUnderline:Enter -> Paragraph:Enter.
                 tokenizer.events[enter].name = Name::Paragraph;
@@ -235,14 +329,21 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                 tokenizer.events[exit + 1].point = tokenizer.events[enter].point.clone();
                 tokenizer.events[exit + 1].link = Some(Link {
                     previous: None,
-                    next: Some(exit + 4),
+                    next: Some(paragraph_enter_after + 1),
                     content: Content::Text,
                 });
-                tokenizer.events[exit + 4].link.as_mut().unwrap().previous = Some(exit + 1);
+                tokenizer.events[paragraph_enter_after + 1]
+                    .link
+                    .as_mut()
+                    .unwrap()
+                    .previous = Some(exit + 1);
                 // Remove *including* HeadingSetextUnderline:Exit, until the line ending.
                 tokenizer.map.add(enter + 1, exit - enter, vec![]);
                 // Remove old Paragraph:Enter.
-                tokenizer.map.add(exit + 3, 1, vec![]);
+                // Its name is changed too, so that a later underline, which
+                // turns this paragraph into a heading, finds the new enter.
+                tokenizer.events[paragraph_enter_after].name = Name::Data;
+                tokenizer.map.add(paragraph_enter_after, 1, vec![]);
             } else {
                 // Swap type.
                 tokenizer.events[enter].name = Name::Paragraph;
@@ -42,6 +42,9 @@
 //! [x][a&amp;b], [a\&b][]
 //! ```
 //!
+//! Use [`serialize_with`][crate::serialize_with] to get the text used for
+//! matching, or to interpret character references and escapes in it.
+//!
 //! When the resource or reference matches, the destination forms the `href`
 //! attribute in case of a [label start (link)][label_start_link], and an
 //! `src` attribute in case of a [label start (image)][label_start_image].
@@ -259,9 +262,10 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
     );
 
     // We don’t care about virtual spaces, so `indices` and `as_str` are fine.
-    let mut id = normalize_identifier(
+    let mut label = String::from(
         Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1).as_str(),
     );
+    let mut id = normalize_identifier(&label);
 
     // See if this matches a footnote definition.
     if start.kind == LabelKind::GfmFootnote {
@@ -275,9 +279,10 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
         new_id.push('^');
         new_id.push_str(&id);
         id = new_id;
+        label.insert(0, '^');
     }
 
-    let defined = tokenizer.parse_state.definitions.contains(&id);
+    let defined = defined(tokenizer, &id, &label);
 
     match tokenizer.current {
         // Resource (`[asd](fgh)`)?
@@ -590,25 +595,21 @@ pub fn reference_full_after(tokenizer: &mut Tokenizer) -> State {
     tokenizer.tokenize_state.token_2 = Name::Data;
     tokenizer.tokenize_state.token_3 = Name::Data;
 
-    if tokenizer
-        .parse_state
-        .definitions
-        // We don’t care about virtual spaces, so `as_str` is fine.
-        .contains(&normalize_identifier(
-            Slice::from_position(
-                tokenizer.parse_state.bytes,
-                &Position::from_exit_event(
-                    &tokenizer.events,
-                    skip::to_back(
-                        &tokenizer.events,
-                        tokenizer.events.len() - 1,
-                        &[Name::ReferenceString],
-                    ),
-                ),
-            )
-            .as_str(),
-        ))
-    {
+    // We don’t care about virtual spaces, so `as_str` is fine.
+    let slice = Slice::from_position(
+        tokenizer.parse_state.bytes,
+        &Position::from_exit_event(
+            &tokenizer.events,
+            skip::to_back(
+                &tokenizer.events,
+                tokenizer.events.len() - 1,
+                &[Name::ReferenceString],
+            ),
+        ),
+    );
+    let label = slice.as_str();
+
+    if defined(tokenizer, &normalize_identifier(label), label) {
         State::Ok
     } else {
         State::Nok
@@ -735,11 +736,17 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
             ],
         );
 
+        let has_caret = !caret.is_empty();
+
+        // Inner media are resolved first, so events may already be added at
+        // the start of this label: ours go before them.
+        tokenizer.map.add_before(label.start.1 + 1, 0, caret);
+
         // Empty events not allowed.
         // Though: if this was what looked like a footnote, but didn’t match,
         // it’s a link instead, and we need to inject the `^`.
-        if label.start.1 != label.end.0 || !caret.is_empty() {
-            tokenizer.map.add(
+        if label.start.1 != label.end.0 || has_caret {
+            tokenizer.map.add_before(
                 label.start.1 + 1,
                 0,
                 vec![Event {
@@ -761,10 +768,6 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
             );
         }
 
-        if !caret.is_empty() {
-            tokenizer.map.add(label.start.1 + 1, 0, caret);
-        }
-
         // Insert a label exit.
         tokenizer.map.add(
             label.end.0 + 4,
@@ -823,3 +826,43 @@ fn mark_as_data(tokenizer: &mut Tokenizer, events: &[LabelStart]) {
         index += 1;
     }
 }
+
+/// Check whether a reference is defined.
+///
+/// `id` is the normalized identifier, `label` the label as written.
+/// References are defined by definitions (in the document or external), or
+/// by the reference resolver.
+/// What the resolver returns is kept, so that compilers use the same
+/// definition, without calling the resolver again.
+fn defined(tokenizer: &mut Tokenizer, id: &str, label: &str) -> bool {
+    if tokenizer
+        .parse_state
+        .definitions
+        .iter()
+        .any(|definition| definition == id)
+        || tokenizer
+            .parse_state
+            .resolved_definitions
+            .iter()
+            .chain(tokenizer.tokenize_state.resolved_definitions.iter())
+            .any(|definition| definition.0 == id)
+    {
+        return true;
+    }
+
+    if let Some((destination, title)) = tokenizer
+        .parse_state
+        .options
+        .reference_resolver
+        .as_ref()
+        .and_then(|resolve| resolve(label))
+    {
+        tokenizer
+            .tokenize_state
+            .resolved_definitions
+            .push((id.into(), destination, title));
+        true
+    } else {
+        false
+    }
+}
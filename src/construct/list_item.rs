@@ -371,6 +371,9 @@ pub fn cont_filled(tokenizer: &mut Tokenizer) -> State {
 }
 
 /// Find adjacent list items with the same marker.
+///
+/// With `loose_list_marker_change`, adjacent items of the same kind (ordered
+/// or unordered) are merged instead.
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     let mut lists_wip: Vec<(u8, usize, usize, usize)> = vec![];
     let mut lists: Vec<(u8, usize, usize, usize)> = vec![];
@@ -383,7 +386,25 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
 
         if event.name == Name::ListItem {
             if event.kind == Kind::Enter {
-                let end = skip::opt(&tokenizer.events, index, &[Name::ListItem]) - 1;
+                // Find the matching exit.
+                // Note: `skip::opt` would also skip directly adjacent items,
+                // which occur when an item ends in unclosed fenced code.
+                let mut end = index + 1;
+                let mut depth = 1;
+                loop {
+                    let event = &tokenizer.events[end];
+                    if event.name == Name::ListItem {
+                        if event.kind == Kind::Enter {
+                            depth += 1;
+                        } else {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                    }
+                    end += 1;
+                }
                 let marker = skip::to(&tokenizer.events, index, &[Name::ListItemMarker]);
                 // Guaranteed to be a valid ASCII byte.
                 let marker = tokenizer.parse_state.bytes[tokenizer.events[marker].point.index];
@@ -406,7 +427,13 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                         ],
                     );
 
-                    if previous.0 == current.0 && previous.1 == current.1 && before == current.2 {
+                    let same_marker = if tokenizer.parse_state.options.loose_list_marker_change {
+                        is_ordered(previous.0) == is_ordered(current.0)
+                    } else {
+                        previous.0 == current.0
+                    };
+
+                    if same_marker && previous.1 == current.1 && before == current.2 {
                         let previous_mut = &mut lists_wip[list_index];
                         previous_mut.3 = current.3;
                         lists.append(&mut lists_wip.split_off(list_index + 1));
@@ -456,9 +483,10 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         let list_item = &lists[index];
         let mut list_start = tokenizer.events[list_item.2].clone();
         let mut list_end = tokenizer.events[list_item.3].clone();
-        let name = match list_item.0 {
-            b'.' | b')' => Name::ListOrdered,
-            _ => Name::ListUnordered,
+        let name = if is_ordered(list_item.0) {
+            Name::ListOrdered
+        } else {
+            Name::ListUnordered
         };
         list_start.name = name.clone();
         list_end.name = name;
@@ -472,3 +500,8 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
+
+/// Check whether a marker is used in ordered lists.
+fn is_ordered(marker: u8) -> bool {
+    matches!(marker, b'.' | b')')
+}
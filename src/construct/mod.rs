@@ -62,6 +62,10 @@
 //!
 //! The following constructs are extensions found in markdown:
 //!
+//! *   [abbreviation][]
+//! *   [abbreviation definition][abbreviation_definition]
+//! *   [attributes][]
+//! *   [definition list][definition_list]
 //! *   [frontmatter][]
 //! *   [gfm autolink literal][gfm_autolink_literal]
 //! *   [gfm footnote definition][gfm_footnote_definition]
@@ -148,7 +152,10 @@
 //!
 //! [bnf]: http://trevorjim.com/a-specification-for-markdown/
 
+pub mod abbreviation;
+pub mod abbreviation_definition;
 pub mod attention;
+pub mod attributes;
 pub mod autolink;
 pub mod blank_line;
 pub mod block_quote;
@@ -157,6 +164,7 @@ pub mod character_reference;
 pub mod code_indented;
 pub mod content;
 pub mod definition;
+pub mod definition_list;
 pub mod document;
 pub mod flow;
 pub mod frontmatter;
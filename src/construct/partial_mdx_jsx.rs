@@ -1038,8 +1038,11 @@ pub fn es_whitespace_start(tokenizer: &mut Tokenizer) -> State {
             State::Next(StateName::MdxJsxEsWhitespaceEolAfter)
         }
         _ => {
-            if kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
-                == CharacterKind::Whitespace
+            // Note: eof is also whitespace to `kind_after_index`, but cannot
+            // be consumed.
+            if tokenizer.current.is_some()
+                && kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
+                    == CharacterKind::Whitespace
             {
                 tokenizer.enter(Name::MdxJsxEsWhitespace);
                 State::Retry(StateName::MdxJsxEsWhitespaceInside)
@@ -1071,8 +1074,9 @@ pub fn es_whitespace_inside(tokenizer: &mut Tokenizer) -> State {
             State::Next(StateName::MdxJsxEsWhitespaceInside)
         }
         _ => {
-            if kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
-                == CharacterKind::Whitespace
+            if tokenizer.current.is_some()
+                && kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
+                    == CharacterKind::Whitespace
             {
                 tokenizer.consume();
                 State::Next(StateName::MdxJsxEsWhitespaceInside)
@@ -17,8 +17,10 @@
 //! In the case of text content, whitespace before a line ending that
 //! consistents solely of spaces, at least 2, forms a hard break (trailing).
 //!
-//! The minimum number of those spaces is defined in
-//! [`HARD_BREAK_PREFIX_SIZE_MIN`][hard_break_prefix_size_min].
+//! The minimum number of those spaces defaults to
+//! [`HARD_BREAK_PREFIX_SIZE_MIN`][hard_break_prefix_size_min], and can be
+//! configured with
+//! [`hard_break_spaces_min`][crate::ParseOptions::hard_break_spaces_min].
 //!
 //! It is also possible to create a hard break with a similar construct: a
 //! [hard break (escape)][hard_break_escape] is a backslash followed
@@ -59,10 +61,7 @@
 
 use crate::event::{Event, Kind, Name};
 use crate::tokenizer::Tokenizer;
-use crate::util::{
-    constant::HARD_BREAK_PREFIX_SIZE_MIN,
-    slice::{Position, Slice},
-};
+use crate::util::slice::{Position, Slice};
 use alloc::vec;
 
 /// Resolve whitespace.
@@ -117,7 +116,7 @@ fn trim_data(
         let diff = slice.bytes.len() - index;
         let name = if hard_break
             && spaces_only
-            && diff >= HARD_BREAK_PREFIX_SIZE_MIN
+            && diff >= tokenizer.parse_state.options.hard_break_spaces_min.max(1)
             && exit_index + 1 < tokenizer.events.len()
         {
             Name::HardBreakTrailing
@@ -184,6 +183,12 @@ fn trim_data(
         if index > 0 || slice.before > 0 {
             let enter_point = tokenizer.events[exit_index - 1].point.clone();
             let mut exit_point = enter_point.clone();
+            // Also skip the rest of a tab that is partially used by
+            // something before.
+            if slice.before > 0 {
+                exit_point.index += 1;
+                exit_point.column += slice.before;
+            }
             exit_point.index += index;
             exit_point.column += index;
             exit_point.vs = 0;
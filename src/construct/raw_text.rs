@@ -15,6 +15,22 @@
 //! sequence ::= 1*'`' | 1*'$'
 //! ```
 //!
+//! When `code_span_lang` is on, code (text) can start with a language hint:
+//! a known language name followed by a colon
+//! (see [`CODE_TEXT_LANGUAGES`][]), directly after the
+//! opening sequence.
+//! The hint is not included in the code, but turned into a class instead:
+//!
+//! ```markdown
+//! `rust:let a = 1;` and `a:b`
+//! ```
+//!
+//! Yields (with `code_span_lang`):
+//!
+//! ```html
+//! <p><code class="language-rust">let a = 1;</code> and <code>a:b</code></p>
+//! ```
+//!
 //! The above grammar shows that it is not possible to create empty raw (text).
 //! It is possible to include the sequence marker (grave accent for code,
 //! dollar for math) in raw (text), by wrapping it in bigger or smaller
@@ -94,6 +110,8 @@
 //!
 //! *   [`CodeText`][Name::CodeText]
 //! *   [`CodeTextData`][Name::CodeTextData]
+//! *   [`CodeTextLanguage`][Name::CodeTextLanguage]
+//! *   [`CodeTextLanguageMarker`][Name::CodeTextLanguageMarker]
 //! *   [`CodeTextSequence`][Name::CodeTextSequence]
 //! *   [`MathText`][Name::MathText]
 //! *   [`MathTextData`][Name::MathTextData]
@@ -118,6 +136,7 @@
 use crate::event::Name;
 use crate::state::{Name as StateName, State};
 use crate::tokenizer::Tokenizer;
+use crate::util::constant::{CODE_TEXT_LANGUAGES, CODE_TEXT_LANGUAGE_SIZE_MAX};
 
 /// Start of raw (text).
 ///
@@ -181,7 +200,42 @@ pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
         State::Nok
     } else {
         tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
-        State::Retry(StateName::RawTextBetween)
+
+        let size = if tokenizer.tokenize_state.marker == b'`'
+            && tokenizer.parse_state.options.constructs.code_span_lang
+        {
+            language_size(tokenizer.parse_state.bytes, tokenizer.point.index)
+        } else {
+            0
+        };
+
+        if size > 0 {
+            tokenizer.tokenize_state.size_b = size;
+            tokenizer.enter(Name::CodeTextLanguage);
+            State::Retry(StateName::RawTextLanguage)
+        } else {
+            State::Retry(StateName::RawTextBetween)
+        }
+    }
+}
+
+/// In language.
+///
+/// ```markdown
+/// > | `rust:a`
+///      ^^^^^
+/// ```
+pub fn language(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.tokenize_state.size_b > 0 {
+        tokenizer.tokenize_state.size_b -= 1;
+        tokenizer.consume();
+        State::Next(StateName::RawTextLanguage)
+    } else {
+        tokenizer.exit(Name::CodeTextLanguage);
+        tokenizer.enter(Name::CodeTextLanguageMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::CodeTextLanguageMarker);
+        State::Next(StateName::RawTextBetween)
     }
 }
 
@@ -269,3 +323,33 @@ pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
         }
     }
 }
+
+/// Get the size of a known language name followed by a colon and more code,
+/// at `index`, or `0` if there is none.
+fn language_size(bytes: &[u8], index: usize) -> usize {
+    let mut end = index;
+
+    while end < bytes.len()
+        && end - index <= CODE_TEXT_LANGUAGE_SIZE_MAX
+        && bytes[end].is_ascii_alphanumeric()
+    {
+        end += 1;
+    }
+
+    if end > index
+        && end - index <= CODE_TEXT_LANGUAGE_SIZE_MAX
+        && bytes.get(end) == Some(&b':')
+        && !matches!(bytes.get(end + 1), None | Some(b'\n' | b'\r' | b'`'))
+    {
+        let name = &bytes[index..end];
+
+        if CODE_TEXT_LANGUAGES
+            .iter()
+            .any(|d| d.as_bytes().eq_ignore_ascii_case(name))
+        {
+            return end - index;
+        }
+    }
+
+    0
+}
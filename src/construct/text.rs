@@ -7,6 +7,7 @@
 //! The constructs found in text are:
 //!
 //! *   [Attention][crate::construct::attention] (emphasis, gfm strikethrough, strong)
+//! *   [Attributes][crate::construct::attributes]
 //! *   [Autolink][crate::construct::autolink]
 //! *   [Character escape][crate::construct::character_escape]
 //! *   [Character reference][crate::construct::character_reference]
@@ -23,7 +24,11 @@
 //!
 //! > 👉 **Note**: for performance reasons, hard break (trailing) is formed by
 //! > [whitespace][crate::construct::partial_whitespace].
+//!
+//! > 👉 **Note**: [abbreviations][crate::construct::abbreviation] are found
+//! > in data after text is parsed.
 
+use crate::construct::abbreviation::resolve as resolve_abbreviation;
 use crate::construct::gfm_autolink_literal::resolve as resolve_gfm_autolink_literal;
 use crate::construct::partial_whitespace::resolve_whitespace;
 use crate::resolve::Name as ResolveName;
@@ -47,10 +52,20 @@ const MARKERS: [u8; 16] = [
     b'`',  // `raw_text` (code (text))
     b'h',  // `gfm_autolink_literal` (`protocol` kind)
     b'w',  // `gfm_autolink_literal` (`www.` kind)
-    b'{',  // `mdx_expression_text`
+    b'{',  // `attributes`, `mdx_expression_text`
     b'~',  // `attention` (gfm strikethrough)
 ];
 
+/// Characters that can start something in text, when GFM autolink literals
+/// are allowed to use other schemes: any ASCII letter can start a scheme.
+const MARKERS_SCHEMES: [u8; 64] = [
+    b'!', b'$', b'&', b'*', b'<', b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K',
+    b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X', b'Y', b'Z', b'[',
+    b'\\', b']', b'_', b'`', b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j', b'k',
+    b'l', b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x', b'y', b'z', b'{',
+    b'~',
+];
+
 /// Start of text.
 ///
 /// There is a slightly weird case where task list items have their check at
@@ -62,7 +77,21 @@ const MARKERS: [u8; 16] = [
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.tokenize_state.markers = &MARKERS;
+    tokenizer.tokenize_state.markers = if tokenizer
+        .parse_state
+        .options
+        .constructs
+        .gfm_autolink_literal
+        && !tokenizer
+            .parse_state
+            .options
+            .gfm_autolink_literal_schemes
+            .is_empty()
+    {
+        &MARKERS_SCHEMES
+    } else {
+        &MARKERS
+    };
     tokenizer.attempt(
         State::Next(StateName::TextBefore),
         State::Next(StateName::TextBefore),
@@ -79,6 +108,10 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 pub fn before(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         None => {
+            // Labels are resolved before everything else, and attention was
+            // registered while parsing: so merging data happens after both of
+            // them turned unmatched markers into data, which leaves no adjacent
+            // data for the compilers.
             tokenizer.register_resolver(ResolveName::Data);
             tokenizer.register_resolver(ResolveName::Text);
             State::Ok
@@ -121,19 +154,23 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::AutolinkStart)
         }
-        Some(b'H' | b'h') => {
+        // `gfm_autolink_literal` (`protocol` kind, then `www.` kind).
+        Some(b'W' | b'w') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeData),
+                State::Next(StateName::TextBeforeGfmAutolinkLiteralWww),
             );
             State::Retry(StateName::GfmAutolinkLiteralProtocolStart)
         }
-        Some(b'W' | b'w') => {
+        // `gfm_autolink_literal` (`protocol` kind).
+        // Letters other than `h` are only markers when other schemes are
+        // allowed.
+        Some(b'A'..=b'Z' | b'a'..=b'z') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
                 State::Next(StateName::TextBeforeData),
             );
-            State::Retry(StateName::GfmAutolinkLiteralWwwStart)
+            State::Retry(StateName::GfmAutolinkLiteralProtocolStart)
         }
         Some(b'[') => {
             tokenizer.attempt(
@@ -156,12 +193,13 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::LabelEndStart)
         }
+        // `attributes`, `mdx_expression_text` (order matters).
         Some(b'{') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeData),
+                State::Next(StateName::TextBeforeMdxExpression),
             );
-            State::Retry(StateName::MdxExpressionTextStart)
+            State::Retry(StateName::AttributesStart)
         }
         _ => State::Retry(StateName::TextBeforeData),
     }
@@ -183,6 +221,22 @@ pub fn before_html(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::HtmlTextStart)
 }
 
+/// Before mdx expression (text).
+///
+/// At `{`, which wasn’t attributes.
+///
+/// ```markdown
+/// > | a {b}
+///       ^
+/// ```
+pub fn before_mdx_expression(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::MdxExpressionTextStart)
+}
+
 /// Before mdx jsx (text).
 ///
 /// At `<`, which wasn’t an autolink or html.
@@ -199,6 +253,22 @@ pub fn before_mdx_jsx(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::MdxJsxTextStart)
 }
 
+/// Before GFM autolink literal (`www.` kind).
+///
+/// At `w`, which wasn’t a GFM autolink literal (`protocol` kind).
+///
+/// ```markdown
+/// > | a www.b.c
+///       ^
+/// ```
+pub fn before_gfm_autolink_literal_www(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::GfmAutolinkLiteralWwwStart)
+}
+
 /// Before hard break escape.
 ///
 /// At `\`, which wasn’t a character escape.
@@ -259,6 +329,14 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         resolve_gfm_autolink_literal(tokenizer);
     }
 
+    // Abbreviations are found after autolink literals, so that they are not
+    // found in URLs.
+    if tokenizer.parse_state.options.constructs.abbreviation
+        && !tokenizer.parse_state.abbreviations.is_empty()
+    {
+        resolve_abbreviation(tokenizer);
+    }
+
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
@@ -8,6 +8,7 @@
 //! ```bnf
 //! ; Restriction: all markers must be identical.
 //! ; Restriction: at least 3 markers must be used.
+//! ; Restriction: the marker must be in `thematic_break_markers`.
 //! thematic_break ::= *space_or_tab 1*(1*marker *space_or_tab)
 //!
 //! marker ::= '*' | '-' | '_'
@@ -99,8 +100,14 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn before(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        Some(b'*' | b'-' | b'_') => {
-            tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
+        Some(byte @ (b'*' | b'-' | b'_'))
+            if tokenizer
+                .parse_state
+                .options
+                .thematic_break_markers
+                .contains(&char::from(byte)) =>
+        {
+            tokenizer.tokenize_state.marker = byte;
             State::Retry(StateName::ThematicBreakAtBreak)
         }
         _ => State::Nok,
@@ -0,0 +1,123 @@
+//! Compile several documents that share definitions.
+
+use crate::mdast::Node;
+use crate::{to_html_with_options, to_mdast, DefinitionConflict, Options};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+/// Several documents that share definitions.
+///
+/// This is useful when content is split over several files, such as the
+/// chapters of a book, where a reference in one file can use a definition
+/// in another file.
+///
+/// Definitions are extracted from the documents themselves.
+/// Definitions in a document take precedence over definitions in other
+/// documents, which take precedence over
+/// [`external_definitions`][crate::ParseOptions::external_definitions].
+/// When several other documents define the same identifier, the first one
+/// wins (or the last one, with
+/// [`definition_conflict`][crate::ParseOptions::definition_conflict]).
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{DocumentSet, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let mut set = DocumentSet::new(Options::default());
+/// set.add("[a]: https://example.com").add("See [a].");
+///
+/// assert_eq!(
+///     set.to_html()?,
+///     vec![
+///         "",
+///         "<p>See <a href=\"https://example.com\">a</a>.</p>"
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct DocumentSet {
+    /// Configuration, used for all documents.
+    options: Options,
+    /// Documents.
+    documents: Vec<String>,
+}
+
+impl DocumentSet {
+    /// Create a new set, which uses `options` for every document.
+    pub fn new(options: Options) -> Self {
+        Self {
+            options,
+            documents: Vec::new(),
+        }
+    }
+
+    /// Add a document.
+    pub fn add(&mut self, value: &str) -> &mut Self {
+        self.documents.push(value.into());
+        self
+    }
+
+    /// Turn each document into HTML, in the order they were added.
+    ///
+    /// ## Errors
+    ///
+    /// Same as [`to_html_with_options()`][]: only MDX has syntax errors.
+    pub fn to_html(&mut self) -> Result<Vec<String>, String> {
+        // Parse every document to find the definitions in them.
+        let mut definitions = BTreeMap::new();
+        for value in &self.documents {
+            let tree = to_mdast(value, &self.options.parse)?;
+            collect_definitions(
+                &tree,
+                &mut definitions,
+                self.options.parse.definition_conflict,
+            );
+        }
+
+        // Definitions from documents win over external ones.
+        let external = core::mem::take(&mut self.options.parse.external_definitions);
+        for (id, definition) in &external {
+            definitions
+                .entry(id.clone())
+                .or_insert_with(|| definition.clone());
+        }
+
+        // Pass all definitions to each document, while it is compiled, as
+        // external definitions.
+        self.options.parse.external_definitions = definitions;
+        let result = self
+            .documents
+            .iter()
+            .map(|value| to_html_with_options(value, &self.options))
+            .collect();
+        self.options.parse.external_definitions = external;
+        result
+    }
+}
+
+/// Collect the definitions in `node`, keeping the first (or last) one of each
+/// identifier.
+fn collect_definitions(
+    node: &Node,
+    definitions: &mut BTreeMap<String, (String, Option<String>)>,
+    conflict: DefinitionConflict,
+) {
+    if let Node::Definition(definition) = node {
+        let value = (definition.url.clone(), definition.title.clone());
+
+        if conflict == DefinitionConflict::KeepLast {
+            definitions.insert(definition.identifier.clone(), value);
+        } else {
+            definitions
+                .entry(definition.identifier.clone())
+                .or_insert(value);
+        }
+    } else if let Some(children) = node.children() {
+        for child in children {
+            collect_definitions(child, definitions, conflict);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Check whether events produce visible content.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+
+/// Check whether events, at the top level, only contain things that are not
+/// displayed.
+pub fn compile(events: &[Event], bytes: &[u8]) -> bool {
+    let mut depth = 0;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if depth == 0
+                && !matches!(
+                    event.name,
+                    Name::AbbreviationDefinition
+                        | Name::BlankLineEnding
+                        | Name::Definition
+                        | Name::Frontmatter
+                        | Name::GfmFootnoteDefinition
+                        | Name::HtmlFlow
+                        | Name::LineEnding
+                        | Name::SpaceOrTab
+                )
+            {
+                return false;
+            }
+
+            depth += 1;
+        } else {
+            depth -= 1;
+
+            if depth == 0 && event.name == Name::HtmlFlow {
+                let value = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+
+                if !is_comments(value.as_str()) {
+                    return false;
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    true
+}
+
+/// Check whether HTML is only comments and whitespace.
+fn is_comments(value: &str) -> bool {
+    let mut rest = value.trim();
+
+    while !rest.is_empty() {
+        if !rest.starts_with("<!--") {
+            return false;
+        }
+
+        // Search from after `<!`, so that `<!-->` is a comment too.
+        match rest[2..].find("-->") {
+            Some(end) => rest = rest[2 + end + 3..].trim_start(),
+            None => return false,
+        }
+    }
+
+    true
+}
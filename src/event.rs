@@ -5,10 +5,330 @@ use crate::util::constant::TAB_SIZE;
 /// Semantic label of a span.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Name {
+    /// Abbreviation.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | *[HTML]: HyperText Markup Language
+    /// > | a HTML b
+    ///       ^^^^
+    /// ```
+    Abbreviation,
+    /// Whole abbreviation definition.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel],
+    ///     [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker],
+    ///     [`AbbreviationDefinitionTitle`][Name::AbbreviationDefinitionTitle],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinition,
+    /// Abbreviation definition label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker],
+    ///     [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///      ^^^^^^
+    /// ```
+    AbbreviationDefinitionLabel,
+    /// Abbreviation definition label marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///      ^    ^
+    /// ```
+    AbbreviationDefinitionLabelMarker,
+    /// Abbreviation definition label data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///       ^^^^
+    /// ```
+    AbbreviationDefinitionLabelString,
+    /// Abbreviation definition marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///     ^      ^
+    /// ```
+    AbbreviationDefinitionMarker,
+    /// Abbreviation definition title.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///              ^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinitionTitle,
     /// Attention sequence.
     ///
     /// > 👉 **Note**: this is used while parsing but compiled away.
     AttentionSequence,
+    /// Whole attributes.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`AttributesClass`][Name::AttributesClass],
+    ///     [`AttributesClassMarker`][Name::AttributesClassMarker],
+    ///     [`AttributesId`][Name::AttributesId],
+    ///     [`AttributesIdMarker`][Name::AttributesIdMarker],
+    ///     [`AttributesInitializerMarker`][Name::AttributesInitializerMarker],
+    ///     [`AttributesMarker`][Name::AttributesMarker],
+    ///     [`AttributesName`][Name::AttributesName],
+    ///     [`AttributesValue`][Name::AttributesValue],
+    ///     [`AttributesValueMarker`][Name::AttributesValueMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///         ^^^^^^^^^^^
+    /// ```
+    Attributes,
+    /// Attributes class.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///              ^
+    /// ```
+    AttributesClass,
+    /// Attributes class marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///             ^
+    /// ```
+    AttributesClassMarker,
+    /// Attributes id.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///           ^
+    /// ```
+    AttributesId,
+    /// Attributes id marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///          ^
+    /// ```
+    AttributesIdMarker,
+    /// Attributes initializer marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///                 ^
+    /// ```
+    AttributesInitializerMarker,
+    /// Attributes marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///         ^         ^
+    /// ```
+    AttributesMarker,
+    /// Attributes name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d=e}
+    ///                ^
+    /// ```
+    AttributesName,
+    /// Attributes value.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d="e"}
+    ///                   ^
+    /// ```
+    AttributesValue,
+    /// Attributes value marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Attributes`][Name::Attributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attributes`][crate::construct::attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # a {#b .c d="e"}
+    ///                  ^ ^
+    /// ```
+    AttributesValueMarker,
     /// Whole autolink.
     ///
     /// ## Info
@@ -506,6 +826,8 @@ pub enum Name {
     ///     [text content][crate::construct::text]
     /// *   **Content model**:
     ///     [`CodeTextData`][Name::CodeTextData],
+    ///     [`CodeTextLanguage`][Name::CodeTextLanguage],
+    ///     [`CodeTextLanguageMarker`][Name::CodeTextLanguageMarker],
     ///     [`CodeTextSequence`][Name::CodeTextSequence],
     ///     [`LineEnding`][Name::LineEnding]
     /// *   **Construct**:
@@ -536,6 +858,42 @@ pub enum Name {
     ///        ^
     /// ```
     CodeTextData,
+    /// Code (text) language.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`CodeText`][Name::CodeText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`raw_text`][crate::construct::raw_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a `rust:b` c
+    ///        ^^^^
+    /// ```
+    CodeTextLanguage,
+    /// Code (text) language marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`CodeText`][Name::CodeText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`raw_text`][crate::construct::raw_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a `rust:b` c
+    ///            ^
+    /// ```
+    CodeTextLanguageMarker,
     /// Code (text) sequence.
     ///
     /// ## Info
@@ -574,6 +932,26 @@ pub enum Name {
     ///     ^^
     /// ```
     Content,
+    /// Custom span, added by a third-party resolver.
+    ///
+    /// > 👉 **Note**: compilers ignore this, but not what is in it.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     any
+    /// *   **Content model**:
+    ///     any
+    /// *   **Construct**:
+    ///     [`resolvers`][crate::ParseOptions::resolvers]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a @@ b
+    ///       ^^
+    /// ```
+    Custom(&'static str),
     /// Data.
     ///
     /// ## Info
@@ -846,6 +1224,109 @@ pub enum Name {
     ///             ^
     /// ```
     DefinitionTitleString,
+    /// Description details.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DescriptionList`][Name::DescriptionList]
+    /// *   **Content model**:
+    ///     [`DescriptionDetailsPrefix`][Name::DescriptionDetailsPrefix],
+    ///     [flow content][crate::construct::flow]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^^^
+    /// ```
+    DescriptionDetails,
+    /// Description details marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DescriptionDetailsPrefix`][Name::DescriptionDetailsPrefix]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^
+    /// ```
+    DescriptionDetailsMarker,
+    /// Description details prefix.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DescriptionDetails`][Name::DescriptionDetails]
+    /// *   **Content model**:
+    ///     [`DescriptionDetailsMarker`][Name::DescriptionDetailsMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^^
+    /// ```
+    DescriptionDetailsPrefix,
+    /// Description list.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [document content][crate::construct::document]
+    /// *   **Content model**:
+    ///     [`BlankLineEnding`][Name::BlankLineEnding],
+    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
+    ///     [`DescriptionDetails`][Name::DescriptionDetails],
+    ///     [`DescriptionTerm`][Name::DescriptionTerm],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | : b
+    ///     ^^^
+    /// ```
+    DescriptionList,
+    /// Description term.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DescriptionList`][Name::DescriptionList]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    ///   | : b
+    /// ```
+    DescriptionTerm,
     /// Emphasis.
     ///
     /// ## Info
@@ -3375,8 +3856,22 @@ pub enum Name {
 }
 
 /// List of void events, used to make sure everything is working well.
-pub const VOID_EVENTS: [Name; 76] = [
+pub const VOID_EVENTS: [Name; 93] = [
+    Name::Abbreviation,
+    Name::AbbreviationDefinitionLabelMarker,
+    Name::AbbreviationDefinitionLabelString,
+    Name::AbbreviationDefinitionMarker,
+    Name::AbbreviationDefinitionTitle,
     Name::AttentionSequence,
+    Name::AttributesClass,
+    Name::AttributesClassMarker,
+    Name::AttributesId,
+    Name::AttributesIdMarker,
+    Name::AttributesInitializerMarker,
+    Name::AttributesMarker,
+    Name::AttributesName,
+    Name::AttributesValue,
+    Name::AttributesValueMarker,
     Name::AutolinkEmail,
     Name::AutolinkMarker,
     Name::AutolinkProtocol,
@@ -3393,12 +3888,15 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::CodeFencedFenceSequence,
     Name::CodeFlowChunk,
     Name::CodeTextData,
+    Name::CodeTextLanguage,
+    Name::CodeTextLanguageMarker,
     Name::CodeTextSequence,
     Name::Data,
     Name::DefinitionDestinationLiteralMarker,
     Name::DefinitionLabelMarker,
     Name::DefinitionMarker,
     Name::DefinitionTitleMarker,
+    Name::DescriptionDetailsMarker,
     Name::EmphasisSequence,
     Name::FrontmatterChunk,
     Name::GfmAutolinkLiteralEmail,
@@ -3503,6 +4001,7 @@ pub struct Point {
 impl Point {
     /// Create a new point, that is shifted from the close earlier current
     /// point, to `index.`
+    #[must_use]
     pub fn shift_to(&self, bytes: &[u8], index: usize) -> Point {
         let mut next = self.clone();
         debug_assert!(index > next.index, "expected to shift forward");
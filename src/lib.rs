@@ -11,6 +11,27 @@
 //!     constructs (GFM, MDX, and the like)
 //! *   [`to_mdast()`][]
 //!     — turn markdown into a syntax tree
+//! *   [`to_html_with_options_lossy()`][], [`to_mdast_lossy()`][]
+//!     — like `to_html_with_options` and `to_mdast`, but for bytes that may
+//!     not be valid UTF-8
+//! *   `to_html_into_writer()` (with the `std` feature)
+//!     — like `to_html_with_options` but writes to an `io::Write`
+//! *   `HtmlCache` (with the `cache` feature)
+//!     — like `to_html_with_options` and `to_mdast`, but remembers
+//!     documents, so that compiling them again is cheap
+//! *   [`DocumentSet`][]
+//!     — like `to_html_with_options`, but for several documents that share
+//!     definitions
+//! *   [`to_markdown()`][]
+//!     — turn a syntax tree into markdown
+//! *   [`tokenize()`][]
+//!     — low-level way to turn a certain content type into events
+//!
+//! None of these functions panic, on any input and with any options (apart
+//! from functions passed in options that panic themselves): they either
+//! return a value or an error (such as for invalid MDX).
+//! A panic is a bug, please report it (the `fuzz/` folder has a target to
+//! find them).
 #![no_std]
 #![deny(clippy::pedantic)]
 #![allow(clippy::doc_link_with_quotes)]
@@ -22,14 +43,25 @@
 )]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+mod block_ranges;
+#[cfg(feature = "cache")]
+mod cache;
+mod character_references;
 mod configuration;
 mod construct;
+mod document_set;
+mod empty;
 mod event;
+mod link_validation;
+mod media;
 mod parser;
 mod resolve;
 mod state;
 mod subtokenize;
 mod to_html;
+mod to_markdown;
 mod to_mdast;
 mod tokenizer;
 mod util;
@@ -48,14 +80,46 @@ pub use util::location::Location;
 
 pub use util::line_ending::LineEnding;
 
+pub use util::serialize::serialize_with;
+
+pub use util::edit_map::EditMap;
+
+pub use block_ranges::{BlockKind, BlockRange};
+#[cfg(feature = "cache")]
+pub use cache::HtmlCache;
+
+pub use character_references::{CharacterReference, CharacterReferenceKind};
+
+pub use document_set::DocumentSet;
+
+pub use link_validation::LinkValidation;
+
+pub use media::Media;
+
 pub use util::mdx::{
     EsmParse as MdxEsmParse, ExpressionKind as MdxExpressionKind,
     ExpressionParse as MdxExpressionParse, Signal as MdxSignal,
 };
 
-pub use configuration::{CompileOptions, Constructs, Options, ParseOptions};
+pub use configuration::{
+    AttributeEscaper, CjkSoftBreak, CodeBlockRenderer, CodeHighlighter, ColumnMode, CompileOptions,
+    Constructs, DefinitionConflict, EscapeContext, GfmFootnoteRefTemplate, HeadingAnchor,
+    ImageAttributes, ImageInfo, OnBlock, Options, ParseOptions, ReferenceResolver, Resolve,
+    SerializeOptions,
+};
+
+pub use event::{
+    Content as ContentType, Event, Kind as EventKind, Link as EventLink, Name as EventName,
+    Point as EventPoint,
+};
+
+#[cfg(feature = "trace")]
+pub use configuration::Trace;
 
-use alloc::string::String;
+#[cfg(feature = "trace")]
+pub use state::Name as StateName;
+
+use alloc::{string::String, vec::Vec};
 
 /// Turn markdown into HTML.
 ///
@@ -114,10 +178,185 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, St
     Ok(to_html::compile(
         &events,
         parse_state.bytes,
+        &parse_state.resolved_definitions,
+        &options.parse,
         &options.compile,
     ))
 }
 
+/// Turn markdown, as bytes that may not be valid UTF-8, into HTML, with
+/// configuration.
+///
+/// Invalid UTF-8 sequences are replaced with U+FFFD REPLACEMENT CHARACTER
+/// (`�`) before parsing, in the same way as [`String::from_utf8_lossy`][].
+/// This never panics on invalid UTF-8.
+///
+/// ## Errors
+///
+/// Same as [`to_html_with_options()`][]: only MDX has syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_options_lossy, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let result = to_html_with_options_lossy(b"*a\xFFb*", &Options::default())?;
+///
+/// assert_eq!(result, "<p><em>a\u{FFFD}b</em></p>");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_with_options_lossy(value: &[u8], options: &Options) -> Result<String, String> {
+    to_html_with_options(&String::from_utf8_lossy(value), options)
+}
+
+/// Turn markdown into HTML, with configuration, written to `out`.
+///
+/// Like [`to_html_with_options()`][], but writes to an
+/// [`io::Write`][std::io::Write] (such as a file or a socket) instead of
+/// returning a `String`.
+/// The HTML of each top-level block is written as soon as that block is
+/// compiled, so the whole document never has to be kept in memory as HTML.
+/// Consider wrapping `out` in a [`BufWriter`][std::io::BufWriter] if it does
+/// not buffer itself.
+///
+/// Only available with the `std` feature.
+///
+/// ## Errors
+///
+/// Errors when writing to `out` fails.
+/// As with [`to_html_with_options()`][], normal markdown does not have
+/// syntax errors, but MDX does: those are turned into errors of kind
+/// [`InvalidData`][std::io::ErrorKind::InvalidData].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_into_writer, Options};
+/// # fn main() -> std::io::Result<()> {
+///
+/// let mut out = vec![];
+/// to_html_into_writer("# Hello, world!", &mut out, &Options::default())?;
+///
+/// assert_eq!(out, b"<h1>Hello, world!</h1>");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn to_html_into_writer<W: std::io::Write>(
+    value: &str,
+    out: W,
+    options: &Options,
+) -> std::io::Result<()> {
+    let (events, parse_state) = parser::parse(value, &options.parse)
+        .map_err(|message| std::io::Error::new(std::io::ErrorKind::InvalidData, message))?;
+    to_html::compile_into(
+        &events,
+        parse_state.bytes,
+        &parse_state.resolved_definitions,
+        &options.parse,
+        &options.compile,
+        &mut to_html::IoSink(out),
+    )
+}
+
+/// Turn a value of a certain content type into events.
+///
+/// Markdown consists of several content types, such as *flow* (blocks),
+/// *text* (phrasing, such as emphasis), and *string* (used in titles,
+/// destinations, and labels, where only character escapes and character
+/// references are allowed).
+/// Where [`to_html()`][] and [`to_mdast()`][] parse whole documents, this
+/// parses `value` as one specific content type, with the same semantics as
+/// when that content occurs in a document.
+///
+/// This is a low-level interface: the result is a flat list of enter and exit
+/// events.
+///
+/// ## Errors
+///
+/// `tokenize()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{tokenize, ContentType, EventKind, EventName, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// // Parse the contents of a title, such as in `[a]: b "c \& &amp; d"`:
+/// let events = tokenize("c \\& &amp; d", &ContentType::String, &ParseOptions::default())?;
+///
+/// let names = events
+///     .iter()
+///     .filter(|event| event.kind == EventKind::Enter)
+///     .map(|event| event.name.clone())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     names,
+///     vec![
+///         EventName::Data,
+///         EventName::CharacterEscape,
+///         EventName::CharacterEscapeMarker,
+///         EventName::CharacterEscapeValue,
+///         EventName::Data,
+///         EventName::CharacterReference,
+///         EventName::CharacterReferenceMarker,
+///         EventName::CharacterReferenceValue,
+///         EventName::CharacterReferenceMarkerSemi,
+///         EventName::Data,
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn tokenize(
+    value: &str,
+    content: &ContentType,
+    options: &ParseOptions,
+) -> Result<Vec<Event>, String> {
+    parser::tokenize(value, content, options)
+}
+
+/// Get the value of string content, such as a title, destination, or the
+/// info of fenced code, with character escapes and references decoded.
+///
+/// This does what `markdown-rs` does with the contents of, say, a title,
+/// such as `c \& &amp; d` in `[a]: b "c \& &amp; d"`, which is useful to
+/// compute what it displays as (for example, for tooltips or ARIA labels).
+/// Nothing else is interpreted: there is no emphasis, code, or the like.
+/// Whether escapes and references are decoded depends on
+/// [`character_escape`][Constructs::character_escape] and
+/// [`character_reference`][Constructs::character_reference] in
+/// `options.constructs`.
+///
+/// ## Errors
+///
+/// `parse_string()` never errors with normal markdown because markdown does
+/// not have syntax errors, so feel free to `unwrap()`.
+/// However, [`resolvers`][ParseOptions::resolvers] can error.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{parse_string, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// assert_eq!(
+///     parse_string("c \\& &amp; *d*", &ParseOptions::default())?,
+///     "c & & *d*"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_string(value: &str, options: &ParseOptions) -> Result<String, String> {
+    let events = parser::tokenize(value, &ContentType::String, options)?;
+    Ok(util::serialize::serialize_events(&events, value.as_bytes()))
+}
+
 /// Turn markdown into a syntax tree.
 ///
 /// ## Errors
@@ -146,3 +385,347 @@ pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, Stri
     let node = to_mdast::compile(&events, parse_state.bytes)?;
     Ok(node)
 }
+
+/// Turn markdown, as bytes that may not be valid UTF-8, into a syntax tree.
+///
+/// Invalid UTF-8 sequences are replaced with U+FFFD REPLACEMENT CHARACTER
+/// (`�`) before parsing, in the same way as [`String::from_utf8_lossy`][].
+/// This never panics on invalid UTF-8.
+///
+/// > 👉 **Note**: offsets in positions are indices into the replaced value,
+/// > which differs from `value` when it contains invalid UTF-8 (a
+/// > replacement character is 3 bytes).
+///
+/// ## Errors
+///
+/// Same as [`to_mdast()`][]: only MDX has syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_mdast_lossy, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let tree = to_mdast_lossy(b"a\xFFb", &ParseOptions::default())?;
+///
+/// assert_eq!(tree.to_string(), "a\u{FFFD}b");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_mdast_lossy(value: &[u8], options: &ParseOptions) -> Result<mdast::Node, String> {
+    to_mdast(&String::from_utf8_lossy(value), options)
+}
+
+/// Get the kind and position of each top-level block.
+///
+/// This is much lighter than [`to_mdast()`][] when only the structure of a
+/// document is needed, such as for an outline or for folding.
+/// Blocks inside containers (such as block quotes and lists) are not
+/// included: the container is.
+///
+/// ## Errors
+///
+/// `block_ranges()` never errors with normal markdown because markdown does
+/// not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{block_ranges, BlockKind, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let ranges = block_ranges("# a\n\n> b", &ParseOptions::default())?;
+///
+/// assert_eq!(ranges[0].kind, BlockKind::Heading);
+/// assert_eq!(ranges[1].kind, BlockKind::BlockQuote);
+/// assert_eq!(ranges[1].position.start.line, 3);
+/// # Ok(())
+/// # }
+/// ```
+pub fn block_ranges(value: &str, options: &ParseOptions) -> Result<Vec<BlockRange>, String> {
+    let (events, _) = parser::parse(value, options)?;
+    Ok(block_ranges::compile(&events))
+}
+
+/// Check whether markdown displays nothing.
+///
+/// This is the case when a document contains only whitespace, definitions
+/// (including footnote definitions and abbreviation definitions),
+/// frontmatter, and HTML (flow) that consists of comments.
+/// It is computed from events, without compiling to HTML or building a
+/// syntax tree, which is useful to decide whether to render something
+/// around a document at all.
+///
+/// > 👉 **Note**: comments are treated as not displayed, which is what
+/// > happens when [`allow_dangerous_html`][CompileOptions::allow_dangerous_html]
+/// > is on; when it is off, they are shown escaped.
+///
+/// ## Errors
+///
+/// `is_empty()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{is_empty, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// assert!(is_empty("[a]: b\n\n<!-- c -->", &ParseOptions::default())?);
+/// assert!(!is_empty("[a]: b\n\n[a]", &ParseOptions::default())?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_empty(value: &str, options: &ParseOptions) -> Result<bool, String> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(empty::compile(&events, parse_state.bytes))
+}
+
+/// Get the character references in markdown, with what they decode to.
+///
+/// This includes references in phrasing content, and in places such as
+/// titles, destinations, and the info of fenced code.
+/// Things that look like references but are not (such as `&#xZZ;` and
+/// `&unknown;`) are not included.
+///
+/// ## Errors
+///
+/// `extract_character_references()` never errors with normal markdown
+/// because markdown does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{extract_character_references, CharacterReferenceKind, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let references = extract_character_references("a &amp; &#65;", &ParseOptions::default())?;
+///
+/// assert_eq!(references[0].kind, CharacterReferenceKind::Named);
+/// assert_eq!(references[0].source, "&amp;");
+/// assert_eq!(references[0].value, "&");
+/// assert_eq!(references[1].kind, CharacterReferenceKind::Decimal);
+/// assert_eq!(references[1].value, "A");
+/// assert_eq!(references[1].position.start.column, 9);
+/// # Ok(())
+/// # }
+/// ```
+pub fn extract_character_references(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<CharacterReference>, String> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(character_references::compile(&events, parse_state.bytes))
+}
+
+/// Get the links and images in events, with what they resolve to.
+///
+/// This is useful when writing a compiler for the events from
+/// [`tokenize()`][], as it saves matching labels, resources, and references
+/// to definitions.
+/// `events` must come from tokenizing `value` with `options`.
+/// The result is in the order that links and images start, and refers to
+/// their enter and exit events by index.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{extract_media, tokenize, ContentType, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let value = "[a](b) ![c][d]\n\n[d]: e \"f\"";
+/// let options = ParseOptions::default();
+/// let events = tokenize(value, &ContentType::Flow, &options)?;
+/// let media = extract_media(value, &events, &options);
+///
+/// assert!(!media[0].image);
+/// assert_eq!(media[0].destination, "b");
+/// assert!(media[1].image);
+/// assert_eq!(media[1].identifier, Some("d".into()));
+/// assert_eq!(media[1].destination, "e");
+/// assert_eq!(media[1].title, Some("f".into()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn extract_media(value: &str, events: &[Event], options: &ParseOptions) -> Vec<Media> {
+    media::compile(events, value.as_bytes(), options)
+}
+
+/// Get the markdown between two events, as it is in `value`.
+///
+/// This is useful with the events from [`tokenize()`][] to get the
+/// verbatim source of something, such as a whole block (to emit fenced code
+/// unchanged) or a part of it.
+/// `events` must come from tokenizing `value`.
+/// The result starts at the point of the event at `enter_index` and ends at
+/// the point of the event at `exit_index`, which is typically an exit.
+/// It is empty when the second point is before the first.
+/// The result is a slice of `value`, which is widened to whole characters
+/// if a point is in the middle of one.
+///
+/// ## Panics
+///
+/// Panics if `enter_index` or `exit_index` are out of bounds of `events`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{source_between, tokenize, ContentType, EventKind, EventName, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let value = "a\n\n```js\nb\n```\n\nc";
+/// let events = tokenize(value, &ContentType::Flow, &ParseOptions::default())?;
+/// let enter = events
+///     .iter()
+///     .position(|d| d.kind == EventKind::Enter && d.name == EventName::CodeFenced)
+///     .unwrap();
+/// let exit = events
+///     .iter()
+///     .position(|d| d.kind == EventKind::Exit && d.name == EventName::CodeFenced)
+///     .unwrap();
+///
+/// assert_eq!(source_between(value, &events, enter, exit), "```js\nb\n```");
+/// # Ok(())
+/// # }
+/// ```
+pub fn source_between<'a>(
+    value: &'a str,
+    events: &[Event],
+    enter_index: usize,
+    exit_index: usize,
+) -> &'a str {
+    let mut start = events[enter_index].point.index.min(value.len());
+    let mut end = events[exit_index].point.index.min(value.len()).max(start);
+
+    while !value.is_char_boundary(start) {
+        start -= 1;
+    }
+
+    while !value.is_char_boundary(end) {
+        end += 1;
+    }
+
+    &value[start..end]
+}
+
+/// Check the destinations of links and images in markdown, without
+/// compiling to HTML.
+///
+/// Each link and image (including references, which get the destination of
+/// their definition) is checked in the same way as
+/// [`to_html_with_options()`][] does: the destination is sanitized, and
+/// dropped if it has a protocol that is not allowed by `options.compile`
+/// (see [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+/// and [`link_protocols`][CompileOptions::link_protocols]).
+/// Autolinks are not included.
+///
+/// ## Errors
+///
+/// `validate_links()` never errors with normal markdown because markdown
+/// does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{validate_links, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let links = validate_links("[a](b) [d](javascript:alert(1))", &Options::default())?;
+///
+/// assert_eq!(links[0].destination, "b");
+/// assert_eq!(links[0].sanitized, Ok("b".into()));
+/// assert_eq!(links[1].destination, "javascript:alert(1)");
+/// assert!(links[1].sanitized.is_err());
+/// assert_eq!(links[1].position.start.column, 8);
+/// # Ok(())
+/// # }
+/// ```
+pub fn validate_links(value: &str, options: &Options) -> Result<Vec<LinkValidation>, String> {
+    let (events, parse_state) = parser::parse(value, &options.parse)?;
+    let media = media::compile(&events, parse_state.bytes, &options.parse);
+    Ok(link_validation::compile(media, &options.compile))
+}
+
+/// Turn a syntax tree into markdown.
+///
+/// Serializes a tree (such as one made by [`to_mdast()`][]) back to
+/// markdown.
+/// Use [`SerializeOptions`][] to configure which markers are used.
+///
+/// Serializing does not keep the exact markdown that was parsed: the tree
+/// does not contain enough info for that.
+/// But parsing the result gives the same tree (ignoring positional info).
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let tree = to_mdast("Hey\n===\n\n- *you*!", &ParseOptions::default())?;
+///
+/// assert_eq!(
+///     to_markdown(&tree, &SerializeOptions::default()),
+///     "# Hey\n\n* *you*!\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_markdown(node: &mdast::Node, options: &SerializeOptions) -> String {
+    to_markdown::serialize(node, options)
+}
+
+/// Escape text for use as the content of HTML elements.
+///
+/// This is the escaping that [`to_html()`][] uses for text, so that HTML
+/// generated around the output of the crate is escaped in the same way.
+///
+/// `&` becomes `&amp;`, `<` becomes `&lt;`, `>` becomes `&gt;`, and `"`
+/// becomes `&quot;`.
+/// NUL (U+0000) becomes the replacement character (U+FFFD).
+/// Nothing else (such as `'`) is changed.
+///
+/// Options that change how text is escaped, such as
+/// [`escape_gt`][CompileOptions::escape_gt] and
+/// [`escape_context`][CompileOptions::escape_context], are not used.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::escape_html;
+///
+/// assert_eq!(escape_html("a < b & \"c\" > d"), "a &lt; b &amp; &quot;c&quot; &gt; d");
+/// assert_eq!(escape_html("e's"), "e's");
+/// ```
+pub fn escape_html(value: &str) -> String {
+    util::encode::encode(value, true)
+}
+
+/// Escape text for use as the value of a (double quoted) HTML attribute.
+///
+/// This is the escaping that [`to_html()`][] uses for attribute values, such
+/// as the `title` of links, so that HTML generated around the output of the
+/// crate is escaped in the same way.
+///
+/// The characters escaped are the same as with [`escape_html()`][]: `&`,
+/// `<`, `>`, and `"` become character references, and NUL (U+0000) becomes
+/// the replacement character (U+FFFD).
+/// Use the result in double quotes: `'` is not escaped.
+///
+/// The [`attribute_escaper`][CompileOptions::attribute_escaper] option is not
+/// used.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::escape_html_attribute;
+///
+/// assert_eq!(escape_html_attribute("a \"b\" <c>"), "a &quot;b&quot; &lt;c&gt;");
+/// ```
+pub fn escape_html_attribute(value: &str) -> String {
+    util::encode::encode(value, true)
+}
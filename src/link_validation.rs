@@ -0,0 +1,76 @@
+//! Check the destinations of links and images against the sanitizer.
+
+use crate::configuration::CompileOptions;
+use crate::media::Media;
+use crate::unist::Position;
+use crate::util::{
+    constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
+    sanitize_uri::{protocol, sanitize},
+};
+use alloc::{format, string::String, vec::Vec};
+
+/// Link or image, and whether its destination survives sanitizing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkValidation {
+    /// Whether this is an image (`true`) or a link (`false`).
+    pub image: bool,
+    /// Place of the link or image in the source.
+    pub position: Position,
+    /// Destination (url), as found in the resource or definition.
+    ///
+    /// Character escapes and references are decoded.
+    pub destination: String,
+    /// Destination as it would be used in HTML (`Ok`), or why it would be
+    /// dropped (`Err`).
+    pub sanitized: Result<String, String>,
+}
+
+/// Check media against the protocols allowed by `options`.
+///
+/// This matches what [`to_html_with_options()`][crate::to_html_with_options]
+/// does with `href` on `a` and `src` on `img`.
+pub fn compile(media: Vec<Media>, options: &CompileOptions) -> Vec<LinkValidation> {
+    media
+        .into_iter()
+        .map(|media| {
+            let sanitized = check(&media.destination, media.image, options);
+            LinkValidation {
+                image: media.image,
+                position: media.position,
+                destination: media.destination,
+                sanitized,
+            }
+        })
+        .collect()
+}
+
+/// Sanitize a destination, or get why it is dropped.
+fn check(destination: &str, image: bool, options: &CompileOptions) -> Result<String, String> {
+    let value = sanitize(destination);
+
+    if options.allow_dangerous_protocol {
+        return Ok(value);
+    }
+
+    // If there is no protocol, it’s relative, and fine.
+    if let Some(protocol) = protocol(&value) {
+        let protocol = protocol.to_lowercase();
+        let allowed = if image {
+            SAFE_PROTOCOL_SRC.contains(&protocol.as_str())
+        } else if let Some(protocols) = &options.link_protocols {
+            protocols.contains(&protocol)
+        } else {
+            SAFE_PROTOCOL_HREF.contains(&protocol.as_str())
+        };
+
+        if !allowed {
+            return Err(format!(
+                "Unexpected protocol `{}` in {}, which is not allowed",
+                protocol,
+                if image { "image" } else { "link" }
+            ));
+        }
+    }
+
+    Ok(value)
+}
@@ -24,6 +24,27 @@ pub enum ReferenceKind {
     Full,
 }
 
+/// Style of a break.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BreakKind {
+    /// The break is formed by a backslash.
+    ///
+    /// ```markdown
+    /// > | a\
+    ///      ^
+    ///   | b
+    /// ```
+    Escape,
+    /// The break is formed by two or more trailing spaces.
+    ///
+    /// ```markdown
+    /// > | a␠␠
+    ///      ^^
+    ///   | b
+    /// ```
+    Trailing,
+}
+
 /// GFM: alignment of phrasing content.
 ///
 /// Used to align the contents of table cells within a table.
@@ -87,6 +108,8 @@ pub enum Node {
     MdxJsxFlowElement(MdxJsxFlowElement),
     /// List.
     List(List),
+    /// Description list.
+    DescriptionList(DescriptionList),
 
     // Frontmatter:
     /// MDX.js ESM.
@@ -156,6 +179,12 @@ pub enum Node {
     /// List item.
     ListItem(ListItem),
 
+    // Description list content.
+    /// Description term.
+    DescriptionTerm(DescriptionTerm),
+    /// Description details.
+    DescriptionDetails(DescriptionDetails),
+
     // Content.
     /// Definition.
     Definition(Definition),
@@ -199,6 +228,9 @@ impl fmt::Debug for Node {
             Node::TableRow(x) => write!(f, "{:?}", x),
             Node::TableCell(x) => write!(f, "{:?}", x),
             Node::ListItem(x) => write!(f, "{:?}", x),
+            Node::DescriptionList(x) => write!(f, "{:?}", x),
+            Node::DescriptionTerm(x) => write!(f, "{:?}", x),
+            Node::DescriptionDetails(x) => write!(f, "{:?}", x),
             Node::Definition(x) => write!(f, "{:?}", x),
             Node::Paragraph(x) => write!(f, "{:?}", x),
         }
@@ -229,6 +261,9 @@ impl ToString for Node {
             Node::TableRow(x) => children_to_string(&x.children),
             Node::TableCell(x) => children_to_string(&x.children),
             Node::ListItem(x) => children_to_string(&x.children),
+            Node::DescriptionList(x) => children_to_string(&x.children),
+            Node::DescriptionTerm(x) => children_to_string(&x.children),
+            Node::DescriptionDetails(x) => children_to_string(&x.children),
             Node::Paragraph(x) => children_to_string(&x.children),
 
             // Literals.
@@ -266,6 +301,9 @@ impl Node {
             Node::BlockQuote(x) => Some(&x.children),
             Node::List(x) => Some(&x.children),
             Node::ListItem(x) => Some(&x.children),
+            Node::DescriptionList(x) => Some(&x.children),
+            Node::DescriptionTerm(x) => Some(&x.children),
+            Node::DescriptionDetails(x) => Some(&x.children),
             Node::Emphasis(x) => Some(&x.children),
             Node::Strong(x) => Some(&x.children),
             Node::Link(x) => Some(&x.children),
@@ -291,6 +329,9 @@ impl Node {
             Node::BlockQuote(x) => Some(&mut x.children),
             Node::List(x) => Some(&mut x.children),
             Node::ListItem(x) => Some(&mut x.children),
+            Node::DescriptionList(x) => Some(&mut x.children),
+            Node::DescriptionTerm(x) => Some(&mut x.children),
+            Node::DescriptionDetails(x) => Some(&mut x.children),
             Node::Emphasis(x) => Some(&mut x.children),
             Node::Strong(x) => Some(&mut x.children),
             Node::Link(x) => Some(&mut x.children),
@@ -342,6 +383,9 @@ impl Node {
             Node::TableRow(x) => x.position.as_ref(),
             Node::TableCell(x) => x.position.as_ref(),
             Node::ListItem(x) => x.position.as_ref(),
+            Node::DescriptionList(x) => x.position.as_ref(),
+            Node::DescriptionTerm(x) => x.position.as_ref(),
+            Node::DescriptionDetails(x) => x.position.as_ref(),
             Node::Definition(x) => x.position.as_ref(),
             Node::Paragraph(x) => x.position.as_ref(),
         }
@@ -381,6 +425,9 @@ impl Node {
             Node::TableRow(x) => x.position.as_mut(),
             Node::TableCell(x) => x.position.as_mut(),
             Node::ListItem(x) => x.position.as_mut(),
+            Node::DescriptionList(x) => x.position.as_mut(),
+            Node::DescriptionTerm(x) => x.position.as_mut(),
+            Node::DescriptionDetails(x) => x.position.as_mut(),
             Node::Definition(x) => x.position.as_mut(),
             Node::Paragraph(x) => x.position.as_mut(),
         }
@@ -420,6 +467,9 @@ impl Node {
             Node::TableRow(x) => x.position = position,
             Node::TableCell(x) => x.position = position,
             Node::ListItem(x) => x.position = position,
+            Node::DescriptionList(x) => x.position = position,
+            Node::DescriptionTerm(x) => x.position = position,
+            Node::DescriptionDetails(x) => x.position = position,
             Node::Definition(x) => x.position = position,
             Node::Paragraph(x) => x.position = position,
         }
@@ -586,6 +636,59 @@ pub struct ListItem {
     pub checked: Option<bool>,
 }
 
+/// Description list.
+///
+/// ```markdown
+/// > | a
+///     ^
+/// > | : b
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DescriptionList {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// One or more of its children are separated with a blank line from its
+    /// siblings (when `true`), or not (when `false`).
+    pub spread: bool,
+}
+
+/// Description term.
+///
+/// ```markdown
+/// > | a
+///     ^
+///   | : b
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DescriptionTerm {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Description details.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DescriptionDetails {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
 /// Html (flow or phrasing).
 ///
 /// ```markdown
@@ -766,6 +869,8 @@ pub struct Break {
     // Void.
     /// Positional info.
     pub position: Option<Position>,
+    /// Style of the break.
+    pub kind: BreakKind,
 }
 
 /// Link.
@@ -787,6 +892,14 @@ pub struct Link {
     /// Advisory info for the resource, such as something that would be
     /// appropriate for a tooltip.
     pub title: Option<String>,
+    /// Whether the link is an autolink (`<https://example.com>`).
+    ///
+    /// GFM autolink literals (`https://example.com`) are not autolinks in
+    /// this sense, as they are not wrapped in angle brackets.
+    /// When serializing to markdown, autolinks are written with angle
+    /// brackets, and other links with the same text and URL are written as
+    /// is where possible.
+    pub autolink: bool,
 }
 
 /// Image.
@@ -1441,11 +1554,14 @@ mod tests {
 
     #[test]
     fn break_node() {
-        let mut node = Node::Break(Break { position: None });
+        let mut node = Node::Break(Break {
+            position: None,
+            kind: BreakKind::Escape,
+        });
 
         assert_eq!(
             format!("{:?}", node),
-            "Break { position: None }",
+            "Break { position: None, kind: Escape }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1456,7 +1572,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Break { position: Some(1:1-1:2 (0-1)) }",
+            "Break { position: Some(1:1-1:2 (0-1)), kind: Escape }",
             "should support `position_set`"
         );
     }
@@ -1949,6 +2065,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn description_list() {
+        let mut node = Node::DescriptionList(DescriptionList {
+            position: None,
+            spread: false,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DescriptionList { children: [], position: None, spread: false }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DescriptionList { children: [], position: Some(1:1-1:2 (0-1)), spread: false }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn description_term() {
+        let mut node = Node::DescriptionTerm(DescriptionTerm {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DescriptionTerm { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DescriptionTerm { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn description_details() {
+        let mut node = Node::DescriptionDetails(DescriptionDetails {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DescriptionDetails { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DescriptionDetails { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
     #[test]
     fn link_reference() {
         let mut node = Node::LinkReference(LinkReference {
@@ -1987,12 +2191,13 @@ mod tests {
             position: None,
             url: "a".into(),
             title: None,
+            autolink: false,
             children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Link { children: [], position: None, url: \"a\", title: None }",
+            "Link { children: [], position: None, url: \"a\", title: None, autolink: false }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2007,7 +2212,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Link { children: [], position: Some(1:1-1:2 (0-1)), url: \"a\", title: None }",
+            "Link { children: [], position: Some(1:1-1:2 (0-1)), url: \"a\", title: None, autolink: false }",
             "should support `position_set`"
         );
     }
@@ -0,0 +1,255 @@
+//! Turn events into the links and images in them, with what they resolve to.
+
+use crate::configuration::{DefinitionConflict, ParseOptions};
+use crate::event::{Event, Kind, Name};
+use crate::mdast::ReferenceKind;
+use crate::unist::{Point, Position};
+use crate::util::{
+    normalize_identifier::normalize_identifier,
+    serialize::serialize_events,
+    slice::{Position as SlicePosition, Slice},
+};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Link or image, and what it resolves to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Media {
+    /// Whether this is an image (`true`) or a link (`false`).
+    pub image: bool,
+    /// Index of the enter event of the link or image.
+    pub start: usize,
+    /// Index of the exit event of the link or image.
+    pub end: usize,
+    /// Place of the link or image in the source.
+    pub position: Position,
+    /// Explicitness of the reference (`[a]`, `[a][]`, `[a][b]`), or `None`
+    /// for a resource (`[a](b)`).
+    pub reference_kind: Option<ReferenceKind>,
+    /// Identifier of the definition, or `None` for a resource.
+    ///
+    /// Normalized in the same way as in mdast.
+    pub identifier: Option<String>,
+    /// Destination (url).
+    ///
+    /// For references, this comes from the definition.
+    /// Character escapes and references are decoded, but the destination is
+    /// not sanitized.
+    pub destination: String,
+    /// Title, if any.
+    ///
+    /// For references, this comes from the definition.
+    pub title: Option<String>,
+}
+
+/// Link or image that is not yet closed.
+#[derive(Debug)]
+struct Open {
+    /// Index into the result, or `None` for a GFM footnote call.
+    media: Option<usize>,
+    /// Whether there was a resource.
+    resource: bool,
+    /// Indices of the label text.
+    label_id: Option<(usize, usize)>,
+    /// Indices of the reference string.
+    reference_id: Option<(usize, usize)>,
+}
+
+/// Turn events into media.
+pub fn compile(events: &[Event], bytes: &[u8], options: &ParseOptions) -> Vec<Media> {
+    let mut definitions = definitions(events, bytes, options);
+    let mut media: Vec<Media> = vec![];
+    let mut stack: Vec<Open> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match (&event.kind, &event.name) {
+            (Kind::Enter, Name::GfmFootnoteCall) => stack.push(Open {
+                media: None,
+                resource: false,
+                label_id: None,
+                reference_id: None,
+            }),
+            (Kind::Enter, Name::Image | Name::Link) => {
+                stack.push(Open {
+                    media: Some(media.len()),
+                    resource: false,
+                    label_id: None,
+                    reference_id: None,
+                });
+                media.push(Media {
+                    image: event.name == Name::Image,
+                    start: index,
+                    end: index,
+                    position: Position {
+                        start: point(event),
+                        end: point(event),
+                    },
+                    reference_kind: None,
+                    identifier: None,
+                    destination: String::new(),
+                    title: None,
+                });
+            }
+            (Kind::Enter, Name::Resource) => {
+                stack.last_mut().unwrap().resource = true;
+            }
+            (Kind::Exit, Name::LabelText) => {
+                stack.last_mut().unwrap().label_id =
+                    Some(SlicePosition::from_exit_event(events, index).to_indices());
+            }
+            (Kind::Exit, Name::ReferenceString) => {
+                stack.last_mut().unwrap().reference_id =
+                    Some(SlicePosition::from_exit_event(events, index).to_indices());
+            }
+            (Kind::Exit, Name::ResourceDestinationString) => {
+                let open = stack.last().unwrap();
+                media[open.media.unwrap()].destination = string(events, bytes, index);
+            }
+            (Kind::Exit, Name::ResourceTitleString) => {
+                let open = stack.last().unwrap();
+                media[open.media.unwrap()].title = Some(string(events, bytes, index));
+            }
+            (Kind::Exit, Name::GfmFootnoteCall | Name::Image | Name::Link) => {
+                let open = stack.pop().unwrap();
+
+                if let Some(media_index) = open.media {
+                    let item = &mut media[media_index];
+                    item.end = index;
+                    item.position.end = point(event);
+
+                    // It’s a reference.
+                    if !open.resource {
+                        item.reference_kind = Some(if open.reference_id.is_some() {
+                            ReferenceKind::Full
+                        } else if index > 0 && events[index - 1].name == Name::Reference {
+                            ReferenceKind::Collapsed
+                        } else {
+                            ReferenceKind::Shortcut
+                        });
+
+                        let indices = open.reference_id.or(open.label_id).unwrap();
+                        let value = Slice::from_indices(bytes, indices.0, indices.1);
+                        let value = value.as_str();
+                        let id = normalize_identifier(value);
+                        let mut definition_index = 0;
+
+                        while definition_index < definitions.len()
+                            && definitions[definition_index].0 != id
+                        {
+                            definition_index += 1;
+                        }
+
+                        // Not defined in the document or externally, so it
+                        // must be resolved.
+                        // Keep the result, so the resolver is called once
+                        // per identifier.
+                        if definition_index == definitions.len() {
+                            let (destination, title) = options
+                                .reference_resolver
+                                .as_ref()
+                                .and_then(|resolve| resolve(value))
+                                .unwrap_or_default();
+                            definitions.push((id.clone(), destination, title));
+                        }
+
+                        item.identifier = Some(id.to_lowercase());
+                        item.destination
+                            .clone_from(&definitions[definition_index].1);
+                        item.title.clone_from(&definitions[definition_index].2);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    media
+}
+
+/// Collect the definitions in the document, followed by the external ones.
+///
+/// The first definition of an identifier is used, unless
+/// `definition_conflict` is `KeepLast`.
+fn definitions(
+    events: &[Event],
+    bytes: &[u8],
+    options: &ParseOptions,
+) -> Vec<(String, String, Option<String>)> {
+    let mut definitions: Vec<(String, String, Option<String>)> = vec![];
+    let mut id = None;
+    let mut destination = None;
+    let mut title = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            match event.name {
+                Name::DefinitionLabelString => {
+                    id = Some(normalize_identifier(
+                        Slice::from_position(bytes, &SlicePosition::from_exit_event(events, index))
+                            .as_str(),
+                    ));
+                }
+                Name::DefinitionDestinationString => {
+                    destination = Some(string(events, bytes, index));
+                }
+                Name::DefinitionTitleString => {
+                    title = Some(string(events, bytes, index));
+                }
+                Name::Definition => {
+                    if let Some(id) = id.take() {
+                        let definition = (id, destination.take().unwrap_or_default(), title);
+
+                        if let Some(existing) = definitions.iter_mut().find(|d| d.0 == definition.0)
+                        {
+                            if options.definition_conflict == DefinitionConflict::KeepLast {
+                                *existing = definition;
+                            }
+                        } else {
+                            definitions.push(definition);
+                        }
+                    }
+
+                    destination = None;
+                    title = None;
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    for (id, (destination, title)) in &options.external_definitions {
+        let id = normalize_identifier(id);
+        if !definitions.iter().any(|d| d.0 == id) {
+            definitions.push((id, destination.clone(), title.clone()));
+        }
+    }
+
+    definitions
+}
+
+/// Get the value of string content, such as a destination or title, with
+/// character escapes and references decoded.
+///
+/// `end` is the index of the exit event.
+fn string(events: &[Event], bytes: &[u8], end: usize) -> String {
+    let mut start = end;
+    while !(events[start].kind == Kind::Enter && events[start].name == events[end].name) {
+        start -= 1;
+    }
+
+    serialize_events(&events[start + 1..end], bytes)
+}
+
+/// Create a point from an event.
+fn point(event: &Event) -> Point {
+    Point::new(event.point.line, event.point.column, event.point.index)
+}
@@ -1,10 +1,14 @@
 //! Turn bytes of markdown into events.
 
-use crate::event::{Event, Point};
+use crate::construct::attributes::resolve as resolve_attributes;
+use crate::event::{Content, Event, Point};
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::subtokenize;
 use crate::tokenizer::Tokenizer;
-use crate::util::location::Location;
+use crate::util::{
+    column::convert as convert_columns, location::Location,
+    normalize_identifier::normalize_identifier,
+};
 use crate::ParseOptions;
 use alloc::{string::String, vec, vec::Vec};
 
@@ -20,10 +24,17 @@ pub struct ParseState<'a> {
     pub options: &'a ParseOptions,
     /// List of chars.
     pub bytes: &'a [u8],
+    /// Set of defined abbreviation labels.
+    pub abbreviations: Vec<String>,
     /// Set of defined definition identifiers.
     pub definitions: Vec<String>,
     /// Set of defined GFM footnote definition identifiers.
     pub gfm_footnote_definitions: Vec<String>,
+    /// List of definitions from the reference resolver, as identifier,
+    /// destination, and title.
+    ///
+    /// Kept so that compilers do not have to call the resolver again.
+    pub resolved_definitions: Vec<(String, String, Option<String>)>,
 }
 
 /// Turn a string of markdown into events.
@@ -32,6 +43,34 @@ pub struct ParseState<'a> {
 pub fn parse<'a>(
     value: &'a str,
     options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>), String> {
+    parse_from(value, options, StateName::DocumentStart)
+}
+
+/// Turn a string of a certain content type into events.
+///
+/// Unlike [`parse()`][], which starts at the document level, this starts
+/// directly in the given content type.
+pub fn tokenize(
+    value: &str,
+    content: &Content,
+    options: &ParseOptions,
+) -> Result<Vec<Event>, String> {
+    let name = match content {
+        Content::Flow => StateName::FlowStart,
+        Content::Content => StateName::ContentDefinitionBefore,
+        Content::String => StateName::StringStart,
+        Content::Text => StateName::TextStart,
+    };
+    let (events, _) = parse_from(value, options, name)?;
+    Ok(events)
+}
+
+/// Turn a string into events, starting at the state `name`.
+fn parse_from<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    name: StateName,
 ) -> Result<(Vec<Event>, ParseState<'a>), String> {
     let bytes = value.as_bytes();
 
@@ -43,8 +82,16 @@ pub fn parse<'a>(
         } else {
             None
         },
-        definitions: vec![],
+        // External definitions are known upfront, so references to them can
+        // be matched like references to definitions in the document.
+        definitions: options
+            .external_definitions
+            .keys()
+            .map(|id| normalize_identifier(id))
+            .collect(),
         gfm_footnote_definitions: vec![],
+        abbreviations: vec![],
+        resolved_definitions: vec![],
     };
 
     let start = Point {
@@ -55,21 +102,31 @@ pub fn parse<'a>(
     };
     let mut tokenizer = Tokenizer::new(start, &parse_state);
 
-    let state = tokenizer.push(
-        (0, 0),
-        (parse_state.bytes.len(), 0),
-        State::Next(StateName::DocumentStart),
-    );
+    let state = tokenizer.push((0, 0), (parse_state.bytes.len(), 0), State::Next(name));
     let mut result = tokenizer.flush(state, true)?;
     let mut events = tokenizer.events;
 
     loop {
         let fn_defs = &mut parse_state.gfm_footnote_definitions;
         let defs = &mut parse_state.definitions;
+        let abbrs = &mut parse_state.abbreviations;
+        let resolved_defs = &mut parse_state.resolved_definitions;
         fn_defs.append(&mut result.gfm_footnote_definitions);
         defs.append(&mut result.definitions);
+        abbrs.append(&mut result.abbreviations);
+        resolved_defs.append(&mut result.resolved_definitions);
 
         if result.done {
+            if options.constructs.attributes {
+                resolve_attributes(&mut events, bytes);
+            }
+
+            convert_columns(&mut events, bytes, options.column_mode);
+
+            for resolve in &options.resolvers {
+                resolve(&mut events, bytes)?;
+            }
+
             return Ok((events, parse_state));
         }
 
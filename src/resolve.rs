@@ -43,6 +43,12 @@ pub enum Name {
     /// They are wrapped into ordered or unordered lists based on whether items
     /// with the same marker occur next to each other.
     ListItem,
+    /// Resolve definition list.
+    ///
+    /// Description details are parsed on their own.
+    /// They are grouped into lists with the paragraphs before them, which
+    /// become terms.
+    DefinitionList,
     /// Resolve content.
     ///
     /// Content is parsed as single lines, as what remains if other flow
@@ -71,6 +77,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> Result<Option<Subresult>,
         Name::HeadingAtx => construct::heading_atx::resolve(tokenizer),
         Name::HeadingSetext => construct::heading_setext::resolve(tokenizer),
         Name::ListItem => construct::list_item::resolve(tokenizer),
+        Name::DefinitionList => construct::definition_list::resolve(tokenizer),
         Name::Content => construct::content::resolve(tokenizer)?,
         Name::Data => construct::partial_data::resolve(tokenizer),
         Name::String => construct::string::resolve(tokenizer),
@@ -43,9 +43,38 @@ impl State {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[allow(clippy::enum_variant_names)]
 pub enum Name {
+    AbbreviationDefinitionStart,
+    AbbreviationDefinitionBefore,
+    AbbreviationDefinitionLabelBefore,
+    AbbreviationDefinitionLabelAtString,
+    AbbreviationDefinitionLabelInside,
+    AbbreviationDefinitionLabelAfter,
+    AbbreviationDefinitionMarkerAfter,
+    AbbreviationDefinitionTitleBefore,
+    AbbreviationDefinitionTitleInside,
+    AbbreviationDefinitionTitleContinue,
+    AbbreviationDefinitionTitleEnd,
+    AbbreviationDefinitionAfter,
+
     AttentionStart,
     AttentionInside,
 
+    AttributesStart,
+    AttributesOpenAfter,
+    AttributesColonAfter,
+    AttributesAttributeBefore,
+    AttributesShorthandBefore,
+    AttributesShorthandInside,
+    AttributesNameInside,
+    AttributesNameAfter,
+    AttributesValueBefore,
+    AttributesValueQuotedBefore,
+    AttributesValueQuotedInside,
+    AttributesValueUnquotedInside,
+    AttributesAttributeAfter,
+    AttributesWhitespaceAfter,
+    AttributesEnd,
+
     AutolinkStart,
     AutolinkOpen,
     AutolinkSchemeOrEmailAtext,
@@ -107,6 +136,14 @@ pub enum Name {
     DefinitionTitleAfter,
     DefinitionTitleAfterOptionalWhitespace,
 
+    DefinitionListStart,
+    DefinitionListBefore,
+    DefinitionListMarkerAfter,
+    DefinitionListWhitespaceAfter,
+    DefinitionListContStart,
+    DefinitionListContBlank,
+    DefinitionListContFilled,
+
     DestinationStart,
     DestinationEnclosedBefore,
     DestinationEnclosed,
@@ -122,6 +159,7 @@ pub enum Name {
     DocumentContainerNewBeforeNotBlockQuote,
     DocumentContainerNewBeforeNotList,
     DocumentContainerNewBeforeNotGfmFootnoteDefinition,
+    DocumentContainerNewBeforeNotDefinitionList,
     DocumentContainerNewAfter,
     DocumentContainersAfter,
     DocumentFlowInside,
@@ -137,6 +175,7 @@ pub enum Name {
     FlowBeforeHeadingAtx,
     FlowBeforeHeadingSetext,
     FlowBeforeThematicBreak,
+    FlowBeforeAbbreviationDefinition,
     FlowAfter,
     FlowBlankLineBefore,
     FlowBlankLineAfter,
@@ -157,6 +196,8 @@ pub enum Name {
     GfmAutolinkLiteralProtocolAfter,
     GfmAutolinkLiteralProtocolPrefixInside,
     GfmAutolinkLiteralProtocolSlashesInside,
+    GfmAutolinkLiteralProtocolHostStart,
+    GfmAutolinkLiteralProtocolIpv6Inside,
 
     GfmAutolinkLiteralWwwStart,
     GfmAutolinkLiteralWwwAfter,
@@ -427,6 +468,7 @@ pub enum Name {
 
     RawTextStart,
     RawTextSequenceOpen,
+    RawTextLanguage,
     RawTextBetween,
     RawTextData,
     RawTextSequenceClose,
@@ -448,8 +490,10 @@ pub enum Name {
     TextStart,
     TextBefore,
     TextBeforeHtml,
+    TextBeforeMdxExpression,
     TextBeforeMdxJsx,
     TextBeforeHardBreakEscape,
+    TextBeforeGfmAutolinkLiteralWww,
     TextBeforeLabelStartLink,
     TextBeforeData,
 
@@ -471,9 +515,42 @@ pub enum Name {
 /// Call the corresponding state for a state name.
 pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
     let func = match name {
+        Name::AbbreviationDefinitionStart => construct::abbreviation_definition::start,
+        Name::AbbreviationDefinitionBefore => construct::abbreviation_definition::before,
+        Name::AbbreviationDefinitionLabelBefore => construct::abbreviation_definition::label_before,
+        Name::AbbreviationDefinitionLabelAtString => {
+            construct::abbreviation_definition::label_at_string
+        }
+        Name::AbbreviationDefinitionLabelInside => construct::abbreviation_definition::label_inside,
+        Name::AbbreviationDefinitionLabelAfter => construct::abbreviation_definition::label_after,
+        Name::AbbreviationDefinitionMarkerAfter => construct::abbreviation_definition::marker_after,
+        Name::AbbreviationDefinitionTitleBefore => construct::abbreviation_definition::title_before,
+        Name::AbbreviationDefinitionTitleInside => construct::abbreviation_definition::title_inside,
+        Name::AbbreviationDefinitionTitleContinue => {
+            construct::abbreviation_definition::title_continue
+        }
+        Name::AbbreviationDefinitionTitleEnd => construct::abbreviation_definition::title_end,
+        Name::AbbreviationDefinitionAfter => construct::abbreviation_definition::after,
+
         Name::AttentionStart => construct::attention::start,
         Name::AttentionInside => construct::attention::inside,
 
+        Name::AttributesStart => construct::attributes::start,
+        Name::AttributesOpenAfter => construct::attributes::open_after,
+        Name::AttributesColonAfter => construct::attributes::colon_after,
+        Name::AttributesAttributeBefore => construct::attributes::attribute_before,
+        Name::AttributesShorthandBefore => construct::attributes::shorthand_before,
+        Name::AttributesShorthandInside => construct::attributes::shorthand_inside,
+        Name::AttributesNameInside => construct::attributes::name_inside,
+        Name::AttributesNameAfter => construct::attributes::name_after,
+        Name::AttributesValueBefore => construct::attributes::value_before,
+        Name::AttributesValueQuotedBefore => construct::attributes::value_quoted_before,
+        Name::AttributesValueQuotedInside => construct::attributes::value_quoted_inside,
+        Name::AttributesValueUnquotedInside => construct::attributes::value_unquoted_inside,
+        Name::AttributesAttributeAfter => construct::attributes::attribute_after,
+        Name::AttributesWhitespaceAfter => construct::attributes::whitespace_after,
+        Name::AttributesEnd => construct::attributes::end,
+
         Name::AutolinkStart => construct::autolink::start,
         Name::AutolinkOpen => construct::autolink::open,
         Name::AutolinkSchemeOrEmailAtext => construct::autolink::scheme_or_email_atext,
@@ -537,6 +614,14 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
             construct::definition::title_after_optional_whitespace
         }
 
+        Name::DefinitionListStart => construct::definition_list::start,
+        Name::DefinitionListBefore => construct::definition_list::before,
+        Name::DefinitionListMarkerAfter => construct::definition_list::marker_after,
+        Name::DefinitionListWhitespaceAfter => construct::definition_list::whitespace_after,
+        Name::DefinitionListContStart => construct::definition_list::cont_start,
+        Name::DefinitionListContBlank => construct::definition_list::cont_blank,
+        Name::DefinitionListContFilled => construct::definition_list::cont_filled,
+
         Name::DestinationStart => construct::partial_destination::start,
         Name::DestinationEnclosedBefore => construct::partial_destination::enclosed_before,
         Name::DestinationEnclosed => construct::partial_destination::enclosed,
@@ -558,6 +643,9 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DocumentContainerNewBeforeNotGfmFootnoteDefinition => {
             construct::document::container_new_before_not_footnote_definition
         }
+        Name::DocumentContainerNewBeforeNotDefinitionList => {
+            construct::document::container_new_before_not_definition_list
+        }
         Name::DocumentContainerNewAfter => construct::document::container_new_after,
         Name::DocumentContainersAfter => construct::document::containers_after,
         Name::DocumentFlowEnd => construct::document::flow_end,
@@ -576,6 +664,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::FlowAfter => construct::flow::after,
         Name::FlowBlankLineBefore => construct::flow::blank_line_before,
         Name::FlowBlankLineAfter => construct::flow::blank_line_after,
+        Name::FlowBeforeAbbreviationDefinition => construct::flow::before_abbreviation_definition,
         Name::FlowBeforeContent => construct::flow::before_content,
 
         Name::FrontmatterStart => construct::frontmatter::start,
@@ -597,6 +686,12 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::GfmAutolinkLiteralProtocolSlashesInside => {
             construct::gfm_autolink_literal::protocol_slashes_inside
         }
+        Name::GfmAutolinkLiteralProtocolHostStart => {
+            construct::gfm_autolink_literal::protocol_host_start
+        }
+        Name::GfmAutolinkLiteralProtocolIpv6Inside => {
+            construct::gfm_autolink_literal::protocol_ipv6_inside
+        }
         Name::GfmAutolinkLiteralWwwAfter => construct::gfm_autolink_literal::www_after,
         Name::GfmAutolinkLiteralWwwStart => construct::gfm_autolink_literal::www_start,
         Name::GfmAutolinkLiteralWwwPrefixInside => {
@@ -925,6 +1020,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
 
         Name::RawTextStart => construct::raw_text::start,
         Name::RawTextSequenceOpen => construct::raw_text::sequence_open,
+        Name::RawTextLanguage => construct::raw_text::language,
         Name::RawTextBetween => construct::raw_text::between,
         Name::RawTextData => construct::raw_text::data,
         Name::RawTextSequenceClose => construct::raw_text::sequence_close,
@@ -946,8 +1042,10 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::TextStart => construct::text::start,
         Name::TextBefore => construct::text::before,
         Name::TextBeforeHtml => construct::text::before_html,
+        Name::TextBeforeMdxExpression => construct::text::before_mdx_expression,
         Name::TextBeforeMdxJsx => construct::text::before_mdx_jsx,
         Name::TextBeforeHardBreakEscape => construct::text::before_hard_break_escape,
+        Name::TextBeforeGfmAutolinkLiteralWww => construct::text::before_gfm_autolink_literal_www,
         Name::TextBeforeLabelStartLink => construct::text::before_label_start_link,
         Name::TextBeforeData => construct::text::before_data,
 
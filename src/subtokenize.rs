@@ -27,8 +27,10 @@ use alloc::{string::String, vec, vec::Vec};
 #[derive(Debug)]
 pub struct Subresult {
     pub done: bool,
+    pub abbreviations: Vec<String>,
     pub gfm_footnote_definitions: Vec<String>,
     pub definitions: Vec<String>,
+    pub resolved_definitions: Vec<(String, String, Option<String>)>,
 }
 
 /// Link two [`Event`][]s.
@@ -83,8 +85,10 @@ pub fn subtokenize(
     let mut index = 0;
     let mut value = Subresult {
         done: true,
+        abbreviations: vec![],
         gfm_footnote_definitions: vec![],
         definitions: vec![],
+        resolved_definitions: vec![],
     };
     let mut acc = (0, 0);
 
@@ -164,10 +168,14 @@ pub fn subtokenize(
                 }
 
                 let mut result = tokenizer.flush(state, true)?;
+                value.abbreviations.append(&mut result.abbreviations);
                 value
                     .gfm_footnote_definitions
                     .append(&mut result.gfm_footnote_definitions);
                 value.definitions.append(&mut result.definitions);
+                value
+                    .resolved_definitions
+                    .append(&mut result.resolved_definitions);
                 value.done = false;
 
                 acc = divide_events(&mut map, events, index, &mut tokenizer.events, acc);
@@ -217,14 +225,11 @@ pub fn divide_events(
                 let old_prev = old_prev.unwrap();
                 let prev_event = &mut child_events[old_prev];
                 // The `index` in `events` where the current link is,
-                // minus one to get the previous link,
                 // minus 2 events (the enter and exit) for each removed
                 // link.
-                let new_link = if slices.is_empty() {
-                    old_prev + link_index + 2
-                } else {
-                    old_prev + link_index - (slices.len() - 1) * 2
-                };
+                // Note that linked events are not always adjacent: other
+                // events (such as a hard break) can be between them.
+                let new_link = child_index + link_index - slices.len() * 2;
                 prev_event.link.as_mut().unwrap().next =
                     Some(new_link + acc_before.1 - acc_before.0);
             }
@@ -1,25 +1,32 @@
 //! Turn events into a string of HTML.
+use crate::block_ranges::kind as block_kind;
 use crate::event::{Event, Kind, Name};
 use crate::mdast::AlignKind;
 use crate::util::{
+    char::{after_index as char_after_index, before_index as char_before_index, cjk},
     character_reference::decode as decode_character_reference,
     constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
     encode::encode,
     gfm_tagfilter::gfm_tagfilter,
-    infer::{gfm_table_align, list_loose},
+    infer::{attributes_index, description_list_loose, gfm_table_align, list_loose},
     normalize_identifier::normalize_identifier,
-    sanitize_uri::{sanitize, sanitize_with_protocols},
+    sanitize_uri::{is_external, normalize, sanitize, sanitize_with_protocols},
     skip,
     slice::{Position, Slice},
+    slug::slug,
+};
+use crate::{
+    CjkSoftBreak, CompileOptions, DefinitionConflict, EscapeContext, ImageInfo, LineEnding,
+    ParseOptions,
 };
-use crate::{CompileOptions, LineEnding};
 use alloc::{
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
-use core::str;
+use core::{fmt, str};
 
 /// Link, image, or footnote call.
 /// Resource or reference.
@@ -50,9 +57,10 @@ struct Media {
     ///
     /// Interpreted string content.
     destination: Option<String>,
-    /// The destination (url).
+    /// The title.
     ///
     /// Interpreted string content.
+    /// Encoded, or not encoded if there is an `attribute_escaper`.
     title: Option<String>,
 }
 
@@ -68,9 +76,21 @@ struct Definition {
     /// The title.
     ///
     /// Interpreted string content.
+    /// Encoded, or not encoded if there is an `attribute_escaper`.
     title: Option<String>,
 }
 
+/// Attributes of an element, from attributes in markdown.
+#[derive(Debug, Default)]
+struct Attributes {
+    /// The `id`.
+    id: Option<String>,
+    /// The classes.
+    classes: Vec<String>,
+    /// Other names and values.
+    rest: Vec<(String, String)>,
+}
+
 /// Context used to compile markdown.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug)]
@@ -82,20 +102,40 @@ struct CompileContext<'a> {
     bytes: &'a [u8],
     /// Configuration.
     options: &'a CompileOptions,
+    /// Configuration used to parse.
+    parse_options: &'a ParseOptions,
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     /// Rank of heading (atx).
     heading_atx_rank: Option<usize>,
     /// Buffer of heading (setext) text.
     heading_setext_buffer: Option<String>,
+    /// `id` of the current heading.
+    heading_id: Option<String>,
+    /// Attributes of the current heading, other than its `id`.
+    heading_attributes: Attributes,
+    /// Number of times each heading `id` was used.
+    heading_id_counts: BTreeMap<String, usize>,
     /// Whether raw (flow) (code (fenced), math (flow)) or code (indented) contains data.
     raw_flow_seen_data: Option<bool>,
     /// Number of raw (flow) fences.
     raw_flow_fences_count: Option<usize>,
+    /// Index into the last buffer where the content of code starts.
+    raw_flow_content_start: Option<usize>,
+    /// Language and content of code (fenced), if it is passed to
+    /// `code_block_renderer`.
+    raw_flow_render: Option<(String, String)>,
+    /// Language and content of code (fenced), if it is passed to
+    /// `code_highlighter`.
+    raw_flow_highlight: Option<(String, String)>,
     /// Whether we are in code (text).
     raw_text_inside: bool,
     /// Whether we are in image text.
     image_alt_inside: bool,
+    /// Whether we are in a paragraph compiled as a figure.
+    figure_inside: bool,
+    /// Caption of the current figure (the title of its image).
+    figure_caption: Option<String>,
     /// Marker of character reference.
     character_reference_marker: Option<u8>,
     /// Whether we are expecting the first list item marker.
@@ -104,6 +144,8 @@ struct CompileContext<'a> {
     media_stack: Vec<Media>,
     /// Stack of containers.
     tight_stack: Vec<bool>,
+    /// List of abbreviation definitions: label and title.
+    abbreviations: Vec<(String, Option<String>)>,
     /// List of definitions.
     definitions: Vec<Definition>,
     /// List of definitions.
@@ -119,6 +161,9 @@ struct CompileContext<'a> {
     // Fields used to influance the current compilation.
     /// Ignore the next line ending.
     slurp_one_line_ending: bool,
+    /// Index of the last line ending checked for being in text, and whether
+    /// it was.
+    soft_break_last: Option<(usize, bool)>,
     /// Whether to encode HTML.
     encode_html: bool,
     // Configuration
@@ -137,6 +182,7 @@ impl<'a> CompileContext<'a> {
         events: &'a [Event],
         bytes: &'a [u8],
         options: &'a CompileOptions,
+        parse_options: &'a ParseOptions,
         line_ending: LineEnding,
     ) -> CompileContext<'a> {
         CompileContext {
@@ -144,12 +190,19 @@ impl<'a> CompileContext<'a> {
             bytes,
             heading_atx_rank: None,
             heading_setext_buffer: None,
+            heading_id: None,
+            heading_attributes: Attributes::default(),
+            heading_id_counts: BTreeMap::new(),
             raw_flow_seen_data: None,
             raw_flow_fences_count: None,
+            raw_flow_content_start: None,
+            raw_flow_render: None,
+            raw_flow_highlight: None,
             raw_text_inside: false,
             character_reference_marker: None,
             list_expect_first_marker: None,
             media_stack: vec![],
+            abbreviations: vec![],
             definitions: vec![],
             gfm_footnote_definitions: vec![],
             gfm_footnote_definition_calls: vec![],
@@ -159,12 +212,16 @@ impl<'a> CompileContext<'a> {
             gfm_table_column: 0,
             tight_stack: vec![],
             slurp_one_line_ending: false,
+            soft_break_last: None,
             image_alt_inside: false,
+            figure_inside: false,
+            figure_caption: None,
             encode_html: true,
             line_ending_default: line_ending,
             buffers: vec![String::new()],
             index: 0,
             options,
+            parse_options,
         }
     }
 
@@ -187,10 +244,242 @@ impl<'a> CompileContext<'a> {
 
     /// Add a line ending.
     fn line_ending(&mut self) {
+        self.trim_trailing_whitespace_if_needed();
         let eol = self.line_ending_default.as_str().to_string();
         self.push(&eol);
     }
 
+    /// Encode text, as needed for the configured escape context.
+    fn encode_text(&self, value: &str) -> String {
+        let mut value = encode(value, self.encode_html);
+
+        if self.encode_html
+            && !self.options.escape_gt
+            && self.raw_flow_seen_data.is_none()
+            && !self.raw_text_inside
+        {
+            value = value.replace("&gt;", ">");
+        }
+
+        if self.encode_html && self.options.escape_context == EscapeContext::Attribute {
+            value.replace('\'', "&#x27;")
+        } else {
+            value
+        }
+    }
+
+    /// Make a link destination safe, as needed for the configured protocols.
+    fn sanitize_href(&self, value: &str) -> String {
+        let url = if self.options.allow_dangerous_protocol {
+            sanitize(value)
+        } else if let Some(protocols) = &self.options.link_protocols {
+            let protocols = protocols.iter().map(String::as_str).collect::<Vec<_>>();
+            sanitize_with_protocols(value, &protocols)
+        } else {
+            sanitize_with_protocols(value, &SAFE_PROTOCOL_HREF)
+        };
+
+        self.escape_url(url, value)
+    }
+
+    /// Make an image source safe, as needed for the configured protocols.
+    fn sanitize_src(&self, value: &str) -> String {
+        let url = if self.options.allow_dangerous_protocol {
+            sanitize(value)
+        } else {
+            sanitize_with_protocols(value, &SAFE_PROTOCOL_SRC)
+        };
+
+        self.escape_url(url, value)
+    }
+
+    /// Use `attribute_escaper`, if given, on a url that was kept by
+    /// sanitizing.
+    fn escape_url(&self, url: String, value: &str) -> String {
+        match &self.options.attribute_escaper {
+            Some(escape) if !url.is_empty() => escape(&normalize(value)),
+            _ => url,
+        }
+    }
+
+    /// Encode the value of an attribute, with `attribute_escaper` if given.
+    fn encode_attribute(&self, value: &str) -> String {
+        if let Some(escape) = &self.options.attribute_escaper {
+            escape(value)
+        } else {
+            encode(value, true)
+        }
+    }
+
+    /// Encode the (not encoded) title of a definition that does not come
+    /// from the document, so that it is like titles in the document.
+    fn encode_title(&self, value: &str) -> String {
+        if self.options.attribute_escaper.is_some() {
+            value.into()
+        } else {
+            encode(value, true)
+        }
+    }
+
+    /// Add a `rel` attribute, if configured and the link is `external`, or
+    /// if the link opens in a new window (`blank`) and `auto_noopener` is on.
+    ///
+    /// Tokens are merged into the `rel` that the link itself has (`own`), if
+    /// any: tokens that are already there are not added again.
+    /// Returns whether `rel` was added.
+    fn push_link_rel(&mut self, own: Option<&str>, external: bool, blank: bool) -> bool {
+        let mut tokens = vec![];
+        let mut add = false;
+
+        if let Some(rel) = &self.options.link_rel {
+            if external {
+                tokens.extend(rel.split_ascii_whitespace().map(String::from));
+                add = true;
+            }
+        }
+
+        if blank && self.options.auto_noopener {
+            tokens.extend(["noopener".into(), "noreferrer".into()]);
+            add = true;
+        }
+
+        if !add {
+            return false;
+        }
+
+        let mut value = String::from(own.unwrap_or_default());
+
+        for token in tokens {
+            if !value
+                .split_ascii_whitespace()
+                .any(|d| d.eq_ignore_ascii_case(&token))
+            {
+                if !value.is_empty() {
+                    value.push(' ');
+                }
+
+                value.push_str(&token);
+            }
+        }
+
+        let value = format!(" rel=\"{}\"", self.encode_attribute(&value));
+        self.push(&value);
+        true
+    }
+
+    /// Remember where the content of code starts, if code lines are
+    /// configured to be wrapped or code is highlighted.
+    fn mark_raw_flow_content_start(&mut self) {
+        if (self.options.code_line_numbers || self.raw_flow_highlight.is_some())
+            && self.events[self.index].name != Name::MathFlowFence
+        {
+            let last_buf_opt = self.buffers.last();
+            let last_buf = last_buf_opt.expect("at least one buffer should exist");
+            self.raw_flow_content_start = Some(last_buf.len());
+        }
+    }
+
+    /// Remove spaces and tabs at the end of the last buffer, if configured
+    /// and not in raw (flow) or dangerous HTML.
+    fn trim_trailing_whitespace_if_needed(&mut self) {
+        if self.options.trim_trailing_whitespace
+            && self.raw_flow_seen_data.is_none()
+            && self.encode_html
+        {
+            let last_buf_opt = self.buffers.last_mut();
+            let last_buf = last_buf_opt.expect("at least one buffer should exist");
+            let len = last_buf.trim_end_matches(|c| c == ' ' || c == '\t').len();
+            last_buf.truncate(len);
+        }
+    }
+
+    /// Get the attributes of the element at `index`.
+    ///
+    /// Pass the index where the attributes are entered, or `None` to get the
+    /// attributes of the element entered at the current index.
+    fn attributes(&self, index: Option<usize>) -> Attributes {
+        let index = if self.parse_options.constructs.attributes {
+            index.or_else(|| attributes_index(self.events, self.index))
+        } else {
+            None
+        };
+
+        if let Some(index) = index {
+            collect_attributes(self.events, self.bytes, index)
+        } else {
+            Attributes::default()
+        }
+    }
+
+    /// Add attributes, except for names in `exclude`, which are already
+    /// there.
+    fn push_attributes(&mut self, attributes: &Attributes, exclude: &[&str]) {
+        if let Some(id) = &attributes.id {
+            let value = format!(" id=\"{}\"", self.encode_attribute(id));
+            self.push(&value);
+        }
+
+        if !attributes.classes.is_empty() {
+            let value = format!(
+                " class=\"{}\"",
+                self.encode_attribute(&attributes.classes.join(" "))
+            );
+            self.push(&value);
+        }
+
+        for (name, value) in &attributes.rest {
+            let event_handler = name.len() > 2 && name[0..2].eq_ignore_ascii_case("on");
+
+            if !exclude.iter().any(|d| name.eq_ignore_ascii_case(d))
+                && (!event_handler || self.options.allow_dangerous_html)
+            {
+                let value = format!(" {}=\"{}\"", name, self.encode_attribute(value));
+                self.push(&value);
+            }
+        }
+    }
+
+    /// Add an opening tag, with the attributes of the element entered at the
+    /// current index.
+    fn push_open(&mut self, name: &str) {
+        let attributes = self.attributes(None);
+        self.push("<");
+        self.push(name);
+        self.push_attributes(&attributes, &[]);
+        self.push(">");
+    }
+
+    /// Add the opening tag of a heading, with its `id`, attributes, and
+    /// anchor, if configured.
+    fn push_heading_open(&mut self, rank: &str) {
+        let attributes = core::mem::take(&mut self.heading_attributes);
+        self.push("<h");
+        self.push(rank);
+
+        if let Some(id) = self.heading_id.take() {
+            let id = encode(&id, true);
+            self.push(" id=\"");
+            self.push(&id);
+            self.push("\"");
+            self.push_attributes(&attributes, &[]);
+            self.push(">");
+
+            if self.options.heading_anchor_links {
+                let anchor = &self.options.heading_anchor;
+                let value = format!(
+                    "<a class=\"{}\" aria-hidden=\"true\" href=\"#{}\">{}</a>",
+                    encode(&anchor.class, true),
+                    id,
+                    anchor.content
+                );
+                self.push(&value);
+            }
+        } else {
+            self.push_attributes(&attributes, &[]);
+            self.push(">");
+        }
+    }
+
     /// Add a line ending if needed (as in, there’s no eol/eof already).
     fn line_ending_if_needed(&mut self) {
         let last_buf_opt = self.buffers.last();
@@ -203,8 +492,79 @@ impl<'a> CompileContext<'a> {
     }
 }
 
+/// Somewhere to write HTML to, while compiling.
+pub trait Sink {
+    /// Error when writing fails.
+    type Error;
+
+    /// Write a chunk of HTML.
+    fn write(&mut self, value: &str) -> Result<(), Self::Error>;
+}
+
+/// Sink for [`fmt::Write`][core::fmt::Write] (such as `String`).
+pub struct FmtSink<W: fmt::Write>(pub W);
+
+impl<W: fmt::Write> Sink for FmtSink<W> {
+    type Error = fmt::Error;
+
+    fn write(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.0.write_str(value)
+    }
+}
+
+/// Sink for [`io::Write`][std::io::Write] (such as files and sockets).
+#[cfg(feature = "std")]
+pub struct IoSink<W: std::io::Write>(pub W);
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Sink for IoSink<W> {
+    type Error = std::io::Error;
+
+    fn write(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.0.write_all(value.as_bytes())
+    }
+}
+
 /// Turn events and bytes into a string of HTML.
-pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> String {
+///
+/// `resolved_definitions` are the definitions that the reference resolver
+/// returned while parsing.
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    resolved_definitions: &[(String, String, Option<String>)],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+) -> String {
+    let mut sink = FmtSink(String::new());
+    compile_into(
+        events,
+        bytes,
+        resolved_definitions,
+        parse_options,
+        options,
+        &mut sink,
+    )
+    .expect("writing to a string should not fail");
+    sink.0
+}
+
+/// Turn events and bytes into HTML, written to `sink`.
+///
+/// The HTML of each top-level block is written when that block is done, so
+/// that large documents do not have to be kept in memory as HTML.
+///
+/// ## Errors
+///
+/// Errors when writing to `sink` fails, in which case compiling stops.
+pub fn compile_into<S: Sink>(
+    events: &[Event],
+    bytes: &[u8],
+    resolved_definitions: &[(String, String, Option<String>)],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+    sink: &mut S,
+) -> Result<(), S::Error> {
     let mut index = 0;
     let mut line_ending_inferred = None;
 
@@ -228,7 +588,8 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     let line_ending_default =
         line_ending_inferred.unwrap_or_else(|| options.default_line_ending.clone());
 
-    let mut context = CompileContext::new(events, bytes, options, line_ending_default);
+    let mut context =
+        CompileContext::new(events, bytes, options, parse_options, line_ending_default);
     let mut definition_indices = vec![];
     let mut index = 0;
     let mut definition_inside = false;
@@ -261,18 +622,70 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
             definition_inside = false;
             definition_indices.last_mut().unwrap().1 = index;
         }
+        // Abbreviation definitions contain no other constructs, so they can
+        // be collected here.
+        else if event.name == Name::AbbreviationDefinitionLabelString {
+            let label = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+            context.abbreviations.push((label.serialize(), None));
+        } else if event.name == Name::AbbreviationDefinitionTitle {
+            let title = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+            let title = context.encode_attribute(title.as_str());
+            context.abbreviations.last_mut().unwrap().1 = Some(title);
+        }
 
         index += 1;
     }
 
+    // Add external definitions after the ones in the document, so that
+    // definitions in the document take precedence.
+    for (id, (destination, title)) in &parse_options.external_definitions {
+        let title = title.as_ref().map(|title| context.encode_title(title));
+        context.definitions.push(Definition {
+            id: normalize_identifier(id),
+            destination: Some(destination.clone()),
+            title,
+        });
+    }
+
+    // Add definitions from the reference resolver last, as they are only
+    // used for references that are not defined otherwise.
+    for (id, destination, title) in resolved_definitions {
+        let title = title.as_ref().map(|title| context.encode_title(title));
+        context.definitions.push(Definition {
+            id: id.clone(),
+            destination: Some(destination.clone()),
+            title,
+        });
+    }
+
     let mut index = 0;
     let jump_default = (events.len(), events.len());
     let mut definition_index = 0;
     let mut jump = definition_indices
         .get(definition_index)
         .unwrap_or(&jump_default);
+    let mut depth = 0;
+    let mut block_index = 0;
+    let mut flushed = false;
 
     while index < events.len() {
+        let event = &events[index];
+
+        // Add HTML before top-level blocks, if configured.
+        // Definitions, which are jumped over, are blocks too.
+        if event.kind == Kind::Enter && depth == 0 {
+            if let Some(kind) = block_kind(&event.name) {
+                if let Some(on_block) = &options.on_block {
+                    if let Some(value) = on_block(kind, block_index) {
+                        context.line_ending_if_needed();
+                        context.push(&value);
+                    }
+                }
+
+                block_index += 1;
+            }
+        }
+
         if index == jump.0 {
             index = jump.1 + 1;
             definition_index += 1;
@@ -280,8 +693,19 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
                 .get(definition_index)
                 .unwrap_or(&jump_default);
         } else {
+            if event.kind == Kind::Enter {
+                depth += 1;
+            } else {
+                depth -= 1;
+            }
+
             handle(&mut context, index);
             index += 1;
+
+            // After a top-level block, write what we have.
+            if depth == 0 {
+                flush(&mut context, sink, &mut flushed)?;
+            }
         }
     }
 
@@ -291,11 +715,64 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     }
 
     debug_assert_eq!(context.buffers.len(), 1, "expected 1 final buffer");
-    context
-        .buffers
-        .get(0)
-        .expect("expected 1 final buffer")
-        .into()
+    let value = context.buffers.get(0).expect("expected 1 final buffer");
+    let value = if flushed || !options.trim_output {
+        value
+    } else {
+        trim_line_ending_start(value)
+    };
+
+    sink.write(if options.trim_output {
+        trim_line_ending_end(value)
+    } else {
+        value
+    })
+}
+
+/// Write the final buffer to `sink`, except for its last character and
+/// trailing whitespace, which can still be checked or changed.
+fn flush<S: Sink>(
+    context: &mut CompileContext,
+    sink: &mut S,
+    flushed: &mut bool,
+) -> Result<(), S::Error> {
+    if context.buffers.len() == 1 {
+        let value = &context.buffers[0];
+        let end = value.trim_end_matches(['\t', '\n', '\r', ' ']);
+        let end = end.char_indices().last().map_or(0, |(index, _)| index);
+
+        if end > 0 {
+            let mut chunk = &value[..end];
+
+            if !*flushed && context.options.trim_output {
+                chunk = trim_line_ending_start(chunk);
+            }
+
+            sink.write(chunk)?;
+            *flushed = true;
+            context.buffers[0].drain(..end);
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove a single line ending from the start of `value`.
+fn trim_line_ending_start(value: &str) -> &str {
+    value
+        .strip_prefix("\r\n")
+        .or_else(|| value.strip_prefix('\n'))
+        .or_else(|| value.strip_prefix('\r'))
+        .unwrap_or(value)
+}
+
+/// Remove a single line ending from the end of `value`.
+fn trim_line_ending_end(value: &str) -> &str {
+    value
+        .strip_suffix("\r\n")
+        .or_else(|| value.strip_suffix('\n'))
+        .or_else(|| value.strip_suffix('\r'))
+        .unwrap_or(value)
 }
 
 /// Handle the event at `index`.
@@ -312,11 +789,11 @@ fn handle(context: &mut CompileContext, index: usize) {
 /// Handle [`Enter`][Kind::Enter].
 fn enter(context: &mut CompileContext) {
     match context.events[context.index].name {
-        Name::CodeFencedFenceInfo
+        Name::Attributes
+        | Name::CodeFencedFenceInfo
         | Name::CodeFencedFenceMeta
         | Name::MathFlowFenceMeta
         | Name::DefinitionLabelString
-        | Name::DefinitionTitleString
         | Name::GfmFootnoteDefinitionPrefix
         | Name::HeadingAtxText
         | Name::HeadingSetextText
@@ -326,8 +803,9 @@ fn enter(context: &mut CompileContext) {
         | Name::MdxTextExpression
         | Name::MdxJsxFlowTag
         | Name::MdxJsxTextTag
-        | Name::ReferenceString
-        | Name::ResourceTitleString => on_enter_buffer(context),
+        | Name::ReferenceString => on_enter_buffer(context),
+
+        Name::DefinitionTitleString | Name::ResourceTitleString => on_enter_title_string(context),
 
         Name::BlockQuote => on_enter_block_quote(context),
         Name::CodeIndented => on_enter_code_indented(context),
@@ -335,6 +813,9 @@ fn enter(context: &mut CompileContext) {
         Name::CodeText | Name::MathText => on_enter_raw_text(context),
         Name::Definition => on_enter_definition(context),
         Name::DefinitionDestinationString => on_enter_definition_destination_string(context),
+        Name::DescriptionDetails => on_enter_description_details(context),
+        Name::DescriptionList => on_enter_description_list(context),
+        Name::DescriptionTerm => on_enter_description_term(context),
         Name::Emphasis => on_enter_emphasis(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmFootnoteDefinition => on_enter_gfm_footnote_definition(context),
@@ -346,6 +827,7 @@ fn enter(context: &mut CompileContext) {
         Name::GfmTableHead => on_enter_gfm_table_head(context),
         Name::GfmTableRow => on_enter_gfm_table_row(context),
         Name::GfmTaskListItemCheck => on_enter_gfm_task_list_item_check(context),
+        Name::HeadingAtx | Name::HeadingSetext => on_enter_heading(context),
         Name::HtmlFlow => on_enter_html_flow(context),
         Name::HtmlText => on_enter_html_text(context),
         Name::Image => on_enter_image(context),
@@ -363,7 +845,8 @@ fn enter(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) {
     match context.events[context.index].name {
-        Name::CodeFencedFenceMeta
+        Name::Attributes
+        | Name::CodeFencedFenceMeta
         | Name::MathFlowFenceMeta
         | Name::MdxJsxTextTag
         | Name::MdxTextExpression
@@ -374,6 +857,7 @@ fn exit(context: &mut CompileContext) {
         Name::CharacterEscapeValue | Name::CodeTextData | Name::Data | Name::MathTextData => {
             on_exit_data(context);
         }
+        Name::Abbreviation => on_exit_abbreviation(context),
         Name::AutolinkEmail => on_exit_autolink_email(context),
         Name::AutolinkProtocol => on_exit_autolink_protocol(context),
         Name::BlankLineEnding => on_exit_blank_line_ending(context),
@@ -395,6 +879,9 @@ fn exit(context: &mut CompileContext) {
         Name::DefinitionDestinationString => on_exit_definition_destination_string(context),
         Name::DefinitionLabelString => on_exit_definition_label_string(context),
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::DescriptionDetails => on_exit_description_details(context),
+        Name::DescriptionList => on_exit_description_list(context),
+        Name::DescriptionTerm => on_exit_description_term(context),
         Name::Emphasis => on_exit_emphasis(context),
         Name::Frontmatter => on_exit_frontmatter(context),
         Name::GfmAutolinkLiteralEmail => on_exit_gfm_autolink_literal_email(context),
@@ -448,6 +935,16 @@ fn on_enter_buffer(context: &mut CompileContext) {
     context.buffer();
 }
 
+/// Handle [`Enter`][Kind::Enter]:{[`DefinitionTitleString`][Name::DefinitionTitleString],[`ResourceTitleString`][Name::ResourceTitleString]}.
+fn on_enter_title_string(context: &mut CompileContext) {
+    context.buffer();
+
+    // Titles are encoded when used, by the attribute escaper.
+    if context.options.attribute_escaper.is_some() {
+        context.encode_html = false;
+    }
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`BlockQuote`][Name::BlockQuote].
 fn on_enter_block_quote(context: &mut CompileContext) {
     context.tight_stack.push(false);
@@ -460,12 +957,29 @@ fn on_enter_code_indented(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
     context.push("<pre><code>");
+    context.mark_raw_flow_content_start();
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeFenced`][Name::CodeFenced],[`MathFlow`][Name::MathFlow]}.
 fn on_enter_raw_flow(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
+
+    // Compile as normal, but into a separate buffer, so that it can be
+    // replaced by the code block renderer.
+    if context.options.code_block_renderer.is_some()
+        && context.events[context.index].name == Name::CodeFenced
+    {
+        context.raw_flow_render = Some(code_fenced_language_and_content(context));
+        context.buffer();
+    }
+
+    if context.options.code_highlighter.is_some()
+        && context.events[context.index].name == Name::CodeFenced
+    {
+        context.raw_flow_highlight = Some(code_fenced_language_and_content(context));
+    }
+
     // Note that no `>` is used, which is added later (due to info)
     context.push("<pre><code");
     context.raw_flow_fences_count = Some(0);
@@ -475,14 +989,105 @@ fn on_enter_raw_flow(context: &mut CompileContext) {
     }
 }
 
+/// Get the language and the content of code (fenced), not encoded, at its
+/// [`Enter`][Kind::Enter].
+fn code_fenced_language_and_content(context: &CompileContext) -> (String, String) {
+    let mut index = context.index + 1;
+    let mut language = String::new();
+    let mut code = String::new();
+    let mut marker = None;
+    let mut info_inside = false;
+    let mut code_inside = false;
+    let mut fences = 0;
+
+    while !(context.events[index].kind == Kind::Exit
+        && context.events[index].name == Name::CodeFenced)
+    {
+        let event = &context.events[index];
+
+        if event.name == Name::CodeFencedFenceInfo {
+            info_inside = event.kind == Kind::Enter;
+        } else if event.kind == Kind::Exit {
+            let slice = Slice::from_position(
+                context.bytes,
+                &Position::from_exit_event(context.events, index),
+            );
+
+            match event.name {
+                Name::CharacterEscapeValue | Name::Data if info_inside => {
+                    language.push_str(slice.as_str());
+                }
+                Name::CharacterReferenceMarker => marker = Some(b'&'),
+                Name::CharacterReferenceMarkerHexadecimal => marker = Some(b'x'),
+                Name::CharacterReferenceMarkerNumeric => marker = Some(b'#'),
+                Name::CharacterReferenceValue if info_inside => {
+                    let value = decode_character_reference(
+                        slice.as_str(),
+                        marker.take().expect("expected `marker` to be set"),
+                        true,
+                    )
+                    .expect("expected to parse only valid named references");
+                    language.push_str(&value);
+                }
+                Name::CodeFencedFence => fences += 1,
+                // Must serialize to get virtual spaces.
+                Name::CodeFlowChunk => code.push_str(&slice.serialize()),
+                // The line ending after the opening fence is not code.
+                Name::LineEnding if fences == 1 => {
+                    if code_inside {
+                        code.push_str(slice.as_str());
+                    }
+                    code_inside = true;
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    // Drop the final line ending.
+    if code.ends_with('\n') {
+        code.pop();
+    }
+    if code.ends_with('\r') {
+        code.pop();
+    }
+
+    // The rest of the info is turned into attributes.
+    if context.options.code_info_as_data_attrs {
+        if let Some(index) = language.find(',') {
+            language.truncate(index);
+        }
+    }
+
+    (language, code)
+}
+
 /// Handle [`Enter`][Kind::Enter]:{[`CodeText`][Name::CodeText],[`MathText`][Name::MathText]}.
 fn on_enter_raw_text(context: &mut CompileContext) {
     context.raw_text_inside = true;
     if !context.image_alt_inside {
-        context.push("<code");
+        let mut attributes = context.attributes(None);
         if context.events[context.index].name == Name::MathText {
-            context.push(" class=\"language-math math-inline\"");
+            attributes
+                .classes
+                .splice(0..0, ["language-math".into(), "math-inline".into()]);
+        }
+        // Language hint, directly after the opening sequence.
+        else if let Some(event) = context.events.get(context.index + 3) {
+            if event.name == Name::CodeTextLanguage {
+                let language = Slice::from_position(
+                    context.bytes,
+                    &Position::from_exit_event(context.events, context.index + 4),
+                );
+                attributes
+                    .classes
+                    .insert(0, format!("language-{}", language.as_str()));
+            }
         }
+        context.push("<code");
+        context.push_attributes(&attributes, &[]);
         context.push(">");
     }
     context.buffer();
@@ -507,10 +1112,30 @@ fn on_enter_definition_destination_string(context: &mut CompileContext) {
     context.encode_html = false;
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DescriptionDetails`][Name::DescriptionDetails].
+fn on_enter_description_details(context: &mut CompileContext) {
+    context.line_ending_if_needed();
+    context.push("<dd>");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DescriptionList`][Name::DescriptionList].
+fn on_enter_description_list(context: &mut CompileContext) {
+    let loose = description_list_loose(context.events, context.index);
+    context.tight_stack.push(!loose);
+    context.line_ending_if_needed();
+    context.push("<dl>");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DescriptionTerm`][Name::DescriptionTerm].
+fn on_enter_description_term(context: &mut CompileContext) {
+    context.line_ending_if_needed();
+    context.push("<dt>");
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<em>");
+        context.push_open("em");
     }
 }
 
@@ -539,7 +1164,7 @@ fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<del>");
+        context.push_open("del");
     }
 }
 
@@ -605,18 +1230,110 @@ fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:{[`HeadingAtx`][Name::HeadingAtx],[`HeadingSetext`][Name::HeadingSetext]}.
+fn on_enter_heading(context: &mut CompileContext) {
+    let mut attributes = context.attributes(None);
+    let id = attributes.id.take();
+    context.heading_attributes = attributes;
+
+    // An `id` in attributes is used as is.
+    if let Some(id) = id {
+        if context.options.heading_ids {
+            context.heading_id_counts.insert(id.clone(), 0);
+        }
+
+        context.heading_id = Some(id);
+        return;
+    }
+
+    if !context.options.heading_ids {
+        return;
+    }
+
+    let name = context.events[context.index].name.clone();
+    let mut index = context.index + 1;
+    let mut text = String::new();
+    let mut marker = None;
+
+    while !(context.events[index].kind == Kind::Exit && context.events[index].name == name) {
+        let event = &context.events[index];
+
+        // Ignore destinations, titles, and references.
+        if event.kind == Kind::Enter && matches!(event.name, Name::Reference | Name::Resource) {
+            index = skip::opt(context.events, index, &[Name::Reference, Name::Resource]);
+            continue;
+        }
+
+        if event.kind == Kind::Exit {
+            let slice = Slice::from_position(
+                context.bytes,
+                &Position::from_exit_event(context.events, index),
+            );
+
+            match event.name {
+                Name::AutolinkEmail
+                | Name::AutolinkProtocol
+                | Name::CharacterEscapeValue
+                | Name::CodeTextData
+                | Name::Data
+                | Name::GfmAutolinkLiteralEmail
+                | Name::GfmAutolinkLiteralMailto
+                | Name::GfmAutolinkLiteralProtocol
+                | Name::GfmAutolinkLiteralWww
+                | Name::GfmAutolinkLiteralXmpp
+                | Name::MathTextData => text.push_str(slice.as_str()),
+                Name::CharacterReferenceMarker => marker = Some(b'&'),
+                Name::CharacterReferenceMarkerHexadecimal => marker = Some(b'x'),
+                Name::CharacterReferenceMarkerNumeric => marker = Some(b'#'),
+                Name::CharacterReferenceValue => {
+                    let value = decode_character_reference(
+                        slice.as_str(),
+                        marker.take().expect("expected `marker` to be set"),
+                        true,
+                    )
+                    .expect("expected to parse only valid named references");
+                    text.push_str(&value);
+                }
+                Name::LineEnding => text.push(' '),
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    let base = slug(text.trim());
+
+    // Empty headings do not get an `id`.
+    if base.is_empty() {
+        return;
+    }
+
+    let mut id = base.clone();
+
+    while context.heading_id_counts.contains_key(&id) {
+        let count = context.heading_id_counts.get_mut(&base).unwrap();
+        *count += 1;
+        id = format!("{}-{}", base, count);
+    }
+
+    context.heading_id_counts.insert(id.clone(), 0);
+    context.heading_id = Some(id);
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`HtmlFlow`][Name::HtmlFlow].
 fn on_enter_html_flow(context: &mut CompileContext) {
     context.line_ending_if_needed();
-    if context.options.allow_dangerous_html {
-        context.encode_html = false;
-    }
+    on_enter_html_text(context);
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`HtmlText`][Name::HtmlText].
 fn on_enter_html_text(context: &mut CompileContext) {
     if context.options.allow_dangerous_html {
         context.encode_html = false;
+    } else if context.options.remove_dangerous_html {
+        context.push("<!-- raw html removed -->");
+        context.buffer();
     }
 }
 
@@ -678,7 +1395,13 @@ fn on_enter_paragraph(context: &mut CompileContext) {
 
     if !tight {
         context.line_ending_if_needed();
-        context.push("<p>");
+
+        if context.options.image_figure && paragraph_is_image(context.events, context.index) {
+            context.figure_inside = true;
+            context.push_open("figure");
+        } else {
+            context.push_open("p");
+        }
     }
 }
 
@@ -699,10 +1422,42 @@ fn on_enter_resource_destination_string(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
 fn on_enter_strong(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<strong>");
+        context.push_open("strong");
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Abbreviation`][Name::Abbreviation].
+fn on_exit_abbreviation(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    );
+    let value = slice.as_str();
+    let text = context.encode_text(value);
+
+    if context.image_alt_inside {
+        context.push(&text);
+        return;
+    }
+
+    // Lookups use the first definition.
+    let title = context
+        .abbreviations
+        .iter()
+        .find(|d| d.0 == value)
+        .and_then(|d| d.1.clone());
+
+    context.push("<abbr");
+    if let Some(title) = title {
+        context.push(" title=\"");
+        context.push(&title);
+        context.push("\"");
+    }
+    context.push(">");
+    context.push(&text);
+    context.push("</abbr>");
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`AutolinkEmail`][Name::AutolinkEmail].
 fn on_exit_autolink_email(context: &mut CompileContext) {
     generate_autolink(
@@ -740,6 +1495,30 @@ fn on_exit_break(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`BlankLineEnding`][Name::BlankLineEnding].
 fn on_exit_blank_line_ending(context: &mut CompileContext) {
+    if context.options.preserve_empty_paragraphs {
+        // Skip over the enter of this blank line, and over whitespace and
+        // block quote prefixes before it.
+        let mut index = context.index - 1;
+
+        while index > 0
+            && matches!(
+                context.events[index - 1].name,
+                Name::SpaceOrTab | Name::BlockQuotePrefix | Name::BlockQuoteMarker
+            )
+        {
+            index -= 1;
+        }
+
+        // Extra blank lines are empty paragraphs.
+        if index > 0
+            && context.events[index - 1].kind == Kind::Exit
+            && context.events[index - 1].name == Name::BlankLineEnding
+        {
+            context.line_ending_if_needed();
+            context.push("<p></p>");
+        }
+    }
+
     if context.index == context.events.len() - 1 {
         context.line_ending_if_needed();
     }
@@ -781,21 +1560,22 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
     let value = decode_character_reference(slice.as_str(), marker, true)
         .expect("expected to parse only valid named references");
 
-    context.push(&encode(&value, context.encode_html));
+    context.push(&context.encode_text(&value));
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFlowChunk`][Name::CodeFlowChunk],[`MathFlowChunk`][Name::MathFlowChunk]}.
 fn on_exit_raw_flow_chunk(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(true);
-    context.push(&encode(
-        &Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        // Must serialize to get virtual spaces.
-        .serialize(),
-        context.encode_html,
-    ));
+    context.push(
+        &context.encode_text(
+            &Slice::from_position(
+                context.bytes,
+                &Position::from_exit_event(context.events, context.index),
+            )
+            // Must serialize to get virtual spaces.
+            .serialize(),
+        ),
+    );
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFencedFence`][Name::CodeFencedFence],[`MathFlowFence`][Name::MathFlowFence]}.
@@ -806,6 +1586,7 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
 
     if count == 0 {
         context.push(">");
+        context.mark_raw_flow_content_start();
         context.slurp_one_line_ending = true;
     }
 
@@ -817,9 +1598,33 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
 /// Note: math (flow) does not support `info`.
 fn on_exit_raw_flow_fence_info(context: &mut CompileContext) {
     let value = context.resume();
-    context.push(" class=\"language-");
-    context.push(&value);
-    context.push("\"");
+
+    if context.options.code_info_as_data_attrs {
+        let mut parts = value.split(',');
+        let language = parts.next().unwrap_or_default();
+
+        if !language.is_empty() {
+            context.push(" class=\"language-");
+            context.push(language);
+            context.push("\"");
+        }
+
+        for part in parts {
+            if !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_'))
+            {
+                context.push(" data-");
+                context.push(part);
+                context.push("=\"\"");
+            }
+        }
+    } else {
+        context.push(" class=\"language-");
+        context.push(&value);
+        context.push("\"");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFenced`][Name::CodeFenced],[`CodeIndented`][Name::CodeIndented],[`MathFlow`][Name::MathFlow]}.
@@ -845,14 +1650,60 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
     // line ending when needed.
     if context
         .raw_flow_seen_data
-        .take()
         .expect("`raw_flow_seen_data` must be defined")
     {
         context.line_ending_if_needed();
     }
 
+    context.raw_flow_seen_data = None;
+
+    if let Some(start) = context.raw_flow_content_start.take() {
+        let last_buf_opt = context.buffers.last_mut();
+        let last_buf = last_buf_opt.expect("at least one buffer should exist");
+        let mut value = last_buf.split_off(start);
+
+        if let Some((language, content)) = context.raw_flow_highlight.take() {
+            let highlight = context
+                .options
+                .code_highlighter
+                .as_ref()
+                .expect("expected `code_highlighter`");
+
+            if let Some(html) = highlight(&language, &content) {
+                // Keep the final line ending.
+                let eol = if value.ends_with("\r\n") {
+                    "\r\n"
+                } else if value.ends_with('\n') {
+                    "\n"
+                } else if value.ends_with('\r') {
+                    "\r"
+                } else {
+                    ""
+                };
+                value = format!("{}{}", html, eol);
+            }
+        }
+
+        if context.options.code_line_numbers {
+            value = wrap_lines(&value);
+        }
+
+        context.push(&value);
+    }
+
     context.push("</code></pre>");
 
+    if let Some((language, content)) = context.raw_flow_render.take() {
+        let default = context.resume();
+        let render = context
+            .options
+            .code_block_renderer
+            .as_ref()
+            .expect("expected `code_block_renderer`");
+        let value = render(&language, &content).unwrap_or(default);
+        context.push(&value);
+    }
+
     if let Some(count) = context.raw_flow_fences_count.take() {
         if count < 2 {
             context.line_ending_if_needed();
@@ -930,14 +1781,18 @@ fn on_exit_drop_slurp(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeTextData`][Name::CodeTextData],[`Data`][Name::Data],[`CharacterEscapeValue`][Name::CharacterEscapeValue]}.
 fn on_exit_data(context: &mut CompileContext) {
-    context.push(&encode(
-        Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        .as_str(),
-        context.encode_html,
-    ));
+    let position = Position::from_exit_event(context.events, context.index);
+    let slice = Slice::from_position(context.bytes, &position);
+    let value = slice.as_str();
+    let encoded = match context.options.expand_tabs {
+        // Code (text) keeps its tabs.
+        Some(size) if context.events[context.index].name == Name::Data && value.contains('\t') => {
+            let column = text_column(context.events, context.bytes, context.index, size);
+            context.encode_text(&expand_tabs(value, column, size))
+        }
+        _ => context.encode_text(value),
+    };
+    context.push(&encoded);
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Definition`][Name::Definition].
@@ -948,11 +1803,25 @@ fn on_exit_definition(context: &mut CompileContext) {
     let id =
         normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str());
 
-    context.definitions.push(Definition {
+    let definition = Definition {
         id,
         destination: media.destination,
         title: media.title,
-    });
+    };
+
+    // Lookups use the first definition, so to use the last one, replace it.
+    if context.parse_options.definition_conflict == DefinitionConflict::KeepLast {
+        if let Some(existing) = context
+            .definitions
+            .iter_mut()
+            .find(|d| d.id == definition.id)
+        {
+            *existing = definition;
+            return;
+        }
+    }
+
+    context.definitions.push(definition);
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`DefinitionDestinationString`][Name::DefinitionDestinationString].
@@ -974,6 +1843,46 @@ fn on_exit_definition_label_string(context: &mut CompileContext) {
 fn on_exit_definition_title_string(context: &mut CompileContext) {
     let buf = context.resume();
     context.media_stack.last_mut().unwrap().title = Some(buf);
+    context.encode_html = true;
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DescriptionDetails`][Name::DescriptionDetails].
+fn on_exit_description_details(context: &mut CompileContext) {
+    let tight = context.tight_stack.last().unwrap_or(&false);
+    let before_details = skip::opt_back(
+        context.events,
+        context.index - 1,
+        &[
+            Name::BlankLineEnding,
+            Name::BlockQuotePrefix,
+            Name::LineEnding,
+            Name::SpaceOrTab,
+            // Also ignore things that don’t contribute to the document.
+            Name::Definition,
+            Name::GfmFootnoteDefinition,
+        ],
+    );
+    let tight_paragraph = *tight && context.events[before_details].name == Name::Paragraph;
+
+    context.slurp_one_line_ending = false;
+
+    if !tight_paragraph {
+        context.line_ending_if_needed();
+    }
+
+    context.push("</dd>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DescriptionList`][Name::DescriptionList].
+fn on_exit_description_list(context: &mut CompileContext) {
+    context.tight_stack.pop();
+    context.line_ending_if_needed();
+    context.push("</dl>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DescriptionTerm`][Name::DescriptionTerm].
+fn on_exit_description_term(context: &mut CompileContext) {
+    context.push("</dt>");
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Emphasis`][Name::Emphasis].
@@ -1089,7 +1998,12 @@ fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
         return;
     }
 
-    context.push("<sup><a href=\"#");
+    if let Some(ref value) = context.options.gfm_footnote_ref_template.tag_name {
+        context.push("<");
+        context.push(&encode(value, context.encode_html));
+        context.push(">");
+    }
+    context.push("<a href=\"#");
     if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
         context.push(&encode(value, context.encode_html));
     } else {
@@ -1113,10 +2027,21 @@ fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
                 .to_string(),
         );
     }
-    context.push("\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">");
+    context.push("\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\"");
+    if let Some(ref value) = context.options.gfm_footnote_ref_template.class {
+        context.push(" class=\"");
+        context.push(&encode(value, context.encode_html));
+        context.push("\"");
+    }
+    context.push(">");
 
     context.push(&(call_index + 1).to_string());
-    context.push("</a></sup>");
+    context.push("</a>");
+    if let Some(ref value) = context.options.gfm_footnote_ref_template.tag_name {
+        context.push("</");
+        context.push(&encode(value, context.encode_html));
+        context.push(">");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmFootnoteDefinitionLabelString`][Name::GfmFootnoteDefinitionLabelString].
@@ -1245,9 +2170,25 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
         .len();
         context.line_ending_if_needed();
         context.heading_atx_rank = Some(rank);
-        context.push("<h");
-        context.push(&rank.to_string());
-        context.push(">");
+        context.push_heading_open(&rank.to_string());
+    }
+    // Closing fence.
+    else if context.options.keep_atx_trailing_hashes {
+        let mut start = context.events[context.index - 1].point.index;
+
+        // Keep the whitespace between text and the closing fence.
+        if context.events[context.index - 2].name == Name::SpaceOrTab
+            && context.events[context.index - 4].name == Name::HeadingAtxText
+        {
+            start = context.events[context.index - 3].point.index;
+        }
+
+        let value = Slice::from_indices(
+            context.bytes,
+            start,
+            context.events[context.index].point.index,
+        );
+        context.push(&context.encode_text(value.as_str()));
     }
 }
 
@@ -1272,20 +2213,25 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
         .expect("`heading_atx_rank` must be set in headings");
     let position = Position::from_exit_event(context.events, context.index);
     let head = context.bytes[position.start.index];
-    let rank = if head == b'-' { "2" } else { "1" };
+    let [equals, dash] = context.options.setext_as_atx_level;
+    let rank = if head == b'-' { dash } else { equals }
+        .clamp(1, 6)
+        .to_string();
 
     context.line_ending_if_needed();
-    context.push("<h");
-    context.push(rank);
-    context.push(">");
+    context.push_heading_open(&rank);
     context.push(&text);
     context.push("</h");
-    context.push(rank);
+    context.push(&rank);
     context.push(">");
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_exit_html(context: &mut CompileContext) {
+    if !context.options.allow_dangerous_html && context.options.remove_dangerous_html {
+        context.resume();
+    }
+
     context.encode_html = true;
 }
 
@@ -1298,9 +2244,9 @@ fn on_exit_html_data(context: &mut CompileContext) {
     let value = slice.as_str();
 
     let encoded = if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
-        encode(&gfm_tagfilter(value), context.encode_html)
+        context.encode_text(&gfm_tagfilter(value))
     } else {
-        encode(value, context.encode_html)
+        context.encode_text(value)
     };
 
     context.push(&encoded);
@@ -1325,11 +2271,28 @@ fn on_exit_line_ending(context: &mut CompileContext) {
     } else if context.slurp_one_line_ending
         // Ignore line endings after definitions.
         || (context.index > 1
-            && (context.events[context.index - 2].name == Name::Definition
-                || context.events[context.index - 2].name == Name::GfmFootnoteDefinition))
+            && matches!(
+                context.events[context.index - 2].name,
+                Name::AbbreviationDefinition | Name::Definition | Name::GfmFootnoteDefinition
+            ))
     {
         context.slurp_one_line_ending = false;
+    } else if context.options.cjk_soft_break != CjkSoftBreak::LineEnding
+        && line_ending_between_cjk(context)
+    {
+        if context.options.cjk_soft_break == CjkSoftBreak::Wbr {
+            context.push("<wbr />");
+        }
     } else {
+        context.trim_trailing_whitespace_if_needed();
+
+        if context.options.soft_break_as_hard_break
+            && !context.image_alt_inside
+            && line_ending_is_soft_break(context)
+        {
+            context.push("<br />");
+        }
+
         context.push(&encode(
             Slice::from_position(
                 context.bytes,
@@ -1341,6 +2304,94 @@ fn on_exit_line_ending(context: &mut CompileContext) {
     }
 }
 
+/// Check whether the line ending at the current (exit) event is a soft break:
+/// directly in text, and not after a hard break.
+fn line_ending_is_soft_break(context: &mut CompileContext) -> bool {
+    if context.index < 2 {
+        return false;
+    }
+
+    // Find the parent, by skipping over siblings.
+    // When a previous line ending in the same parent is found, that was
+    // already checked: which keeps this linear in long paragraphs.
+    let mut index = context.index - 1;
+    let mut depth = 0;
+    let mut in_text = false;
+
+    while index > 0 {
+        index -= 1;
+        let event = &context.events[index];
+
+        if event.kind == Kind::Exit {
+            if depth == 0 && event.name == Name::LineEnding {
+                if let Some((last, last_in_text)) = context.soft_break_last {
+                    if last == index {
+                        in_text = last_in_text;
+                        break;
+                    }
+                }
+            }
+
+            depth += 1;
+        } else if depth == 0 {
+            in_text = matches!(
+                event.name,
+                Name::Paragraph
+                    | Name::DescriptionTerm
+                    | Name::HeadingSetextText
+                    | Name::LabelText
+                    | Name::EmphasisText
+                    | Name::StrongText
+                    | Name::GfmStrikethroughText
+            );
+            break;
+        } else {
+            depth -= 1;
+        }
+    }
+
+    context.soft_break_last = Some((context.index, in_text));
+
+    let before = skip::opt_back(context.events, context.index - 2, &[Name::SpaceOrTab]);
+
+    in_text
+        && !matches!(
+            context.events[before].name,
+            Name::HardBreakEscape | Name::HardBreakTrailing
+        )
+}
+
+/// Check whether the line ending at the current (exit) event is directly
+/// between two CJK characters in text.
+fn line_ending_between_cjk(context: &CompileContext) -> bool {
+    let index = context.index;
+
+    if index < 2 {
+        return false;
+    }
+
+    // Only data counts: that excludes hard breaks and other constructs.
+    // Whitespace (which is trimmed) and container prefixes are skipped.
+    let before = skip::opt_back(context.events, index - 2, &[Name::SpaceOrTab]);
+    let after = skip::opt(
+        context.events,
+        index + 1,
+        &[Name::BlockQuotePrefix, Name::SpaceOrTab],
+    );
+
+    after < context.events.len()
+        && context.events[before].name == Name::Data
+        && context.events[after].name == Name::Data
+        && matches!(
+            char_before_index(context.bytes, context.events[before].point.index),
+            Some(char) if cjk(char)
+        )
+        && matches!(
+            char_after_index(context.bytes, context.events[after].point.index),
+            Some(char) if cjk(char)
+        )
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
 fn on_exit_list(context: &mut CompileContext) {
     context.tight_stack.pop();
@@ -1417,28 +2468,39 @@ fn on_exit_media(context: &mut CompileContext) {
 
     let media = context.media_stack.pop().unwrap();
     let label = media.label.unwrap();
-    let id = media.reference_id.or(media.label_id).map(|indices| {
-        normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str())
-    });
-
     let definition_index = if media.destination.is_none() {
-        id.map(|id| {
+        media.reference_id.or(media.label_id).map(|indices| {
+            let slice = Slice::from_indices(context.bytes, indices.0, indices.1);
+            let value = slice.as_str();
+            let id = normalize_identifier(value);
             let mut index = 0;
 
             while index < context.definitions.len() && context.definitions[index].id != id {
                 index += 1;
             }
 
-            debug_assert!(
-                index < context.definitions.len(),
-                "expected defined definition"
-            );
+            // References are only made to defined identifiers, but do not
+            // crash if that changes.
+            if index == context.definitions.len() {
+                context.definitions.push(Definition {
+                    id,
+                    destination: None,
+                    title: None,
+                });
+            }
+
             index
         })
     } else {
         None
     };
 
+    // Whether the destination points to another site.
+    // Checked before sanitizing, as that encodes backslashes, which browsers
+    // treat like slashes at the start (`\\a` is `//a`).
+    let mut external = false;
+    let mut url = String::new();
+
     if !is_in_image {
         if media.image {
             context.push("<img src=\"");
@@ -1453,18 +2515,12 @@ fn on_exit_media(context: &mut CompileContext) {
         };
 
         if let Some(destination) = destination {
-            let url = if context.options.allow_dangerous_protocol {
-                sanitize(destination)
+            url = if media.image {
+                context.sanitize_src(destination)
             } else {
-                sanitize_with_protocols(
-                    destination,
-                    if media.image {
-                        &SAFE_PROTOCOL_SRC
-                    } else {
-                        &SAFE_PROTOCOL_HREF
-                    },
-                )
+                context.sanitize_href(destination)
             };
+            external = !url.is_empty() && is_external(destination);
             context.push(&url);
         }
 
@@ -1480,19 +2536,93 @@ fn on_exit_media(context: &mut CompileContext) {
     if !is_in_image {
         context.push("\"");
 
-        let title = if let Some(index) = definition_index {
+        let mut title = if let Some(index) = definition_index {
             context.definitions[index].title.clone()
         } else {
             media.title
         };
 
-        if let Some(title) = title {
+        // Not encoded yet, as there is an attribute escaper.
+        if context.options.attribute_escaper.is_some() {
+            if media.image && context.figure_inside {
+                context.figure_caption = title.as_ref().map(|title| encode(title, true));
+            }
+
+            title = title.map(|title| context.encode_attribute(&title));
+        } else if media.image && context.figure_inside {
+            context.figure_caption.clone_from(&title);
+        }
+
+        if let Some(title) = &title {
             context.push(" title=\"");
-            context.push(&title);
+            context.push(title);
             context.push("\"");
         };
 
+        let next = context.index + 1;
+        let attributes = if next < context.events.len()
+            && context.events[next].kind == Kind::Enter
+            && context.events[next].name == Name::Attributes
+        {
+            Some(context.attributes(Some(next)))
+        } else {
+            None
+        };
+
+        // Whether `rel` from attributes was merged into the one we add.
+        let mut rel_merged = false;
+
+        if !media.image {
+            let mut own = None;
+            let mut blank = false;
+
+            if let Some(attributes) = &attributes {
+                for (name, value) in &attributes.rest {
+                    if name.eq_ignore_ascii_case("rel") {
+                        own = own.or(Some(value.as_str()));
+                    } else if name.eq_ignore_ascii_case("target") {
+                        blank = blank || value.eq_ignore_ascii_case("_blank");
+                    }
+                }
+            }
+
+            rel_merged = context.push_link_rel(own, external, blank);
+        }
+
+        if let Some(attributes) = attributes {
+            let exclude: &[&str] = if media.image {
+                &["src", "alt", "title"]
+            } else if rel_merged {
+                &["href", "title", "rel"]
+            } else {
+                &["href", "title"]
+            };
+            context.push_attributes(&attributes, exclude);
+        }
+
         if media.image {
+            if let Some(image_attributes) = &context.options.image_attributes {
+                let attributes = image_attributes(&ImageInfo {
+                    src: url,
+                    alt: label.clone(),
+                    title,
+                });
+
+                for (name, value) in attributes {
+                    // Do not override attributes that are already there.
+                    if !["src", "alt", "title"]
+                        .iter()
+                        .any(|d| name.eq_ignore_ascii_case(d))
+                    {
+                        context.push(" ");
+                        context.push(&name);
+                        context.push("=\"");
+                        context.push(&context.encode_attribute(&value));
+                        context.push("\"");
+                    }
+                }
+            }
+
             context.push(" /");
         }
 
@@ -1514,6 +2644,16 @@ fn on_exit_paragraph(context: &mut CompileContext) {
 
     if *tight {
         context.slurp_one_line_ending = true;
+    } else if context.figure_inside {
+        context.figure_inside = false;
+
+        if let Some(caption) = context.figure_caption.take() {
+            context.push("<figcaption>");
+            context.push(&caption);
+            context.push("</figcaption>");
+        }
+
+        context.push("</figure>");
     } else {
         context.push("</p>");
     }
@@ -1539,6 +2679,7 @@ fn on_exit_resource_destination_string(context: &mut CompileContext) {
 fn on_exit_resource_title_string(context: &mut CompileContext) {
     let buf = context.resume();
     context.media_stack.last_mut().unwrap().title = Some(buf);
+    context.encode_html = true;
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Strong`][Name::Strong].
@@ -1550,6 +2691,36 @@ fn on_exit_strong(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_exit_thematic_break(context: &mut CompileContext) {
+    if context.options.collapse_thematic_breaks {
+        let mut index = context.index - 1;
+
+        while context.events[index].name != Name::ThematicBreak {
+            index -= 1;
+        }
+
+        // Skip over whitespace, blank lines, and block quote prefixes before
+        // this break.
+        while index > 0
+            && matches!(
+                context.events[index - 1].name,
+                Name::SpaceOrTab
+                    | Name::LineEnding
+                    | Name::BlankLineEnding
+                    | Name::BlockQuotePrefix
+                    | Name::BlockQuoteMarker
+            )
+        {
+            index -= 1;
+        }
+
+        // Drop breaks directly after other breaks, and the line ending after
+        // them.
+        if index > 0 && context.events[index - 1].name == Name::ThematicBreak {
+            context.slurp_one_line_ending = true;
+            return;
+        }
+    }
+
     context.line_ending_if_needed();
     context.push("<hr />");
 }
@@ -1571,7 +2742,7 @@ fn generate_footnote_section(context: &mut CompileContext) {
     }
     context.push(">");
     if let Some(ref value) = context.options.gfm_footnote_label {
-        context.push(&encode(value, context.encode_html));
+        context.push(&context.encode_text(value));
     } else {
         context.push("Footnotes");
     }
@@ -1718,7 +2889,9 @@ fn generate_autolink(
         index += 1;
     }
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
+    let is_link = !context.image_alt_inside && (!is_in_link || !is_gfm_literal);
+
+    if is_link {
         context.push("<a href=\"");
         let url = if let Some(protocol) = protocol {
             format!("{}{}", protocol, value)
@@ -1726,19 +2899,262 @@ fn generate_autolink(
             value.into()
         };
 
-        let url = if context.options.allow_dangerous_protocol {
-            sanitize(&url)
-        } else {
-            sanitize_with_protocols(&url, &SAFE_PROTOCOL_HREF)
-        };
-
+        let url = context.sanitize_href(&url);
         context.push(&url);
-        context.push("\">");
+        context.push("\"");
+        context.push_link_rel(None, is_external(&url), false);
+        context.push(">");
     }
 
-    context.push(&encode(value, context.encode_html));
+    if is_link && context.options.autolink_break_opportunities {
+        let bytes = value.as_bytes();
+        let mut start = 0;
+        let mut index = 0;
+
+        // Add an opportunity after each `/`, `?`, `&`, and `.`, except at the
+        // end.
+        while index < bytes.len() {
+            index += 1;
+
+            if index < bytes.len() && matches!(bytes[index - 1], b'/' | b'?' | b'&' | b'.') {
+                context.push(&context.encode_text(&value[start..index]));
+                context.push("<wbr />");
+                start = index;
+            }
+        }
+
+        context.push(&context.encode_text(&value[start..]));
+    } else {
+        context.push(&context.encode_text(value));
+    }
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
+    if is_link {
         context.push("</a>");
     }
 }
+
+/// Wrap each line of (compiled) code in a `<span class="line">`.
+///
+/// Line endings are kept after each span.
+fn wrap_lines(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut result = String::with_capacity(value.len());
+    let mut index = 0;
+    let mut start = 0;
+
+    while index < bytes.len() {
+        if matches!(bytes[index], b'\n' | b'\r') {
+            result.push_str("<span class=\"line\">");
+            result.push_str(&value[start..index]);
+            result.push_str("</span>");
+            start = index;
+            // Keep a CRLF together.
+            if bytes[index] == b'\r' && index + 1 < bytes.len() && bytes[index + 1] == b'\n' {
+                index += 1;
+            }
+            index += 1;
+            result.push_str(&value[start..index]);
+            start = index;
+        } else {
+            index += 1;
+        }
+    }
+
+    // Last line, without line ending.
+    if start < bytes.len() {
+        result.push_str("<span class=\"line\">");
+        result.push_str(&value[start..]);
+        result.push_str("</span>");
+    }
+
+    result
+}
+
+/// Expand tabs in `value`, which starts at `column` in the line of text, to
+/// spaces.
+///
+/// Tab stops are every `size` characters.
+fn expand_tabs(value: &str, column: usize, size: usize) -> String {
+    let mut column = column;
+    let mut result = String::with_capacity(value.len());
+
+    for char in value.chars() {
+        match char {
+            '\t' => {
+                if size > 0 {
+                    let spaces = size - column % size;
+                    result.push_str(&" ".repeat(spaces));
+                    column += spaces;
+                }
+            }
+            '\n' | '\r' => {
+                result.push(char);
+                column = 0;
+            }
+            _ => {
+                result.push(char);
+                column += 1;
+            }
+        }
+    }
+
+    result
+}
+
+/// Get the column at which the data exited at `index` starts, in characters
+/// of the text it is in, as displayed.
+///
+/// The column is counted from the start of the line of text: markdown syntax
+/// (such as block quote markers, heading sequences, or emphasis markers) is
+/// not counted, character references count as what they decode to, and tabs
+/// in data before are expanded with `size`.
+fn text_column(events: &[Event], bytes: &[u8], index: usize, size: usize) -> usize {
+    let enter = index - 1;
+    let mut start = enter;
+
+    while start > 0 {
+        let event = &events[start - 1];
+
+        if (event.kind == Kind::Exit && event.name == Name::LineEnding)
+            || (event.kind == Kind::Enter
+                && matches!(
+                    event.name,
+                    Name::GfmTableCellText
+                        | Name::HeadingAtxText
+                        | Name::HeadingSetextText
+                        | Name::Paragraph
+                ))
+        {
+            break;
+        }
+
+        start -= 1;
+    }
+
+    let mut column = 0;
+    let mut marker = None;
+    let mut index = start;
+
+    while index < enter {
+        let event = &events[index];
+
+        // Ignore destinations, titles, and references.
+        if event.kind == Kind::Enter && matches!(event.name, Name::Reference | Name::Resource) {
+            index = skip::opt(events, index, &[Name::Reference, Name::Resource]);
+            continue;
+        }
+
+        if event.kind == Kind::Exit {
+            let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+
+            match event.name {
+                Name::Data => {
+                    column += expand_tabs(slice.as_str(), column, size).chars().count();
+                }
+                Name::AutolinkEmail
+                | Name::AutolinkProtocol
+                | Name::CharacterEscapeValue
+                | Name::CodeTextData
+                | Name::GfmAutolinkLiteralEmail
+                | Name::GfmAutolinkLiteralMailto
+                | Name::GfmAutolinkLiteralProtocol
+                | Name::GfmAutolinkLiteralWww
+                | Name::GfmAutolinkLiteralXmpp
+                | Name::MathTextData => column += slice.as_str().chars().count(),
+                Name::CharacterReferenceMarker => marker = Some(b'&'),
+                Name::CharacterReferenceMarkerHexadecimal => marker = Some(b'x'),
+                Name::CharacterReferenceMarkerNumeric => marker = Some(b'#'),
+                Name::CharacterReferenceValue => {
+                    if let Some(value) = decode_character_reference(
+                        slice.as_str(),
+                        marker.take().unwrap_or(b'&'),
+                        true,
+                    ) {
+                        column += value.chars().count();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    column
+}
+
+/// Check whether the paragraph entered at `index` contains only an image.
+fn paragraph_is_image(events: &[Event], index: usize) -> bool {
+    let mut index = index + 1;
+
+    if !(events[index].kind == Kind::Enter && events[index].name == Name::Image) {
+        return false;
+    }
+
+    let mut depth = 0;
+
+    // Find the exit of the image; images can contain images (in their alt).
+    loop {
+        let event = &events[index];
+
+        if event.name == Name::Image {
+            if event.kind == Kind::Enter {
+                depth += 1;
+            } else {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    events[index + 1].kind == Kind::Exit && events[index + 1].name == Name::Paragraph
+}
+
+/// Collect the attributes entered at `index`.
+fn collect_attributes(events: &[Event], bytes: &[u8], index: usize) -> Attributes {
+    let mut index = index + 1;
+    let mut list: Vec<(String, String)> = vec![];
+    let mut attributes = Attributes::default();
+
+    while !(events[index].kind == Kind::Exit && events[index].name == Name::Attributes) {
+        if events[index].kind == Kind::Exit {
+            let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+            let value = slice.as_str();
+
+            match events[index].name {
+                Name::AttributesClass => list.push(("class".into(), value.into())),
+                Name::AttributesId => list.push(("id".into(), value.into())),
+                Name::AttributesName => list.push((value.into(), String::new())),
+                Name::AttributesValue => list.last_mut().unwrap().1 = value.into(),
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    for (name, value) in list {
+        if name.eq_ignore_ascii_case("id") {
+            attributes.id = Some(value);
+        } else if name.eq_ignore_ascii_case("class") {
+            attributes
+                .classes
+                .extend(value.split_ascii_whitespace().map(String::from));
+        } else if let Some(existing) = attributes
+            .rest
+            .iter_mut()
+            .find(|d| d.0.eq_ignore_ascii_case(&name))
+        {
+            existing.1 = value;
+        } else {
+            attributes.rest.push((name, value));
+        }
+    }
+
+    attributes
+}
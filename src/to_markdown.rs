@@ -0,0 +1,1118 @@
+//! Turn a syntax tree into markdown.
+
+use crate::mdast::{
+    AlignKind, AttributeContent, AttributeValue, BreakKind, Code, Definition, DescriptionList,
+    Heading, Image, ImageReference, Link, LinkReference, List, ListItem, Math, Node, ReferenceKind,
+    Table,
+};
+use crate::util::normalize_identifier::normalize_identifier;
+use crate::{ParseOptions, SerializeOptions};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Context used while serializing.
+#[derive(Debug)]
+struct State<'a> {
+    /// Configuration.
+    options: &'a SerializeOptions,
+    /// Identifiers of definitions found in the tree.
+    identifiers: Vec<String>,
+    /// Definitions found in the tree: normalized identifier and label.
+    labels: Vec<(String, String)>,
+    /// Links turned into references, when `reference_links` is on:
+    /// identifier, url, and title.
+    references: Vec<(String, String, Option<String>)>,
+    /// Whether we are in a GFM table cell.
+    in_table: bool,
+}
+
+/// Turn a syntax tree into markdown.
+pub fn serialize(node: &Node, options: &SerializeOptions) -> String {
+    let mut state = State {
+        options,
+        identifiers: vec![],
+        labels: vec![],
+        references: vec![],
+        in_table: false,
+    };
+
+    collect_identifiers(node, &mut state);
+
+    let mut result = one(&mut state, node);
+
+    if !state.references.is_empty() {
+        if !result.is_empty() {
+            result.push_str("\n\n");
+        }
+
+        let mut index = 0;
+        while index < state.references.len() {
+            let (identifier, url, title) = &state.references[index];
+            if index > 0 {
+                result.push('\n');
+            }
+            result.push('[');
+            result.push_str(identifier);
+            result.push_str("]: ");
+            result.push_str(&resource(url, title.as_deref()));
+            index += 1;
+        }
+    }
+
+    if !result.is_empty() && !result.ends_with('\n') {
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Collect identifiers of definitions, so that references to links don’t
+/// clash with them, and their labels, so that references can use them.
+fn collect_identifiers(node: &Node, state: &mut State) {
+    if let Node::Definition(definition) = node {
+        let identifier = normalize_identifier(&definition.identifier);
+        state.identifiers.push(identifier.to_lowercase());
+
+        if let Some(label) = &definition.label {
+            state.labels.push((identifier, label.clone()));
+        }
+    }
+
+    if let Some(children) = node.children() {
+        for child in children {
+            collect_identifiers(child, state);
+        }
+    }
+}
+
+/// Serialize one node, of any kind.
+fn one(state: &mut State, node: &Node) -> String {
+    match node {
+        Node::Root(x) => flow(state, &x.children, false),
+        Node::BlockQuote(x) => block_quote(state, &x.children),
+        Node::FootnoteDefinition(x) => {
+            let value = flow(state, &x.children, false);
+            let first = format!("[^{}]:", label(x.label.as_deref(), &x.identifier));
+            if value.is_empty() {
+                first
+            } else {
+                indent(&value, &format!("{} ", first), "    ")
+            }
+        }
+        Node::MdxJsxFlowElement(x) => {
+            let value = flow(state, &x.children, false);
+            let mut result = jsx_tag_open(x.name.as_deref(), &x.attributes, value.is_empty());
+            if !value.is_empty() {
+                result.push('\n');
+                result.push_str(&indent(&value, "  ", "  "));
+                result.push('\n');
+                result.push_str(&jsx_tag_close(x.name.as_deref()));
+            }
+            result
+        }
+        Node::List(x) => list(state, x, false),
+        Node::DescriptionList(x) => description_list(state, x),
+        Node::MdxjsEsm(x) => x.value.clone(),
+        Node::Toml(x) => format!("+++\n{}\n+++", x.value),
+        Node::Yaml(x) => format!("---\n{}\n---", x.value),
+        Node::Code(x) => code(state, x),
+        Node::Math(x) => math(x),
+        Node::MdxFlowExpression(x) => format!("{{{}}}", x.value),
+        Node::Heading(x) => heading(state, x),
+        Node::Table(x) => table(state, x),
+        Node::ThematicBreak(_) => "***".into(),
+        Node::TableRow(x) => table_row(state, &x.children),
+        Node::TableCell(x) => table_cell(state, &x.children),
+        Node::ListItem(x) => {
+            let marker = state.options.bullet.to_string();
+            list_item(state, x, &marker, x.spread)
+        }
+        Node::DescriptionTerm(x) => {
+            let mut result = String::new();
+            phrasing(state, &x.children, &mut result);
+            encode_final_whitespace(&mut result);
+            result
+        }
+        Node::DescriptionDetails(x) => description_details(state, &x.children, false),
+        Node::Definition(x) => definition(x),
+        Node::Paragraph(x) => {
+            let mut result = String::new();
+            phrasing(state, &x.children, &mut result);
+            encode_final_whitespace(&mut result);
+            result
+        }
+        _ => {
+            let mut result = String::new();
+            phrasing(state, core::slice::from_ref(node), &mut result);
+            result
+        }
+    }
+}
+
+/// Serialize flow children.
+///
+/// When `tight`, children are not separated by blank lines (where possible).
+fn flow(state: &mut State, children: &[Node], tight: bool) -> String {
+    let mut result = String::new();
+    let mut index = 0;
+    // Whether the previous list used the other bullet.
+    let mut other = false;
+
+    while index < children.len() {
+        let child = &children[index];
+        let previous = if index > 0 {
+            Some(&children[index - 1])
+        } else {
+            None
+        };
+
+        let value = if let Node::List(list_node) = child {
+            // Adjacent lists of the same kind would be merged into one list,
+            // so alternate their markers.
+            other = if let Some(Node::List(previous)) = previous {
+                previous.ordered == list_node.ordered && !other
+            } else {
+                false
+            };
+            list(state, list_node, other)
+        } else {
+            one(state, child)
+        };
+
+        if let Some(previous) = previous {
+            result.push_str(
+                if tight
+                    && !(matches!(previous, Node::Paragraph(_))
+                        && matches!(
+                            child,
+                            Node::Paragraph(_) | Node::Definition(_) | Node::Html(_)
+                        ))
+                {
+                    "\n"
+                } else {
+                    "\n\n"
+                },
+            );
+        }
+
+        result.push_str(&value);
+        index += 1;
+    }
+
+    result
+}
+
+/// Serialize a block quote.
+fn block_quote(state: &mut State, children: &[Node]) -> String {
+    let value = flow(state, children, false);
+
+    if value.is_empty() {
+        ">".into()
+    } else {
+        indent(&value, "> ", "> ")
+    }
+}
+
+/// Serialize a list.
+///
+/// When `other`, the other marker is used.
+fn list(state: &mut State, node: &List, other: bool) -> String {
+    let bullet = match state.options.bullet {
+        '+' => '+',
+        '-' => '-',
+        _ => '*',
+    };
+    let bullet = if other {
+        if bullet == '-' {
+            '*'
+        } else {
+            '-'
+        }
+    } else {
+        bullet
+    };
+    let delimiter = if other { ')' } else { '.' };
+    let spread = node.spread;
+    let mut result = String::new();
+    let mut number = node.start.unwrap_or(1);
+    let mut index = 0;
+
+    while index < node.children.len() {
+        if index > 0 {
+            result.push_str(if spread { "\n\n" } else { "\n" });
+
+            // An item with one child is only seen as spread when more than
+            // one blank line follows it.
+            if let Node::ListItem(previous) = &node.children[index - 1] {
+                if previous.spread && previous.children.len() < 2 {
+                    result.push('\n');
+                }
+            }
+        }
+
+        let marker = if node.ordered {
+            format!("{}{}", number, delimiter)
+        } else {
+            bullet.to_string()
+        };
+
+        if let Node::ListItem(item) = &node.children[index] {
+            result.push_str(&list_item(state, item, &marker, item.spread));
+        } else {
+            let value = one(state, &node.children[index]);
+            result.push_str(&indent(&value, &format!("{} ", marker), ""));
+        }
+
+        number += 1;
+        index += 1;
+    }
+
+    result
+}
+
+/// Serialize a list item.
+fn list_item(state: &mut State, node: &ListItem, marker: &str, spread: bool) -> String {
+    let mut value = flow(state, &node.children, !spread);
+
+    if let Some(checked) = node.checked {
+        value.insert_str(0, if checked { "[x] " } else { "[ ] " });
+    }
+
+    if value.is_empty() {
+        return marker.into();
+    }
+
+    // A thematic break made with the same marker would be seen as just a
+    // thematic break.
+    if marker == "*" && matches!(node.children.first(), Some(Node::ThematicBreak(_))) {
+        value.replace_range(0..3, "---");
+    }
+
+    let mut rest = String::new();
+    rest.push_str(&" ".repeat(marker.len() + 1));
+    indent(&value, &format!("{} ", marker), &rest)
+}
+
+/// Serialize a description list.
+fn description_list(state: &mut State, node: &DescriptionList) -> String {
+    let mut result = String::new();
+    let mut index = 0;
+
+    while index < node.children.len() {
+        let child = &node.children[index];
+
+        if index > 0 {
+            let previous = &node.children[index - 1];
+            // Details must directly follow their term, and a term directly
+            // after details would be seen as lazy continuation of them.
+            result.push_str(if matches!(previous, Node::DescriptionTerm(_)) {
+                "\n"
+            } else if node.spread || matches!(child, Node::DescriptionTerm(_)) {
+                "\n\n"
+            } else {
+                "\n"
+            });
+        }
+
+        let value = if let Node::DescriptionDetails(details) = child {
+            description_details(state, &details.children, node.spread)
+        } else {
+            one(state, child)
+        };
+
+        result.push_str(&value);
+        index += 1;
+    }
+
+    result
+}
+
+/// Serialize description details.
+fn description_details(state: &mut State, children: &[Node], spread: bool) -> String {
+    let value = flow(state, children, !spread);
+
+    if value.is_empty() {
+        ":".into()
+    } else {
+        indent(&value, ": ", "    ")
+    }
+}
+
+/// Serialize code (flow).
+fn code(state: &State, node: &Code) -> String {
+    let mut info = node.lang.clone().unwrap_or_default();
+
+    if let Some(meta) = &node.meta {
+        info.push(' ');
+        info.push_str(meta);
+    }
+
+    let marker = if state.options.fence == '~' || info.contains('`') {
+        '~'
+    } else {
+        '`'
+    };
+
+    let fence = marker
+        .to_string()
+        .repeat((longest_run(&node.value, marker) + 1).max(3));
+
+    if node.value.is_empty() {
+        format!("{}{}\n{}", fence, info, fence)
+    } else {
+        format!("{}{}\n{}\n{}", fence, info, node.value, fence)
+    }
+}
+
+/// Serialize math (flow).
+fn math(node: &Math) -> String {
+    let fence = "$".repeat((longest_run(&node.value, '$') + 1).max(2));
+    let meta = node.meta.as_deref().unwrap_or_default();
+
+    if node.value.is_empty() {
+        format!("{}{}\n{}", fence, meta, fence)
+    } else {
+        format!("{}{}\n{}\n{}", fence, meta, node.value, fence)
+    }
+}
+
+/// Serialize a heading.
+fn heading(state: &mut State, node: &Heading) -> String {
+    let mut value = String::new();
+    phrasing(state, &node.children, &mut value);
+    encode_final_whitespace(&mut value);
+
+    // Headings (atx) cannot contain line endings, use a heading (setext).
+    if value.contains('\n') && node.depth < 3 {
+        value.push('\n');
+        value.push_str(if node.depth == 1 { "===" } else { "---" });
+        return value;
+    }
+
+    // A final number sign would be seen as a closing sequence.
+    if value.ends_with('#') {
+        value.pop();
+        value.push_str("\\#");
+    }
+
+    let mut result = "#".repeat(node.depth.into());
+
+    if !value.is_empty() {
+        result.push(' ');
+        result.push_str(&value);
+    }
+
+    result
+}
+
+/// Serialize a definition.
+fn definition(node: &Definition) -> String {
+    format!(
+        "[{}]: {}",
+        label(node.label.as_deref(), &node.identifier),
+        resource(&node.url, node.title.as_deref())
+    )
+}
+
+/// Serialize a GFM table.
+fn table(state: &mut State, node: &Table) -> String {
+    let mut result = String::new();
+    let mut index = 0;
+
+    while index < node.children.len() {
+        if index > 0 {
+            result.push('\n');
+        }
+
+        result.push_str(&one(state, &node.children[index]));
+
+        // Delimiter row.
+        if index == 0 {
+            let size = node.children[0]
+                .children()
+                .map_or(0, Vec::len)
+                .max(node.align.len());
+            let mut column = 0;
+            result.push('\n');
+            result.push('|');
+            while column < size {
+                result.push_str(
+                    match node.align.get(column).copied().unwrap_or(AlignKind::None) {
+                        AlignKind::Left => " :-- |",
+                        AlignKind::Right => " --: |",
+                        AlignKind::Center => " :-: |",
+                        AlignKind::None => " --- |",
+                    },
+                );
+                column += 1;
+            }
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+/// Serialize a GFM table row.
+fn table_row(state: &mut State, children: &[Node]) -> String {
+    let mut result = String::from("|");
+
+    for child in children {
+        let value = one(state, child);
+        result.push(' ');
+        result.push_str(&value);
+        result.push_str(" |");
+    }
+
+    result
+}
+
+/// Serialize a GFM table cell.
+fn table_cell(state: &mut State, children: &[Node]) -> String {
+    let mut result = String::new();
+    let in_table = state.in_table;
+    state.in_table = true;
+    phrasing(state, children, &mut result);
+    state.in_table = in_table;
+    encode_final_whitespace(&mut result);
+    result
+}
+
+/// Serialize phrasing children into `result`.
+fn phrasing(state: &mut State, children: &[Node], result: &mut String) {
+    let mut index = 0;
+
+    while index < children.len() {
+        let next = children.get(index + 1);
+
+        match &children[index] {
+            Node::Text(x) => text(state, &x.value, result),
+            Node::Break(x) => result.push_str(match x.kind {
+                BreakKind::Escape => "\\\n",
+                BreakKind::Trailing => "  \n",
+            }),
+            Node::InlineCode(x) => {
+                let value = if state.in_table {
+                    x.value.replace('|', "\\|")
+                } else {
+                    x.value.clone()
+                };
+                result.push_str(&raw_text(&value, '`'));
+            }
+            Node::InlineMath(x) => result.push_str(&raw_text(&x.value, '$')),
+            Node::Delete(x) => {
+                result.push_str("~~");
+                phrasing(state, &x.children, result);
+                result.push_str("~~");
+            }
+            Node::Emphasis(x) => {
+                let marker = attention_marker(state.options.emphasis, &x.children, result, next);
+                attention(state, &x.children, &marker.to_string(), result);
+            }
+            Node::Strong(x) => {
+                let marker = attention_marker(state.options.strong, &x.children, result, next);
+                attention(state, &x.children, &marker.to_string().repeat(2), result);
+            }
+            Node::MdxTextExpression(x) => {
+                result.push('{');
+                result.push_str(&x.value);
+                result.push('}');
+            }
+            Node::FootnoteReference(x) => {
+                result.push_str("[^");
+                result.push_str(&label(x.label.as_deref(), &x.identifier));
+                result.push(']');
+            }
+            Node::Html(x) => result.push_str(&x.value),
+            Node::Image(x) => image(state, x, result),
+            Node::ImageReference(x) => image_reference(state, x, result),
+            Node::MdxJsxTextElement(x) => {
+                let mut value = String::new();
+                phrasing(state, &x.children, &mut value);
+                result.push_str(&jsx_tag_open(
+                    x.name.as_deref(),
+                    &x.attributes,
+                    value.is_empty(),
+                ));
+                if !value.is_empty() {
+                    result.push_str(&value);
+                    result.push_str(&jsx_tag_close(x.name.as_deref()));
+                }
+            }
+            Node::Link(x) => link(state, x, result),
+            Node::LinkReference(x) => link_reference(state, x, result),
+            // Flow content in phrasing, such as when serializing a loose node.
+            node => result.push_str(&one(state, node)),
+        }
+
+        index += 1;
+    }
+}
+
+/// Serialize text.
+///
+/// Characters that would otherwise form markdown syntax are escaped.
+fn text(state: &State, value: &str, result: &mut String) {
+    let chars = value.chars().collect::<Vec<_>>();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let char = chars[index];
+        let before = if index == 0 {
+            result.chars().next_back()
+        } else {
+            Some(chars[index - 1])
+        };
+        let after = chars.get(index + 1).copied();
+        let line_start = matches!(before, None | Some('\n'));
+
+        match char {
+            // Whitespace at the start or end of a line would be dropped, and
+            // a line ending at the start of a line would form a blank line.
+            ' ' | '\t' if line_start || after == Some('\n') => {
+                result.push_str(if char == ' ' { "&#x20;" } else { "&#x9;" });
+            }
+            '\n' if line_start => result.push_str("&#xA;"),
+            // A parenthesis after a reference would be seen as a resource.
+            '(' if index == 0 && before == Some(']') => result.push_str("\\("),
+            '*' | '`' | '[' | ']' | '<' | '~' => {
+                result.push('\\');
+                result.push(char);
+            }
+            // Intraword underscores cannot form attention.
+            '_' if !(before.map_or(false, char::is_alphanumeric)
+                && after.map_or(false, char::is_alphanumeric)) =>
+            {
+                result.push_str("\\_");
+            }
+            '\\' if after.map_or(true, |d| d.is_ascii_punctuation() || d == '\n') => {
+                result.push_str("\\\\");
+            }
+            '&' if after.map_or(false, |d| d.is_ascii_alphanumeric() || d == '#') => {
+                result.push_str("\\&");
+            }
+            '|' if state.in_table || line_start => result.push_str("\\|"),
+            '#' | '>' | '-' | '=' if line_start => {
+                result.push('\\');
+                result.push(char);
+            }
+            '+' if line_start && matches!(after, None | Some(' ' | '\t' | '\n')) => {
+                result.push_str("\\+");
+            }
+            // Numbers followed by a `.` or `)` at the start of a line would
+            // form a list item.
+            '0'..='9' if line_start => {
+                let mut end = index;
+                while end < chars.len() && chars[end].is_ascii_digit() {
+                    end += 1;
+                }
+                result.extend(&chars[index..end]);
+                if end < chars.len() && matches!(chars[end], '.' | ')') {
+                    result.push('\\');
+                    result.push(chars[end]);
+                    end += 1;
+                }
+                index = end;
+                continue;
+            }
+            _ => result.push(char),
+        }
+
+        index += 1;
+    }
+}
+
+/// Figure out which marker to use for attention.
+///
+/// Emphasis directly in attention uses the other marker, as they would
+/// otherwise be seen as one sequence (`*_a_*` is not `**a**`, `**_a_**` is
+/// not `***a***`).
+/// Strong can use the same marker (`****a****` is strong in strong).
+/// Underscores cannot form attention inside words.
+fn attention_marker(marker: char, children: &[Node], result: &str, next: Option<&Node>) -> char {
+    let mut marker = if marker == '_' { '_' } else { '*' };
+
+    if matches!(
+        (children.first(), children.last()),
+        (Some(Node::Emphasis(_)), _) | (_, Some(Node::Emphasis(_)))
+    ) {
+        marker = if marker == '_' { '*' } else { '_' };
+    }
+
+    if marker == '_' {
+        let before = result.chars().next_back();
+        let after = if let Some(Node::Text(x)) = next {
+            x.value.chars().next()
+        } else {
+            None
+        };
+
+        if before.map_or(false, char::is_alphanumeric) || after.map_or(false, char::is_alphanumeric)
+        {
+            marker = '*';
+        }
+    }
+
+    marker
+}
+
+/// Serialize attention (emphasis, strong).
+fn attention(state: &mut State, children: &[Node], sequence: &str, result: &mut String) {
+    result.push_str(sequence);
+    let start = result.len();
+    phrasing(state, children, result);
+
+    // Whitespace next to sequences prevents them from forming attention.
+    if result[start..].starts_with(' ') {
+        result.replace_range(start..=start, "&#x20;");
+    }
+    encode_final_whitespace(result);
+
+    result.push_str(sequence);
+}
+
+/// Serialize code (text) or math (text).
+fn raw_text(value: &str, marker: char) -> String {
+    let mut size = 1;
+
+    while value
+        .split(|d| d != marker)
+        .any(|run| run.chars().count() == size)
+    {
+        size += 1;
+    }
+
+    let sequence = marker.to_string().repeat(size);
+    let pad = value.starts_with(marker) || value.ends_with(marker);
+
+    if pad {
+        format!("{} {} {}", sequence, value, sequence)
+    } else {
+        format!("{}{}{}", sequence, value, sequence)
+    }
+}
+
+/// Serialize a link.
+fn link(state: &mut State, node: &Link, result: &mut String) {
+    escape_exclamation_mark(result);
+
+    // Autolink.
+    if node.title.is_none() && node.children.len() == 1 {
+        if let Node::Text(x) = &node.children[0] {
+            if autolink_safe(&node.url)
+                && (x.value == node.url
+                    || (x.value.contains('@')
+                        && node.url.strip_prefix("mailto:") == Some(&x.value)))
+            {
+                if node.autolink {
+                    result.push('<');
+                    result.push_str(&x.value);
+                    result.push('>');
+                    return;
+                }
+
+                // Other links that look like autolinks, such as GFM autolink
+                // literals, are written as is, when that is safe.
+                let mut value = String::new();
+                text(state, &x.value, &mut value);
+                if value == x.value {
+                    result.push_str(&x.value);
+                    return;
+                }
+            }
+        }
+    }
+
+    result.push('[');
+    phrasing(state, &node.children, result);
+    result.push(']');
+
+    if state.options.reference_links {
+        let identifier = reference_identifier(state, &node.url, node.title.as_deref());
+        result.push('[');
+        result.push_str(&identifier);
+        result.push(']');
+    } else {
+        result.push('(');
+        result.push_str(&resource(&node.url, node.title.as_deref()));
+        result.push(')');
+    }
+}
+
+/// Serialize an image.
+fn image(state: &State, node: &Image, result: &mut String) {
+    result.push_str("![");
+    text(state, &node.alt, result);
+    result.push_str("](");
+    result.push_str(&resource(&node.url, node.title.as_deref()));
+    result.push(')');
+}
+
+/// Serialize a link reference.
+fn link_reference(state: &mut State, node: &LinkReference, result: &mut String) {
+    escape_exclamation_mark(result);
+    let mut value = String::new();
+    phrasing(state, &node.children, &mut value);
+    let mut children = node.children.clone();
+    children.iter_mut().for_each(strip_position);
+    reference(
+        state,
+        &value,
+        |value| phrasing_of(value).map_or(false, |d| d.children() == Some(&children)),
+        node.reference_kind,
+        &node.identifier,
+        node.label.as_deref(),
+        result,
+    );
+}
+
+/// Serialize an image reference.
+fn image_reference(state: &State, node: &ImageReference, result: &mut String) {
+    result.push('!');
+    let mut value = String::new();
+    text(state, &node.alt, &mut value);
+    reference(
+        state,
+        &value,
+        |value| phrasing_of(value).map_or(false, |d| d.to_string() == node.alt),
+        node.reference_kind,
+        &node.identifier,
+        node.label.as_deref(),
+        result,
+    );
+}
+
+/// Serialize a reference, with its content.
+///
+/// `same` checks whether other content is seen as the same as `content`.
+fn reference(
+    state: &State,
+    content: &str,
+    same: impl Fn(&str) -> bool,
+    reference_kind: ReferenceKind,
+    identifier: &str,
+    label: Option<&str>,
+    result: &mut String,
+) {
+    let normalized = normalize_identifier(identifier);
+    let mut kind = reference_kind;
+    let mut content = content.to_string();
+
+    // When the content does not match the identifier, it cannot be used as
+    // the label.
+    // Escapes in content often cause that, so the label of the reference or
+    // of its definition is used instead, when it is seen as the same content.
+    if kind != ReferenceKind::Full && normalize_identifier(&content) != normalized {
+        let definitions = state
+            .labels
+            .iter()
+            .filter(|(identifier, _)| *identifier == normalized)
+            .map(|(_, label)| label.as_str());
+
+        if let Some(value) = label
+            .into_iter()
+            .chain(definitions)
+            .map(escape_label)
+            .find(|value| normalize_identifier(value) == normalized && same(value))
+        {
+            content = value;
+        } else {
+            kind = ReferenceKind::Full;
+        }
+    }
+
+    result.push('[');
+    result.push_str(&content);
+    result.push(']');
+
+    match kind {
+        ReferenceKind::Shortcut => {}
+        ReferenceKind::Collapsed => result.push_str("[]"),
+        ReferenceKind::Full => {
+            result.push('[');
+            result.push_str(&self::label(label, identifier));
+            result.push(']');
+        }
+    }
+}
+
+/// Get an identifier for a link, when `reference_links` is on.
+fn reference_identifier(state: &mut State, url: &str, title: Option<&str>) -> String {
+    if let Some((identifier, _, _)) = state
+        .references
+        .iter()
+        .find(|(_, d, e)| d == url && e.as_deref() == title)
+    {
+        return identifier.clone();
+    }
+
+    let mut number = state.references.len() + 1;
+    let mut identifier = number.to_string();
+
+    while state.identifiers.contains(&identifier) {
+        number += 1;
+        identifier = number.to_string();
+    }
+
+    state.identifiers.push(identifier.clone());
+    state
+        .references
+        .push((identifier.clone(), url.into(), title.map(Into::into)));
+    identifier
+}
+
+/// Serialize a destination and optional title.
+fn resource(url: &str, title: Option<&str>) -> String {
+    let mut result = destination(url);
+
+    if let Some(title) = title {
+        result.push_str(" \"");
+        for (index, char) in title.char_indices() {
+            match char {
+                '"' | '\\' => {
+                    result.push('\\');
+                    result.push(char);
+                }
+                '&' if next_is_reference_like(title, index) => result.push_str("\\&"),
+                _ => result.push(char),
+            }
+        }
+        result.push('"');
+    }
+
+    result
+}
+
+/// Serialize a destination.
+fn destination(url: &str) -> String {
+    let enclosed = url.is_empty()
+        || url
+            .chars()
+            .any(|d| d.is_ascii_control() || d == ' ' || d == '<' || d == '>');
+    let mut result = String::new();
+
+    if enclosed {
+        result.push('<');
+    }
+
+    for (index, char) in url.char_indices() {
+        match char {
+            '<' | '>' | '\\' => {
+                result.push('\\');
+                result.push(char);
+            }
+            '(' | ')' if !enclosed => {
+                result.push('\\');
+                result.push(char);
+            }
+            '&' if next_is_reference_like(url, index) => result.push_str("\\&"),
+            _ => result.push(char),
+        }
+    }
+
+    if enclosed {
+        result.push('>');
+    }
+
+    result
+}
+
+/// Check if the byte after `index` (an ampersand) could start a character
+/// reference.
+fn next_is_reference_like(value: &str, index: usize) -> bool {
+    value
+        .as_bytes()
+        .get(index + 1)
+        .map_or(false, |d| d.is_ascii_alphanumeric() || *d == b'#')
+}
+
+/// Check if a URL can be used in an autolink.
+///
+/// Character escapes and references do not work in autolinks, so
+/// backslashes and ampersands are kept as they are.
+fn autolink_safe(url: &str) -> bool {
+    let url = url.strip_prefix("mailto:").unwrap_or(url);
+    let scheme = url.split(':').next().unwrap_or_default();
+
+    ((url.contains('@') && !url.contains(':'))
+        || (url.len() > scheme.len()
+            && (2..=32).contains(&scheme.len())
+            && scheme.starts_with(|d: char| d.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|d| d.is_ascii_alphanumeric() || matches!(d, '+' | '-' | '.'))))
+        && !url
+            .chars()
+            .any(|d| d.is_ascii_control() || matches!(d, ' ' | '<' | '>'))
+}
+
+/// Escape an exclamation mark at the end of `result`, so that it does not
+/// turn a following link into an image.
+fn escape_exclamation_mark(result: &mut String) {
+    if result.ends_with('!') && !result.ends_with("\\!") {
+        result.pop();
+        result.push_str("\\!");
+    }
+}
+
+/// Serialize a label (of a definition or reference).
+///
+/// Labels are parsed from `identifier`, which is a source value, and `label`,
+/// which is not.
+/// `label` is used when it matches `identifier`.
+fn label(label: Option<&str>, identifier: &str) -> String {
+    if let Some(label) = label {
+        let result = escape_label(label);
+
+        if normalize_identifier(&result) == normalize_identifier(identifier) {
+            return result;
+        }
+    }
+
+    identifier.into()
+}
+
+/// Escape brackets and backslashes in a label.
+fn escape_label(value: &str) -> String {
+    let mut result = String::new();
+
+    for char in value.chars() {
+        if matches!(char, '[' | ']' | '\\') {
+            result.push('\\');
+        }
+        result.push(char);
+    }
+
+    result
+}
+
+/// Parse `value` as a paragraph, without positional info.
+///
+/// This is used to check whether other content is seen as the same.
+fn phrasing_of(value: &str) -> Option<Node> {
+    let mut tree = crate::to_mdast(value, &ParseOptions::default()).ok()?;
+    strip_position(&mut tree);
+
+    if let Node::Root(mut root) = tree {
+        if root.children.len() == 1 && matches!(root.children[0], Node::Paragraph(_)) {
+            return root.children.pop();
+        }
+    }
+
+    None
+}
+
+/// Remove positional info from a node and its descendants.
+fn strip_position(node: &mut Node) {
+    node.position_set(None);
+
+    if let Some(children) = node.children_mut() {
+        children.iter_mut().for_each(strip_position);
+    }
+}
+
+/// Serialize the opening tag of a JSX element.
+///
+/// When `close`, the tag is self-closing.
+fn jsx_tag_open(name: Option<&str>, attributes: &[AttributeContent], close: bool) -> String {
+    let mut result = String::from("<");
+    result.push_str(name.unwrap_or_default());
+
+    for attribute in attributes {
+        result.push(' ');
+        match attribute {
+            AttributeContent::Expression(value, _) => {
+                result.push('{');
+                result.push_str(value);
+                result.push('}');
+            }
+            AttributeContent::Property(property) => {
+                result.push_str(&property.name);
+                match &property.value {
+                    Some(AttributeValue::Literal(value)) => {
+                        let quote = if value.contains('"') { '\'' } else { '"' };
+                        result.push('=');
+                        result.push(quote);
+                        result.push_str(value);
+                        result.push(quote);
+                    }
+                    Some(AttributeValue::Expression(value, _)) => {
+                        result.push_str("={");
+                        result.push_str(value);
+                        result.push('}');
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+
+    if close {
+        if name.is_some() {
+            result.push_str(" />");
+        } else {
+            result.push_str("></>");
+        }
+    } else {
+        result.push('>');
+    }
+
+    result
+}
+
+/// Serialize the closing tag of a JSX element.
+fn jsx_tag_close(name: Option<&str>) -> String {
+    format!("</{}>", name.unwrap_or_default())
+}
+
+/// Get the size of the longest run of `marker` in `value`.
+fn longest_run(value: &str, marker: char) -> usize {
+    value
+        .split(|d| d != marker)
+        .map(|run| run.chars().count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Encode a final space, tab, or line ending, which would otherwise be
+/// dropped.
+fn encode_final_whitespace(value: &mut String) {
+    if value.ends_with(' ') {
+        value.pop();
+        value.push_str("&#x20;");
+    } else if value.ends_with('\t') {
+        value.pop();
+        value.push_str("&#x9;");
+    } else if value.ends_with('\n') && !value.ends_with("\\\n") && !value.ends_with("  \n") {
+        value.pop();
+        value.push_str("&#xA;");
+    }
+}
+
+/// Prefix each line in `value`: the first line with `first`, the rest with
+/// `rest`.
+///
+/// Empty lines are prefixed with `rest`, without trailing whitespace.
+fn indent(value: &str, first: &str, rest: &str) -> String {
+    let mut result = String::new();
+
+    for (index, line) in value.split('\n').enumerate() {
+        if index > 0 {
+            result.push('\n');
+        }
+
+        if line.is_empty() {
+            result.push_str((if index == 0 { first } else { rest }).trim_end());
+        } else {
+            result.push_str(if index == 0 { first } else { rest });
+            result.push_str(line);
+        }
+    }
+
+    result
+}
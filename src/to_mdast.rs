@@ -2,18 +2,19 @@
 
 use crate::event::{Event, Kind, Name, Point as EventPoint};
 use crate::mdast::{
-    AttributeContent, AttributeValue, BlockQuote, Break, Code, Definition, Delete, Emphasis,
-    FootnoteDefinition, FootnoteReference, Heading, Html, Image, ImageReference, InlineCode,
-    InlineMath, Link, LinkReference, List, ListItem, Math, MdxFlowExpression, MdxJsxAttribute,
-    MdxJsxFlowElement, MdxJsxTextElement, MdxTextExpression, MdxjsEsm, Node, Paragraph,
-    ReferenceKind, Root, Strong, Table, TableCell, TableRow, Text, ThematicBreak, Toml, Yaml,
+    AttributeContent, AttributeValue, BlockQuote, Break, BreakKind, Code, Definition, Delete,
+    DescriptionDetails, DescriptionList, DescriptionTerm, Emphasis, FootnoteDefinition,
+    FootnoteReference, Heading, Html, Image, ImageReference, InlineCode, InlineMath, Link,
+    LinkReference, List, ListItem, Math, MdxFlowExpression, MdxJsxAttribute, MdxJsxFlowElement,
+    MdxJsxTextElement, MdxTextExpression, MdxjsEsm, Node, Paragraph, ReferenceKind, Root, Strong,
+    Table, TableCell, TableRow, Text, ThematicBreak, Toml, Yaml,
 };
 use crate::unist::{Point, Position};
 use crate::util::{
     character_reference::{
         decode as decode_character_reference, parse as parse_character_reference,
     },
-    infer::{gfm_table_align, list_item_loose, list_loose},
+    infer::{description_list_loose, gfm_table_align, list_item_loose, list_loose},
     mdx_collect::collect,
     normalize_identifier::normalize_identifier,
     slice::{Position as SlicePosition, Slice},
@@ -257,12 +258,16 @@ fn handle(context: &mut CompileContext, index: usize) -> Result<(), String> {
 /// Handle [`Enter`][Kind::Enter].
 fn enter(context: &mut CompileContext) -> Result<(), String> {
     match context.events[context.index].name {
-        Name::AutolinkEmail
+        // Abbreviations have no node in mdast: they are text.
+        Name::Abbreviation
+        | Name::AutolinkEmail
         | Name::AutolinkProtocol
         | Name::CharacterEscapeValue
         | Name::CharacterReference
         | Name::CodeFlowChunk
         | Name::CodeTextData
+        | Name::CodeTextLanguage
+        | Name::CodeTextLanguageMarker
         | Name::Data
         | Name::FrontmatterChunk
         | Name::HtmlFlowData
@@ -288,6 +293,9 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         Name::CodeIndented => on_enter_code_indented(context),
         Name::CodeText => on_enter_code_text(context),
         Name::Definition => on_enter_definition(context),
+        Name::DescriptionDetails => on_enter_description_details(context),
+        Name::DescriptionList => on_enter_description_list(context),
+        Name::DescriptionTerm => on_enter_description_term(context),
         Name::Emphasis => on_enter_emphasis(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmAutolinkLiteralEmail
@@ -339,6 +347,9 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
         | Name::BlockQuote
         | Name::CharacterReference
         | Name::Definition
+        | Name::DescriptionDetails
+        | Name::DescriptionList
+        | Name::DescriptionTerm
         | Name::Emphasis
         | Name::GfmFootnoteDefinition
         | Name::GfmStrikethrough
@@ -352,9 +363,12 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
         | Name::ThematicBreak => {
             on_exit(context)?;
         }
-        Name::CharacterEscapeValue
+        Name::Abbreviation
+        | Name::CharacterEscapeValue
         | Name::CodeFlowChunk
         | Name::CodeTextData
+        | Name::CodeTextLanguage
+        | Name::CodeTextLanguageMarker
         | Name::Data
         | Name::FrontmatterChunk
         | Name::HtmlFlowData
@@ -459,6 +473,7 @@ fn on_enter_autolink(context: &mut CompileContext) {
     context.tail_push(Node::Link(Link {
         url: String::new(),
         title: None,
+        autolink: true,
         children: vec![],
         position: None,
     }));
@@ -568,6 +583,33 @@ fn on_enter_definition(context: &mut CompileContext) {
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DescriptionDetails`][Name::DescriptionDetails].
+fn on_enter_description_details(context: &mut CompileContext) {
+    context.tail_push(Node::DescriptionDetails(DescriptionDetails {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DescriptionList`][Name::DescriptionList].
+fn on_enter_description_list(context: &mut CompileContext) {
+    let spread = description_list_loose(context.events, context.index);
+
+    context.tail_push(Node::DescriptionList(DescriptionList {
+        spread,
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DescriptionTerm`][Name::DescriptionTerm].
+fn on_enter_description_term(context: &mut CompileContext) {
+    context.tail_push(Node::DescriptionTerm(DescriptionTerm {
+        children: vec![],
+        position: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     context.tail_push(Node::Emphasis(Emphasis {
@@ -578,7 +620,13 @@ fn on_enter_emphasis(context: &mut CompileContext) {
 
 /// Handle [`Enter`][Kind::Enter]:{[`GfmAutolinkLiteralEmail`][Name::GfmAutolinkLiteralEmail],[`GfmAutolinkLiteralMailto`][Name::GfmAutolinkLiteralMailto],[`GfmAutolinkLiteralProtocol`][Name::GfmAutolinkLiteralProtocol],[`GfmAutolinkLiteralWww`][Name::GfmAutolinkLiteralWww],[`GfmAutolinkLiteralXmpp`][Name::GfmAutolinkLiteralXmpp]}.
 fn on_enter_gfm_autolink_literal(context: &mut CompileContext) {
-    on_enter_autolink(context);
+    context.tail_push(Node::Link(Link {
+        url: String::new(),
+        title: None,
+        autolink: false,
+        children: vec![],
+        position: None,
+    }));
     on_enter_data(context);
 }
 
@@ -637,9 +685,16 @@ fn on_enter_gfm_table_cell(context: &mut CompileContext) {
     }));
 }
 
-/// Handle [`Enter`][Kind::Enter]:[`HardBreakEscape`][Name::HardBreakEscape].
+/// Handle [`Enter`][Kind::Enter]:{[`HardBreakEscape`][Name::HardBreakEscape],[`HardBreakTrailing`][Name::HardBreakTrailing]}.
 fn on_enter_hard_break(context: &mut CompileContext) {
-    context.tail_push(Node::Break(Break { position: None }));
+    context.tail_push(Node::Break(Break {
+        position: None,
+        kind: if context.events[context.index].name == Name::HardBreakEscape {
+            BreakKind::Escape
+        } else {
+            BreakKind::Trailing
+        },
+    }));
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`Frontmatter`][Name::Frontmatter].
@@ -730,6 +785,7 @@ fn on_enter_link(context: &mut CompileContext) {
     context.tail_push(Node::Link(Link {
         url: String::new(),
         title: None,
+        autolink: false,
         children: vec![],
         position: None,
     }));
@@ -1239,10 +1295,10 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
     let head = context.bytes[position.start.index];
     let depth = if head == b'-' { 2 } else { 1 };
 
+    // Note: an unclosed MDX JSX (text) element can be on the stack instead,
+    // which is an error when the heading exits.
     if let Node::Heading(node) = context.tail_mut() {
         node.depth = depth;
-    } else {
-        unreachable!("expected heading on stack");
     }
 }
 
@@ -1292,7 +1348,8 @@ fn on_exit_line_ending(context: &mut CompileContext) -> Result<(), String> {
     // Line ending is a part of nodes that accept phrasing.
     else if matches!(
         context.tail_mut(),
-        Node::Emphasis(_)
+        Node::DescriptionTerm(_)
+            | Node::Emphasis(_)
             | Node::Heading(_)
             | Node::Paragraph(_)
             | Node::Strong(_)
@@ -11,6 +11,8 @@
 use crate::event::{Content, Event, Kind, Link, Name, Point, VOID_EVENTS};
 use crate::parser::ParseState;
 use crate::resolve::{call as call_resolve, Name as ResolveName};
+#[cfg(feature = "trace")]
+use crate::state::Name as StateName;
 use crate::state::{call, State};
 use crate::subtokenize::Subresult;
 use crate::util::{char::format_byte_opt, constant::TAB_SIZE, edit_map::EditMap};
@@ -31,6 +33,8 @@ pub enum Container {
     ListItem,
     /// [GFM: Footnote definition][crate::construct::gfm_footnote_definition].
     GfmFootnoteDefinition,
+    /// [Definition list][crate::construct::definition_list] (details).
+    DescriptionDetails,
 }
 
 /// Info used to tokenize a container.
@@ -222,10 +226,15 @@ pub struct TokenizeState<'a> {
     /// Used when tokenizing [text content][crate::construct::text].
     pub labels: Vec<Label>,
 
+    /// List of defined abbreviation labels.
+    pub abbreviations: Vec<String>,
     /// List of defined definition identifiers.
     pub definitions: Vec<String>,
     /// List of defined GFM footnote definition identifiers.
     pub gfm_footnote_definitions: Vec<String>,
+    /// List of definitions from the reference resolver, as identifier,
+    /// destination, and title.
+    pub resolved_definitions: Vec<(String, String, Option<String>)>,
 
     // Last error message provided at an EOF of an expression.
     pub mdx_last_parse_error: Option<String>,
@@ -344,8 +353,10 @@ impl<'a> Tokenizer<'a> {
                 document_child_state: None,
                 document_child: None,
                 document_at_first_paragraph_of_list_item: false,
+                abbreviations: vec![],
                 definitions: vec![],
                 gfm_footnote_definitions: vec![],
+                resolved_definitions: vec![],
                 mdx_last_parse_error: None,
                 end: 0,
                 label_starts: vec![],
@@ -469,7 +480,11 @@ impl<'a> Tokenizer<'a> {
                     self.point.line += 1;
                     self.point.column = 1;
 
-                    if self.point.line - self.first_line + 1 > self.column_start.len() {
+                    // Fill gaps too: lines before this one may not have a
+                    // start yet (such as when no skip was defined for the
+                    // first line), and a later attempt that is reverted
+                    // must not move to the start of the wrong line.
+                    while self.point.line - self.first_line + 1 > self.column_start.len() {
                         self.column_start.push((self.point.index, self.point.vs));
                     }
 
@@ -622,19 +637,25 @@ impl<'a> Tokenizer<'a> {
 
         let mut value = Subresult {
             done: false,
+            abbreviations: self.tokenize_state.abbreviations.split_off(0),
             gfm_footnote_definitions: self.tokenize_state.gfm_footnote_definitions.split_off(0),
             definitions: self.tokenize_state.definitions.split_off(0),
+            resolved_definitions: self.tokenize_state.resolved_definitions.split_off(0),
         };
 
         if resolve {
             let resolvers = self.resolvers.split_off(0);
             let mut index = 0;
+            let abbrs = &mut value.abbreviations;
             let defs = &mut value.definitions;
             let fn_defs = &mut value.gfm_footnote_definitions;
+            let resolved_defs = &mut value.resolved_definitions;
             while index < resolvers.len() {
                 if let Some(mut result) = call_resolve(self, resolvers[index])? {
+                    abbrs.append(&mut result.abbreviations);
                     fn_defs.append(&mut result.gfm_footnote_definitions);
                     defs.append(&mut result.definitions);
+                    resolved_defs.append(&mut result.resolved_definitions);
                 }
                 index += 1;
             }
@@ -737,11 +758,15 @@ fn push_impl(
 
                     log::debug!("feed:    {} to {:?}", format_byte_opt(byte), name);
                     tokenizer.expect(byte);
+                    #[cfg(feature = "trace")]
+                    trace(tokenizer, name);
                     state = call(tokenizer, name);
                 };
             }
             State::Retry(name) => {
                 log::debug!("retry:   `{:?}`", name);
+                #[cfg(feature = "trace")]
+                trace(tokenizer, name);
                 state = call(tokenizer, name);
             }
         }
@@ -761,6 +786,14 @@ fn push_impl(
     state
 }
 
+/// Pass a state to the `trace` function, if given.
+#[cfg(feature = "trace")]
+fn trace(tokenizer: &Tokenizer, name: StateName) {
+    if let Some(trace) = &tokenizer.parse_state.options.trace {
+        trace(name, &tokenizer.point);
+    }
+}
+
 /// Figure out how to handle a byte.
 fn byte_action(bytes: &[u8], point: &Point) -> ByteAction {
     if point.index < bytes.len() {
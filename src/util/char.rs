@@ -92,8 +92,8 @@ pub fn classify(char: char) -> Kind {
     if char.is_whitespace() {
         Kind::Whitespace
     }
-    // Unicode punctuation.
-    else if PUNCTUATION.contains(&char) {
+    // ASCII punctuation and Unicode punctuation.
+    else if char.is_ascii_punctuation() || PUNCTUATION.binary_search(&char).is_ok() {
         Kind::Punctuation
     }
     // Everything else.
@@ -102,6 +102,31 @@ pub fn classify(char: char) -> Kind {
     }
 }
 
+/// Check whether a `char` is a CJK (Chinese, Japanese, or Korean)
+/// character, which is typically written without spaces between them.
+///
+/// This is a heuristic that covers the common blocks (ideographs, kana,
+/// hangul, CJK punctuation, and fullwidth forms), not a Unicode property.
+pub fn cjk(char: char) -> bool {
+    matches!(
+        char,
+        // Hangul Jamo.
+        '\u{1100}'..='\u{11FF}'
+        // CJK punctuation, kana, bopomofo, hangul compatibility jamo, and
+        // more, up to and including CJK Unified Ideographs (except for the
+        // ideographic space).
+        | '\u{3001}'..='\u{9FFF}'
+        // Hangul syllables.
+        | '\u{AC00}'..='\u{D7AF}'
+        // CJK compatibility ideographs.
+        | '\u{F900}'..='\u{FAFF}'
+        // Halfwidth and fullwidth forms.
+        | '\u{FF01}'..='\u{FFEF}'
+        // CJK Unified Ideographs extensions and supplements.
+        | '\u{20000}'..='\u{3134F}'
+    )
+}
+
 /// Like [`classify`], but supports eof as whitespace.
 pub fn classify_opt(char_opt: Option<char>) -> Kind {
     char_opt.map_or(Kind::Whitespace, classify)
@@ -173,6 +198,34 @@ mod tests {
         );
 
         assert_eq!(classify('a'), Kind::Other, "should classify other");
+
+        assert_eq!(
+            classify('「'),
+            Kind::Punctuation,
+            "should classify unicode punctuation"
+        );
+
+        assert_eq!(
+            classify('中'),
+            Kind::Other,
+            "should classify other (unicode)"
+        );
+
+        assert_eq!(
+            (
+                classify('\u{AD}'),
+                classify('\u{200B}'),
+                classify('\u{200C}'),
+                classify('\u{200D}')
+            ),
+            (Kind::Other, Kind::Other, Kind::Other, Kind::Other),
+            "should classify invisible formatting characters as other"
+        );
+
+        assert!(
+            PUNCTUATION.windows(2).all(|pair| pair[0] < pair[1]),
+            "should have sorted punctuation (for `binary_search`)"
+        );
     }
 
     #[test]
@@ -169,43 +169,12 @@ pub fn parse(value: &str) -> String {
     let mut start = 0;
 
     while index < len {
-        if bytes[index] == b'&' {
-            let (marker, value_start) = if index + 1 < len && bytes[index + 1] == b'#' {
-                if index + 2 < len && matches!(bytes[index + 2], b'x' | b'X') {
-                    (b'x', index + 3)
-                } else {
-                    (b'#', index + 2)
-                }
-            } else {
-                (b'&', index + 1)
-            };
-
-            let max = value_max(marker);
-            let test = value_test(marker);
-            let mut value_index = 0;
-            while value_index < max && (value_start + value_index) < len {
-                if !test(&bytes[value_start + value_index]) {
-                    break;
-                }
-                value_index += 1;
-            }
-
-            let value_end = value_start + value_index;
-
-            // Non empty and terminated.
-            if value_index > 0 && bytes[value_end] == b';' {
-                if let Some(decoded) = decode(
-                    str::from_utf8(&bytes[value_start..value_end]).unwrap(),
-                    marker,
-                    false,
-                ) {
-                    result.push_str(&value[start..index]);
-                    result.push_str(&decoded);
-                    start = value_end + 1;
-                    index = start;
-                    continue;
-                }
-            }
+        if let Some((decoded, end)) = decode_at(bytes, index, false) {
+            result.push_str(&value[start..index]);
+            result.push_str(&decoded);
+            start = end;
+            index = start;
+            continue;
         }
 
         index += 1;
@@ -215,3 +184,52 @@ pub fn parse(value: &str) -> String {
 
     result
 }
+
+/// Decode a character reference that starts at `index` in `bytes`.
+///
+/// Returns the decoded value and the index after the reference (after `;`),
+/// or `None` if there is no valid character reference at `index`.
+///
+/// The `html5` boolean specifies whether the 2125 names from HTML 5 or the
+/// 252 names from HTML 4 are supported.
+pub fn decode_at(bytes: &[u8], index: usize, html5: bool) -> Option<(String, usize)> {
+    let len = bytes.len();
+
+    if bytes[index] != b'&' {
+        return None;
+    }
+
+    let (marker, value_start) = if index + 1 < len && bytes[index + 1] == b'#' {
+        if index + 2 < len && matches!(bytes[index + 2], b'x' | b'X') {
+            (b'x', index + 3)
+        } else {
+            (b'#', index + 2)
+        }
+    } else {
+        (b'&', index + 1)
+    };
+
+    let max = value_max(marker);
+    let test = value_test(marker);
+    let mut value_index = 0;
+    while value_index < max && (value_start + value_index) < len {
+        if !test(&bytes[value_start + value_index]) {
+            break;
+        }
+        value_index += 1;
+    }
+
+    let value_end = value_start + value_index;
+
+    // Non empty and terminated.
+    if value_index > 0 && value_end < len && bytes[value_end] == b';' {
+        decode(
+            str::from_utf8(&bytes[value_start..value_end]).unwrap(),
+            marker,
+            html5,
+        )
+        .map(|decoded| (decoded, value_end + 1))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,122 @@
+//! Count columns in different units.
+
+use crate::event::Event;
+use crate::util::unicode::MARK;
+use crate::ColumnMode;
+use alloc::{vec, vec::Vec};
+use core::str;
+
+/// Zero-width joiner.
+const ZWJ: char = '\u{200D}';
+
+/// Change the columns of points in `events`, which are counted in bytes, to
+/// be counted in `mode`.
+///
+/// Lines and indices are not changed.
+pub fn convert(events: &mut [Event], bytes: &[u8], mode: ColumnMode) {
+    if mode == ColumnMode::Utf8CodeUnit {
+        return;
+    }
+
+    let value = str::from_utf8(bytes).expect("expected `bytes` to be from a `str`");
+    let line_starts = line_starts(bytes);
+    let mut counter = Counter::new(0);
+
+    for event in events {
+        let line_start = line_starts[event.point.line - 1];
+        event.point.column = counter.column(value, mode, line_start, event.point.index);
+    }
+}
+
+/// Get the index where each line starts.
+fn line_starts(bytes: &[u8]) -> Vec<usize> {
+    let mut starts = vec![0];
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'\n' || (bytes[index] == b'\r' && bytes.get(index + 1) != Some(&b'\n'))
+        {
+            starts.push(index + 1);
+        }
+
+        index += 1;
+    }
+
+    starts
+}
+
+/// Incremental column counter.
+///
+/// Points are mostly in order, so counting continues from the previous
+/// point when it is on the same line and before the current one.
+/// That prevents quadratic behavior on long lines.
+struct Counter {
+    /// Index where the current line starts.
+    line_start: usize,
+    /// Index counted up to.
+    index: usize,
+    /// Column at `index`.
+    column: usize,
+    /// Character before `index`.
+    previous: Option<char>,
+    /// Number of regional indicators directly before `index`.
+    regional_indicators: usize,
+}
+
+impl Counter {
+    /// Create a new counter at the start of a line.
+    fn new(line_start: usize) -> Counter {
+        Counter {
+            line_start,
+            index: line_start,
+            column: 1,
+            previous: None,
+            regional_indicators: 0,
+        }
+    }
+
+    /// Get the column of `index`, on the line starting at `line_start`.
+    fn column(&mut self, value: &str, mode: ColumnMode, line_start: usize, index: usize) -> usize {
+        if line_start != self.line_start || index < self.index {
+            *self = Counter::new(line_start);
+        }
+
+        while self.index < index {
+            let char = value[self.index..]
+                .chars()
+                .next()
+                .expect("expected a character before `index`");
+
+            if mode == ColumnMode::Utf16CodeUnit {
+                self.column += char.len_utf16();
+            } else if !self.extends(char) {
+                self.column += 1;
+            }
+
+            self.regional_indicators = if regional_indicator(char) {
+                self.regional_indicators + 1
+            } else {
+                0
+            };
+            self.previous = Some(char);
+            self.index += char.len_utf8();
+        }
+
+        self.column
+    }
+
+    /// Check whether `char` continues the grapheme of the previous character.
+    fn extends(&self, char: char) -> bool {
+        self.previous.is_some()
+            && (char == ZWJ
+                || self.previous == Some(ZWJ)
+                || ('\u{1F3FB}'..='\u{1F3FF}').contains(&char)
+                || (regional_indicator(char) && self.regional_indicators % 2 == 1)
+                || MARK.binary_search(&char).is_ok())
+    }
+}
+
+/// Check whether `char` is a regional indicator (used in pairs for flags).
+fn regional_indicator(char: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&char)
+}
@@ -67,6 +67,59 @@ pub const CHARACTER_REFERENCE_NAMED_SIZE_MAX: usize = 31;
 /// [raw_flow]: crate::construct::raw_flow
 pub const CODE_FENCED_SEQUENCE_SIZE_MIN: usize = 3;
 
+/// The number of the longest language name in [`CODE_TEXT_LANGUAGES`][].
+///
+/// This is currently the size of `javascript` and `typescript`.
+pub const CODE_TEXT_LANGUAGE_SIZE_MAX: usize = 10;
+
+/// List of language names that can start [code (text)][raw_text], followed
+/// by a colon, when `code_span_lang` is on.
+///
+/// Language name matching must be performed insensitive to case, and thus
+/// this list includes lowercase names.
+/// Single letter names (such as `c` and `r`) are not included, as they would
+/// match drive letters (`` `c:\` ``).
+///
+/// [raw_text]: crate::construct::raw_text
+pub const CODE_TEXT_LANGUAGES: [&str; 36] = [
+    "bash",
+    "cpp",
+    "csharp",
+    "css",
+    "diff",
+    "go",
+    "haskell",
+    "html",
+    "java",
+    "javascript",
+    "js",
+    "json",
+    "jsx",
+    "kotlin",
+    "lua",
+    "markdown",
+    "md",
+    "php",
+    "py",
+    "python",
+    "rb",
+    "rs",
+    "ruby",
+    "rust",
+    "scala",
+    "sh",
+    "shell",
+    "sql",
+    "swift",
+    "toml",
+    "ts",
+    "tsx",
+    "typescript",
+    "xml",
+    "yaml",
+    "yml",
+];
+
 /// The number of markers needed for [frontmatter][] to form.
 ///
 /// Like many things in markdown, the number is `3`.
@@ -99,7 +152,7 @@ pub const GFM_HTML_TAGFILTER_NAMES: [&str; 9] = [
     "xmp",
 ];
 
-/// The number of preceding spaces needed for a [hard break
+/// The default number of preceding spaces needed for a [hard break
 /// (trailing)][whitespace] to form.
 ///
 /// [whitespace]: crate::construct::partial_whitespace
@@ -2726,6 +2779,12 @@ mod tests {
             "`CHARACTER_REFERENCE_NAMED_SIZE_MAX`"
         );
 
+        assert_eq!(
+            CODE_TEXT_LANGUAGE_SIZE_MAX,
+            longest(&CODE_TEXT_LANGUAGES).unwrap().len(),
+            "`CODE_TEXT_LANGUAGE_SIZE_MAX`"
+        );
+
         assert_eq!(
             GFM_HTML_TAGFILTER_SIZE_MAX,
             longest(&GFM_HTML_TAGFILTER_NAMES).unwrap().len(),
@@ -9,7 +9,7 @@
 //! through another tokenizer and inject the result.
 
 use crate::event::Event;
-use alloc::{vec, vec::Vec};
+use alloc::{collections::BTreeMap, vec::Vec};
 
 /// Shift `previous` and `next` links according to `jumps`.
 ///
@@ -55,16 +55,23 @@ fn shift_links(events: &mut [Event], jumps: &[(usize, usize, usize)]) {
 }
 
 /// Tracks a bunch of edits.
-#[derive(Debug)]
+///
+/// Edits are made with indices into the events as they are before any edit,
+/// and are applied together when consumed.
+/// Links between events are kept correct.
+#[derive(Debug, Default)]
 pub struct EditMap {
-    /// Record of changes.
-    map: Vec<(usize, usize, Vec<Event>)>,
+    /// Record of changes: indices, to how many events to remove there and
+    /// which events to add.
+    map: BTreeMap<usize, (usize, Vec<Event>)>,
 }
 
 impl EditMap {
     /// Create a new edit map.
     pub fn new() -> EditMap {
-        EditMap { map: vec![] }
+        EditMap {
+            map: BTreeMap::new(),
+        }
     }
     /// Create an edit: a remove and/or add at a certain place.
     pub fn add(&mut self, index: usize, remove: usize, add: Vec<Event>) {
@@ -76,20 +83,23 @@ impl EditMap {
     }
     /// Done, change the events.
     pub fn consume(&mut self, events: &mut Vec<Event>) {
-        self.map
-            .sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
         if self.map.is_empty() {
             return;
         }
 
+        // Sorted by index.
+        let mut map = core::mem::take(&mut self.map)
+            .into_iter()
+            .map(|(at, (remove, add))| (at, remove, add))
+            .collect::<Vec<_>>();
+
         // Calculate jumps: where items in the current list move to.
-        let mut jumps = Vec::with_capacity(self.map.len());
+        let mut jumps = Vec::with_capacity(map.len());
         let mut index = 0;
         let mut add_acc = 0;
         let mut remove_acc = 0;
-        while index < self.map.len() {
-            let (at, remove, add) = &self.map[index];
+        while index < map.len() {
+            let (at, remove, add) = &map[index];
             remove_acc += remove;
             add_acc += add.len();
             jumps.push((*at, remove_acc, add_acc));
@@ -99,13 +109,13 @@ impl EditMap {
         shift_links(events, &jumps);
 
         let len_before = events.len();
-        let mut index = self.map.len();
+        let mut index = map.len();
         let mut vecs = Vec::with_capacity(index * 2 + 1);
         while index > 0 {
             index -= 1;
-            vecs.push(events.split_off(self.map[index].0 + self.map[index].1));
-            vecs.push(self.map[index].2.split_off(0));
-            events.truncate(self.map[index].0);
+            vecs.push(events.split_off(map[index].0 + map[index].1));
+            vecs.push(map[index].2.split_off(0));
+            events.truncate(map[index].0);
         }
         vecs.push(events.split_off(0));
 
@@ -114,35 +124,25 @@ impl EditMap {
         while let Some(mut slice) = vecs.pop() {
             events.append(&mut slice);
         }
-
-        self.map.truncate(0);
     }
 }
 
 /// Create an edit.
 fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, mut add: Vec<Event>, before: bool) {
-    let mut index = 0;
-
     if remove == 0 && add.is_empty() {
         return;
     }
 
-    while index < edit_map.map.len() {
-        if edit_map.map[index].0 == at {
-            edit_map.map[index].1 += remove;
+    if let Some(edit) = edit_map.map.get_mut(&at) {
+        edit.0 += remove;
 
-            if before {
-                add.append(&mut edit_map.map[index].2);
-                edit_map.map[index].2 = add;
-            } else {
-                edit_map.map[index].2.append(&mut add);
-            }
-
-            return;
+        if before {
+            add.append(&mut edit.1);
+            edit.1 = add;
+        } else {
+            edit.1.append(&mut add);
         }
-
-        index += 1;
+    } else {
+        edit_map.map.insert(at, (remove, add));
     }
-
-    edit_map.map.push((at, remove, add));
 }
@@ -4,7 +4,9 @@
 
 use crate::event::{Event, Kind, Name};
 use crate::mdast::AlignKind;
+use crate::util::skip;
 use alloc::{vec, vec::Vec};
+use core::slice;
 
 /// Figure out if a list is spread or not.
 ///
@@ -142,6 +144,147 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
     false
 }
 
+/// Figure out if a description list is spread or not.
+///
+/// Blank lines between details, or between blocks in details, make the list
+/// loose.
+/// Blank lines before terms do not.
+pub fn description_list_loose(events: &[Event], mut index: usize) -> bool {
+    debug_assert!(
+        matches!(events[index].name, Name::DescriptionList),
+        "expected description list"
+    );
+    let mut balance = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            balance += 1;
+        } else {
+            balance -= 1;
+
+            if (balance == 1 || balance == 2) && event.name == Name::BlankLineEnding {
+                let after = skip::opt(
+                    events,
+                    index + 1,
+                    &[
+                        Name::BlankLineEnding,
+                        Name::BlockQuotePrefix,
+                        Name::LineEnding,
+                        Name::SpaceOrTab,
+                    ],
+                );
+
+                // Blank line before a term:
+                //
+                // ```markdown
+                //   | a
+                //   | : b
+                // > | ␊
+                //     ^
+                //   | c
+                //   | : d
+                // ```
+                //
+                // Or at the end of the last details:
+                //
+                // ```markdown
+                //   | a
+                //   | : b
+                // > | ␊
+                //     ^
+                // ```
+                let at_end = after == events.len()
+                    || (events[after].kind == Kind::Exit
+                        && matches!(
+                            events[after].name,
+                            Name::DescriptionDetails | Name::DescriptionList
+                        ));
+
+                if !at_end && events[after].name != Name::DescriptionTerm {
+                    return true;
+                }
+            }
+
+            // Done.
+            if balance == 0 && event.name == Name::DescriptionList {
+                break;
+            }
+        }
+
+        index += 1;
+    }
+
+    false
+}
+
+/// Spans that attributes apply to when directly after them.
+pub const ATTRIBUTES_SPANS: [Name; 7] = [
+    Name::CodeText,
+    Name::Emphasis,
+    Name::GfmStrikethrough,
+    Name::Image,
+    Name::Link,
+    Name::MathText,
+    Name::Strong,
+];
+
+/// Figure out which attributes apply to the element entered at `index`.
+///
+/// Returns the index of where those attributes are entered.
+pub fn attributes_index(events: &[Event], index: usize) -> Option<usize> {
+    let name = &events[index].name;
+    let exit = skip::opt(events, index, slice::from_ref(name)) - 1;
+
+    if !matches!(
+        name,
+        Name::HeadingAtx | Name::HeadingSetext | Name::Paragraph
+    ) {
+        return if exit + 1 < events.len()
+            && events[exit + 1].kind == Kind::Enter
+            && events[exit + 1].name == Name::Attributes
+        {
+            Some(exit + 1)
+        } else {
+            None
+        };
+    }
+
+    // Find where the text of headings ends.
+    let mut end = exit;
+
+    if *name != Name::Paragraph {
+        while end > index
+            && !(events[end].kind == Kind::Exit
+                && matches!(
+                    events[end].name,
+                    Name::HeadingAtxText | Name::HeadingSetextText
+                ))
+        {
+            end -= 1;
+        }
+
+        if end == index {
+            return None;
+        }
+    }
+
+    let before = skip::opt_back(events, end - 1, &[Name::SpaceOrTab]);
+
+    if events[before].kind == Kind::Exit && events[before].name == Name::Attributes {
+        let enter = skip::to_back(events, before - 1, &[Name::Attributes]);
+        let previous = &events[enter - 1];
+
+        // Attributes directly after a span apply to that span.
+        if !(previous.kind == Kind::Exit && ATTRIBUTES_SPANS.contains(&previous.name)) {
+            return Some(enter);
+        }
+    }
+
+    None
+}
+
 /// Figure out the alignment of a GFM table.
 pub fn gfm_table_align(events: &[Event], mut index: usize) -> Vec<AlignKind> {
     debug_assert!(
@@ -2,6 +2,7 @@
 
 pub mod char;
 pub mod character_reference;
+pub mod column;
 pub mod constant;
 pub mod edit_map;
 pub mod encode;
@@ -14,6 +15,8 @@ pub mod mdx;
 pub mod mdx_collect;
 pub mod normalize_identifier;
 pub mod sanitize_uri;
+pub mod serialize;
 pub mod skip;
 pub mod slice;
+pub mod slug;
 pub mod unicode;
@@ -54,22 +54,10 @@ pub fn sanitize(value: &str) -> String {
 pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     let value = sanitize(value);
 
-    let end = value.find(|c| matches!(c, '?' | '#' | '/'));
-    let mut colon = value.find(|c| matches!(c, ':'));
-
-    // If the first colon is after `?`, `#`, or `/`, it’s not a protocol.
-    if let Some(end) = end {
-        if let Some(index) = colon {
-            if index > end {
-                colon = None;
-            }
-        }
-    }
-
     // If there is no protocol, it’s relative, and fine.
-    if let Some(colon) = colon {
+    if let Some(protocol) = protocol(&value) {
         // If it is a protocol, it should be allowed.
-        let protocol = value[0..colon].to_lowercase();
+        let protocol = protocol.to_lowercase();
         if !protocols.contains(&protocol.as_str()) {
             return String::new();
         }
@@ -78,6 +66,50 @@ pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     value
 }
 
+/// Get the protocol of a URL, if it has one (it is not relative).
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sanitize_uri::protocol;
+///
+/// assert_eq!(protocol("https://example.com"), Some("https"));
+/// assert_eq!(protocol("/a:b"), None);
+/// ```
+pub fn protocol(value: &str) -> Option<&str> {
+    let end = value.find(|c| matches!(c, '?' | '#' | '/'));
+    let colon = value.find(|c| matches!(c, ':'))?;
+
+    // If the first colon is after `?`, `#`, or `/`, it’s not a protocol.
+    if let Some(end) = end {
+        if colon > end {
+            return None;
+        }
+    }
+
+    Some(&value[0..colon])
+}
+
+/// Check whether a URL points to another site: it has a protocol, or it is
+/// protocol-relative (it starts with two slashes, where browsers also accept
+/// backslashes).
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sanitize_uri::is_external;
+///
+/// assert!(is_external("https://example.com"));
+/// assert!(is_external("//example.com"));
+/// assert!(!is_external("/a"));
+/// ```
+pub fn is_external(value: &str) -> bool {
+    let bytes = value.as_bytes();
+
+    protocol(value).is_some()
+        || (bytes.len() > 1 && matches!(bytes[0], b'/' | b'\\') && matches!(bytes[1], b'/' | b'\\'))
+}
+
 /// Normalize a URL (such as used in [definitions][definition],
 /// [references][label_end]).
 ///
@@ -99,7 +131,7 @@ pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
 ///
 /// [definition]: crate::construct::definition
 /// [label_end]: crate::construct::label_end
-fn normalize(value: &str) -> String {
+pub fn normalize(value: &str) -> String {
     let chars = value.chars().collect::<Vec<_>>();
     // Note: it’ll grow bigger for each non-ascii or non-safe character.
     let mut result = String::with_capacity(value.len());
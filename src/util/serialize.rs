@@ -0,0 +1,156 @@
+//! Serialize markdown source, optionally interpreting it.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    character_reference::{decode as decode_character_reference, decode_at},
+    slice::{Position as SlicePosition, Slice},
+};
+use alloc::string::String;
+
+/// Serialize a value (such as the text of a label) from markdown source,
+/// optionally decoding character references and character escapes.
+///
+/// When `character_references` is `true`, character references (such as
+/// `&amp;`) are decoded.
+/// When `character_escapes` is `true`, character escapes (such as `\&`) are
+/// resolved to the escaped character.
+/// An escaped ampersand does not start a character reference.
+///
+/// Importantly, `CommonMark` does *not* interpret labels when matching
+/// references to definitions: passing `false` for both gives the text that
+/// is used for matching (before normalizing whitespace and case).
+/// That is why `[a&amp;b]` and `[a\&b]` do not match a definition `[a&b]`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::serialize_with;
+///
+/// // Text used for matching:
+/// assert_eq!(serialize_with("a&amp;b", false, false), "a&amp;b");
+///
+/// // Decode character references:
+/// assert_eq!(serialize_with("a&amp;b", true, false), "a&b");
+///
+/// // Resolve character escapes:
+/// assert_eq!(serialize_with("a\\&b", false, true), "a&b");
+///
+/// // An escaped ampersand does not start a character reference:
+/// assert_eq!(serialize_with("a\\&amp;b", true, true), "a&amp;b");
+/// ```
+///
+/// ## References
+///
+/// *   [*§ 2.4 Backslash escapes* in `CommonMark`](https://spec.commonmark.org/0.30/#backslash-escapes)
+/// *   [*§ 2.5 Entity and numeric character references* in `CommonMark`](https://spec.commonmark.org/0.30/#entity-and-numeric-character-references)
+/// *   [*§ 4.7 Link reference definitions* in `CommonMark`](https://spec.commonmark.org/0.30/#link-reference-definitions)
+pub fn serialize_with(value: &str, character_references: bool, character_escapes: bool) -> String {
+    let bytes = value.as_bytes();
+    let len = bytes.len();
+    // Grows a bit smaller with each reference or escape.
+    let mut result = String::with_capacity(len);
+    let mut index = 0;
+    let mut start = 0;
+
+    while index < len {
+        if character_escapes
+            && bytes[index] == b'\\'
+            && index + 1 < len
+            && bytes[index + 1].is_ascii_punctuation()
+        {
+            result.push_str(&value[start..index]);
+            // Keep the escaped character, skip over it.
+            start = index + 1;
+            index += 2;
+            continue;
+        }
+
+        if character_references {
+            if let Some((decoded, end)) = decode_at(bytes, index, true) {
+                result.push_str(&value[start..index]);
+                result.push_str(&decoded);
+                start = end;
+                index = end;
+                continue;
+            }
+        }
+
+        index += 1;
+    }
+
+    result.push_str(&value[start..]);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_with() {
+        assert_eq!(
+            serialize_with("a&amp;b\\&c", false, false),
+            "a&amp;b\\&c",
+            "should not interpret anything by default"
+        );
+        assert_eq!(
+            serialize_with("a&amp;b &#35; &#x23; &copy; &nope; &amp", true, false),
+            "a&b # # © &nope; &amp",
+            "should decode character references"
+        );
+        assert_eq!(
+            serialize_with("a\\&b \\a \\\\ \\", false, true),
+            "a&b \\a \\ \\",
+            "should resolve character escapes before ASCII punctuation"
+        );
+        assert_eq!(
+            serialize_with("\\&amp; \\\\&amp;", true, true),
+            "&amp; \\&",
+            "should not decode escaped character references"
+        );
+        assert_eq!(
+            serialize_with("\\&amp;", true, false),
+            "\\&",
+            "should decode character references after backslashes when not resolving escapes"
+        );
+    }
+}
+
+/// Serialize the value of string content (such as a destination or title)
+/// from its events, with character escapes and references decoded.
+///
+/// Unlike [`serialize_with`][], this uses what was parsed, so it follows
+/// which constructs are turned on.
+pub fn serialize_events(events: &[Event], bytes: &[u8]) -> String {
+    let mut result = String::new();
+    let mut marker = b'&';
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            let slice = Slice::from_position(bytes, &SlicePosition::from_exit_event(events, index));
+
+            match event.name {
+                Name::CharacterEscapeValue | Name::Data | Name::LineEnding => {
+                    result.push_str(slice.as_str());
+                }
+                Name::CharacterReference => marker = b'&',
+                Name::CharacterReferenceMarkerHexadecimal => marker = b'x',
+                Name::CharacterReferenceMarkerNumeric => marker = b'#',
+                Name::CharacterReferenceValue => {
+                    if let Some(value) = decode_character_reference(slice.as_str(), marker, true) {
+                        result.push_str(&value);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    result
+}
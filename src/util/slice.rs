@@ -2,7 +2,7 @@
 
 use crate::event::{Event, Kind, Point};
 use crate::util::constant::TAB_SIZE;
-use alloc::{format, string::String, vec};
+use alloc::{format, string::String};
 use core::str;
 
 /// A range between two points.
@@ -72,8 +72,11 @@ impl<'a> Slice<'a> {
 
         // If we have virtual spaces before, it means we are past the actual
         // character at that index, and those virtual spaces.
+        // The spaces left go up to the next tab stop.
+        // Columns in points depend on `column_mode`, so the column is
+        // counted again in bytes.
         if before > 0 {
-            before = TAB_SIZE - before;
+            before = TAB_SIZE - (byte_column(bytes, start) + before - 1) % TAB_SIZE;
             start += 1;
         };
 
@@ -111,15 +114,43 @@ impl<'a> Slice<'a> {
     ///
     /// > 👉 **Note**: cannot represent virtual spaces.
     pub fn as_str(&self) -> &str {
-        str::from_utf8(self.bytes).unwrap()
+        str::from_utf8(self.bytes).expect("expected slice to be valid UTF-8")
     }
 
     /// Turn the slice into a `String`.
     ///
     /// Supports virtual spaces.
     pub fn serialize(&self) -> String {
-        let prefix = String::from_utf8(vec![b' '; self.before]).unwrap();
-        let suffix = String::from_utf8(vec![b' '; self.after]).unwrap();
-        format!("{}{}{}", prefix, self.as_str(), suffix)
+        format!(
+            "{}{}{}",
+            " ".repeat(self.before),
+            self.as_str(),
+            " ".repeat(self.after)
+        )
     }
 }
+
+/// Get the column of `index` in `bytes`, counted in bytes, with tabs counted
+/// up to the next tab stop.
+///
+/// This is how the tokenizer counts columns, before they are converted to
+/// `column_mode`.
+fn byte_column(bytes: &[u8], index: usize) -> usize {
+    let mut line_start = index;
+
+    while line_start > 0 && !matches!(bytes[line_start - 1], b'\n' | b'\r') {
+        line_start -= 1;
+    }
+
+    let mut column = 1;
+
+    for byte in &bytes[line_start..index] {
+        column += if *byte == b'\t' {
+            TAB_SIZE - (column - 1) % TAB_SIZE
+        } else {
+            1
+        };
+    }
+
+    column
+}
@@ -0,0 +1,37 @@
+//! Make slugs for heading `id`s.
+
+use alloc::string::String;
+
+/// Make a slug from the text of a heading, like GitHub does.
+///
+/// The text is lowercased, everything that is not a letter, number, mark,
+/// space, dash (`-`), or underscore (`_`) is removed, and then each space is
+/// turned into a dash.
+///
+/// Making slugs unique is up to the caller.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::slug::slug;
+///
+/// assert_eq!(slug("Hello, World!"), "hello-world");
+/// assert_eq!(slug("a  b"), "a--b");
+/// ```
+///
+/// ## References
+///
+/// *   [`github-slugger`](https://github.com/Flet/github-slugger)
+pub fn slug(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for char in value.chars().flat_map(char::to_lowercase) {
+        if char == ' ' {
+            result.push('-');
+        } else if char.is_alphanumeric() || char == '-' || char == '_' {
+            result.push(char);
+        }
+    }
+
+    result
+}
@@ -12,6 +12,8 @@
 /// comes before or after them.
 /// One such difference is if those characters are Unicode punctuation.
 ///
+/// Sorted, so it can be searched with `binary_search`.
+///
 /// ## References
 ///
 /// *   [*§ 2.1 Characters and lines* in `CommonMark`](https://spec.commonmark.org/0.30/#unicode-punctuation-character)
@@ -859,3 +861,2423 @@ pub const PUNCTUATION: [char; 842] = [
     '\u{1E95E}',
     '\u{1E95F}',
 ];
+
+/// List of characters that are considered marks.
+///
+/// > 👉 **Important**: this module is generated by `generate/src/main.rs`.
+/// > It is generate from the latest Unicode data.
+///
+/// Marks (such as combining accents) combine with the character before them.
+/// They are used when counting columns in graphemes.
+///
+/// Sorted, so it can be searched with `binary_search`.
+pub const MARK: [char; 2408] = [
+    '\u{0300}',
+    '\u{0301}',
+    '\u{0302}',
+    '\u{0303}',
+    '\u{0304}',
+    '\u{0305}',
+    '\u{0306}',
+    '\u{0307}',
+    '\u{0308}',
+    '\u{0309}',
+    '\u{030A}',
+    '\u{030B}',
+    '\u{030C}',
+    '\u{030D}',
+    '\u{030E}',
+    '\u{030F}',
+    '\u{0310}',
+    '\u{0311}',
+    '\u{0312}',
+    '\u{0313}',
+    '\u{0314}',
+    '\u{0315}',
+    '\u{0316}',
+    '\u{0317}',
+    '\u{0318}',
+    '\u{0319}',
+    '\u{031A}',
+    '\u{031B}',
+    '\u{031C}',
+    '\u{031D}',
+    '\u{031E}',
+    '\u{031F}',
+    '\u{0320}',
+    '\u{0321}',
+    '\u{0322}',
+    '\u{0323}',
+    '\u{0324}',
+    '\u{0325}',
+    '\u{0326}',
+    '\u{0327}',
+    '\u{0328}',
+    '\u{0329}',
+    '\u{032A}',
+    '\u{032B}',
+    '\u{032C}',
+    '\u{032D}',
+    '\u{032E}',
+    '\u{032F}',
+    '\u{0330}',
+    '\u{0331}',
+    '\u{0332}',
+    '\u{0333}',
+    '\u{0334}',
+    '\u{0335}',
+    '\u{0336}',
+    '\u{0337}',
+    '\u{0338}',
+    '\u{0339}',
+    '\u{033A}',
+    '\u{033B}',
+    '\u{033C}',
+    '\u{033D}',
+    '\u{033E}',
+    '\u{033F}',
+    '\u{0340}',
+    '\u{0341}',
+    '\u{0342}',
+    '\u{0343}',
+    '\u{0344}',
+    '\u{0345}',
+    '\u{0346}',
+    '\u{0347}',
+    '\u{0348}',
+    '\u{0349}',
+    '\u{034A}',
+    '\u{034B}',
+    '\u{034C}',
+    '\u{034D}',
+    '\u{034E}',
+    '\u{034F}',
+    '\u{0350}',
+    '\u{0351}',
+    '\u{0352}',
+    '\u{0353}',
+    '\u{0354}',
+    '\u{0355}',
+    '\u{0356}',
+    '\u{0357}',
+    '\u{0358}',
+    '\u{0359}',
+    '\u{035A}',
+    '\u{035B}',
+    '\u{035C}',
+    '\u{035D}',
+    '\u{035E}',
+    '\u{035F}',
+    '\u{0360}',
+    '\u{0361}',
+    '\u{0362}',
+    '\u{0363}',
+    '\u{0364}',
+    '\u{0365}',
+    '\u{0366}',
+    '\u{0367}',
+    '\u{0368}',
+    '\u{0369}',
+    '\u{036A}',
+    '\u{036B}',
+    '\u{036C}',
+    '\u{036D}',
+    '\u{036E}',
+    '\u{036F}',
+    '\u{0483}',
+    '\u{0484}',
+    '\u{0485}',
+    '\u{0486}',
+    '\u{0487}',
+    '\u{0488}',
+    '\u{0489}',
+    '\u{0591}',
+    '\u{0592}',
+    '\u{0593}',
+    '\u{0594}',
+    '\u{0595}',
+    '\u{0596}',
+    '\u{0597}',
+    '\u{0598}',
+    '\u{0599}',
+    '\u{059A}',
+    '\u{059B}',
+    '\u{059C}',
+    '\u{059D}',
+    '\u{059E}',
+    '\u{059F}',
+    '\u{05A0}',
+    '\u{05A1}',
+    '\u{05A2}',
+    '\u{05A3}',
+    '\u{05A4}',
+    '\u{05A5}',
+    '\u{05A6}',
+    '\u{05A7}',
+    '\u{05A8}',
+    '\u{05A9}',
+    '\u{05AA}',
+    '\u{05AB}',
+    '\u{05AC}',
+    '\u{05AD}',
+    '\u{05AE}',
+    '\u{05AF}',
+    '\u{05B0}',
+    '\u{05B1}',
+    '\u{05B2}',
+    '\u{05B3}',
+    '\u{05B4}',
+    '\u{05B5}',
+    '\u{05B6}',
+    '\u{05B7}',
+    '\u{05B8}',
+    '\u{05B9}',
+    '\u{05BA}',
+    '\u{05BB}',
+    '\u{05BC}',
+    '\u{05BD}',
+    '\u{05BF}',
+    '\u{05C1}',
+    '\u{05C2}',
+    '\u{05C4}',
+    '\u{05C5}',
+    '\u{05C7}',
+    '\u{0610}',
+    '\u{0611}',
+    '\u{0612}',
+    '\u{0613}',
+    '\u{0614}',
+    '\u{0615}',
+    '\u{0616}',
+    '\u{0617}',
+    '\u{0618}',
+    '\u{0619}',
+    '\u{061A}',
+    '\u{064B}',
+    '\u{064C}',
+    '\u{064D}',
+    '\u{064E}',
+    '\u{064F}',
+    '\u{0650}',
+    '\u{0651}',
+    '\u{0652}',
+    '\u{0653}',
+    '\u{0654}',
+    '\u{0655}',
+    '\u{0656}',
+    '\u{0657}',
+    '\u{0658}',
+    '\u{0659}',
+    '\u{065A}',
+    '\u{065B}',
+    '\u{065C}',
+    '\u{065D}',
+    '\u{065E}',
+    '\u{065F}',
+    '\u{0670}',
+    '\u{06D6}',
+    '\u{06D7}',
+    '\u{06D8}',
+    '\u{06D9}',
+    '\u{06DA}',
+    '\u{06DB}',
+    '\u{06DC}',
+    '\u{06DF}',
+    '\u{06E0}',
+    '\u{06E1}',
+    '\u{06E2}',
+    '\u{06E3}',
+    '\u{06E4}',
+    '\u{06E7}',
+    '\u{06E8}',
+    '\u{06EA}',
+    '\u{06EB}',
+    '\u{06EC}',
+    '\u{06ED}',
+    '\u{0711}',
+    '\u{0730}',
+    '\u{0731}',
+    '\u{0732}',
+    '\u{0733}',
+    '\u{0734}',
+    '\u{0735}',
+    '\u{0736}',
+    '\u{0737}',
+    '\u{0738}',
+    '\u{0739}',
+    '\u{073A}',
+    '\u{073B}',
+    '\u{073C}',
+    '\u{073D}',
+    '\u{073E}',
+    '\u{073F}',
+    '\u{0740}',
+    '\u{0741}',
+    '\u{0742}',
+    '\u{0743}',
+    '\u{0744}',
+    '\u{0745}',
+    '\u{0746}',
+    '\u{0747}',
+    '\u{0748}',
+    '\u{0749}',
+    '\u{074A}',
+    '\u{07A6}',
+    '\u{07A7}',
+    '\u{07A8}',
+    '\u{07A9}',
+    '\u{07AA}',
+    '\u{07AB}',
+    '\u{07AC}',
+    '\u{07AD}',
+    '\u{07AE}',
+    '\u{07AF}',
+    '\u{07B0}',
+    '\u{07EB}',
+    '\u{07EC}',
+    '\u{07ED}',
+    '\u{07EE}',
+    '\u{07EF}',
+    '\u{07F0}',
+    '\u{07F1}',
+    '\u{07F2}',
+    '\u{07F3}',
+    '\u{07FD}',
+    '\u{0816}',
+    '\u{0817}',
+    '\u{0818}',
+    '\u{0819}',
+    '\u{081B}',
+    '\u{081C}',
+    '\u{081D}',
+    '\u{081E}',
+    '\u{081F}',
+    '\u{0820}',
+    '\u{0821}',
+    '\u{0822}',
+    '\u{0823}',
+    '\u{0825}',
+    '\u{0826}',
+    '\u{0827}',
+    '\u{0829}',
+    '\u{082A}',
+    '\u{082B}',
+    '\u{082C}',
+    '\u{082D}',
+    '\u{0859}',
+    '\u{085A}',
+    '\u{085B}',
+    '\u{0898}',
+    '\u{0899}',
+    '\u{089A}',
+    '\u{089B}',
+    '\u{089C}',
+    '\u{089D}',
+    '\u{089E}',
+    '\u{089F}',
+    '\u{08CA}',
+    '\u{08CB}',
+    '\u{08CC}',
+    '\u{08CD}',
+    '\u{08CE}',
+    '\u{08CF}',
+    '\u{08D0}',
+    '\u{08D1}',
+    '\u{08D2}',
+    '\u{08D3}',
+    '\u{08D4}',
+    '\u{08D5}',
+    '\u{08D6}',
+    '\u{08D7}',
+    '\u{08D8}',
+    '\u{08D9}',
+    '\u{08DA}',
+    '\u{08DB}',
+    '\u{08DC}',
+    '\u{08DD}',
+    '\u{08DE}',
+    '\u{08DF}',
+    '\u{08E0}',
+    '\u{08E1}',
+    '\u{08E3}',
+    '\u{08E4}',
+    '\u{08E5}',
+    '\u{08E6}',
+    '\u{08E7}',
+    '\u{08E8}',
+    '\u{08E9}',
+    '\u{08EA}',
+    '\u{08EB}',
+    '\u{08EC}',
+    '\u{08ED}',
+    '\u{08EE}',
+    '\u{08EF}',
+    '\u{08F0}',
+    '\u{08F1}',
+    '\u{08F2}',
+    '\u{08F3}',
+    '\u{08F4}',
+    '\u{08F5}',
+    '\u{08F6}',
+    '\u{08F7}',
+    '\u{08F8}',
+    '\u{08F9}',
+    '\u{08FA}',
+    '\u{08FB}',
+    '\u{08FC}',
+    '\u{08FD}',
+    '\u{08FE}',
+    '\u{08FF}',
+    '\u{0900}',
+    '\u{0901}',
+    '\u{0902}',
+    '\u{0903}',
+    '\u{093A}',
+    '\u{093B}',
+    '\u{093C}',
+    '\u{093E}',
+    '\u{093F}',
+    '\u{0940}',
+    '\u{0941}',
+    '\u{0942}',
+    '\u{0943}',
+    '\u{0944}',
+    '\u{0945}',
+    '\u{0946}',
+    '\u{0947}',
+    '\u{0948}',
+    '\u{0949}',
+    '\u{094A}',
+    '\u{094B}',
+    '\u{094C}',
+    '\u{094D}',
+    '\u{094E}',
+    '\u{094F}',
+    '\u{0951}',
+    '\u{0952}',
+    '\u{0953}',
+    '\u{0954}',
+    '\u{0955}',
+    '\u{0956}',
+    '\u{0957}',
+    '\u{0962}',
+    '\u{0963}',
+    '\u{0981}',
+    '\u{0982}',
+    '\u{0983}',
+    '\u{09BC}',
+    '\u{09BE}',
+    '\u{09BF}',
+    '\u{09C0}',
+    '\u{09C1}',
+    '\u{09C2}',
+    '\u{09C3}',
+    '\u{09C4}',
+    '\u{09C7}',
+    '\u{09C8}',
+    '\u{09CB}',
+    '\u{09CC}',
+    '\u{09CD}',
+    '\u{09D7}',
+    '\u{09E2}',
+    '\u{09E3}',
+    '\u{09FE}',
+    '\u{0A01}',
+    '\u{0A02}',
+    '\u{0A03}',
+    '\u{0A3C}',
+    '\u{0A3E}',
+    '\u{0A3F}',
+    '\u{0A40}',
+    '\u{0A41}',
+    '\u{0A42}',
+    '\u{0A47}',
+    '\u{0A48}',
+    '\u{0A4B}',
+    '\u{0A4C}',
+    '\u{0A4D}',
+    '\u{0A51}',
+    '\u{0A70}',
+    '\u{0A71}',
+    '\u{0A75}',
+    '\u{0A81}',
+    '\u{0A82}',
+    '\u{0A83}',
+    '\u{0ABC}',
+    '\u{0ABE}',
+    '\u{0ABF}',
+    '\u{0AC0}',
+    '\u{0AC1}',
+    '\u{0AC2}',
+    '\u{0AC3}',
+    '\u{0AC4}',
+    '\u{0AC5}',
+    '\u{0AC7}',
+    '\u{0AC8}',
+    '\u{0AC9}',
+    '\u{0ACB}',
+    '\u{0ACC}',
+    '\u{0ACD}',
+    '\u{0AE2}',
+    '\u{0AE3}',
+    '\u{0AFA}',
+    '\u{0AFB}',
+    '\u{0AFC}',
+    '\u{0AFD}',
+    '\u{0AFE}',
+    '\u{0AFF}',
+    '\u{0B01}',
+    '\u{0B02}',
+    '\u{0B03}',
+    '\u{0B3C}',
+    '\u{0B3E}',
+    '\u{0B3F}',
+    '\u{0B40}',
+    '\u{0B41}',
+    '\u{0B42}',
+    '\u{0B43}',
+    '\u{0B44}',
+    '\u{0B47}',
+    '\u{0B48}',
+    '\u{0B4B}',
+    '\u{0B4C}',
+    '\u{0B4D}',
+    '\u{0B55}',
+    '\u{0B56}',
+    '\u{0B57}',
+    '\u{0B62}',
+    '\u{0B63}',
+    '\u{0B82}',
+    '\u{0BBE}',
+    '\u{0BBF}',
+    '\u{0BC0}',
+    '\u{0BC1}',
+    '\u{0BC2}',
+    '\u{0BC6}',
+    '\u{0BC7}',
+    '\u{0BC8}',
+    '\u{0BCA}',
+    '\u{0BCB}',
+    '\u{0BCC}',
+    '\u{0BCD}',
+    '\u{0BD7}',
+    '\u{0C00}',
+    '\u{0C01}',
+    '\u{0C02}',
+    '\u{0C03}',
+    '\u{0C04}',
+    '\u{0C3C}',
+    '\u{0C3E}',
+    '\u{0C3F}',
+    '\u{0C40}',
+    '\u{0C41}',
+    '\u{0C42}',
+    '\u{0C43}',
+    '\u{0C44}',
+    '\u{0C46}',
+    '\u{0C47}',
+    '\u{0C48}',
+    '\u{0C4A}',
+    '\u{0C4B}',
+    '\u{0C4C}',
+    '\u{0C4D}',
+    '\u{0C55}',
+    '\u{0C56}',
+    '\u{0C62}',
+    '\u{0C63}',
+    '\u{0C81}',
+    '\u{0C82}',
+    '\u{0C83}',
+    '\u{0CBC}',
+    '\u{0CBE}',
+    '\u{0CBF}',
+    '\u{0CC0}',
+    '\u{0CC1}',
+    '\u{0CC2}',
+    '\u{0CC3}',
+    '\u{0CC4}',
+    '\u{0CC6}',
+    '\u{0CC7}',
+    '\u{0CC8}',
+    '\u{0CCA}',
+    '\u{0CCB}',
+    '\u{0CCC}',
+    '\u{0CCD}',
+    '\u{0CD5}',
+    '\u{0CD6}',
+    '\u{0CE2}',
+    '\u{0CE3}',
+    '\u{0D00}',
+    '\u{0D01}',
+    '\u{0D02}',
+    '\u{0D03}',
+    '\u{0D3B}',
+    '\u{0D3C}',
+    '\u{0D3E}',
+    '\u{0D3F}',
+    '\u{0D40}',
+    '\u{0D41}',
+    '\u{0D42}',
+    '\u{0D43}',
+    '\u{0D44}',
+    '\u{0D46}',
+    '\u{0D47}',
+    '\u{0D48}',
+    '\u{0D4A}',
+    '\u{0D4B}',
+    '\u{0D4C}',
+    '\u{0D4D}',
+    '\u{0D57}',
+    '\u{0D62}',
+    '\u{0D63}',
+    '\u{0D81}',
+    '\u{0D82}',
+    '\u{0D83}',
+    '\u{0DCA}',
+    '\u{0DCF}',
+    '\u{0DD0}',
+    '\u{0DD1}',
+    '\u{0DD2}',
+    '\u{0DD3}',
+    '\u{0DD4}',
+    '\u{0DD6}',
+    '\u{0DD8}',
+    '\u{0DD9}',
+    '\u{0DDA}',
+    '\u{0DDB}',
+    '\u{0DDC}',
+    '\u{0DDD}',
+    '\u{0DDE}',
+    '\u{0DDF}',
+    '\u{0DF2}',
+    '\u{0DF3}',
+    '\u{0E31}',
+    '\u{0E34}',
+    '\u{0E35}',
+    '\u{0E36}',
+    '\u{0E37}',
+    '\u{0E38}',
+    '\u{0E39}',
+    '\u{0E3A}',
+    '\u{0E47}',
+    '\u{0E48}',
+    '\u{0E49}',
+    '\u{0E4A}',
+    '\u{0E4B}',
+    '\u{0E4C}',
+    '\u{0E4D}',
+    '\u{0E4E}',
+    '\u{0EB1}',
+    '\u{0EB4}',
+    '\u{0EB5}',
+    '\u{0EB6}',
+    '\u{0EB7}',
+    '\u{0EB8}',
+    '\u{0EB9}',
+    '\u{0EBA}',
+    '\u{0EBB}',
+    '\u{0EBC}',
+    '\u{0EC8}',
+    '\u{0EC9}',
+    '\u{0ECA}',
+    '\u{0ECB}',
+    '\u{0ECC}',
+    '\u{0ECD}',
+    '\u{0F18}',
+    '\u{0F19}',
+    '\u{0F35}',
+    '\u{0F37}',
+    '\u{0F39}',
+    '\u{0F3E}',
+    '\u{0F3F}',
+    '\u{0F71}',
+    '\u{0F72}',
+    '\u{0F73}',
+    '\u{0F74}',
+    '\u{0F75}',
+    '\u{0F76}',
+    '\u{0F77}',
+    '\u{0F78}',
+    '\u{0F79}',
+    '\u{0F7A}',
+    '\u{0F7B}',
+    '\u{0F7C}',
+    '\u{0F7D}',
+    '\u{0F7E}',
+    '\u{0F7F}',
+    '\u{0F80}',
+    '\u{0F81}',
+    '\u{0F82}',
+    '\u{0F83}',
+    '\u{0F84}',
+    '\u{0F86}',
+    '\u{0F87}',
+    '\u{0F8D}',
+    '\u{0F8E}',
+    '\u{0F8F}',
+    '\u{0F90}',
+    '\u{0F91}',
+    '\u{0F92}',
+    '\u{0F93}',
+    '\u{0F94}',
+    '\u{0F95}',
+    '\u{0F96}',
+    '\u{0F97}',
+    '\u{0F99}',
+    '\u{0F9A}',
+    '\u{0F9B}',
+    '\u{0F9C}',
+    '\u{0F9D}',
+    '\u{0F9E}',
+    '\u{0F9F}',
+    '\u{0FA0}',
+    '\u{0FA1}',
+    '\u{0FA2}',
+    '\u{0FA3}',
+    '\u{0FA4}',
+    '\u{0FA5}',
+    '\u{0FA6}',
+    '\u{0FA7}',
+    '\u{0FA8}',
+    '\u{0FA9}',
+    '\u{0FAA}',
+    '\u{0FAB}',
+    '\u{0FAC}',
+    '\u{0FAD}',
+    '\u{0FAE}',
+    '\u{0FAF}',
+    '\u{0FB0}',
+    '\u{0FB1}',
+    '\u{0FB2}',
+    '\u{0FB3}',
+    '\u{0FB4}',
+    '\u{0FB5}',
+    '\u{0FB6}',
+    '\u{0FB7}',
+    '\u{0FB8}',
+    '\u{0FB9}',
+    '\u{0FBA}',
+    '\u{0FBB}',
+    '\u{0FBC}',
+    '\u{0FC6}',
+    '\u{102B}',
+    '\u{102C}',
+    '\u{102D}',
+    '\u{102E}',
+    '\u{102F}',
+    '\u{1030}',
+    '\u{1031}',
+    '\u{1032}',
+    '\u{1033}',
+    '\u{1034}',
+    '\u{1035}',
+    '\u{1036}',
+    '\u{1037}',
+    '\u{1038}',
+    '\u{1039}',
+    '\u{103A}',
+    '\u{103B}',
+    '\u{103C}',
+    '\u{103D}',
+    '\u{103E}',
+    '\u{1056}',
+    '\u{1057}',
+    '\u{1058}',
+    '\u{1059}',
+    '\u{105E}',
+    '\u{105F}',
+    '\u{1060}',
+    '\u{1062}',
+    '\u{1063}',
+    '\u{1064}',
+    '\u{1067}',
+    '\u{1068}',
+    '\u{1069}',
+    '\u{106A}',
+    '\u{106B}',
+    '\u{106C}',
+    '\u{106D}',
+    '\u{1071}',
+    '\u{1072}',
+    '\u{1073}',
+    '\u{1074}',
+    '\u{1082}',
+    '\u{1083}',
+    '\u{1084}',
+    '\u{1085}',
+    '\u{1086}',
+    '\u{1087}',
+    '\u{1088}',
+    '\u{1089}',
+    '\u{108A}',
+    '\u{108B}',
+    '\u{108C}',
+    '\u{108D}',
+    '\u{108F}',
+    '\u{109A}',
+    '\u{109B}',
+    '\u{109C}',
+    '\u{109D}',
+    '\u{135D}',
+    '\u{135E}',
+    '\u{135F}',
+    '\u{1712}',
+    '\u{1713}',
+    '\u{1714}',
+    '\u{1715}',
+    '\u{1732}',
+    '\u{1733}',
+    '\u{1734}',
+    '\u{1752}',
+    '\u{1753}',
+    '\u{1772}',
+    '\u{1773}',
+    '\u{17B4}',
+    '\u{17B5}',
+    '\u{17B6}',
+    '\u{17B7}',
+    '\u{17B8}',
+    '\u{17B9}',
+    '\u{17BA}',
+    '\u{17BB}',
+    '\u{17BC}',
+    '\u{17BD}',
+    '\u{17BE}',
+    '\u{17BF}',
+    '\u{17C0}',
+    '\u{17C1}',
+    '\u{17C2}',
+    '\u{17C3}',
+    '\u{17C4}',
+    '\u{17C5}',
+    '\u{17C6}',
+    '\u{17C7}',
+    '\u{17C8}',
+    '\u{17C9}',
+    '\u{17CA}',
+    '\u{17CB}',
+    '\u{17CC}',
+    '\u{17CD}',
+    '\u{17CE}',
+    '\u{17CF}',
+    '\u{17D0}',
+    '\u{17D1}',
+    '\u{17D2}',
+    '\u{17D3}',
+    '\u{17DD}',
+    '\u{180B}',
+    '\u{180C}',
+    '\u{180D}',
+    '\u{180F}',
+    '\u{1885}',
+    '\u{1886}',
+    '\u{18A9}',
+    '\u{1920}',
+    '\u{1921}',
+    '\u{1922}',
+    '\u{1923}',
+    '\u{1924}',
+    '\u{1925}',
+    '\u{1926}',
+    '\u{1927}',
+    '\u{1928}',
+    '\u{1929}',
+    '\u{192A}',
+    '\u{192B}',
+    '\u{1930}',
+    '\u{1931}',
+    '\u{1932}',
+    '\u{1933}',
+    '\u{1934}',
+    '\u{1935}',
+    '\u{1936}',
+    '\u{1937}',
+    '\u{1938}',
+    '\u{1939}',
+    '\u{193A}',
+    '\u{193B}',
+    '\u{1A17}',
+    '\u{1A18}',
+    '\u{1A19}',
+    '\u{1A1A}',
+    '\u{1A1B}',
+    '\u{1A55}',
+    '\u{1A56}',
+    '\u{1A57}',
+    '\u{1A58}',
+    '\u{1A59}',
+    '\u{1A5A}',
+    '\u{1A5B}',
+    '\u{1A5C}',
+    '\u{1A5D}',
+    '\u{1A5E}',
+    '\u{1A60}',
+    '\u{1A61}',
+    '\u{1A62}',
+    '\u{1A63}',
+    '\u{1A64}',
+    '\u{1A65}',
+    '\u{1A66}',
+    '\u{1A67}',
+    '\u{1A68}',
+    '\u{1A69}',
+    '\u{1A6A}',
+    '\u{1A6B}',
+    '\u{1A6C}',
+    '\u{1A6D}',
+    '\u{1A6E}',
+    '\u{1A6F}',
+    '\u{1A70}',
+    '\u{1A71}',
+    '\u{1A72}',
+    '\u{1A73}',
+    '\u{1A74}',
+    '\u{1A75}',
+    '\u{1A76}',
+    '\u{1A77}',
+    '\u{1A78}',
+    '\u{1A79}',
+    '\u{1A7A}',
+    '\u{1A7B}',
+    '\u{1A7C}',
+    '\u{1A7F}',
+    '\u{1AB0}',
+    '\u{1AB1}',
+    '\u{1AB2}',
+    '\u{1AB3}',
+    '\u{1AB4}',
+    '\u{1AB5}',
+    '\u{1AB6}',
+    '\u{1AB7}',
+    '\u{1AB8}',
+    '\u{1AB9}',
+    '\u{1ABA}',
+    '\u{1ABB}',
+    '\u{1ABC}',
+    '\u{1ABD}',
+    '\u{1ABE}',
+    '\u{1ABF}',
+    '\u{1AC0}',
+    '\u{1AC1}',
+    '\u{1AC2}',
+    '\u{1AC3}',
+    '\u{1AC4}',
+    '\u{1AC5}',
+    '\u{1AC6}',
+    '\u{1AC7}',
+    '\u{1AC8}',
+    '\u{1AC9}',
+    '\u{1ACA}',
+    '\u{1ACB}',
+    '\u{1ACC}',
+    '\u{1ACD}',
+    '\u{1ACE}',
+    '\u{1B00}',
+    '\u{1B01}',
+    '\u{1B02}',
+    '\u{1B03}',
+    '\u{1B04}',
+    '\u{1B34}',
+    '\u{1B35}',
+    '\u{1B36}',
+    '\u{1B37}',
+    '\u{1B38}',
+    '\u{1B39}',
+    '\u{1B3A}',
+    '\u{1B3B}',
+    '\u{1B3C}',
+    '\u{1B3D}',
+    '\u{1B3E}',
+    '\u{1B3F}',
+    '\u{1B40}',
+    '\u{1B41}',
+    '\u{1B42}',
+    '\u{1B43}',
+    '\u{1B44}',
+    '\u{1B6B}',
+    '\u{1B6C}',
+    '\u{1B6D}',
+    '\u{1B6E}',
+    '\u{1B6F}',
+    '\u{1B70}',
+    '\u{1B71}',
+    '\u{1B72}',
+    '\u{1B73}',
+    '\u{1B80}',
+    '\u{1B81}',
+    '\u{1B82}',
+    '\u{1BA1}',
+    '\u{1BA2}',
+    '\u{1BA3}',
+    '\u{1BA4}',
+    '\u{1BA5}',
+    '\u{1BA6}',
+    '\u{1BA7}',
+    '\u{1BA8}',
+    '\u{1BA9}',
+    '\u{1BAA}',
+    '\u{1BAB}',
+    '\u{1BAC}',
+    '\u{1BAD}',
+    '\u{1BE6}',
+    '\u{1BE7}',
+    '\u{1BE8}',
+    '\u{1BE9}',
+    '\u{1BEA}',
+    '\u{1BEB}',
+    '\u{1BEC}',
+    '\u{1BED}',
+    '\u{1BEE}',
+    '\u{1BEF}',
+    '\u{1BF0}',
+    '\u{1BF1}',
+    '\u{1BF2}',
+    '\u{1BF3}',
+    '\u{1C24}',
+    '\u{1C25}',
+    '\u{1C26}',
+    '\u{1C27}',
+    '\u{1C28}',
+    '\u{1C29}',
+    '\u{1C2A}',
+    '\u{1C2B}',
+    '\u{1C2C}',
+    '\u{1C2D}',
+    '\u{1C2E}',
+    '\u{1C2F}',
+    '\u{1C30}',
+    '\u{1C31}',
+    '\u{1C32}',
+    '\u{1C33}',
+    '\u{1C34}',
+    '\u{1C35}',
+    '\u{1C36}',
+    '\u{1C37}',
+    '\u{1CD0}',
+    '\u{1CD1}',
+    '\u{1CD2}',
+    '\u{1CD4}',
+    '\u{1CD5}',
+    '\u{1CD6}',
+    '\u{1CD7}',
+    '\u{1CD8}',
+    '\u{1CD9}',
+    '\u{1CDA}',
+    '\u{1CDB}',
+    '\u{1CDC}',
+    '\u{1CDD}',
+    '\u{1CDE}',
+    '\u{1CDF}',
+    '\u{1CE0}',
+    '\u{1CE1}',
+    '\u{1CE2}',
+    '\u{1CE3}',
+    '\u{1CE4}',
+    '\u{1CE5}',
+    '\u{1CE6}',
+    '\u{1CE7}',
+    '\u{1CE8}',
+    '\u{1CED}',
+    '\u{1CF4}',
+    '\u{1CF7}',
+    '\u{1CF8}',
+    '\u{1CF9}',
+    '\u{1DC0}',
+    '\u{1DC1}',
+    '\u{1DC2}',
+    '\u{1DC3}',
+    '\u{1DC4}',
+    '\u{1DC5}',
+    '\u{1DC6}',
+    '\u{1DC7}',
+    '\u{1DC8}',
+    '\u{1DC9}',
+    '\u{1DCA}',
+    '\u{1DCB}',
+    '\u{1DCC}',
+    '\u{1DCD}',
+    '\u{1DCE}',
+    '\u{1DCF}',
+    '\u{1DD0}',
+    '\u{1DD1}',
+    '\u{1DD2}',
+    '\u{1DD3}',
+    '\u{1DD4}',
+    '\u{1DD5}',
+    '\u{1DD6}',
+    '\u{1DD7}',
+    '\u{1DD8}',
+    '\u{1DD9}',
+    '\u{1DDA}',
+    '\u{1DDB}',
+    '\u{1DDC}',
+    '\u{1DDD}',
+    '\u{1DDE}',
+    '\u{1DDF}',
+    '\u{1DE0}',
+    '\u{1DE1}',
+    '\u{1DE2}',
+    '\u{1DE3}',
+    '\u{1DE4}',
+    '\u{1DE5}',
+    '\u{1DE6}',
+    '\u{1DE7}',
+    '\u{1DE8}',
+    '\u{1DE9}',
+    '\u{1DEA}',
+    '\u{1DEB}',
+    '\u{1DEC}',
+    '\u{1DED}',
+    '\u{1DEE}',
+    '\u{1DEF}',
+    '\u{1DF0}',
+    '\u{1DF1}',
+    '\u{1DF2}',
+    '\u{1DF3}',
+    '\u{1DF4}',
+    '\u{1DF5}',
+    '\u{1DF6}',
+    '\u{1DF7}',
+    '\u{1DF8}',
+    '\u{1DF9}',
+    '\u{1DFA}',
+    '\u{1DFB}',
+    '\u{1DFC}',
+    '\u{1DFD}',
+    '\u{1DFE}',
+    '\u{1DFF}',
+    '\u{20D0}',
+    '\u{20D1}',
+    '\u{20D2}',
+    '\u{20D3}',
+    '\u{20D4}',
+    '\u{20D5}',
+    '\u{20D6}',
+    '\u{20D7}',
+    '\u{20D8}',
+    '\u{20D9}',
+    '\u{20DA}',
+    '\u{20DB}',
+    '\u{20DC}',
+    '\u{20DD}',
+    '\u{20DE}',
+    '\u{20DF}',
+    '\u{20E0}',
+    '\u{20E1}',
+    '\u{20E2}',
+    '\u{20E3}',
+    '\u{20E4}',
+    '\u{20E5}',
+    '\u{20E6}',
+    '\u{20E7}',
+    '\u{20E8}',
+    '\u{20E9}',
+    '\u{20EA}',
+    '\u{20EB}',
+    '\u{20EC}',
+    '\u{20ED}',
+    '\u{20EE}',
+    '\u{20EF}',
+    '\u{20F0}',
+    '\u{2CEF}',
+    '\u{2CF0}',
+    '\u{2CF1}',
+    '\u{2D7F}',
+    '\u{2DE0}',
+    '\u{2DE1}',
+    '\u{2DE2}',
+    '\u{2DE3}',
+    '\u{2DE4}',
+    '\u{2DE5}',
+    '\u{2DE6}',
+    '\u{2DE7}',
+    '\u{2DE8}',
+    '\u{2DE9}',
+    '\u{2DEA}',
+    '\u{2DEB}',
+    '\u{2DEC}',
+    '\u{2DED}',
+    '\u{2DEE}',
+    '\u{2DEF}',
+    '\u{2DF0}',
+    '\u{2DF1}',
+    '\u{2DF2}',
+    '\u{2DF3}',
+    '\u{2DF4}',
+    '\u{2DF5}',
+    '\u{2DF6}',
+    '\u{2DF7}',
+    '\u{2DF8}',
+    '\u{2DF9}',
+    '\u{2DFA}',
+    '\u{2DFB}',
+    '\u{2DFC}',
+    '\u{2DFD}',
+    '\u{2DFE}',
+    '\u{2DFF}',
+    '\u{302A}',
+    '\u{302B}',
+    '\u{302C}',
+    '\u{302D}',
+    '\u{302E}',
+    '\u{302F}',
+    '\u{3099}',
+    '\u{309A}',
+    '\u{A66F}',
+    '\u{A670}',
+    '\u{A671}',
+    '\u{A672}',
+    '\u{A674}',
+    '\u{A675}',
+    '\u{A676}',
+    '\u{A677}',
+    '\u{A678}',
+    '\u{A679}',
+    '\u{A67A}',
+    '\u{A67B}',
+    '\u{A67C}',
+    '\u{A67D}',
+    '\u{A69E}',
+    '\u{A69F}',
+    '\u{A6F0}',
+    '\u{A6F1}',
+    '\u{A802}',
+    '\u{A806}',
+    '\u{A80B}',
+    '\u{A823}',
+    '\u{A824}',
+    '\u{A825}',
+    '\u{A826}',
+    '\u{A827}',
+    '\u{A82C}',
+    '\u{A880}',
+    '\u{A881}',
+    '\u{A8B4}',
+    '\u{A8B5}',
+    '\u{A8B6}',
+    '\u{A8B7}',
+    '\u{A8B8}',
+    '\u{A8B9}',
+    '\u{A8BA}',
+    '\u{A8BB}',
+    '\u{A8BC}',
+    '\u{A8BD}',
+    '\u{A8BE}',
+    '\u{A8BF}',
+    '\u{A8C0}',
+    '\u{A8C1}',
+    '\u{A8C2}',
+    '\u{A8C3}',
+    '\u{A8C4}',
+    '\u{A8C5}',
+    '\u{A8E0}',
+    '\u{A8E1}',
+    '\u{A8E2}',
+    '\u{A8E3}',
+    '\u{A8E4}',
+    '\u{A8E5}',
+    '\u{A8E6}',
+    '\u{A8E7}',
+    '\u{A8E8}',
+    '\u{A8E9}',
+    '\u{A8EA}',
+    '\u{A8EB}',
+    '\u{A8EC}',
+    '\u{A8ED}',
+    '\u{A8EE}',
+    '\u{A8EF}',
+    '\u{A8F0}',
+    '\u{A8F1}',
+    '\u{A8FF}',
+    '\u{A926}',
+    '\u{A927}',
+    '\u{A928}',
+    '\u{A929}',
+    '\u{A92A}',
+    '\u{A92B}',
+    '\u{A92C}',
+    '\u{A92D}',
+    '\u{A947}',
+    '\u{A948}',
+    '\u{A949}',
+    '\u{A94A}',
+    '\u{A94B}',
+    '\u{A94C}',
+    '\u{A94D}',
+    '\u{A94E}',
+    '\u{A94F}',
+    '\u{A950}',
+    '\u{A951}',
+    '\u{A952}',
+    '\u{A953}',
+    '\u{A980}',
+    '\u{A981}',
+    '\u{A982}',
+    '\u{A983}',
+    '\u{A9B3}',
+    '\u{A9B4}',
+    '\u{A9B5}',
+    '\u{A9B6}',
+    '\u{A9B7}',
+    '\u{A9B8}',
+    '\u{A9B9}',
+    '\u{A9BA}',
+    '\u{A9BB}',
+    '\u{A9BC}',
+    '\u{A9BD}',
+    '\u{A9BE}',
+    '\u{A9BF}',
+    '\u{A9C0}',
+    '\u{A9E5}',
+    '\u{AA29}',
+    '\u{AA2A}',
+    '\u{AA2B}',
+    '\u{AA2C}',
+    '\u{AA2D}',
+    '\u{AA2E}',
+    '\u{AA2F}',
+    '\u{AA30}',
+    '\u{AA31}',
+    '\u{AA32}',
+    '\u{AA33}',
+    '\u{AA34}',
+    '\u{AA35}',
+    '\u{AA36}',
+    '\u{AA43}',
+    '\u{AA4C}',
+    '\u{AA4D}',
+    '\u{AA7B}',
+    '\u{AA7C}',
+    '\u{AA7D}',
+    '\u{AAB0}',
+    '\u{AAB2}',
+    '\u{AAB3}',
+    '\u{AAB4}',
+    '\u{AAB7}',
+    '\u{AAB8}',
+    '\u{AABE}',
+    '\u{AABF}',
+    '\u{AAC1}',
+    '\u{AAEB}',
+    '\u{AAEC}',
+    '\u{AAED}',
+    '\u{AAEE}',
+    '\u{AAEF}',
+    '\u{AAF5}',
+    '\u{AAF6}',
+    '\u{ABE3}',
+    '\u{ABE4}',
+    '\u{ABE5}',
+    '\u{ABE6}',
+    '\u{ABE7}',
+    '\u{ABE8}',
+    '\u{ABE9}',
+    '\u{ABEA}',
+    '\u{ABEC}',
+    '\u{ABED}',
+    '\u{FB1E}',
+    '\u{FE00}',
+    '\u{FE01}',
+    '\u{FE02}',
+    '\u{FE03}',
+    '\u{FE04}',
+    '\u{FE05}',
+    '\u{FE06}',
+    '\u{FE07}',
+    '\u{FE08}',
+    '\u{FE09}',
+    '\u{FE0A}',
+    '\u{FE0B}',
+    '\u{FE0C}',
+    '\u{FE0D}',
+    '\u{FE0E}',
+    '\u{FE0F}',
+    '\u{FE20}',
+    '\u{FE21}',
+    '\u{FE22}',
+    '\u{FE23}',
+    '\u{FE24}',
+    '\u{FE25}',
+    '\u{FE26}',
+    '\u{FE27}',
+    '\u{FE28}',
+    '\u{FE29}',
+    '\u{FE2A}',
+    '\u{FE2B}',
+    '\u{FE2C}',
+    '\u{FE2D}',
+    '\u{FE2E}',
+    '\u{FE2F}',
+    '\u{101FD}',
+    '\u{102E0}',
+    '\u{10376}',
+    '\u{10377}',
+    '\u{10378}',
+    '\u{10379}',
+    '\u{1037A}',
+    '\u{10A01}',
+    '\u{10A02}',
+    '\u{10A03}',
+    '\u{10A05}',
+    '\u{10A06}',
+    '\u{10A0C}',
+    '\u{10A0D}',
+    '\u{10A0E}',
+    '\u{10A0F}',
+    '\u{10A38}',
+    '\u{10A39}',
+    '\u{10A3A}',
+    '\u{10A3F}',
+    '\u{10AE5}',
+    '\u{10AE6}',
+    '\u{10D24}',
+    '\u{10D25}',
+    '\u{10D26}',
+    '\u{10D27}',
+    '\u{10EAB}',
+    '\u{10EAC}',
+    '\u{10F46}',
+    '\u{10F47}',
+    '\u{10F48}',
+    '\u{10F49}',
+    '\u{10F4A}',
+    '\u{10F4B}',
+    '\u{10F4C}',
+    '\u{10F4D}',
+    '\u{10F4E}',
+    '\u{10F4F}',
+    '\u{10F50}',
+    '\u{10F82}',
+    '\u{10F83}',
+    '\u{10F84}',
+    '\u{10F85}',
+    '\u{11000}',
+    '\u{11001}',
+    '\u{11002}',
+    '\u{11038}',
+    '\u{11039}',
+    '\u{1103A}',
+    '\u{1103B}',
+    '\u{1103C}',
+    '\u{1103D}',
+    '\u{1103E}',
+    '\u{1103F}',
+    '\u{11040}',
+    '\u{11041}',
+    '\u{11042}',
+    '\u{11043}',
+    '\u{11044}',
+    '\u{11045}',
+    '\u{11046}',
+    '\u{11070}',
+    '\u{11073}',
+    '\u{11074}',
+    '\u{1107F}',
+    '\u{11080}',
+    '\u{11081}',
+    '\u{11082}',
+    '\u{110B0}',
+    '\u{110B1}',
+    '\u{110B2}',
+    '\u{110B3}',
+    '\u{110B4}',
+    '\u{110B5}',
+    '\u{110B6}',
+    '\u{110B7}',
+    '\u{110B8}',
+    '\u{110B9}',
+    '\u{110BA}',
+    '\u{110C2}',
+    '\u{11100}',
+    '\u{11101}',
+    '\u{11102}',
+    '\u{11127}',
+    '\u{11128}',
+    '\u{11129}',
+    '\u{1112A}',
+    '\u{1112B}',
+    '\u{1112C}',
+    '\u{1112D}',
+    '\u{1112E}',
+    '\u{1112F}',
+    '\u{11130}',
+    '\u{11131}',
+    '\u{11132}',
+    '\u{11133}',
+    '\u{11134}',
+    '\u{11145}',
+    '\u{11146}',
+    '\u{11173}',
+    '\u{11180}',
+    '\u{11181}',
+    '\u{11182}',
+    '\u{111B3}',
+    '\u{111B4}',
+    '\u{111B5}',
+    '\u{111B6}',
+    '\u{111B7}',
+    '\u{111B8}',
+    '\u{111B9}',
+    '\u{111BA}',
+    '\u{111BB}',
+    '\u{111BC}',
+    '\u{111BD}',
+    '\u{111BE}',
+    '\u{111BF}',
+    '\u{111C0}',
+    '\u{111C9}',
+    '\u{111CA}',
+    '\u{111CB}',
+    '\u{111CC}',
+    '\u{111CE}',
+    '\u{111CF}',
+    '\u{1122C}',
+    '\u{1122D}',
+    '\u{1122E}',
+    '\u{1122F}',
+    '\u{11230}',
+    '\u{11231}',
+    '\u{11232}',
+    '\u{11233}',
+    '\u{11234}',
+    '\u{11235}',
+    '\u{11236}',
+    '\u{11237}',
+    '\u{1123E}',
+    '\u{112DF}',
+    '\u{112E0}',
+    '\u{112E1}',
+    '\u{112E2}',
+    '\u{112E3}',
+    '\u{112E4}',
+    '\u{112E5}',
+    '\u{112E6}',
+    '\u{112E7}',
+    '\u{112E8}',
+    '\u{112E9}',
+    '\u{112EA}',
+    '\u{11300}',
+    '\u{11301}',
+    '\u{11302}',
+    '\u{11303}',
+    '\u{1133B}',
+    '\u{1133C}',
+    '\u{1133E}',
+    '\u{1133F}',
+    '\u{11340}',
+    '\u{11341}',
+    '\u{11342}',
+    '\u{11343}',
+    '\u{11344}',
+    '\u{11347}',
+    '\u{11348}',
+    '\u{1134B}',
+    '\u{1134C}',
+    '\u{1134D}',
+    '\u{11357}',
+    '\u{11362}',
+    '\u{11363}',
+    '\u{11366}',
+    '\u{11367}',
+    '\u{11368}',
+    '\u{11369}',
+    '\u{1136A}',
+    '\u{1136B}',
+    '\u{1136C}',
+    '\u{11370}',
+    '\u{11371}',
+    '\u{11372}',
+    '\u{11373}',
+    '\u{11374}',
+    '\u{11435}',
+    '\u{11436}',
+    '\u{11437}',
+    '\u{11438}',
+    '\u{11439}',
+    '\u{1143A}',
+    '\u{1143B}',
+    '\u{1143C}',
+    '\u{1143D}',
+    '\u{1143E}',
+    '\u{1143F}',
+    '\u{11440}',
+    '\u{11441}',
+    '\u{11442}',
+    '\u{11443}',
+    '\u{11444}',
+    '\u{11445}',
+    '\u{11446}',
+    '\u{1145E}',
+    '\u{114B0}',
+    '\u{114B1}',
+    '\u{114B2}',
+    '\u{114B3}',
+    '\u{114B4}',
+    '\u{114B5}',
+    '\u{114B6}',
+    '\u{114B7}',
+    '\u{114B8}',
+    '\u{114B9}',
+    '\u{114BA}',
+    '\u{114BB}',
+    '\u{114BC}',
+    '\u{114BD}',
+    '\u{114BE}',
+    '\u{114BF}',
+    '\u{114C0}',
+    '\u{114C1}',
+    '\u{114C2}',
+    '\u{114C3}',
+    '\u{115AF}',
+    '\u{115B0}',
+    '\u{115B1}',
+    '\u{115B2}',
+    '\u{115B3}',
+    '\u{115B4}',
+    '\u{115B5}',
+    '\u{115B8}',
+    '\u{115B9}',
+    '\u{115BA}',
+    '\u{115BB}',
+    '\u{115BC}',
+    '\u{115BD}',
+    '\u{115BE}',
+    '\u{115BF}',
+    '\u{115C0}',
+    '\u{115DC}',
+    '\u{115DD}',
+    '\u{11630}',
+    '\u{11631}',
+    '\u{11632}',
+    '\u{11633}',
+    '\u{11634}',
+    '\u{11635}',
+    '\u{11636}',
+    '\u{11637}',
+    '\u{11638}',
+    '\u{11639}',
+    '\u{1163A}',
+    '\u{1163B}',
+    '\u{1163C}',
+    '\u{1163D}',
+    '\u{1163E}',
+    '\u{1163F}',
+    '\u{11640}',
+    '\u{116AB}',
+    '\u{116AC}',
+    '\u{116AD}',
+    '\u{116AE}',
+    '\u{116AF}',
+    '\u{116B0}',
+    '\u{116B1}',
+    '\u{116B2}',
+    '\u{116B3}',
+    '\u{116B4}',
+    '\u{116B5}',
+    '\u{116B6}',
+    '\u{116B7}',
+    '\u{1171D}',
+    '\u{1171E}',
+    '\u{1171F}',
+    '\u{11720}',
+    '\u{11721}',
+    '\u{11722}',
+    '\u{11723}',
+    '\u{11724}',
+    '\u{11725}',
+    '\u{11726}',
+    '\u{11727}',
+    '\u{11728}',
+    '\u{11729}',
+    '\u{1172A}',
+    '\u{1172B}',
+    '\u{1182C}',
+    '\u{1182D}',
+    '\u{1182E}',
+    '\u{1182F}',
+    '\u{11830}',
+    '\u{11831}',
+    '\u{11832}',
+    '\u{11833}',
+    '\u{11834}',
+    '\u{11835}',
+    '\u{11836}',
+    '\u{11837}',
+    '\u{11838}',
+    '\u{11839}',
+    '\u{1183A}',
+    '\u{11930}',
+    '\u{11931}',
+    '\u{11932}',
+    '\u{11933}',
+    '\u{11934}',
+    '\u{11935}',
+    '\u{11937}',
+    '\u{11938}',
+    '\u{1193B}',
+    '\u{1193C}',
+    '\u{1193D}',
+    '\u{1193E}',
+    '\u{11940}',
+    '\u{11942}',
+    '\u{11943}',
+    '\u{119D1}',
+    '\u{119D2}',
+    '\u{119D3}',
+    '\u{119D4}',
+    '\u{119D5}',
+    '\u{119D6}',
+    '\u{119D7}',
+    '\u{119DA}',
+    '\u{119DB}',
+    '\u{119DC}',
+    '\u{119DD}',
+    '\u{119DE}',
+    '\u{119DF}',
+    '\u{119E0}',
+    '\u{119E4}',
+    '\u{11A01}',
+    '\u{11A02}',
+    '\u{11A03}',
+    '\u{11A04}',
+    '\u{11A05}',
+    '\u{11A06}',
+    '\u{11A07}',
+    '\u{11A08}',
+    '\u{11A09}',
+    '\u{11A0A}',
+    '\u{11A33}',
+    '\u{11A34}',
+    '\u{11A35}',
+    '\u{11A36}',
+    '\u{11A37}',
+    '\u{11A38}',
+    '\u{11A39}',
+    '\u{11A3B}',
+    '\u{11A3C}',
+    '\u{11A3D}',
+    '\u{11A3E}',
+    '\u{11A47}',
+    '\u{11A51}',
+    '\u{11A52}',
+    '\u{11A53}',
+    '\u{11A54}',
+    '\u{11A55}',
+    '\u{11A56}',
+    '\u{11A57}',
+    '\u{11A58}',
+    '\u{11A59}',
+    '\u{11A5A}',
+    '\u{11A5B}',
+    '\u{11A8A}',
+    '\u{11A8B}',
+    '\u{11A8C}',
+    '\u{11A8D}',
+    '\u{11A8E}',
+    '\u{11A8F}',
+    '\u{11A90}',
+    '\u{11A91}',
+    '\u{11A92}',
+    '\u{11A93}',
+    '\u{11A94}',
+    '\u{11A95}',
+    '\u{11A96}',
+    '\u{11A97}',
+    '\u{11A98}',
+    '\u{11A99}',
+    '\u{11C2F}',
+    '\u{11C30}',
+    '\u{11C31}',
+    '\u{11C32}',
+    '\u{11C33}',
+    '\u{11C34}',
+    '\u{11C35}',
+    '\u{11C36}',
+    '\u{11C38}',
+    '\u{11C39}',
+    '\u{11C3A}',
+    '\u{11C3B}',
+    '\u{11C3C}',
+    '\u{11C3D}',
+    '\u{11C3E}',
+    '\u{11C3F}',
+    '\u{11C92}',
+    '\u{11C93}',
+    '\u{11C94}',
+    '\u{11C95}',
+    '\u{11C96}',
+    '\u{11C97}',
+    '\u{11C98}',
+    '\u{11C99}',
+    '\u{11C9A}',
+    '\u{11C9B}',
+    '\u{11C9C}',
+    '\u{11C9D}',
+    '\u{11C9E}',
+    '\u{11C9F}',
+    '\u{11CA0}',
+    '\u{11CA1}',
+    '\u{11CA2}',
+    '\u{11CA3}',
+    '\u{11CA4}',
+    '\u{11CA5}',
+    '\u{11CA6}',
+    '\u{11CA7}',
+    '\u{11CA9}',
+    '\u{11CAA}',
+    '\u{11CAB}',
+    '\u{11CAC}',
+    '\u{11CAD}',
+    '\u{11CAE}',
+    '\u{11CAF}',
+    '\u{11CB0}',
+    '\u{11CB1}',
+    '\u{11CB2}',
+    '\u{11CB3}',
+    '\u{11CB4}',
+    '\u{11CB5}',
+    '\u{11CB6}',
+    '\u{11D31}',
+    '\u{11D32}',
+    '\u{11D33}',
+    '\u{11D34}',
+    '\u{11D35}',
+    '\u{11D36}',
+    '\u{11D3A}',
+    '\u{11D3C}',
+    '\u{11D3D}',
+    '\u{11D3F}',
+    '\u{11D40}',
+    '\u{11D41}',
+    '\u{11D42}',
+    '\u{11D43}',
+    '\u{11D44}',
+    '\u{11D45}',
+    '\u{11D47}',
+    '\u{11D8A}',
+    '\u{11D8B}',
+    '\u{11D8C}',
+    '\u{11D8D}',
+    '\u{11D8E}',
+    '\u{11D90}',
+    '\u{11D91}',
+    '\u{11D93}',
+    '\u{11D94}',
+    '\u{11D95}',
+    '\u{11D96}',
+    '\u{11D97}',
+    '\u{11EF3}',
+    '\u{11EF4}',
+    '\u{11EF5}',
+    '\u{11EF6}',
+    '\u{16AF0}',
+    '\u{16AF1}',
+    '\u{16AF2}',
+    '\u{16AF3}',
+    '\u{16AF4}',
+    '\u{16B30}',
+    '\u{16B31}',
+    '\u{16B32}',
+    '\u{16B33}',
+    '\u{16B34}',
+    '\u{16B35}',
+    '\u{16B36}',
+    '\u{16F4F}',
+    '\u{16F51}',
+    '\u{16F52}',
+    '\u{16F53}',
+    '\u{16F54}',
+    '\u{16F55}',
+    '\u{16F56}',
+    '\u{16F57}',
+    '\u{16F58}',
+    '\u{16F59}',
+    '\u{16F5A}',
+    '\u{16F5B}',
+    '\u{16F5C}',
+    '\u{16F5D}',
+    '\u{16F5E}',
+    '\u{16F5F}',
+    '\u{16F60}',
+    '\u{16F61}',
+    '\u{16F62}',
+    '\u{16F63}',
+    '\u{16F64}',
+    '\u{16F65}',
+    '\u{16F66}',
+    '\u{16F67}',
+    '\u{16F68}',
+    '\u{16F69}',
+    '\u{16F6A}',
+    '\u{16F6B}',
+    '\u{16F6C}',
+    '\u{16F6D}',
+    '\u{16F6E}',
+    '\u{16F6F}',
+    '\u{16F70}',
+    '\u{16F71}',
+    '\u{16F72}',
+    '\u{16F73}',
+    '\u{16F74}',
+    '\u{16F75}',
+    '\u{16F76}',
+    '\u{16F77}',
+    '\u{16F78}',
+    '\u{16F79}',
+    '\u{16F7A}',
+    '\u{16F7B}',
+    '\u{16F7C}',
+    '\u{16F7D}',
+    '\u{16F7E}',
+    '\u{16F7F}',
+    '\u{16F80}',
+    '\u{16F81}',
+    '\u{16F82}',
+    '\u{16F83}',
+    '\u{16F84}',
+    '\u{16F85}',
+    '\u{16F86}',
+    '\u{16F87}',
+    '\u{16F8F}',
+    '\u{16F90}',
+    '\u{16F91}',
+    '\u{16F92}',
+    '\u{16FE4}',
+    '\u{16FF0}',
+    '\u{16FF1}',
+    '\u{1BC9D}',
+    '\u{1BC9E}',
+    '\u{1CF00}',
+    '\u{1CF01}',
+    '\u{1CF02}',
+    '\u{1CF03}',
+    '\u{1CF04}',
+    '\u{1CF05}',
+    '\u{1CF06}',
+    '\u{1CF07}',
+    '\u{1CF08}',
+    '\u{1CF09}',
+    '\u{1CF0A}',
+    '\u{1CF0B}',
+    '\u{1CF0C}',
+    '\u{1CF0D}',
+    '\u{1CF0E}',
+    '\u{1CF0F}',
+    '\u{1CF10}',
+    '\u{1CF11}',
+    '\u{1CF12}',
+    '\u{1CF13}',
+    '\u{1CF14}',
+    '\u{1CF15}',
+    '\u{1CF16}',
+    '\u{1CF17}',
+    '\u{1CF18}',
+    '\u{1CF19}',
+    '\u{1CF1A}',
+    '\u{1CF1B}',
+    '\u{1CF1C}',
+    '\u{1CF1D}',
+    '\u{1CF1E}',
+    '\u{1CF1F}',
+    '\u{1CF20}',
+    '\u{1CF21}',
+    '\u{1CF22}',
+    '\u{1CF23}',
+    '\u{1CF24}',
+    '\u{1CF25}',
+    '\u{1CF26}',
+    '\u{1CF27}',
+    '\u{1CF28}',
+    '\u{1CF29}',
+    '\u{1CF2A}',
+    '\u{1CF2B}',
+    '\u{1CF2C}',
+    '\u{1CF2D}',
+    '\u{1CF30}',
+    '\u{1CF31}',
+    '\u{1CF32}',
+    '\u{1CF33}',
+    '\u{1CF34}',
+    '\u{1CF35}',
+    '\u{1CF36}',
+    '\u{1CF37}',
+    '\u{1CF38}',
+    '\u{1CF39}',
+    '\u{1CF3A}',
+    '\u{1CF3B}',
+    '\u{1CF3C}',
+    '\u{1CF3D}',
+    '\u{1CF3E}',
+    '\u{1CF3F}',
+    '\u{1CF40}',
+    '\u{1CF41}',
+    '\u{1CF42}',
+    '\u{1CF43}',
+    '\u{1CF44}',
+    '\u{1CF45}',
+    '\u{1CF46}',
+    '\u{1D165}',
+    '\u{1D166}',
+    '\u{1D167}',
+    '\u{1D168}',
+    '\u{1D169}',
+    '\u{1D16D}',
+    '\u{1D16E}',
+    '\u{1D16F}',
+    '\u{1D170}',
+    '\u{1D171}',
+    '\u{1D172}',
+    '\u{1D17B}',
+    '\u{1D17C}',
+    '\u{1D17D}',
+    '\u{1D17E}',
+    '\u{1D17F}',
+    '\u{1D180}',
+    '\u{1D181}',
+    '\u{1D182}',
+    '\u{1D185}',
+    '\u{1D186}',
+    '\u{1D187}',
+    '\u{1D188}',
+    '\u{1D189}',
+    '\u{1D18A}',
+    '\u{1D18B}',
+    '\u{1D1AA}',
+    '\u{1D1AB}',
+    '\u{1D1AC}',
+    '\u{1D1AD}',
+    '\u{1D242}',
+    '\u{1D243}',
+    '\u{1D244}',
+    '\u{1DA00}',
+    '\u{1DA01}',
+    '\u{1DA02}',
+    '\u{1DA03}',
+    '\u{1DA04}',
+    '\u{1DA05}',
+    '\u{1DA06}',
+    '\u{1DA07}',
+    '\u{1DA08}',
+    '\u{1DA09}',
+    '\u{1DA0A}',
+    '\u{1DA0B}',
+    '\u{1DA0C}',
+    '\u{1DA0D}',
+    '\u{1DA0E}',
+    '\u{1DA0F}',
+    '\u{1DA10}',
+    '\u{1DA11}',
+    '\u{1DA12}',
+    '\u{1DA13}',
+    '\u{1DA14}',
+    '\u{1DA15}',
+    '\u{1DA16}',
+    '\u{1DA17}',
+    '\u{1DA18}',
+    '\u{1DA19}',
+    '\u{1DA1A}',
+    '\u{1DA1B}',
+    '\u{1DA1C}',
+    '\u{1DA1D}',
+    '\u{1DA1E}',
+    '\u{1DA1F}',
+    '\u{1DA20}',
+    '\u{1DA21}',
+    '\u{1DA22}',
+    '\u{1DA23}',
+    '\u{1DA24}',
+    '\u{1DA25}',
+    '\u{1DA26}',
+    '\u{1DA27}',
+    '\u{1DA28}',
+    '\u{1DA29}',
+    '\u{1DA2A}',
+    '\u{1DA2B}',
+    '\u{1DA2C}',
+    '\u{1DA2D}',
+    '\u{1DA2E}',
+    '\u{1DA2F}',
+    '\u{1DA30}',
+    '\u{1DA31}',
+    '\u{1DA32}',
+    '\u{1DA33}',
+    '\u{1DA34}',
+    '\u{1DA35}',
+    '\u{1DA36}',
+    '\u{1DA3B}',
+    '\u{1DA3C}',
+    '\u{1DA3D}',
+    '\u{1DA3E}',
+    '\u{1DA3F}',
+    '\u{1DA40}',
+    '\u{1DA41}',
+    '\u{1DA42}',
+    '\u{1DA43}',
+    '\u{1DA44}',
+    '\u{1DA45}',
+    '\u{1DA46}',
+    '\u{1DA47}',
+    '\u{1DA48}',
+    '\u{1DA49}',
+    '\u{1DA4A}',
+    '\u{1DA4B}',
+    '\u{1DA4C}',
+    '\u{1DA4D}',
+    '\u{1DA4E}',
+    '\u{1DA4F}',
+    '\u{1DA50}',
+    '\u{1DA51}',
+    '\u{1DA52}',
+    '\u{1DA53}',
+    '\u{1DA54}',
+    '\u{1DA55}',
+    '\u{1DA56}',
+    '\u{1DA57}',
+    '\u{1DA58}',
+    '\u{1DA59}',
+    '\u{1DA5A}',
+    '\u{1DA5B}',
+    '\u{1DA5C}',
+    '\u{1DA5D}',
+    '\u{1DA5E}',
+    '\u{1DA5F}',
+    '\u{1DA60}',
+    '\u{1DA61}',
+    '\u{1DA62}',
+    '\u{1DA63}',
+    '\u{1DA64}',
+    '\u{1DA65}',
+    '\u{1DA66}',
+    '\u{1DA67}',
+    '\u{1DA68}',
+    '\u{1DA69}',
+    '\u{1DA6A}',
+    '\u{1DA6B}',
+    '\u{1DA6C}',
+    '\u{1DA75}',
+    '\u{1DA84}',
+    '\u{1DA9B}',
+    '\u{1DA9C}',
+    '\u{1DA9D}',
+    '\u{1DA9E}',
+    '\u{1DA9F}',
+    '\u{1DAA1}',
+    '\u{1DAA2}',
+    '\u{1DAA3}',
+    '\u{1DAA4}',
+    '\u{1DAA5}',
+    '\u{1DAA6}',
+    '\u{1DAA7}',
+    '\u{1DAA8}',
+    '\u{1DAA9}',
+    '\u{1DAAA}',
+    '\u{1DAAB}',
+    '\u{1DAAC}',
+    '\u{1DAAD}',
+    '\u{1DAAE}',
+    '\u{1DAAF}',
+    '\u{1E000}',
+    '\u{1E001}',
+    '\u{1E002}',
+    '\u{1E003}',
+    '\u{1E004}',
+    '\u{1E005}',
+    '\u{1E006}',
+    '\u{1E008}',
+    '\u{1E009}',
+    '\u{1E00A}',
+    '\u{1E00B}',
+    '\u{1E00C}',
+    '\u{1E00D}',
+    '\u{1E00E}',
+    '\u{1E00F}',
+    '\u{1E010}',
+    '\u{1E011}',
+    '\u{1E012}',
+    '\u{1E013}',
+    '\u{1E014}',
+    '\u{1E015}',
+    '\u{1E016}',
+    '\u{1E017}',
+    '\u{1E018}',
+    '\u{1E01B}',
+    '\u{1E01C}',
+    '\u{1E01D}',
+    '\u{1E01E}',
+    '\u{1E01F}',
+    '\u{1E020}',
+    '\u{1E021}',
+    '\u{1E023}',
+    '\u{1E024}',
+    '\u{1E026}',
+    '\u{1E027}',
+    '\u{1E028}',
+    '\u{1E029}',
+    '\u{1E02A}',
+    '\u{1E130}',
+    '\u{1E131}',
+    '\u{1E132}',
+    '\u{1E133}',
+    '\u{1E134}',
+    '\u{1E135}',
+    '\u{1E136}',
+    '\u{1E2AE}',
+    '\u{1E2EC}',
+    '\u{1E2ED}',
+    '\u{1E2EE}',
+    '\u{1E2EF}',
+    '\u{1E8D0}',
+    '\u{1E8D1}',
+    '\u{1E8D2}',
+    '\u{1E8D3}',
+    '\u{1E8D4}',
+    '\u{1E8D5}',
+    '\u{1E8D6}',
+    '\u{1E944}',
+    '\u{1E945}',
+    '\u{1E946}',
+    '\u{1E947}',
+    '\u{1E948}',
+    '\u{1E949}',
+    '\u{1E94A}',
+    '\u{E0100}',
+    '\u{E0101}',
+    '\u{E0102}',
+    '\u{E0103}',
+    '\u{E0104}',
+    '\u{E0105}',
+    '\u{E0106}',
+    '\u{E0107}',
+    '\u{E0108}',
+    '\u{E0109}',
+    '\u{E010A}',
+    '\u{E010B}',
+    '\u{E010C}',
+    '\u{E010D}',
+    '\u{E010E}',
+    '\u{E010F}',
+    '\u{E0110}',
+    '\u{E0111}',
+    '\u{E0112}',
+    '\u{E0113}',
+    '\u{E0114}',
+    '\u{E0115}',
+    '\u{E0116}',
+    '\u{E0117}',
+    '\u{E0118}',
+    '\u{E0119}',
+    '\u{E011A}',
+    '\u{E011B}',
+    '\u{E011C}',
+    '\u{E011D}',
+    '\u{E011E}',
+    '\u{E011F}',
+    '\u{E0120}',
+    '\u{E0121}',
+    '\u{E0122}',
+    '\u{E0123}',
+    '\u{E0124}',
+    '\u{E0125}',
+    '\u{E0126}',
+    '\u{E0127}',
+    '\u{E0128}',
+    '\u{E0129}',
+    '\u{E012A}',
+    '\u{E012B}',
+    '\u{E012C}',
+    '\u{E012D}',
+    '\u{E012E}',
+    '\u{E012F}',
+    '\u{E0130}',
+    '\u{E0131}',
+    '\u{E0132}',
+    '\u{E0133}',
+    '\u{E0134}',
+    '\u{E0135}',
+    '\u{E0136}',
+    '\u{E0137}',
+    '\u{E0138}',
+    '\u{E0139}',
+    '\u{E013A}',
+    '\u{E013B}',
+    '\u{E013C}',
+    '\u{E013D}',
+    '\u{E013E}',
+    '\u{E013F}',
+    '\u{E0140}',
+    '\u{E0141}',
+    '\u{E0142}',
+    '\u{E0143}',
+    '\u{E0144}',
+    '\u{E0145}',
+    '\u{E0146}',
+    '\u{E0147}',
+    '\u{E0148}',
+    '\u{E0149}',
+    '\u{E014A}',
+    '\u{E014B}',
+    '\u{E014C}',
+    '\u{E014D}',
+    '\u{E014E}',
+    '\u{E014F}',
+    '\u{E0150}',
+    '\u{E0151}',
+    '\u{E0152}',
+    '\u{E0153}',
+    '\u{E0154}',
+    '\u{E0155}',
+    '\u{E0156}',
+    '\u{E0157}',
+    '\u{E0158}',
+    '\u{E0159}',
+    '\u{E015A}',
+    '\u{E015B}',
+    '\u{E015C}',
+    '\u{E015D}',
+    '\u{E015E}',
+    '\u{E015F}',
+    '\u{E0160}',
+    '\u{E0161}',
+    '\u{E0162}',
+    '\u{E0163}',
+    '\u{E0164}',
+    '\u{E0165}',
+    '\u{E0166}',
+    '\u{E0167}',
+    '\u{E0168}',
+    '\u{E0169}',
+    '\u{E016A}',
+    '\u{E016B}',
+    '\u{E016C}',
+    '\u{E016D}',
+    '\u{E016E}',
+    '\u{E016F}',
+    '\u{E0170}',
+    '\u{E0171}',
+    '\u{E0172}',
+    '\u{E0173}',
+    '\u{E0174}',
+    '\u{E0175}',
+    '\u{E0176}',
+    '\u{E0177}',
+    '\u{E0178}',
+    '\u{E0179}',
+    '\u{E017A}',
+    '\u{E017B}',
+    '\u{E017C}',
+    '\u{E017D}',
+    '\u{E017E}',
+    '\u{E017F}',
+    '\u{E0180}',
+    '\u{E0181}',
+    '\u{E0182}',
+    '\u{E0183}',
+    '\u{E0184}',
+    '\u{E0185}',
+    '\u{E0186}',
+    '\u{E0187}',
+    '\u{E0188}',
+    '\u{E0189}',
+    '\u{E018A}',
+    '\u{E018B}',
+    '\u{E018C}',
+    '\u{E018D}',
+    '\u{E018E}',
+    '\u{E018F}',
+    '\u{E0190}',
+    '\u{E0191}',
+    '\u{E0192}',
+    '\u{E0193}',
+    '\u{E0194}',
+    '\u{E0195}',
+    '\u{E0196}',
+    '\u{E0197}',
+    '\u{E0198}',
+    '\u{E0199}',
+    '\u{E019A}',
+    '\u{E019B}',
+    '\u{E019C}',
+    '\u{E019D}',
+    '\u{E019E}',
+    '\u{E019F}',
+    '\u{E01A0}',
+    '\u{E01A1}',
+    '\u{E01A2}',
+    '\u{E01A3}',
+    '\u{E01A4}',
+    '\u{E01A5}',
+    '\u{E01A6}',
+    '\u{E01A7}',
+    '\u{E01A8}',
+    '\u{E01A9}',
+    '\u{E01AA}',
+    '\u{E01AB}',
+    '\u{E01AC}',
+    '\u{E01AD}',
+    '\u{E01AE}',
+    '\u{E01AF}',
+    '\u{E01B0}',
+    '\u{E01B1}',
+    '\u{E01B2}',
+    '\u{E01B3}',
+    '\u{E01B4}',
+    '\u{E01B5}',
+    '\u{E01B6}',
+    '\u{E01B7}',
+    '\u{E01B8}',
+    '\u{E01B9}',
+    '\u{E01BA}',
+    '\u{E01BB}',
+    '\u{E01BC}',
+    '\u{E01BD}',
+    '\u{E01BE}',
+    '\u{E01BF}',
+    '\u{E01C0}',
+    '\u{E01C1}',
+    '\u{E01C2}',
+    '\u{E01C3}',
+    '\u{E01C4}',
+    '\u{E01C5}',
+    '\u{E01C6}',
+    '\u{E01C7}',
+    '\u{E01C8}',
+    '\u{E01C9}',
+    '\u{E01CA}',
+    '\u{E01CB}',
+    '\u{E01CC}',
+    '\u{E01CD}',
+    '\u{E01CE}',
+    '\u{E01CF}',
+    '\u{E01D0}',
+    '\u{E01D1}',
+    '\u{E01D2}',
+    '\u{E01D3}',
+    '\u{E01D4}',
+    '\u{E01D5}',
+    '\u{E01D6}',
+    '\u{E01D7}',
+    '\u{E01D8}',
+    '\u{E01D9}',
+    '\u{E01DA}',
+    '\u{E01DB}',
+    '\u{E01DC}',
+    '\u{E01DD}',
+    '\u{E01DE}',
+    '\u{E01DF}',
+    '\u{E01E0}',
+    '\u{E01E1}',
+    '\u{E01E2}',
+    '\u{E01E3}',
+    '\u{E01E4}',
+    '\u{E01E5}',
+    '\u{E01E6}',
+    '\u{E01E7}',
+    '\u{E01E8}',
+    '\u{E01E9}',
+    '\u{E01EA}',
+    '\u{E01EB}',
+    '\u{E01EC}',
+    '\u{E01ED}',
+    '\u{E01EE}',
+    '\u{E01EF}',
+];
@@ -0,0 +1,176 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn abbreviation() -> Result<(), String> {
+    let abbr = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                abbreviation: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("*[HTML]: HyperText Markup Language\n\nHTML"),
+        "<p>*[HTML]: HyperText Markup Language</p>\n<p>HTML</p>",
+        "should not support abbreviations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: HyperText Markup Language\n\na HTML b", &abbr)?,
+        "<p>a <abbr title=\"HyperText Markup Language\">HTML</abbr> b</p>",
+        "should support abbreviations"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: HyperText Markup Language\n\nHTML, and HTML.\n\n# HTML",
+            &abbr
+        )?,
+        "<p><abbr title=\"HyperText Markup Language\">HTML</abbr>, and <abbr title=\"HyperText Markup Language\">HTML</abbr>.</p>\n<h1><abbr title=\"HyperText Markup Language\">HTML</abbr></h1>",
+        "should support an abbreviation used multiple times"
+    );
+
+    assert_eq!(
+        to_html_with_options("HTML\n\n*[HTML]: HyperText Markup Language", &abbr)?,
+        "<p><abbr title=\"HyperText Markup Language\">HTML</abbr></p>\n",
+        "should support abbreviations before their definition"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\nHTMLs XHTML HTML5 éHTML", &abbr)?,
+        "<p>HTMLs XHTML HTML5 éHTML</p>",
+        "should not match in the middle of a word"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\nhtml Html", &abbr)?,
+        "<p>html Html</p>",
+        "should match case-sensitive"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\n(HTML) *HTML* _HTML_", &abbr)?,
+        "<p>(<abbr title=\"a\">HTML</abbr>) <em><abbr title=\"a\">HTML</abbr></em> <em><abbr title=\"a\">HTML</abbr></em></p>",
+        "should match next to punctuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[W3C]: a\n*[W3C HTML]: b\n\nW3C HTML, W3C", &abbr)?,
+        "<p><abbr title=\"b\">W3C HTML</abbr>, <abbr title=\"a\">W3C</abbr></p>",
+        "should prefer the longest match"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[C++]: a\n\nC++ C++x", &abbr)?,
+        "<p><abbr title=\"a\">C++</abbr> C++x</p>",
+        "should support labels with punctuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a]: b\n*[a]: c\n\na", &abbr)?,
+        "<p><abbr title=\"b\">a</abbr></p>",
+        "should use the first definition"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a]:\n\na", &abbr)?,
+        "<p><abbr>a</abbr></p>",
+        "should support a definition w/o title"
+    );
+
+    assert_eq!(
+        to_html_with_options("  *[a]:\t b \"c\" &amp; \\d  \n\na", &abbr)?,
+        "<p><abbr title=\"b &quot;c&quot; &amp;amp; \\d\">a</abbr></p>",
+        "should support whitespace, and not interpret titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("    *[a]: b", &abbr)?,
+        "<pre><code>*[a]: b\n</code></pre>",
+        "should not support a definition indented with 4 spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[]: a\n*[ b]: c\n*[d ]: e\n*[f] g\n*[h]", &abbr)?,
+        "<p>*[]: a\n*[ b]: c\n*[d ]: e\n*[f] g\n*[h]</p>",
+        "should not support empty labels, whitespace around labels, or a missing `:`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n*[a]: b", &abbr)?,
+        "<p>a\n*[a]: b</p>",
+        "should not support a definition interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("> *[a]: b\n\na", &abbr)?,
+        "<blockquote>\n</blockquote>\n<p><abbr title=\"b\">a</abbr></p>",
+        "should support definitions in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[a]: b\n\n`a` [a](a \"a\") ![a](c) <https://a.com> <span title=\"a\">",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        abbreviation: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..CompileOptions::default()
+                }
+            }
+        )?,
+        "<p><code>a</code> <a href=\"a\" title=\"a\"><abbr title=\"b\">a</abbr></a> <img src=\"c\" alt=\"a\" /> <a href=\"https://a.com\">https://a.com</a> <span title=\"a\"></p>",
+        "should not match in code, destinations, titles, alts, autolinks, or HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[www]: a\n\nwww.example.com www",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        abbreviation: true,
+                        ..Constructs::gfm()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"http://www.example.com\">www.example.com</a> <abbr title=\"a\">www</abbr></p>",
+        "should not match in GFM autolink literals"
+    );
+
+    assert_eq!(
+        to_mdast("*[a]: b\n\nc a d", &abbr.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "c a d".into(),
+                    position: Some(Position::new(3, 1, 9, 3, 6, 14))
+                })],
+                position: Some(Position::new(3, 1, 9, 3, 6, 14))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 6, 14))
+        }),
+        "should support abbreviations as text in mdast"
+    );
+
+    Ok(())
+}
@@ -36,6 +36,24 @@ fn attention() -> Result<(), String> {
         "should not support emphasis if the opening is not left flanking (2b)"
     );
 
+    assert_eq!(
+        to_html("a*$foo$*"),
+        "<p>a*$foo$*</p>",
+        "should not support emphasis if the opening is not left flanking (2c, ASCII symbols are punctuation)"
+    );
+
+    assert_eq!(
+        to_html("a**+foo+**b"),
+        "<p>a**+foo+**b</p>",
+        "should not support strong if the opening is not left flanking (ASCII symbols are punctuation)"
+    );
+
+    assert_eq!(
+        to_html("*(foo)*$bar"),
+        "<p><em>(foo)</em>$bar</p>",
+        "should support emphasis if the closing is followed by an ASCII symbol"
+    );
+
     assert_eq!(
         to_html("* a *"),
         "<p>* a *</p>",
@@ -807,6 +825,42 @@ fn attention() -> Result<(), String> {
         "should not end emphasis inside code (2)"
     );
 
+    assert_eq!(
+        to_html("*foo`*`"),
+        "<p>*foo<code>*</code></p>",
+        "should not end emphasis inside code (3)"
+    );
+
+    assert_eq!(
+        to_html("`*`foo*"),
+        "<p><code>*</code>foo*</p>",
+        "should not start emphasis inside code"
+    );
+
+    assert_eq!(
+        to_html("*a`b*c`"),
+        "<p>*a<code>b*c</code></p>",
+        "should prefer code over emphasis when they overlap (1)"
+    );
+
+    assert_eq!(
+        to_html("**a`b**c`"),
+        "<p>**a<code>b**c</code></p>",
+        "should prefer code over emphasis when they overlap (2)"
+    );
+
+    assert_eq!(
+        to_html("*a`code`b*"),
+        "<p><em>a<code>code</code>b</em></p>",
+        "should support code inside emphasis"
+    );
+
+    assert_eq!(
+        to_html("*a `b*` c*"),
+        "<p><em>a <code>b*</code> c</em></p>",
+        "should pair emphasis around code that includes a marker"
+    );
+
     assert_eq!(
         to_html("**a<http://foo.bar/?q=**>"),
         "<p>**a<a href=\"http://foo.bar/?q=**\">http://foo.bar/?q=**</a></p>",
@@ -876,5 +930,278 @@ fn attention() -> Result<(), String> {
         "should support attention as `Emphasis`, `Strong`s in mdast"
     );
 
+    assert_eq!(
+        to_html("&*a*&amp;"),
+        "<p>&amp;<em>a</em>&amp;</p>",
+        "should support character references next to attention"
+    );
+
+    assert_eq!(
+        to_html("a*&#65;b*"),
+        "<p>a*Ab*</p>",
+        "should see a character reference after a sequence as `&` (punctuation), not what it decodes to"
+    );
+
+    assert_eq!(
+        to_html("a*&#65;b* a*Ab*"),
+        "<p>a*Ab* a<em>Ab</em></p>",
+        "should see a character reference after a sequence as `&` (punctuation), not what it decodes to (2)"
+    );
+
+    assert_eq!(
+        to_html("**&nbsp;a&nbsp;**"),
+        "<p><strong>\u{a0}a\u{a0}</strong></p>",
+        "should see a character reference before a sequence as `;` (punctuation), not what it decodes to"
+    );
+
+    assert_eq!(
+        to_html("a_&amp;_b"),
+        "<p>a_&amp;_b</p>",
+        "should see character references as punctuation around underscores"
+    );
+
+    assert_eq!(
+        to_html("_a&#95;b_"),
+        "<p><em>a_b</em></p>",
+        "should not see a character reference for a marker as a marker"
+    );
+
+    assert_eq!(
+        to_html("*\u{200B}a* *a\u{200B}*"),
+        "<p><em>\u{200B}a</em> <em>a\u{200B}</em></p>",
+        "should see a zero-width space next to a sequence as other, not whitespace"
+    );
+
+    assert_eq!(
+        to_html("a\u{200B}_b_\u{200B}c"),
+        "<p>a\u{200B}_b_\u{200B}c</p>",
+        "should see a zero-width space around underscores as other (intraword)"
+    );
+
+    assert_eq!(
+        to_html("a\u{AD}*b*\u{AD}c"),
+        "<p>a\u{AD}<em>b</em>\u{AD}c</p>",
+        "should see a soft hyphen next to a sequence as other"
+    );
+
+    assert_eq!(
+        to_html("a\u{200D}*b*\u{200C}c"),
+        "<p>a\u{200D}<em>b</em>\u{200C}c</p>",
+        "should see zero-width (non-)joiners next to a sequence as other"
+    );
+
+    assert_eq!(
+        to_html("**\u{200B}**"),
+        "<p><strong>\u{200B}</strong></p>",
+        "should support attention around just a zero-width space"
+    );
+
+    let max = Options {
+        parse: ParseOptions {
+            max_attention_sequences: Some(4),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("*a* _b_ *c* _d_", &max)?,
+        "<p><em>a</em> <em>b</em> *c* _d_</p>",
+        "should support `max_attention_sequences`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a*
+
+*b* *c*",
+            &max
+        )?,
+        "<p><em>a</em></p>
+<p><em>b</em> <em>c</em></p>",
+        "should count `max_attention_sequences` per text"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a **b** c*", &max)?,
+        "<p><em>a <strong>b</strong> c</em></p>",
+        "should support nested attention in `max_attention_sequences`"
+    );
+
+    assert_eq!(
+        to_html_with_options(&("a ".to_string() + &"*".repeat(100_000)), &max)?,
+        "<p>a ".to_string() + &"*".repeat(100_000) + "</p>",
+        "should support a very long run of asterisks w/ `max_attention_sequences`"
+    );
+
+    assert!(
+        to_html_with_options(
+            &"a**b ".repeat(20_000),
+            &Options {
+                parse: ParseOptions {
+                    max_attention_sequences: Some(100),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )?
+        .starts_with("<p>a<strong>b a</strong>b a<strong>b"),
+        "should support many attention sequences w/ `max_attention_sequences`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn attention_long_line() {
+    assert!(
+        to_html(&"a *b* c* **d [e *f] g* h](i) _j ".repeat(5_000)).starts_with(
+            "<p>a <em>b</em> c* *<em>d [e <em>f] g</em> h](i) _j a <em>b</em> c</em> *<em>d"
+        ),
+        "should support a very long line with lots of attention"
+    );
+}
+
+#[test]
+fn attention_sequence_lengths() {
+    assert_eq!(
+        to_html("***a***"),
+        "<p><em><strong>a</strong></em></p>",
+        "should support 3 markers on both sides as strong in emphasis"
+    );
+
+    assert_eq!(
+        to_html("****a****"),
+        "<p><strong><strong>a</strong></strong></p>",
+        "should support 4 markers on both sides as strong in strong"
+    );
+
+    assert_eq!(
+        to_html("*****a*****"),
+        "<p><em><strong><strong>a</strong></strong></em></p>",
+        "should support 5 markers on both sides as strong in strong in emphasis"
+    );
+
+    assert_eq!(
+        to_html("******a******"),
+        "<p><strong><strong><strong>a</strong></strong></strong></p>",
+        "should support 6 markers on both sides as strong in strong in strong"
+    );
+
+    assert_eq!(
+        to_html("_____a_____"),
+        "<p><em><strong><strong>a</strong></strong></em></p>",
+        "should support 5 markers on both sides w/ `_`"
+    );
+
+    assert_eq!(
+        to_html("***a**"),
+        "<p>*<strong>a</strong></p>",
+        "should leave extra opening markers as data"
+    );
+
+    assert_eq!(
+        to_html("**a***"),
+        "<p><strong>a</strong>*</p>",
+        "should leave extra closing markers as data"
+    );
+
+    assert_eq!(
+        to_html("*a****a**"),
+        "<p><em>a</em>***a**</p>",
+        "should not match a closer that can open if the lengths sum to a multiple of 3"
+    );
+
+    assert_eq!(
+        to_html("****a***a"),
+        "<p>*<em><strong>a</strong></em>a</p>",
+        "should use the whole closing sequence for the rule of 3 after some markers are used"
+    );
+
+    assert_eq!(
+        to_html("a ***a****a"),
+        "<p>a <em><strong>a</strong></em>*a</p>",
+        "should use the whole opening sequence for the rule of 3 after some markers are used"
+    );
+
+    assert_eq!(
+        to_html("**a* a*a"),
+        "<p>*<em>a</em> a*a</p>",
+        "should not match the rest of a sequence if the lengths sum to a multiple of 3"
+    );
+
+    assert_eq!(
+        to_html("***a*a*a*"),
+        "<p><em><em><em>a</em>a</em>a</em></p>",
+        "should support emphasis matched one marker at a time"
+    );
+}
+
+#[test]
+fn attention_word_characters() -> Result<(), String> {
+    let brackets = Options {
+        parse: ParseOptions {
+            attention_word_characters: vec!['「', '」'],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a*「b」*c"),
+        "<p>a*「b」*c</p>",
+        "should not support attention between word characters and punctuation by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a*「b」*c", &brackets)?,
+        "<p>a<em>「b」</em>c</p>",
+        "should support attention next to extra word characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a**「b」**c", &brackets)?,
+        "<p>a<strong>「b」</strong>c</p>",
+        "should support strong next to extra word characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a *b* c", &brackets)?,
+        "<p>a <em>b</em> c</p>",
+        "should not affect other characters"
+    );
+
+    let hyphen = Options {
+        parse: ParseOptions {
+            attention_word_characters: vec!['-', '\''],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a-_b_-c"),
+        "<p>a-<em>b</em>-c</p>",
+        "should support underscores next to punctuation by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a-_b_-c l'_d_'e", &hyphen)?,
+        "<p>a-_b_-c l'_d_'e</p>",
+        "should not support intraword underscores next to extra word characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a-*b*-c", &hyphen)?,
+        "<p>a-<em>b</em>-c</p>",
+        "should support intraword asterisks next to extra word characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a -_b_- c", &hyphen)?,
+        "<p>a -_b_- c</p>",
+        "should not support underscores between extra word characters"
+    );
+
     Ok(())
 }
@@ -0,0 +1,251 @@
+use markdown::{
+    mdast::{Emphasis, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn attributes() -> Result<(), String> {
+    let attributes = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                attributes: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("# a {.b}"),
+        "<h1>a {.b}</h1>",
+        "should not support attributes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a {.b}", &attributes)?,
+        "<h1 class=\"b\">a</h1>",
+        "should support a class on a heading (atx)"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a {#b .c .d} ##", &attributes)?,
+        "<h1 id=\"b\" class=\"c d\">a</h1>",
+        "should support an id and multiple classes on a heading (atx) w/ a closing sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {.b}\n=", &attributes)?,
+        "<h1 class=\"b\">a</h1>",
+        "should support attributes on a heading (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb {.c}", &attributes)?,
+        "<p class=\"c\">a\nb</p>",
+        "should support attributes on a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a *b*{#c} d", &attributes)?,
+        "<p>a <em id=\"c\">b</em> d</p>",
+        "should support an id on emphasis"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "**a**{.b} `c`{.d} $e${.f} ~~g~~{.h}",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        attributes: true,
+                        gfm_strikethrough: true,
+                        math_text: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><strong class=\"b\">a</strong> <code class=\"d\">c</code> <code class=\"language-math math-inline f\">e</code> <del class=\"h\">g</del></p>",
+        "should support attributes on strong, code, math, and strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b){.c} ![d](e){.f}", &attributes)?,
+        "<p><a href=\"b\" class=\"c\">a</a> <img src=\"e\" alt=\"d\" class=\"f\" /></p>",
+        "should support attributes on links and images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b){href=c title=d} ![f](g){src=h alt=i}", &attributes)?,
+        "<p><a href=\"b\">a</a> <img src=\"g\" alt=\"f\" /></p>",
+        "should not overwrite attributes of links and images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b){rel=me}", &attributes)?,
+        "<p><a href=\"b\" rel=\"me\">a</a></p>",
+        "should support `rel` on links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b){rel=\"me nofollow\"} [c](/d){rel=me}",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        attributes: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    link_rel: Some("nofollow noopener".into()),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<p><a href=\"https://b\" rel=\"me nofollow noopener\">a</a> <a href=\"/d\" rel=\"me\">c</a></p>",
+        "should merge `link_rel` into `rel` on links"
+    );
+
+    assert_eq!(
+        to_html_with_options("a *b*{.c}", &attributes)?,
+        "<p>a <em class=\"c\">b</em></p>",
+        "should prefer a span over a paragraph w/o whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("a *b* {.c}", &attributes)?,
+        "<p class=\"c\">a <em>b</em></p>",
+        "should prefer a paragraph over a span w/ whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {b=c d='e f' g=\"h'i\"}", &attributes)?,
+        "<p b=\"c\" d=\"e f\" g=\"h'i\">a</p>",
+        "should support unquoted, single quoted, and double quoted values"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {b=\"&<>\\\"}", &attributes)?,
+        "<p b=\"&amp;&lt;&gt;\\\">a</p>",
+        "should encode values"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {#b id=c .d class=\"e f\" g=h g=i}", &attributes)?,
+        "<p id=\"c\" class=\"d e f\" g=\"i\">a</p>",
+        "should join classes, and use the last id or value"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {: .b }", &attributes)?,
+        "<p class=\"b\">a</p>",
+        "should support a colon and whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {onclick=\"b\" c=d}", &attributes)?,
+        "<p c=\"d\">a</p>",
+        "should not support event handlers by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a {onclick=\"b\"}",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        attributes: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..CompileOptions::default()
+                }
+            }
+        )?,
+        "<p onclick=\"b\">a</p>",
+        "should support event handlers w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a {} b { } c {.} d {#} e {.b.c} f {g} g {h=} i {j=\"k",
+            &attributes
+        )?,
+        "<p>a {} b { } c {.} d {#} e {.b.c} f {g} g {h=} i {j=&quot;k</p>",
+        "should not support empty or invalid attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a{.b}\n\na {.b} c\n\n{.b}\n\na\n{.b}", &attributes)?,
+        "<p>a{.b}</p>\n<p>a {.b} c</p>\n<p>{.b}</p>\n<p>a\n{.b}</p>",
+        "should not support attributes that do not apply to something"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {.b}\n\n> c {.d}\n\n* e {.f}", &attributes)?,
+        "<p class=\"b\">a</p>\n<blockquote>\n<p class=\"d\">c</p>\n</blockquote>\n<ul>\n<li>e</li>\n</ul>",
+        "should support paragraphs in containers, but not in tight lists"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a {#b}\n\n# b",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        attributes: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    heading_ids: true,
+                    ..CompileOptions::default()
+                }
+            }
+        )?,
+        "<h1 id=\"b\">a</h1>\n<h1 id=\"b-1\">b</h1>",
+        "should use an id over a generated heading id"
+    );
+
+    assert_eq!(
+        to_mdast("a *b*{.c} d {.e}", &attributes.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                        })],
+                        position: Some(Position::new(1, 3, 2, 1, 6, 5))
+                    }),
+                    Node::Text(Text {
+                        value: " d".into(),
+                        position: Some(Position::new(1, 10, 9, 1, 12, 11))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 17, 16))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 17, 16))
+        }),
+        "should ignore attributes in mdast"
+    );
+
+    Ok(())
+}
@@ -83,6 +83,42 @@ fn autolink() -> Result<(), String> {
         "should not support protocol autolinks w/ spaces"
     );
 
+    assert_eq!(
+        to_html("<http://a b>"),
+        "<p>&lt;http://a b&gt;</p>",
+        "should not support protocol autolinks w/ spaces (2)"
+    );
+
+    assert_eq!(
+        to_html("<http://a\tb>"),
+        "<p>&lt;http://a\tb&gt;</p>",
+        "should not support protocol autolinks w/ tabs"
+    );
+
+    assert_eq!(
+        to_html("<http://a\nb>"),
+        "<p>&lt;http://a\nb&gt;</p>",
+        "should not support protocol autolinks w/ line endings"
+    );
+
+    assert_eq!(
+        to_html("<http://a<b>"),
+        "<p>&lt;http://a&lt;b&gt;</p>",
+        "should not support protocol autolinks w/ `<`"
+    );
+
+    assert_eq!(
+        to_html("<http://a b> <http://c>"),
+        "<p>&lt;http://a b&gt; <a href=\"http://c\">http://c</a></p>",
+        "should support an autolink after a failed one"
+    );
+
+    assert_eq!(
+        to_html_with_options("<http://a b> <http://a<b>", &Options::gfm())?,
+        "<p>&lt;<a href=\"http://a\">http://a</a> b&gt; &lt;<a href=\"http://a\">http://a</a>&lt;b&gt;</p>",
+        "should support GFM autolink literals in failed autolinks"
+    );
+
     assert_eq!(
         to_html("<http://example.com/\\[\\>"),
         "<p><a href=\"http://example.com/%5C%5B%5C\">http://example.com/\\[\\</a></p>",
@@ -269,6 +305,113 @@ fn autolink() -> Result<(), String> {
         "should support turning off autolinks"
     );
 
+    let https = Options {
+        parse: ParseOptions {
+            autolink_scheme_allowlist: Some(vec!["https".into()]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("<ftp://x>", &https)?,
+        "<p>&lt;ftp://x&gt;</p>",
+        "should not support schemes not in `autolink_scheme_allowlist`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://x> <HTTPS://y>", &https)?,
+        "<p><a href=\"https://x\">https://x</a> <a href=\"HTTPS://y\">HTTPS://y</a></p>",
+        "should support schemes in `autolink_scheme_allowlist`, case-insensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("<http://x> <httpsx://y> <http:z>", &https)?,
+        "<p>&lt;http://x&gt; &lt;httpsx://y&gt; &lt;http:z&gt;</p>",
+        "should not support prefixes or extensions of schemes in `autolink_scheme_allowlist`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@b.co>", &https)?,
+        "<p><a href=\"mailto:a@b.co\">a@b.co</a></p>",
+        "should support email autolinks w/ `autolink_scheme_allowlist`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<https://x>",
+            &Options {
+                parse: ParseOptions {
+                    autolink_scheme_allowlist: Some(vec![]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>&lt;https://x&gt;</p>",
+        "should support an empty `autolink_scheme_allowlist`"
+    );
+
+    let protocol_only = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                autolink_email: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("<http://x> <a@b.com>", &protocol_only)?,
+        "<p><a href=\"http://x\">http://x</a> &lt;a@b.com&gt;</p>",
+        "should support turning off email autolinks w/ `autolink_email: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<http://x> <a@b.com>",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        autolink_protocol: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>&lt;http://x&gt; <a href=\"mailto:a@b.com\">a@b.com</a></p>",
+        "should support turning off protocol autolinks w/ `autolink_protocol: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<http://x> <a@b.com>",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        autolink: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>&lt;http://x&gt; &lt;a@b.com&gt;</p>",
+        "should support turning off both forms w/ `autolink: false`"
+    );
+
+    let mut constructs = Constructs::default();
+    constructs.set("autolink", false)?;
+    assert!(
+        !constructs.autolink_email && !constructs.autolink_protocol,
+        "should turn both forms off when setting `autolink` by name"
+    );
+
     assert_eq!(
         to_mdast(
             "a <https://alpha.com> b <bravo@charlie.com> c.",
@@ -284,6 +427,7 @@ fn autolink() -> Result<(), String> {
                     Node::Link(Link {
                         url: "https://alpha.com".into(),
                         title: None,
+                        autolink: true,
                         children: vec![Node::Text(Text {
                             value: "https://alpha.com".into(),
                             position: Some(Position::new(1, 4, 3, 1, 21, 20))
@@ -297,6 +441,7 @@ fn autolink() -> Result<(), String> {
                     Node::Link(Link {
                         url: "mailto:bravo@charlie.com".into(),
                         title: None,
+                        autolink: true,
                         children: vec![Node::Text(Text {
                             value: "bravo@charlie.com".into(),
                             position: Some(Position::new(1, 26, 25, 1, 43, 42))
@@ -312,7 +457,45 @@ fn autolink() -> Result<(), String> {
             })],
             position: Some(Position::new(1, 1, 0, 1, 47, 46))
         }),
-        "should support autolinks as `Link`s in mdast"
+        "should support autolinks as `Link`s w/ `autolink` in mdast"
+    );
+
+    let wbr = Options {
+        compile: CompileOptions {
+            autolink_break_opportunities: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("<https://a.b/c/d?e=f&g=h>", &wbr)?,
+        "<p><a href=\"https://a.b/c/d?e=f&amp;g=h\">https:/<wbr />/<wbr />a.<wbr />b/<wbr />c/<wbr />d?<wbr />e=f&amp;<wbr />g=h</a></p>",
+        "should support `autolink_break_opportunities` (keeping `href`)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.b/>", &wbr)?,
+        "<p><a href=\"https://a.b/\">https:/<wbr />/<wbr />a.<wbr />b/</a></p>",
+        "should not add a break opportunity at the end of an autolink"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a.b@c.d>", &wbr)?,
+        "<p><a href=\"mailto:a.b@c.d\">a.<wbr />b@c.<wbr />d</a></p>",
+        "should support `autolink_break_opportunities` in email autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("![<https://a.b/c>](d)", &wbr)?,
+        "<p><img src=\"d\" alt=\"https://a.b/c\" /></p>",
+        "should not add break opportunities in alt text"
+    );
+
+    assert_eq!(
+        to_html_with_options("[https://a.b/c](https://d.e/f)", &wbr)?,
+        "<p><a href=\"https://d.e/f\">https://a.b/c</a></p>",
+        "should not add break opportunities to other links"
     );
 
     Ok(())
@@ -0,0 +1,100 @@
+use markdown::{block_ranges, unist::Position, BlockKind, BlockRange, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn block_ranges_mixed() -> Result<(), String> {
+    let value = "# a\n\nb\nc\n\n> d\n> e\n\n* f\n* g\n\n```h\ni\n```\n\n    j\n\n***\n\n[k]: l\n\n<div>\n\nm\n===";
+
+    assert_eq!(
+        block_ranges(value, &ParseOptions::default())?,
+        vec![
+            BlockRange {
+                kind: BlockKind::Heading,
+                position: Position::new(1, 1, 0, 1, 4, 3)
+            },
+            BlockRange {
+                kind: BlockKind::Paragraph,
+                position: Position::new(3, 1, 5, 4, 2, 8)
+            },
+            BlockRange {
+                kind: BlockKind::BlockQuote,
+                position: Position::new(6, 1, 10, 7, 4, 17)
+            },
+            BlockRange {
+                kind: BlockKind::List,
+                position: Position::new(9, 1, 19, 11, 1, 27)
+            },
+            BlockRange {
+                kind: BlockKind::Code,
+                position: Position::new(12, 1, 28, 14, 4, 38)
+            },
+            BlockRange {
+                kind: BlockKind::Code,
+                position: Position::new(16, 1, 40, 16, 6, 45)
+            },
+            BlockRange {
+                kind: BlockKind::ThematicBreak,
+                position: Position::new(18, 1, 47, 18, 4, 50)
+            },
+            BlockRange {
+                kind: BlockKind::Definition,
+                position: Position::new(20, 1, 52, 20, 7, 58)
+            },
+            BlockRange {
+                kind: BlockKind::Html,
+                position: Position::new(22, 1, 60, 22, 6, 65)
+            },
+            BlockRange {
+                kind: BlockKind::Heading,
+                position: Position::new(24, 1, 67, 25, 4, 72)
+            },
+        ],
+        "should support a mixed document"
+    );
+
+    assert_eq!(
+        block_ranges("", &ParseOptions::default())?,
+        vec![],
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        block_ranges(
+            "---\na: b\n---\n\n| a |\n| - |\n\n[^a]: b\n\n$$\nc\n$$",
+            &ParseOptions {
+                constructs: markdown::Constructs {
+                    frontmatter: true,
+                    math_flow: true,
+                    ..markdown::Constructs::gfm()
+                },
+                ..ParseOptions::gfm()
+            }
+        )?
+        .into_iter()
+        .map(|range| range.kind)
+        .collect::<Vec<_>>(),
+        vec![
+            BlockKind::Frontmatter,
+            BlockKind::GfmTable,
+            BlockKind::GfmFootnoteDefinition,
+            BlockKind::Math
+        ],
+        "should support extensions"
+    );
+
+    assert_eq!(
+        block_ranges("{a}\n\n<B>\n\nc\n\n</B>", &ParseOptions::mdx())?
+            .into_iter()
+            .map(|range| range.kind)
+            .collect::<Vec<_>>(),
+        vec![
+            BlockKind::MdxFlowExpression,
+            BlockKind::MdxJsxFlowTag,
+            BlockKind::Paragraph,
+            BlockKind::MdxJsxFlowTag
+        ],
+        "should support MDX"
+    );
+
+    Ok(())
+}
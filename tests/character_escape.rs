@@ -85,6 +85,52 @@ fn character_escape() -> Result<(), String> {
         "should escape in fenced code info"
     );
 
+    assert_eq!(
+        to_html("\\a"),
+        "<p>\\a</p>",
+        "should keep a backslash before a letter"
+    );
+
+    assert_eq!(
+        to_html("\\é \\0 \\\u{a0}a"),
+        "<p>\\é \\0 \\\u{a0}a</p>",
+        "should keep a backslash before non-ascii, digits, and unicode whitespace"
+    );
+
+    assert_eq!(to_html("\\*a\\*"), "<p>*a*</p>", "should escape asterisks");
+
+    assert_eq!(to_html("\\\\"), "<p>\\</p>", "should escape a backslash");
+
+    assert_eq!(
+        to_html("\\\\*a*"),
+        "<p>\\<em>a</em></p>",
+        "should not escape what follows an escaped backslash"
+    );
+
+    assert_eq!(
+        to_html("\\\\\\*"),
+        "<p>\\*</p>",
+        "should support an escaped backslash before an escaped asterisk"
+    );
+
+    assert_eq!(
+        to_html("a\\"),
+        "<p>a\\</p>",
+        "should keep a backslash at the end of a paragraph"
+    );
+
+    assert_eq!(
+        to_html("a\\\r\nb"),
+        "<p>a<br />\r\nb</p>",
+        "should form a hard break w/ a backslash before a CRLF"
+    );
+
+    assert_eq!(
+        to_html("a\\\\\nb"),
+        "<p>a\\\nb</p>",
+        "should not form a hard break w/ an escaped backslash before a line ending"
+    );
+
     assert_eq!(
         to_html_with_options(
             "\\> a",
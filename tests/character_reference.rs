@@ -214,6 +214,47 @@ fn character_reference() -> Result<(), String> {
         "should support turning off character references"
     );
 
+    let off = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                character_reference: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("&#65; &#x42; &nbsp;", &off)?,
+        "<p>&amp;#65; &amp;#x42; &amp;nbsp;</p>",
+        "should pass numeric and named character references through as text if turned off"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b&amp;c \"&#65;\") ![d&#65;](e)\n\n```f&amp;g\n```",
+            &off
+        )?,
+        "<p><a href=\"b&amp;amp;c\" title=\"&amp;#65;\">a</a> <img src=\"e\" alt=\"d&amp;#65;\" /></p>\n<pre><code class=\"language-f&amp;amp;g\"></code></pre>",
+        "should pass character references through in destinations, titles, alt, and info if turned off"
+    );
+
+    assert_eq!(
+        to_mdast("&amp; &#65;", &off.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "&amp; &#65;".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 12, 11))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should pass character references through in mdast if turned off"
+    );
+
     assert_eq!(
         to_mdast("&nbsp; &amp; &copy; &AElig; &Dcaron;\n&frac34; &HilbertSpace; &DifferentialD;\n&ClockwiseContourIntegral; &ngE;\n&#35; &#1234; &#992; &#0;\n&#X22; &#XD06; &#xcab;", &Default::default())?,
         Node::Root(Root {
@@ -0,0 +1,79 @@
+use markdown::{
+    extract_character_references, unist::Position, CharacterReference, CharacterReferenceKind,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn character_references() -> Result<(), String> {
+    assert_eq!(
+        extract_character_references("a &amp; &#65; &#x42; b", &ParseOptions::default())?,
+        vec![
+            CharacterReference {
+                kind: CharacterReferenceKind::Named,
+                position: Position::new(1, 3, 2, 1, 8, 7),
+                source: "&amp;".into(),
+                value: "&".into()
+            },
+            CharacterReference {
+                kind: CharacterReferenceKind::Decimal,
+                position: Position::new(1, 9, 8, 1, 14, 13),
+                source: "&#65;".into(),
+                value: "A".into()
+            },
+            CharacterReference {
+                kind: CharacterReferenceKind::Hexadecimal,
+                position: Position::new(1, 15, 14, 1, 21, 20),
+                source: "&#x42;".into(),
+                value: "B".into()
+            },
+        ],
+        "should support named, decimal, and hexadecimal references"
+    );
+
+    assert_eq!(
+        extract_character_references("&#xZZ; &#; &unknown; &amp", &ParseOptions::default())?,
+        vec![],
+        "should not include things that are not references"
+    );
+
+    assert_eq!(
+        extract_character_references("&#0; &#xD800; &#x110000;", &ParseOptions::default())?
+            .iter()
+            .map(|d| d.value.as_str())
+            .collect::<Vec<_>>(),
+        vec!["\u{FFFD}", "\u{FFFD}", "\u{FFFD}"],
+        "should decode references to invalid code points as U+FFFD"
+    );
+
+    assert_eq!(
+        extract_character_references("&NotEqualTilde;", &ParseOptions::default())?[0].value,
+        "\u{2242}\u{338}",
+        "should support named references that decode to two characters"
+    );
+
+    assert_eq!(
+        extract_character_references(
+            "[a](b&amp;c \"d&quot;\")\n\n```e&lt;\n&gt;\n```\n\n`&amp;`",
+            &ParseOptions::default()
+        )?
+        .iter()
+        .map(|d| (d.source.as_str(), d.position.start.line))
+        .collect::<Vec<_>>(),
+        vec![("&amp;", 1), ("&quot;", 1), ("&lt;", 3)],
+        "should support references in destinations, titles, and info, but not in code"
+    );
+
+    assert_eq!(
+        extract_character_references("> a\n> &copy;", &ParseOptions::default())?,
+        vec![CharacterReference {
+            kind: CharacterReferenceKind::Named,
+            position: Position::new(2, 3, 6, 2, 9, 12),
+            source: "&copy;".into(),
+            value: "©".into()
+        }],
+        "should support references in containers"
+    );
+
+    Ok(())
+}
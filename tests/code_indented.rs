@@ -122,6 +122,24 @@ fn code_indented() -> Result<(), String> {
         "should not support lazyness (7)"
     );
 
+    assert_eq!(
+        to_html(">     a\n>     b"),
+        "<blockquote>\n<pre><code>a\nb\n</code></pre>\n</blockquote>",
+        "should support continuing code in a block quote that starts on the same line"
+    );
+
+    assert_eq!(
+        to_html("-     a\n      b"),
+        "<ul>\n<li>\n<pre><code>a\nb\n</code></pre>\n</li>\n</ul>",
+        "should support continuing code in a list item that starts on the same line"
+    );
+
+    assert_eq!(
+        to_html("    a\n>     b"),
+        "<pre><code>a\n</code></pre>\n<blockquote>\n<pre><code>b\n</code></pre>\n</blockquote>",
+        "should not support continuing code into a new container"
+    );
+
     let off = Options {
         parse: ParseOptions {
             constructs: Constructs {
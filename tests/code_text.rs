@@ -207,3 +207,125 @@ fn code_text() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+fn code_text_lang() -> Result<(), String> {
+    let lang = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                code_span_lang: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("`rust:x`"),
+        "<p><code>rust:x</code></p>",
+        "should not support language hints by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("`rust:x`", &lang)?,
+        "<p><code class=\"language-rust\">x</code></p>",
+        "should support a language hint"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a:b`", &lang)?,
+        "<p><code>a:b</code></p>",
+        "should not support an unknown language"
+    );
+
+    assert_eq!(
+        to_html_with_options("`Rust:x`", &lang)?,
+        "<p><code class=\"language-Rust\">x</code></p>",
+        "should support a language hint insensitive to case"
+    );
+
+    assert_eq!(
+        to_html_with_options("``rust:a`b``", &lang)?,
+        "<p><code class=\"language-rust\">a`b</code></p>",
+        "should support a language hint w/ more accents"
+    );
+
+    assert_eq!(
+        to_html_with_options("`rust: x `", &lang)?,
+        "<p><code class=\"language-rust\">x</code></p>",
+        "should strip padding after a language hint"
+    );
+
+    assert_eq!(
+        to_html_with_options("` rust:x`", &lang)?,
+        "<p><code> rust:x</code></p>",
+        "should not support a language hint after a space"
+    );
+
+    assert_eq!(
+        to_html_with_options("`rust:`", &lang)?,
+        "<p><code>rust:</code></p>",
+        "should not support a language hint w/o code"
+    );
+
+    assert_eq!(
+        to_html_with_options("`rust::x`", &lang)?,
+        "<p><code class=\"language-rust\">:x</code></p>",
+        "should support a colon after a language hint"
+    );
+
+    assert_eq!(
+        to_html_with_options("`c:\\x`", &lang)?,
+        "<p><code>c:\\x</code></p>",
+        "should not support single letter languages (drive letters)"
+    );
+
+    assert_eq!(
+        to_html_with_options("`http://a`", &lang)?,
+        "<p><code>http://a</code></p>",
+        "should not support protocols"
+    );
+
+    assert_eq!(
+        to_html_with_options("`rust:x", &lang)?,
+        "<p>`rust:x</p>",
+        "should not support a language hint in unclosed code"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$rust:x$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        code_span_lang: true,
+                        math_text: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><code class=\"language-math math-inline\">rust:x</code></p>",
+        "should not support a language hint in math (text)"
+    );
+
+    assert_eq!(
+        to_mdast("`rust:x`", &lang.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::InlineCode(InlineCode {
+                    value: "rust:x".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 9, 8))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 9, 8))
+        }),
+        "should keep a language hint in the value in mdast"
+    );
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use pretty_assertions::assert_eq;
 #[rustfmt::skip]
 #[test]
 fn commonmark() -> Result<(), String> {
+    // Strict `CommonMark` defaults, except that the spec includes raw HTML.
     let danger = Options {
         compile: CompileOptions {
             allow_dangerous_html: true,
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Definition, Node, Root},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, DefinitionConflict, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -83,6 +83,36 @@ fn definition() -> Result<(), String> {
         "should support definitions w/ explicit empty destinations"
     );
 
+    assert_eq!(
+        to_html("[foo]: <>\n[foo]"),
+        "<p><a href=\"\">foo</a></p>",
+        "should support definitions w/ explicit empty destinations, directly followed by a reference"
+    );
+
+    assert_eq!(
+        to_html("[foo]: #\n\n[foo]"),
+        "<p><a href=\"#\">foo</a></p>",
+        "should support definitions w/ only a fragment as destination"
+    );
+
+    assert_eq!(
+        to_html("[foo]: # \"\"\n\n[foo]"),
+        "<p><a href=\"#\">foo</a></p>",
+        "should support definitions w/ only a fragment as destination and an empty title"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\nbar\n\n[foo]"),
+        "<p>bar</p>\n<p><a href=\"/url\">foo</a></p>",
+        "should support definitions directly followed by a paragraph"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url \"title\"\nbar [foo]"),
+        "<p>bar <a href=\"/url\" title=\"title\">foo</a></p>",
+        "should support definitions directly followed by a paragraph w/ a reference"
+    );
+
     assert_eq!(
         to_html_with_options("[foo]: <bar>(baz)\n\n[foo]", &danger)?,
         "<p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>",
@@ -119,6 +149,36 @@ fn definition() -> Result<(), String> {
         "should match w/ case-insensitive (2)"
     );
 
+    assert_eq!(
+        to_html("[ẞ]: /url\n\n[ss]"),
+        "<p><a href=\"/url\">ss</a></p>",
+        "should match w/ case-insensitive (capital sharp s, `ss`)"
+    );
+
+    assert_eq!(
+        to_html("[SS]: /url\n\n[ß]"),
+        "<p><a href=\"/url\">ß</a></p>",
+        "should match w/ case-insensitive (`SS`, sharp s)"
+    );
+
+    assert_eq!(
+        to_html("[ΟΔΟΣ]: /url\n\n[οδος] [οδοσ] [Οδος]"),
+        "<p><a href=\"/url\">οδος</a> <a href=\"/url\">οδοσ</a> <a href=\"/url\">Οδος</a></p>",
+        "should match w/ case-insensitive (final and non-final sigma)"
+    );
+
+    assert_eq!(
+        to_html("[ÉCOLE ÅNGSTRÖM]: /url\n\n[école ångström]"),
+        "<p><a href=\"/url\">école ångström</a></p>",
+        "should match w/ case-insensitive (accented latin)"
+    );
+
+    assert_eq!(
+        to_html("[ϴ]: /url\n\n[θ]"),
+        "<p><a href=\"/url\">θ</a></p>",
+        "should match w/ case-insensitive (characters w/o round trip)"
+    );
+
     assert_eq!(
         to_html("[ı]: a\n\n[I]"),
         "<p><a href=\"a\">I</a></p>",
@@ -222,6 +282,84 @@ fn definition() -> Result<(), String> {
         "should not support setext heading underlines after definitions (2)"
     );
 
+    assert_eq!(
+        to_html("[foo]: /url\n==="),
+        "<p>===</p>",
+        "should not support setext heading underlines after definitions (3)"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url \"a\"\n===\n[foo]"),
+        "<p>===\n<a href=\"/url\" title=\"a\">foo</a></p>",
+        "should not support setext heading underlines after definitions w/ titles"
+    );
+
+    assert_eq!(
+        to_html("> [foo]: /url\n> ===\n> [foo]"),
+        "<blockquote>\n<p>===\n<a href=\"/url\">foo</a></p>\n</blockquote>",
+        "should not support setext heading underlines after definitions in containers"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n===\n  bar"),
+        "<p>===\nbar</p>",
+        "should not support setext heading underlines after definitions, w/ an indented line after"
+    );
+
+    assert_eq!(
+        to_html("> [foo]: /url\n> ===\n> bar\n> ---"),
+        "<blockquote>\n<h2>===\nbar</h2>\n</blockquote>",
+        "should support a setext heading after an underline after definitions in containers"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n---\n[foo]"),
+        "<hr />\n<p><a href=\"/url\">foo</a></p>",
+        "should support thematic breaks after definitions"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n  ---  "),
+        "<hr />",
+        "should support thematic breaks w/ whitespace after definitions"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n--\n[foo]"),
+        "<p>--\n<a href=\"/url\">foo</a></p>",
+        "should not support too short thematic breaks after definitions"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n===\nbar\n---"),
+        "<h2>===\nbar</h2>",
+        "should support a setext heading after an underline after definitions"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n===\nbar\n===\n[foo]"),
+        "<h1>===\nbar</h1>\n<p><a href=\"/url\">foo</a></p>",
+        "should support a setext heading after an underline after definitions (2)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[foo]: /url\n---",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        thematic_break: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>---</p>",
+        "should not support thematic breaks after definitions if they are turned off"
+    );
+
     assert_eq!(
         to_html(
             "[foo]: /foo-url \"foo\"\n[bar]: /bar-url\n  \"bar\"\n[baz]: /baz-url\n\n[foo],\n[bar],\n[baz]"),
@@ -308,6 +446,48 @@ fn definition() -> Result<(), String> {
         "should support character escapes at the start of a title"
     );
 
+    assert_eq!(
+        to_html("[x]: a \"He said \\\"hi\\\"\"\n\n[x]"),
+        "<p><a href=\"a\" title=\"He said &quot;hi&quot;\">x</a></p>",
+        "should support escaped delimiters in double quoted titles"
+    );
+
+    assert_eq!(
+        to_html("[x]: a 'it\\'s'\n\n[x]"),
+        "<p><a href=\"a\" title=\"it's\">x</a></p>",
+        "should support escaped delimiters in single quoted titles"
+    );
+
+    assert_eq!(
+        to_html("[x]: a (b \\(c\\) d)\n\n[x]"),
+        "<p><a href=\"a\" title=\"b (c) d\">x</a></p>",
+        "should support escaped delimiters in paren titles"
+    );
+
+    assert_eq!(
+        to_html("[x]: a \"b\\\\\"\n\n[x]"),
+        "<p><a href=\"a\" title=\"b\\\">x</a></p>",
+        "should support an escaped backslash before the closing delimiter of a double quoted title"
+    );
+
+    assert_eq!(
+        to_html("[x]: a 'b\\\\'\n\n[x]"),
+        "<p><a href=\"a\" title=\"b\\\">x</a></p>",
+        "should support an escaped backslash before the closing delimiter of a single quoted title"
+    );
+
+    assert_eq!(
+        to_html("[x]: a (b\\\\)\n\n[x]"),
+        "<p><a href=\"a\" title=\"b\\\">x</a></p>",
+        "should support an escaped backslash before the closing delimiter of a paren title"
+    );
+
+    assert_eq!(
+        to_html("[x]: a \"b\\\\\\\"\"\n\n[x]"),
+        "<p><a href=\"a\" title=\"b\\&quot;\">x</a></p>",
+        "should support an escaped backslash followed by an escaped delimiter"
+    );
+
     assert_eq!(
         to_html("[x]: a \"'\"\n\n[x]"),
         "<p><a href=\"a\" title=\"'\">x</a></p>",
@@ -489,6 +669,36 @@ fn definition() -> Result<(), String> {
         "should prefer code (fenced) over definition titles"
     );
 
+    assert_eq!(
+        to_html("> [a]: /url\n\n[a]"),
+        "<blockquote>\n</blockquote>\n<p><a href=\"/url\">a</a></p>",
+        "should support definitions in block quotes (before the reference)"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n> [a]: /url"),
+        "<p><a href=\"/url\">a</a></p>\n<blockquote>\n</blockquote>",
+        "should support definitions in block quotes (after the reference)"
+    );
+
+    assert_eq!(
+        to_html("- [a]: /url\n\n[a]"),
+        "<ul>\n<li></li>\n</ul>\n<p><a href=\"/url\">a</a></p>",
+        "should support definitions in list items (before the reference)"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n1. b\n\n   [a]: /url 'c'"),
+        "<p><a href=\"/url\" title=\"c\">a</a></p>\n<ol>\n<li>\n<p>b</p>\n</li>\n</ol>",
+        "should support definitions in list items (after the reference)"
+    );
+
+    assert_eq!(
+        to_html("> - > [a]: /url\n\n[a]"),
+        "<blockquote>\n<ul>\n<li>\n<blockquote>\n</blockquote>\n</li>\n</ul>\n</blockquote>\n<p><a href=\"/url\">a</a></p>",
+        "should support definitions in nested containers"
+    );
+
     assert_eq!(
         to_html_with_options(
             "[foo]: /url \"title\"",
@@ -507,6 +717,43 @@ fn definition() -> Result<(), String> {
         "should support turning off definitions"
     );
 
+    let external = Options {
+        parse: ParseOptions {
+            external_definitions: vec![
+                ("a".into(), ("b".into(), None)),
+                ("C".into(), ("d".into(), Some("e & f".into()))),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a]", &external)?,
+        "<p><a href=\"b\">a</a></p>",
+        "should support external definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[c] ![x][C]", &external)?,
+        "<p><a href=\"d\" title=\"e &amp; f\">c</a> <img src=\"d\" alt=\"x\" title=\"e &amp; f\" /></p>",
+        "should support external definitions w/ titles, matching normalized identifiers"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: z", &external)?,
+        "<p><a href=\"z\">a</a></p>\n",
+        "should prefer definitions in the document over external definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[b]", &external)?,
+        "<p>[b]</p>",
+        "should not support references to undefined definitions w/ external definitions"
+    );
+
     assert_eq!(
         to_mdast("[a]: <b> 'c'", &Default::default())?,
         Node::Root(Root {
@@ -522,5 +769,75 @@ fn definition() -> Result<(), String> {
         "should support definitions as `Definition`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("[a]: <>", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Definition(Definition {
+                url: "".into(),
+                identifier: "a".into(),
+                label: Some("a".into()),
+                title: None,
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+        }),
+        "should support definitions w/ explicit empty destinations in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("[a]: # \"\"", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Definition(Definition {
+                url: "#".into(),
+                identifier: "a".into(),
+                label: Some("a".into()),
+                title: None,
+                position: Some(Position::new(1, 1, 0, 1, 10, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9))
+        }),
+        "should support definitions w/ a fragment destination and an empty title in mdast"
+    );
+
+    let last = Options {
+        parse: ParseOptions {
+            definition_conflict: DefinitionConflict::KeepLast,
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[foo]: first\n[foo]: second\n\n[foo]", &last)?,
+        "<p><a href=\"second\">foo</a></p>",
+        "should match w/ the last definition w/ `DefinitionConflict::KeepLast`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[foo]\n\n[FOO]: first 'a'\n[Foo]: second\n[foo]: third", &last)?,
+        "<p><a href=\"third\">foo</a></p>\n",
+        "should match w/ the last definition w/ `DefinitionConflict::KeepLast` (normalized, without title)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[foo] [bar]\n\n[foo]: first\n[bar]: a\n[foo]: second",
+            &last
+        )?,
+        "<p><a href=\"second\">foo</a> <a href=\"a\">bar</a></p>\n",
+        "should not affect other definitions w/ `DefinitionConflict::KeepLast`"
+    );
+
+    let mut last = last;
+    last.parse
+        .external_definitions
+        .insert("foo".into(), ("external".into(), None));
+
+    assert_eq!(
+        to_html_with_options("[foo]\n\n[foo]: first\n[foo]: second", &last)?,
+        "<p><a href=\"second\">foo</a></p>\n",
+        "should prefer document definitions over external ones w/ `DefinitionConflict::KeepLast`"
+    );
+
     Ok(())
 }
@@ -0,0 +1,198 @@
+use markdown::{
+    mdast::{DescriptionDetails, DescriptionList, DescriptionTerm, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn definition_list() -> Result<(), String> {
+    let dl = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                definition_list: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a\n: b"),
+        "<p>a\n: b</p>",
+        "should not support definition lists by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support a definition list"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n: c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dd>c</dd>\n</dl>",
+        "should support multiple details"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n: c", &dl)?,
+        "<dl>\n<dt>a\nb</dt>\n<dd>c</dd>\n</dl>",
+        "should support a term of multiple lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\nc\n: d", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dt>c</dt>\n<dd>d</dd>\n</dl>",
+        "should support multiple terms"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n: c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<p>b</p>\n</dd>\n<dd>\n<p>c</p>\n</dd>\n</dl>",
+        "should support loose definition lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:    b", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support lots of whitespace after the marker"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n   : b", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support whitespace before the marker"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\nc", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b\nc</dd>\n</dl>",
+        "should support lazy continuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n: c\nd\n: e", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dd>c\nd</dd>\n<dd>e</dd>\n</dl>",
+        "should support lazy continuation between details"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n    c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<p>b</p>\n<p>c</p>\n</dd>\n</dl>",
+        "should support indented continuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n    - c\n    - d", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<p>b</p>\n<ul>\n<li>c</li>\n<li>d</li>\n</ul>\n</dd>\n</dl>",
+        "should support flow in details"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: > b\n: c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<blockquote>\n<p>b</p>\n</blockquote>\n</dd>\n<dd>c</dd>\n</dl>",
+        "should support containers in details"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n    : c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<dl>\n<dt>b</dt>\n<dd>c</dd>\n</dl>\n</dd>\n</dl>",
+        "should support nested definition lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\nc", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n<p>c</p>",
+        "should exit details at an unindented paragraph after a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\nb\n: c\n\nd", &dl)?,
+        "<p>a</p>\n<dl>\n<dt>b</dt>\n<dd>c</dd>\n</dl>\n<p>d</p>",
+        "should support paragraphs around definition lists"
+    );
+
+    assert_eq!(
+        to_html_with_options(": a", &dl)?,
+        "<p>: a</p>",
+        "should not support details without a term"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n: b", &dl)?,
+        "<p>a</p>\n<p>: b</p>",
+        "should not support a blank line between a term and details"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n: b", &dl)?,
+        "<h1>a</h1>\n<p>: b</p>",
+        "should not support a heading as a term"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:b", &dl)?,
+        "<p>a\n:b</p>",
+        "should not support a marker w/o whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: ", &dl)?,
+        "<p>a\n:</p>",
+        "should not support empty details"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> : b", &dl)?,
+        "<blockquote>\n<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n</blockquote>",
+        "should support definition lists in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n: b", &dl)?,
+        "<blockquote>\n<p>a\n: b</p>\n</blockquote>",
+        "should not support details outside of the container of their term"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  : b", &dl)?,
+        "<ul>\n<li>\n<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n</li>\n</ul>",
+        "should support definition lists in list items"
+    );
+
+    assert_eq!(
+        to_mdast("a\n: b", &dl.parse)?,
+        Node::Root(Root {
+            children: vec![Node::DescriptionList(DescriptionList {
+                children: vec![
+                    Node::DescriptionTerm(DescriptionTerm {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::DescriptionDetails(DescriptionDetails {
+                        children: vec![Node::Paragraph(Paragraph {
+                            children: vec![Node::Text(Text {
+                                value: "b".into(),
+                                position: Some(Position::new(2, 3, 4, 2, 4, 5))
+                            })],
+                            position: Some(Position::new(2, 3, 4, 2, 4, 5))
+                        })],
+                        position: Some(Position::new(2, 1, 2, 2, 4, 5))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 2, 4, 5)),
+                spread: false
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 4, 5))
+        }),
+        "should support definition lists as `DescriptionList`s in mdast"
+    );
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use markdown::{mdast, to_html, to_html_with_options, to_mdast, Options};
+use markdown::{
+    mdast, to_html, to_html_with_options, to_mdast, CompileOptions, Constructs, Options,
+    ParseOptions,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -117,5 +120,92 @@ fn fuzz() -> Result<(), String> {
         "11: gfm task list items followed by eols (GH-24)"
     );
 
+    let frontmatter = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                frontmatter: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("---\n\n", &frontmatter),
+        Ok("<hr />\n".into()),
+        "12-a: unclosed frontmatter followed by a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\na\n\nb", &frontmatter),
+        Ok("<hr />\n<p>a</p>\n<p>b</p>".into()),
+        "12-b: unclosed frontmatter followed by several lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\n---\n\n", &frontmatter),
+        Ok("".into()),
+        "12-c: closed frontmatter followed by a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <", &Options { parse: ParseOptions::mdx(), ..Default::default() }),
+        Err("1:4: Unexpected end of file before name, expected a character that can start a name, such as a letter, `$`, or `_`".into()),
+        "13: mdx: jsx at the end of a paragraph"
+    );
+
+    assert_eq!(
+        to_mdast("<>'\n-", &ParseOptions::mdx()),
+        Err("2:2: Expected a closing tag for `<>` (1:1) before the end of `HeadingSetext` (mdx-jsx:end-tag-mismatch)".into()),
+        "14: mdx: unclosed jsx in a setext heading"
+    );
+
+    assert_eq!(
+        to_html("- ```\n1."),
+        "<ul>\n<li>\n<pre><code>\n</code></pre>\n</li>\n</ul>\n<ol>\n<li></li>\n</ol>",
+        "15-a: list item ending in unclosed fenced code, followed by another kind of list"
+    );
+
+    assert!(
+        matches!(
+            to_mdast("- ```\n1.", &Default::default()),
+            Ok(mdast::Node::Root(_))
+        ),
+        "15-b: list item ending in unclosed fenced code, followed by another kind of list (mdast)"
+    );
+
+    let dangerous = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..CompileOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html_with_options("<HTML", &dangerous),
+        Ok("<HTML".into()),
+        "16-a: gfm: tag filter at the end of html (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <b", &dangerous),
+        Ok("<p>a &lt;b</p>".into()),
+        "16-b: gfm: tag filter at the end of data"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <!--<b\nc-->", &dangerous),
+        Ok("<p>a <!--<b\nc--></p>".into()),
+        "16-c: gfm: tag filter at the end of html (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<title", &dangerous),
+        Ok("&lt;title".into()),
+        "16-d: gfm: tag filter at the end of html, with a disallowed name"
+    );
+
     Ok(())
 }
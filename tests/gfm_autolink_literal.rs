@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Link, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Options, ParseOptions,
+    CompileOptions, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -2734,6 +2734,86 @@ www.a/~
         "should match www (path start) like GitHub does (except for the bracket bug)"
     );
 
+    let ftp = Options {
+        parse: ParseOptions {
+            gfm_autolink_literal_schemes: vec!["ftp".into()],
+            ..ParseOptions::gfm()
+        },
+        compile: CompileOptions {
+            allow_dangerous_protocol: true,
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("a ftp://b.c d", &Options::gfm())?,
+        "<p>a ftp://b.c d</p>",
+        "should not support other schemes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ftp://b.c d", &ftp)?,
+        "<p>a <a href=\"ftp://b.c\">ftp://b.c</a> d</p>",
+        "should support other schemes w/ `gfm_autolink_literal_schemes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("FTP://b.c", &ftp)?,
+        "<p><a href=\"FTP://b.c\">FTP://b.c</a></p>",
+        "should support other schemes case-insensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ftp://b.c/d. e", &ftp)?,
+        "<p>a <a href=\"ftp://b.c/d\">ftp://b.c/d</a>. e</p>",
+        "should trim trailing punctuation from other schemes"
+    );
+
+    assert_eq!(
+        to_html_with_options("aftp://b.c ftp:b.c ftp:// foo:bar", &ftp)?,
+        "<p>aftp://b.c ftp:b.c ftp:// foo:bar</p>",
+        "should not support other schemes after letters, w/o slashes, or w/o domain"
+    );
+
+    assert_eq!(
+        to_html_with_options("https://a.b www.c.d e@f.g ftp://h.i", &ftp)?,
+        "<p><a href=\"https://a.b\">https://a.b</a> <a href=\"http://www.c.d\">www.c.d</a> <a href=\"mailto:e@f.g\">e@f.g</a> <a href=\"ftp://h.i\">ftp://h.i</a></p>",
+        "should still support `http`, `https`, `www.`, and emails w/ `gfm_autolink_literal_schemes`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "wss://a.b www.c.d",
+            &Options {
+                parse: ParseOptions {
+                    gfm_autolink_literal_schemes: vec!["wss".into()],
+                    ..ParseOptions::gfm()
+                },
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    ..CompileOptions::gfm()
+                },
+            }
+        )?,
+        "<p><a href=\"wss://a.b\">wss://a.b</a> <a href=\"http://www.c.d\">www.c.d</a></p>",
+        "should support other schemes starting with `w`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "ftp://a.b",
+            &Options {
+                parse: ParseOptions {
+                    gfm_autolink_literal_schemes: vec!["ftp".into()],
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p><a href=\"\">ftp://a.b</a></p>",
+        "should not output dangerous protocols for other schemes by default"
+    );
+
     assert_eq!(
         to_mdast(
             "a https://alpha.com b bravo@charlie.com c www.delta.com d xmpp:echo@foxtrot.com e mailto:golf@hotel.com f.",
@@ -2749,6 +2829,7 @@ www.a/~
                     Node::Link(Link {
                         url: "https://alpha.com".into(),
                         title: None,
+                        autolink: false,
                         children: vec![Node::Text(Text {
                             value: "https://alpha.com".into(),
                             position: Some(Position::new(1, 3, 2, 1, 20, 19))
@@ -2762,6 +2843,7 @@ www.a/~
                     Node::Link(Link {
                         url: "mailto:bravo@charlie.com".into(),
                         title: None,
+                        autolink: false,
                         children: vec![Node::Text(Text {
                             value: "bravo@charlie.com".into(),
                             position: Some(Position::new(1, 23, 22, 1, 40, 39))
@@ -2775,6 +2857,7 @@ www.a/~
                     Node::Link(Link {
                         url: "http://www.delta.com".into(),
                         title: None,
+                        autolink: false,
                         children: vec![Node::Text(Text {
                             value: "www.delta.com".into(),
                             position: Some(Position::new(1, 43, 42, 1, 56, 55))
@@ -2788,6 +2871,7 @@ www.a/~
                     Node::Link(Link {
                         url: "xmpp:echo@foxtrot.com".into(),
                         title: None,
+                        autolink: false,
                         children: vec![Node::Text(Text {
                             value: "xmpp:echo@foxtrot.com".into(),
                             position: Some(Position::new(1, 59, 58, 1, 80, 79))
@@ -2801,6 +2885,7 @@ www.a/~
                     Node::Link(Link {
                         url: "mailto:golf@hotel.com".into(),
                         title: None,
+                        autolink: false,
                         children: vec![Node::Text(Text {
                             value: "mailto:golf@hotel.com".into(),
                             position: Some(Position::new(1, 83, 82, 1, 104, 103))
@@ -2819,5 +2904,46 @@ www.a/~
         "should support GFM autolink literals as `Link`s in mdast"
     );
 
+    assert_eq!(
+        to_html_with_options("http://[::1]:8080/ http://[2001:db8::1]/a", &Options::gfm())?,
+        "<p><a href=\"http://%5B::1%5D:8080/\">http://[::1]:8080/</a> <a href=\"http://%5B2001:db8::1%5D/a\">http://[2001:db8::1]/a</a></p>",
+        "should support IPv6 hosts in protocol literals"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "http://[::1 http://[] http://[1.2] http://[::g]",
+            &Options::gfm()
+        )?,
+        "<p>http://[::1 http://[] http://[1.2] http://[::g]</p>",
+        "should not support unclosed or invalid IPv6 hosts"
+    );
+
+    assert_eq!(
+        to_html_with_options("http://localhost:3000 http://a.com:80.", &Options::gfm())?,
+        "<p><a href=\"http://localhost:3000\">http://localhost:3000</a> <a href=\"http://a.com:80\">http://a.com:80</a>.</p>",
+        "should support ports (w/o trailing punctuation)"
+    );
+
+    let wbr = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            autolink_break_opportunities: true,
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("a www.b.c/d?e&f. g@h.i", &wbr)?,
+        "<p>a <a href=\"http://www.b.c/d?e&amp;f\">www.<wbr />b.<wbr />c/<wbr />d?<wbr />e&amp;<wbr />f</a>. <a href=\"mailto:g@h.i\">g@h.<wbr />i</a></p>",
+        "should support `autolink_break_opportunities` in GFM autolink literals"
+    );
+
+    assert_eq!(
+        to_html_with_options("[https://a.b/c](d)", &wbr)?,
+        "<p><a href=\"d\">https://a.b/c</a></p>",
+        "should not add break opportunities to GFM autolink literals in links"
+    );
+
     Ok(())
 }
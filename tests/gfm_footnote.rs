@@ -2,7 +2,7 @@ use markdown::{
     mdast::{FootnoteDefinition, FootnoteReference, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Options, ParseOptions,
+    CompileOptions, GfmFootnoteRefTemplate, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -121,6 +121,58 @@ fn gfm_footnote() -> Result<(), String> {
         "should support `options.gfm_footnote_clobber_prefix`"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "a[^b] c[^b]\n\n[^b]: d",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_ref_template: GfmFootnoteRefTemplate {
+                        tag_name: None,
+                        class: Some("footnote-ref".into()),
+                    },
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p>a<a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\" class=\"footnote-ref\">1</a> c<a href=\"#user-content-fn-b\" id=\"user-content-fnref-b-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\" class=\"footnote-ref\">1</a></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-b\">
+<p>d <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-b-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>
+</li>
+</ol>
+</section>
+",
+        "should support `options.gfm_footnote_ref_template` (no wrapper, class)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b]\n\n[^b]: c",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_ref_template: GfmFootnoteRefTemplate {
+                        tag_name: Some("span".into()),
+                        class: Some("x\"y".into()),
+                    },
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p>a<span><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\" class=\"x&quot;y\">1</a></span></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-b\">
+<p>c <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support `options.gfm_footnote_ref_template` (other wrapper, encoded class)"
+    );
+
     assert_eq!(
         to_html_with_options("A paragraph.\n\n[^a]: whatevs", &Options::gfm())?,
         "<p>A paragraph.</p>\n",
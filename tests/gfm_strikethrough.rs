@@ -62,6 +62,36 @@ fn gfm_strikethrough() -> Result<(), String> {
         "should close if preceded and followed by punctuation"
     );
 
+    assert_eq!(
+        to_html_with_options("a*~~b~~*c", &Options::gfm())?,
+        "<p>a<em><del>b</del></em>c</p>",
+        "should support emphasis around strikethrough, inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("a**~b~**c", &Options::gfm())?,
+        "<p>a<strong><del>b</del></strong>c</p>",
+        "should support strong around strikethrough, inside words"
+    );
+
+    assert_eq!(
+        to_html("a*~~b~~*c"),
+        "<p>a*~~b~~*c</p>",
+        "should not support emphasis around tildes, inside words, w/o strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("a*$b$*c", &Options::gfm())?,
+        "<p>a*$b$*c</p>",
+        "should not support emphasis around other punctuation, inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("a~~*b*~~c", &Options::gfm())?,
+        "<p>a~~<em>b</em>~~c</p>",
+        "should not support strikethrough around emphasis, inside words"
+    );
+
     assert_eq!(
         to_html_with_options(
             r###"
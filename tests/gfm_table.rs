@@ -1837,6 +1837,48 @@ normal escape: <a href="https://github.com/github/cmark-gfm/issues/277">https://
         "should match loose escapes like GitHub"
     );
 
+    assert_eq!(
+        to_html_with_options("| *a* | `b` |\n| - | - |\n| *c* | `d` |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th><em>a</em></th>\n<th><code>b</code></th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><em>c</em></td>\n<td><code>d</code></td>\n</tr>\n</tbody>\n</table>",
+        "should support emphasis and code (text) in cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| [c](d) | ![e](f) |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><a href=\"d\">c</a></td>\n<td><img src=\"f\" alt=\"e\" /></td>\n</tr>\n</tbody>\n</table>",
+        "should support links and images in cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| [b][] |\n\n[b]: c", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><a href=\"c\">b</a></td>\n</tr>\n</tbody>\n</table>\n",
+        "should support references to definitions after the table in cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| **b** ~~c~~ <https://d> |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><strong>b</strong> <del>c</del> <a href=\"https://d\">https://d</a></td>\n</tr>\n</tbody>\n</table>",
+        "should support strong, strikethrough, and autolinks in cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| c \\| d | e |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c | d</td>\n<td>e</td>\n</tr>\n</tbody>\n</table>",
+        "should not split cells at escaped pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| [b \\| c](d) |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><a href=\"d\">b | c</a></td>\n</tr>\n</tbody>\n</table>",
+        "should support escaped pipes in links in cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| *b | c* |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>*b</td>\n</tr>\n</tbody>\n</table>",
+        "should not support attention across cells"
+    );
+
     assert_eq!(
         to_mdast(
             "| none | left | right | center |\n| - | :- | -: | :-: |\n| a |\n| b | c | d | e | f |",
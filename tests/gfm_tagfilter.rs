@@ -49,6 +49,40 @@ fn gfm_tagfilter() -> Result<(), String> {
         "should filter"
     );
 
+    let filter = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            gfm_tagfilter: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    for name in [
+        "title",
+        "textarea",
+        "style",
+        "xmp",
+        "iframe",
+        "noembed",
+        "noframes",
+        "script",
+        "plaintext",
+    ] {
+        assert_eq!(
+            to_html_with_options(&format!("a <{}>b</{}>", name, name), &filter)?,
+            format!("<p>a &lt;{}>b&lt;/{}></p>", name, name),
+            "should filter `{}`",
+            name
+        );
+    }
+
+    assert_eq!(
+        to_html_with_options("a <div>b</div> <titles> <scripts/>", &filter)?,
+        "<p>a <div>b</div> <titles> <scripts/></p>",
+        "should not filter other tags"
+    );
+
     assert_eq!(
         to_html_with_options(
             r###"
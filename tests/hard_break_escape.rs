@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Break, Node, Paragraph, Root, Text},
+    mdast::{Break, BreakKind, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
     Constructs, Options, ParseOptions,
@@ -72,7 +72,8 @@ fn hard_break_escape() -> Result<(), String> {
                         position: Some(Position::new(1, 1, 0, 1, 2, 1))
                     }),
                     Node::Break(Break {
-                        position: Some(Position::new(1, 2, 1, 2, 1, 3))
+                        position: Some(Position::new(1, 2, 1, 2, 1, 3)),
+                        kind: BreakKind::Escape
                     }),
                     Node::Text(Text {
                         value: "b.".into(),
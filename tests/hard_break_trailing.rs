@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Break, Node, Paragraph, Root, Text},
+    mdast::{Break, BreakKind, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
     Constructs, Options, ParseOptions,
@@ -138,7 +138,8 @@ fn hard_break_trailing() -> Result<(), String> {
                         position: Some(Position::new(1, 1, 0, 1, 2, 1))
                     }),
                     Node::Break(Break {
-                        position: Some(Position::new(1, 2, 1, 2, 1, 4))
+                        position: Some(Position::new(1, 2, 1, 2, 1, 4)),
+                        kind: BreakKind::Trailing
                     }),
                     Node::Text(Text {
                         value: "b.".into(),
@@ -152,5 +153,72 @@ fn hard_break_trailing() -> Result<(), String> {
         "should support hard break (trailing) as `Break`s in mdast"
     );
 
+    let one = Options {
+        parse: ParseOptions {
+            hard_break_spaces_min: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let three = Options {
+        parse: ParseOptions {
+            hard_break_spaces_min: 3,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a \nb"),
+        "<p>a\nb</p>",
+        "should not support one trailing space by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \nb", &one)?,
+        "<p>a<br />\nb</p>",
+        "should support one trailing space w/ `hard_break_spaces_min: 1`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb", &one)?,
+        "<p>a<br />\nb</p>",
+        "should support two trailing spaces w/ `hard_break_spaces_min: 1`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb", &one)?,
+        "<p>a\nb</p>",
+        "should not support no trailing spaces w/ `hard_break_spaces_min: 1`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb", &three)?,
+        "<p>a\nb</p>",
+        "should not support two trailing spaces w/ `hard_break_spaces_min: 3`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a   \nb", &three)?,
+        "<p>a<br />\nb</p>",
+        "should support three trailing spaces w/ `hard_break_spaces_min: 3`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\nb",
+            &Options {
+                parse: ParseOptions {
+                    hard_break_spaces_min: 0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a\nb</p>",
+        "should treat `hard_break_spaces_min: 0` as `1`"
+    );
+
     Ok(())
 }
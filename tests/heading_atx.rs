@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Heading, Node, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -152,6 +152,36 @@ fn heading_atx() -> Result<(), String> {
         "should not support a closing sequence w/o whitespace before it"
     );
 
+    assert_eq!(
+        to_html("# foo\t#"),
+        "<h1>foo</h1>",
+        "should support a closing sequence w/ a tab before it"
+    );
+
+    assert_eq!(
+        to_html("# foo ## #"),
+        "<h1>foo ##</h1>",
+        "should support only the last sequence as a closing sequence"
+    );
+
+    assert_eq!(
+        to_html("# ## #"),
+        "<h1>##</h1>",
+        "should support a sequence before a closing sequence as text"
+    );
+
+    assert_eq!(
+        to_html("# ## a"),
+        "<h1>## a</h1>",
+        "should support a sequence at the start of text"
+    );
+
+    assert_eq!(
+        to_html("# #a"),
+        "<h1>#a</h1>",
+        "should support a sequence directly followed by text"
+    );
+
     assert_eq!(
         to_html("### foo \\###"),
         "<h3>foo ###</h3>",
@@ -194,6 +224,48 @@ fn heading_atx() -> Result<(), String> {
         "should support empty atx headings (2)"
     );
 
+    assert_eq!(
+        to_html("#"),
+        "<h1></h1>",
+        "should support empty atx headings (3)"
+    );
+
+    assert_eq!(
+        to_html("## "),
+        "<h2></h2>",
+        "should support empty atx headings w/ a trailing space"
+    );
+
+    assert_eq!(
+        to_html("###   "),
+        "<h3></h3>",
+        "should support empty atx headings w/ trailing spaces"
+    );
+
+    assert_eq!(
+        to_html("#\t"),
+        "<h1></h1>",
+        "should support empty atx headings w/ a trailing tab"
+    );
+
+    assert_eq!(
+        to_html("# #"),
+        "<h1></h1>",
+        "should support empty atx headings w/ a closing sequence"
+    );
+
+    assert_eq!(
+        to_html("## ##  "),
+        "<h2></h2>",
+        "should support empty atx headings w/ a closing sequence and trailing spaces"
+    );
+
+    assert_eq!(
+        to_html("###### #"),
+        "<h6></h6>",
+        "should support empty atx headings w/ the maximum opening sequence"
+    );
+
     assert_eq!(
         to_html("> #\na"),
         "<blockquote>\n<h1></h1>\n</blockquote>\n<p>a</p>",
@@ -240,5 +312,90 @@ fn heading_atx() -> Result<(), String> {
         "should support heading (atx) as `Heading`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("# ## a #", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                depth: 1,
+                children: vec![Node::Text(Text {
+                    value: "## a".into(),
+                    position: Some(Position::new(1, 3, 2, 1, 7, 6))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 9, 8))
+        }),
+        "should support a sequence at the start of text in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("## #", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                depth: 2,
+                children: vec![],
+                position: Some(Position::new(1, 1, 0, 1, 5, 4))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 5, 4))
+        }),
+        "should support empty headings w/ a closing sequence in mdast"
+    );
+
+    let keep = Options {
+        compile: CompileOptions {
+            keep_atx_trailing_hashes: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("# Title #", &keep)?,
+        "<h1>Title #</h1>",
+        "should keep a closing sequence w/ `keep_atx_trailing_hashes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("  ###   Title  \t ###   ", &keep)?,
+        "<h3>Title  \t ###</h3>",
+        "should keep the whitespace before a closing sequence, but not after it, w/ `keep_atx_trailing_hashes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Title#", &keep)?,
+        "<h1>Title#</h1>",
+        "should not affect hashes w/o whitespace before them w/ `keep_atx_trailing_hashes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Title \\#", &keep)?,
+        "<h1>Title #</h1>",
+        "should not affect escaped hashes w/ `keep_atx_trailing_hashes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# ###\n#", &keep)?,
+        "<h1>###</h1>\n<h1></h1>",
+        "should keep a closing sequence in an empty heading w/ `keep_atx_trailing_hashes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# ## #", &keep)?,
+        "<h1>## #</h1>",
+        "should keep only the closing sequence w/ `keep_atx_trailing_hashes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# *a* ##", &keep)?,
+        "<h1><em>a</em> ##</h1>",
+        "should keep a closing sequence after phrasing w/ `keep_atx_trailing_hashes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("Title\n=====", &keep)?,
+        "<h1>Title</h1>",
+        "should not affect setext headings w/ `keep_atx_trailing_hashes`"
+    );
+
     Ok(())
 }
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Heading, Node, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -255,6 +255,18 @@ fn heading_setext() -> Result<(), String> {
         "should support a two setext heading underlines after a definition, as a setext heading"
     );
 
+    assert_eq!(
+        to_html("a\n---\n---"),
+        "<h2>a</h2>\n<hr />",
+        "should support a thematic break after a setext heading"
+    );
+
+    assert_eq!(
+        to_html("a\n===\n==="),
+        "<h1>a</h1>\n<p>===</p>",
+        "should not support two setext heading underlines after each other"
+    );
+
     assert_eq!(
         to_html("> ===\na"),
         "<blockquote>\n<p>===\na</p>\n</blockquote>",
@@ -297,6 +309,145 @@ fn heading_setext() -> Result<(), String> {
         "should support turning off setext underlines"
     );
 
+    let no_setext = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                heading_setext: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("Title\n===", &no_setext)?,
+        "<p>Title\n===</p>",
+        "should support turning off setext underlines (equals)"
+    );
+
+    assert_eq!(
+        to_html_with_options("Title\n---", &no_setext)?,
+        "<p>Title</p>\n<hr />",
+        "should support turning off setext underlines (dash), forming a thematic break instead"
+    );
+
+    assert_eq!(
+        to_html_with_options("> Title\n> ===\nmore", &no_setext)?,
+        "<blockquote>\n<p>Title\n===\nmore</p>\n</blockquote>",
+        "should support turning off setext underlines in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Title\n===", &no_setext)?,
+        "<h1>Title</h1>\n<p>===</p>",
+        "should support heading (atx) when turning off setext underlines"
+    );
+
+    let match_width = Options {
+        parse: ParseOptions {
+            setext_underline_match_width: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("Title\n=====\n\nTitle\n----", &match_width)?,
+        "<h1>Title</h1>\n<h2>Title</h2>",
+        "should support `setext_underline_match_width` w/ matching widths"
+    );
+
+    assert_eq!(
+        to_html_with_options("Title\n====\n\nTitle\n------", &match_width)?,
+        "<h1>Title</h1>\n<h2>Title</h2>",
+        "should support `setext_underline_match_width` w/ widths that differ by one"
+    );
+
+    assert_eq!(
+        to_html_with_options("Title\n===", &match_width)?,
+        "<p>Title\n===</p>",
+        "should not form a heading if an equals underline is too short, continuing the paragraph instead"
+    );
+
+    assert_eq!(
+        to_html_with_options("Title\n---------", &match_width)?,
+        "<p>Title</p>\n<hr />",
+        "should not form a heading if a dash underline is too long, forming a thematic break instead"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nTitle\n=====", &match_width)?,
+        "<h1>a\nTitle</h1>",
+        "should use the width of the last line of text"
+    );
+
+    assert_eq!(
+        to_html_with_options("  Title \n =====  ", &match_width)?,
+        "<h1>Title</h1>",
+        "should not count whitespace around text or underline"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文\n====\n\n中文\n==", &match_width)?,
+        "<h1>中文</h1>\n<p>中文\n==</p>",
+        "should count CJK characters as two"
+    );
+
+    assert_eq!(
+        to_html_with_options("> Title\n> =====\n\n- Title\n  ==", &match_width)?,
+        "<blockquote>\n<h1>Title</h1>\n</blockquote>\n<ul>\n<li>Title\n==</li>\n</ul>",
+        "should support `setext_underline_match_width` in containers"
+    );
+
+    let levels = |setext_as_atx_level| Options {
+        compile: CompileOptions {
+            setext_as_atx_level,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-", &levels([1, 2]))?,
+        "<h1>a</h1>\n<h2>b</h2>",
+        "should support `setext_as_atx_level` w/ the default ranks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-\n\n# c", &levels([3, 4]))?,
+        "<h3>a</h3>\n<h4>b</h4>\n<h1>c</h1>",
+        "should support `setext_as_atx_level` w/ other ranks, but not affect ATX headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-", &levels([2, 2]))?,
+        "<h2>a</h2>\n<h2>b</h2>",
+        "should support `setext_as_atx_level` w/ the same rank for both underlines"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-", &levels([0, 9]))?,
+        "<h1>a</h1>\n<h6>b</h6>",
+        "should clamp `setext_as_atx_level` to ranks 1 through 6"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n-",
+            &Options {
+                compile: CompileOptions {
+                    setext_as_atx_level: [2, 3],
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h3 id=\"a\">a</h3>",
+        "should support `setext_as_atx_level` w/ `heading_ids`"
+    );
+
     assert_eq!(
         to_mdast("alpha\nbravo\n==", &Default::default())?,
         Node::Root(Root {
@@ -1139,3 +1139,146 @@ fn html_flow_7_complete() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+fn html_flow_ends() -> Result<(), String> {
+    let danger = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            allow_dangerous_protocol: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    // Kinds 1 through 5 end at a closing condition, not at a blank line, and
+    // can interrupt paragraphs.
+    assert_eq!(
+        to_html_with_options("<script>\na\n\nb\n</script>\n*c*", &danger)?,
+        "<script>\na\n\nb\n</script>\n<p><em>c</em></p>",
+        "should end raw (type 1) at a closing tag, not at a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<script>\nb", &danger)?,
+        "<p>a</p>\n<script>\nb",
+        "should support raw (type 1) interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- a\n\nb -->\n*c*", &danger)?,
+        "<!-- a\n\nb -->\n<p><em>c</em></p>",
+        "should end comments (type 2) at `-->`, not at a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<!-- b -->", &danger)?,
+        "<p>a</p>\n<!-- b -->",
+        "should support comments (type 2) interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("<?a\n\n?>\n*b*", &danger)?,
+        "<?a\n\n?>\n<p><em>b</em></p>",
+        "should end instructions (type 3) at `?>`, not at a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<?b?>", &danger)?,
+        "<p>a</p>\n<?b?>",
+        "should support instructions (type 3) interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!DOCTYPE html>\n*a*", &danger)?,
+        "<!DOCTYPE html>\n<p><em>a</em></p>",
+        "should end declarations (type 4) at `>`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<!DOCTYPE html>", &danger)?,
+        "<p>a</p>\n<!DOCTYPE html>",
+        "should support declarations (type 4) interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("<![CDATA[\n\n]]>\n*a*", &danger)?,
+        "<![CDATA[\n\n]]>\n<p><em>a</em></p>",
+        "should end cdata (type 5) at `]]>`, not at a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<![CDATA[b]]>", &danger)?,
+        "<p>a</p>\n<![CDATA[b]]>",
+        "should support cdata (type 5) interrupting a paragraph"
+    );
+
+    // Kinds 6 and 7 end at a blank line.
+    assert_eq!(
+        to_html_with_options("<div>\n*a*\n\n*b*", &danger)?,
+        "<div>\n*a*\n<p><em>b</em></p>",
+        "should end basic (type 6) at a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("</div>\n*a*", &danger)?,
+        "</div>\n*a*",
+        "should support basic (type 6) closing tags"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<div>\nb", &danger)?,
+        "<p>a</p>\n<div>\nb",
+        "should support basic (type 6) interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n\n*a*\n\n</div>", &danger)?,
+        "<div>\n<p><em>a</em></p>\n</div>",
+        "should support markdown between basic (type 6) tags, w/ blank lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("<custom-elem>\n*a*\n\n*b*", &danger)?,
+        "<custom-elem>\n*a*\n<p><em>b</em></p>",
+        "should end complete (type 7) at a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("</custom-elem>\n*a*", &danger)?,
+        "</custom-elem>\n*a*",
+        "should support complete (type 7) closing tags"
+    );
+
+    assert_eq!(
+        to_html_with_options("<custom-elem a=\"b\">\n*c*\n\n*d*", &danger)?,
+        "<custom-elem a=\"b\">\n*c*\n<p><em>d</em></p>",
+        "should support complete (type 7) tags w/ attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<custom-elem>\nb", &danger)?,
+        "<p>a\n<custom-elem>\nb</p>",
+        "should not support complete (type 7) interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("<custom-elem> x\n*a*", &danger)?,
+        "<p><custom-elem> x\n<em>a</em></p>",
+        "should not support complete (type 7) w/ more on the line"
+    );
+
+    assert_eq!(
+        to_html_with_options("<custom-elem>\n\n*a*\n\n</custom-elem>", &danger)?,
+        "<custom-elem>\n<p><em>a</em></p>\n</custom-elem>",
+        "should support markdown between complete (type 7) tags, w/ blank lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("- <custom-elem>\n\n  *a*", &danger)?,
+        "<ul>\n<li>\n<custom-elem>\n<p><em>a</em></p>\n</li>\n</ul>",
+        "should end complete (type 7) at a blank line in a container"
+    );
+
+    Ok(())
+}
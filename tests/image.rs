@@ -5,6 +5,7 @@ use markdown::{
     CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
+use std::sync::Arc;
 
 #[test]
 fn image() -> Result<(), String> {
@@ -214,6 +215,65 @@ fn image() -> Result<(), String> {
         "should support turning off label start (image)"
     );
 
+    let lazy = Options {
+        compile: CompileOptions {
+            image_attributes: Some(Arc::new(|_image| {
+                vec![
+                    ("loading".into(), "lazy".into()),
+                    ("decoding".into(), "async".into()),
+                ]
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("![a](b) ![c][d]\n\n[d]: e 'f'", &lazy)?,
+        "<p><img src=\"b\" alt=\"a\" loading=\"lazy\" decoding=\"async\" /> <img src=\"e\" alt=\"c\" title=\"f\" loading=\"lazy\" decoding=\"async\" /></p>\n",
+        "should support `image_attributes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![a](b)](c)", &lazy)?,
+        "<p><a href=\"c\"><img src=\"b\" alt=\"a\" loading=\"lazy\" decoding=\"async\" /></a></p>",
+        "should support `image_attributes` for images in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ![b](c)](d)", &lazy)?,
+        "<p><img src=\"d\" alt=\"a b\" loading=\"lazy\" decoding=\"async\" /></p>",
+        "should not call `image_attributes` for images in images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a *b*](c&amp;d \"100x50\")",
+            &Options {
+                compile: CompileOptions {
+                    image_attributes: Some(Arc::new(|image| {
+                        let mut attributes = vec![
+                            ("src".into(), "x".into()),
+                            ("ALT".into(), "x".into()),
+                            ("data-src".into(), image.src.clone()),
+                            ("data-alt".into(), image.alt.clone()),
+                        ];
+                        if let Some(title) = &image.title {
+                            let mut parts = title.split('x');
+                            attributes.push(("width".into(), parts.next().unwrap().into()));
+                            attributes.push(("height".into(), parts.next().unwrap().into()));
+                        }
+                        attributes
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"c&amp;d\" alt=\"a b\" title=\"100x50\" data-src=\"c&amp;amp;d\" data-alt=\"a b\" width=\"100\" height=\"50\" /></p>",
+        "should pass image info to `image_attributes`, and not override `src`, `alt`, or `title`"
+    );
+
     assert_eq!(
         to_html("![](javascript:alert(1))"),
         "<p><img src=\"\" alt=\"\" /></p>",
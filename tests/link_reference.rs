@@ -1,8 +1,10 @@
 use markdown::{
-    mdast::{Definition, LinkReference, Node, Paragraph, ReferenceKind, Root, Text},
-    to_html, to_html_with_options, to_mdast,
+    mdast::{
+        Definition, ImageReference, LinkReference, Node, Paragraph, ReferenceKind, Root, Text,
+    },
+    to_html, to_html_with_options, to_mdast, tokenize,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, ContentType, EventKind, EventName, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -29,6 +31,18 @@ fn link_reference() -> Result<(), String> {
         "should support balanced brackets in link references"
     );
 
+    assert_eq!(
+        to_html("[ref]: /uri\n\n[a [b] c][ref]"),
+        "<p><a href=\"/uri\">a [b] c</a></p>",
+        "should support balanced brackets that are not a link in link references"
+    );
+
+    assert_eq!(
+        to_html("[a [b] c]\n\n[a [b] c]: /uri"),
+        "<p>[a [b] c]</p>\n<p>[a [b] c]: /uri</p>",
+        "should not support balanced brackets in a reference label"
+    );
+
     assert_eq!(
         to_html("[ref]: /uri\n\n[link \\[bar][ref]"),
         "<p><a href=\"/uri\">link [bar</a></p>",
@@ -107,6 +121,48 @@ fn link_reference() -> Result<(), String> {
         "should match references to definitions w/ collapsing"
     );
 
+    assert_eq!(
+        to_html("[ref label]: /url\n\n[text][ref\nlabel]"),
+        "<p><a href=\"/url\">text</a></p>",
+        "should match references spanning lines to definitions"
+    );
+
+    assert_eq!(
+        to_html("[ref label]: /url\r\n\r\n[text][ref\r\nlabel]"),
+        "<p><a href=\"/url\">text</a></p>",
+        "should match references spanning lines (CRLF)"
+    );
+
+    assert_eq!(
+        to_html("[ref label]: /url\r\r[text][ref\rlabel]"),
+        "<p><a href=\"/url\">text</a></p>",
+        "should match references spanning lines (CR)"
+    );
+
+    assert_eq!(
+        to_html("[ref label]: /url\n\n[text][ref\n  label]"),
+        "<p><a href=\"/url\">text</a></p>",
+        "should match references spanning lines w/ indent"
+    );
+
+    assert_eq!(
+        to_html("[ref label]: /url\n\n* [text][ref\n  label]"),
+        "<ul>\n<li><a href=\"/url\">text</a></li>\n</ul>",
+        "should match references spanning lines in list items"
+    );
+
+    assert_eq!(
+        to_html("[b c]: d\n\n[a][b  \nc]"),
+        "<p><a href=\"d\">a</a></p>",
+        "should match references spanning lines w/ trailing whitespace"
+    );
+
+    assert_eq!(
+        to_html("[b c]: d\n\n[b  \nc]"),
+        "<p><a href=\"d\">b<br />\nc</a></p>",
+        "should match shortcut references w/ a hard break"
+    );
+
     assert_eq!(
         to_html("[bar]: /url \"title\"\n\n[foo] [bar]"),
         "<p>[foo] <a href=\"/url\" title=\"title\">bar</a></p>",
@@ -161,6 +217,36 @@ fn link_reference() -> Result<(), String> {
         "should support escapes"
     );
 
+    assert_eq!(
+        to_html("[a\\]b]: /uri\n\n[a\\]b] [a\\]b][] [c][a\\]b]"),
+        "<p><a href=\"/uri\">a]b</a> <a href=\"/uri\">a]b</a> <a href=\"/uri\">c</a></p>",
+        "should support escaped closing brackets in definitions and references"
+    );
+
+    assert_eq!(
+        to_html("[a\\]b]\n\n[a\\]b]: /uri"),
+        "<p><a href=\"/uri\">a]b</a></p>\n",
+        "should support escaped closing brackets in references before definitions"
+    );
+
+    assert_eq!(
+        to_html("[a\\[b]: /uri\n\n[a\\[b] [c][A\\[B]"),
+        "<p><a href=\"/uri\">a[b</a> <a href=\"/uri\">c</a></p>",
+        "should support escaped opening brackets in definitions and references"
+    );
+
+    assert_eq!(
+        to_html("[a\\]b]: /uri\n\n[a]b]"),
+        "<p>[a]b]</p>",
+        "should not match references w/o escapes to definitions w/ escaped brackets"
+    );
+
+    assert_eq!(
+        to_html("[a\\*b]: /uri\n\n[a*b] [a\\*b]"),
+        "<p>[a*b] <a href=\"/uri\">a*b</a></p>",
+        "should match the escapes themselves, not what they escape (as the spec says)"
+    );
+
     assert_eq!(
         to_html("[]: /uri\n\n[]"),
         "<p>[]: /uri</p>\n<p>[]</p>",
@@ -493,5 +579,86 @@ fn link_reference() -> Result<(), String> {
         "should support link (reference) as `LinkReference`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("[a]: c\n\n[A] ![A][] [b][ A ]", &Default::default())?
+            .children()
+            .unwrap()[1],
+        Node::Paragraph(Paragraph {
+            children: vec![
+                Node::LinkReference(LinkReference {
+                    reference_kind: ReferenceKind::Shortcut,
+                    identifier: "a".into(),
+                    label: Some("A".into()),
+                    children: vec![Node::Text(Text {
+                        value: "A".into(),
+                        position: Some(Position::new(3, 2, 9, 3, 3, 10))
+                    })],
+                    position: Some(Position::new(3, 1, 8, 3, 4, 11))
+                }),
+                Node::Text(Text {
+                    value: " ".into(),
+                    position: Some(Position::new(3, 4, 11, 3, 5, 12))
+                }),
+                Node::ImageReference(ImageReference {
+                    reference_kind: ReferenceKind::Collapsed,
+                    identifier: "a".into(),
+                    label: Some("A".into()),
+                    alt: "A".into(),
+                    position: Some(Position::new(3, 5, 12, 3, 11, 18))
+                }),
+                Node::Text(Text {
+                    value: " ".into(),
+                    position: Some(Position::new(3, 11, 18, 3, 12, 19))
+                }),
+                Node::LinkReference(LinkReference {
+                    reference_kind: ReferenceKind::Full,
+                    identifier: "a".into(),
+                    label: Some(" A ".into()),
+                    children: vec![Node::Text(Text {
+                        value: "b".into(),
+                        position: Some(Position::new(3, 13, 20, 3, 14, 21))
+                    })],
+                    position: Some(Position::new(3, 12, 19, 3, 20, 27))
+                }),
+            ],
+            position: Some(Position::new(3, 1, 8, 3, 20, 27))
+        }),
+        "should support the kind, normalized identifier, and source label of references in mdast"
+    );
+
+    assert_eq!(
+        to_html(&"a [b] [c](d) ![e] [f][g] *h [i* j]\n\n[g]: k\n\n".repeat(3)),
+        "<p>a [b] <a href=\"d\">c</a> ![e] <a href=\"k\">f</a> <em>h [i</em> j]</p>\n".repeat(3),
+        "should support many unmatched and matched labels next to each other"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn link_reference_data() -> Result<(), String> {
+    let events = tokenize(
+        &"a [b] [c](d) ![e] [f][g] *h [i* j]\n\n[g]: k\n\n".repeat(3),
+        &ContentType::Flow,
+        &ParseOptions::default(),
+    )?;
+    let mut index = 1;
+
+    while index < events.len() {
+        let previous = &events[index - 1];
+        let event = &events[index];
+
+        assert!(
+            !(previous.kind == EventKind::Exit
+                && previous.name == EventName::Data
+                && event.kind == EventKind::Enter
+                && event.name == EventName::Data),
+            "should not have adjacent data around labels (at {:?})",
+            event.point
+        );
+
+        index += 1;
+    }
+
     Ok(())
 }
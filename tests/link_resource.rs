@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Link, Node, Paragraph, Root, Text},
+    mdast::{Image, Link, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Options,
@@ -221,6 +221,48 @@ fn link_resource() -> Result<(), String> {
         "should support balanced brackets"
     );
 
+    assert_eq!(
+        to_html("[a [b] c](url)"),
+        "<p><a href=\"url\">a [b] c</a></p>",
+        "should support balanced brackets that are not a link in link text"
+    );
+
+    assert_eq!(
+        to_html("[see [1]](url)"),
+        "<p><a href=\"url\">see [1]</a></p>",
+        "should support balanced brackets at the end of link text"
+    );
+
+    assert_eq!(
+        to_html("[[1]](url)"),
+        "<p><a href=\"url\">[1]</a></p>",
+        "should support balanced brackets as the whole link text"
+    );
+
+    assert_eq!(
+        to_html("[a [b]\nc](url)"),
+        "<p><a href=\"url\">a [b]\nc</a></p>",
+        "should support balanced brackets before a line ending in link text"
+    );
+
+    assert_eq!(
+        to_html("[a [b] c](url)\n\n[b]: x"),
+        "<p>[a <a href=\"x\">b</a> c](url)</p>\n",
+        "should not support balanced brackets that form a reference in link text"
+    );
+
+    assert_eq!(
+        to_html("[a [b](c) d](e)"),
+        "<p>[a <a href=\"c\">b</a> d](e)</p>",
+        "should not support balanced brackets that form a link in link text"
+    );
+
+    assert_eq!(
+        to_html("[a ![b](c) d](e)"),
+        "<p><a href=\"e\">a <img src=\"c\" alt=\"b\" /> d</a></p>",
+        "should support images in link text"
+    );
+
     assert_eq!(
         to_html("[link] bar](/uri)"),
         "<p>[link] bar](/uri)</p>",
@@ -251,6 +293,24 @@ fn link_resource() -> Result<(), String> {
         "should support an image as content"
     );
 
+    assert_eq!(
+        to_html("[![a](i)](u)"),
+        "<p><a href=\"u\"><img src=\"i\" alt=\"a\" /></a></p>",
+        "should support an image as content (minimal)"
+    );
+
+    assert_eq!(
+        to_html("[![a](i) b ![c](j)](u)"),
+        "<p><a href=\"u\"><img src=\"i\" alt=\"a\" /> b <img src=\"j\" alt=\"c\" /></a></p>",
+        "should support several images and text as content"
+    );
+
+    assert_eq!(
+        to_html("[![a][b]](u)\n\n[b]: i"),
+        "<p><a href=\"u\"><img src=\"i\" alt=\"a\" /></a></p>\n",
+        "should support an image (reference) as content"
+    );
+
     assert_eq!(
         to_html("[foo [bar](/uri)](/uri)"),
         "<p>[foo <a href=\"/uri\">bar</a>](/uri)</p>",
@@ -480,6 +540,7 @@ fn link_resource() -> Result<(), String> {
                     Node::Link(Link {
                         url: String::new(),
                         title: None,
+                        autolink: false,
                         children: vec![Node::Text(Text {
                             value: "alpha".into(),
                             position: Some(Position::new(1, 4, 3, 1, 9, 8))
@@ -493,6 +554,7 @@ fn link_resource() -> Result<(), String> {
                     Node::Link(Link {
                         url: "charlie".into(),
                         title: Some("delta".into()),
+                        autolink: false,
                         children: vec![Node::Text(Text {
                             value: "bravo".into(),
                             position: Some(Position::new(1, 16, 15, 1, 21, 20))
@@ -511,5 +573,133 @@ fn link_resource() -> Result<(), String> {
         "should support link (resource) as `Link`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("[![a](i)](u)", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Link(Link {
+                    url: "u".into(),
+                    title: None,
+                    autolink: false,
+                    children: vec![Node::Image(Image {
+                        alt: "a".into(),
+                        url: "i".into(),
+                        title: None,
+                        position: Some(Position::new(1, 2, 1, 1, 9, 8))
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+        }),
+        "should support an image in a link in mdast"
+    );
+
+    assert_eq!(
+        to_html("[a](b \"c\\\\\") [d](e 'f\\\\') [g](h (i\\\\))"),
+        "<p><a href=\"b\" title=\"c\\\">a</a> <a href=\"e\" title=\"f\\\">d</a> <a href=\"h\" title=\"i\\\">g</a></p>",
+        "should support an escaped backslash before the closing delimiter of a title"
+    );
+
+    assert_eq!(
+        to_html("[a]() ![b]()"),
+        "<p><a href=\"\">a</a> <img src=\"\" alt=\"b\" /></p>",
+        "should support an empty resource"
+    );
+
+    assert_eq!(
+        to_html("[a]( ) [b](\t) [c](\n)"),
+        "<p><a href=\"\">a</a> <a href=\"\">b</a> <a href=\"\">c</a></p>",
+        "should support a resource w/ only whitespace"
+    );
+
+    assert_eq!(
+        to_html("[a]( \"t\") [b]( 't') [c]( (t))"),
+        "<p><a href=\"%22t%22\">a</a> <a href=\"'t'\">b</a> <a href=\"(t)\">c</a></p>",
+        "should not support a title w/o destination (the title is a raw destination)"
+    );
+
+    assert_eq!(
+        to_html("[a](<> \"t\")"),
+        "<p><a href=\"\" title=\"t\">a</a></p>",
+        "should support a title w/ an empty enclosed destination"
+    );
+
+    assert_eq!(
+        to_mdast("[a]( )", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Link(Link {
+                    url: String::new(),
+                    title: None,
+                    autolink: false,
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 2, 1, 1, 3, 2))
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 7, 6))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 7, 6))
+        }),
+        "should support a resource w/ only whitespace in mdast"
+    );
+
+    assert_eq!(
+        to_html("[a](bad [b](good) text"),
+        "<p>[a](bad <a href=\"good\">b</a> text</p>",
+        "should support a link after a failed resource (unclosed)"
+    );
+
+    assert_eq!(
+        to_html("[a](bad\n[b](good)"),
+        "<p>[a](bad\n<a href=\"good\">b</a></p>",
+        "should support a link after a failed resource (unclosed, line ending)"
+    );
+
+    assert_eq!(
+        to_html("[a](bad [b](good) [c](bad"),
+        "<p>[a](bad <a href=\"good\">b</a> [c](bad</p>",
+        "should support a link between failed resources"
+    );
+
+    assert_eq!(
+        to_html("[a](b c) [d](e)"),
+        "<p>[a](b c) <a href=\"e\">d</a></p>",
+        "should support a link after a failed resource (whitespace in destination)"
+    );
+
+    assert_eq!(
+        to_html("[a](<b) [c](d)"),
+        "<p>[a](&lt;b) <a href=\"d\">c</a></p>",
+        "should support a link after a failed resource (unclosed enclosed destination)"
+    );
+
+    assert_eq!(
+        to_html("[a](b 'c) [d](e)"),
+        "<p>[a](b 'c) <a href=\"e\">d</a></p>",
+        "should support a link after a failed resource (unclosed title)"
+    );
+
+    assert_eq!(
+        to_html("[a](b(c) [d](e)"),
+        "<p>[a](b(c) <a href=\"e\">d</a></p>",
+        "should support a link after a failed resource (unbalanced parens)"
+    );
+
+    assert_eq!(
+        to_html("![a](bad ![b](good) c"),
+        "<p>![a](bad <img src=\"good\" alt=\"b\" /> c</p>",
+        "should support an image after a failed resource"
+    );
+
+    assert_eq!(
+        to_html("[[a](b) [c](d"),
+        "<p>[<a href=\"b\">a</a> [c](d</p>",
+        "should support a failed resource after a link in brackets"
+    );
+
     Ok(())
 }
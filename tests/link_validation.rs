@@ -0,0 +1,112 @@
+use markdown::{unist::Position, validate_links, CompileOptions, LinkValidation, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn link_validation() -> Result<(), String> {
+    assert_eq!(
+        validate_links(
+            "[a](https://example.com) [b](javascript:alert(1))",
+            &Options::default()
+        )?,
+        vec![
+            LinkValidation {
+                image: false,
+                position: Position::new(1, 1, 0, 1, 25, 24),
+                destination: "https://example.com".into(),
+                sanitized: Ok("https://example.com".into())
+            },
+            LinkValidation {
+                image: false,
+                position: Position::new(1, 26, 25, 1, 50, 49),
+                destination: "javascript:alert(1)".into(),
+                sanitized: Err(
+                    "Unexpected protocol `javascript` in link, which is not allowed".into()
+                )
+            }
+        ],
+        "should keep safe links and drop dangerous ones"
+    );
+
+    assert_eq!(
+        validate_links("[a](<b c>) [d](#e) [f]()", &Options::default())?
+            .iter()
+            .map(|d| d.sanitized.clone())
+            .collect::<Vec<_>>(),
+        vec![Ok("b%20c".into()), Ok("#e".into()), Ok(String::new())],
+        "should keep relative links (encoded)"
+    );
+
+    assert_eq!(
+        validate_links("[a]\n\n[a]: JAVASCRIPT:alert(1)", &Options::default())?[0].sanitized,
+        Err("Unexpected protocol `javascript` in link, which is not allowed".into()),
+        "should check the destination of definitions for references"
+    );
+
+    assert_eq!(
+        validate_links("[a](java&#x73;cript:alert(1))", &Options::default())?[0],
+        LinkValidation {
+            image: false,
+            position: Position::new(1, 1, 0, 1, 30, 29),
+            destination: "javascript:alert(1)".into(),
+            sanitized: Err("Unexpected protocol `javascript` in link, which is not allowed".into())
+        },
+        "should check decoded destinations"
+    );
+
+    assert_eq!(
+        validate_links("![a](mailto:b) [c](mailto:d)", &Options::default())?
+            .iter()
+            .map(|d| (d.image, d.sanitized.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                true,
+                Err("Unexpected protocol `mailto` in image, which is not allowed".into())
+            ),
+            (false, Ok("mailto:d".into()))
+        ],
+        "should use fewer protocols for images"
+    );
+
+    assert_eq!(
+        validate_links(
+            "[a](javascript:alert(1))",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?[0]
+            .sanitized,
+        Ok("javascript:alert(1)".into()),
+        "should support `allow_dangerous_protocol`"
+    );
+
+    assert_eq!(
+        validate_links(
+            "[a](mailto:b) [c](https://d)",
+            &Options {
+                compile: CompileOptions {
+                    link_protocols: Some(vec!["https".into()]),
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?
+        .iter()
+        .map(|d| d.sanitized.is_ok())
+        .collect::<Vec<_>>(),
+        vec![false, true],
+        "should support `link_protocols`"
+    );
+
+    assert_eq!(
+        validate_links("<javascript:alert(1)> [a] [b]: c", &Options::default())?,
+        vec![],
+        "should not include autolinks or things that are not links"
+    );
+
+    Ok(())
+}
@@ -204,6 +204,48 @@ fn list() -> Result<(), String> {
         "should support a single empty item"
     );
 
+    assert_eq!(
+        to_html("-\n- a"),
+        "<ul>\n<li></li>\n<li>a</li>\n</ul>",
+        "should support an empty first item, w/o loosening the list"
+    );
+
+    assert_eq!(
+        to_html("1.\n2. a"),
+        "<ol>\n<li></li>\n<li>a</li>\n</ol>",
+        "should support an empty first item (ordered)"
+    );
+
+    assert_eq!(
+        to_html("2.\n3. a"),
+        "<ol start=\"2\">\n<li></li>\n<li>a</li>\n</ol>",
+        "should support an empty first item w/ a start (ordered)"
+    );
+
+    assert_eq!(
+        to_html("-\n-\n-"),
+        "<ul>\n<li></li>\n<li></li>\n<li></li>\n</ul>",
+        "should support only empty items"
+    );
+
+    assert_eq!(
+        to_html("- a\n-"),
+        "<ul>\n<li>a</li>\n<li></li>\n</ul>",
+        "should support an empty last item"
+    );
+
+    assert_eq!(
+        to_html("-\n  a\n- b"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>",
+        "should support content after a bare marker in the first item"
+    );
+
+    assert_eq!(
+        to_html("-\n\n- a"),
+        "<ul>\n<li></li>\n<li>\n<p>a</p>\n</li>\n</ul>",
+        "should support an empty first item in a loose list"
+    );
+
     assert_eq!(
         to_html("foo\n*\n\nfoo\n1."),
         "<p>foo\n*</p>\n<p>foo\n1.</p>",
@@ -329,6 +371,56 @@ fn list() -> Result<(), String> {
         "should support a new list by changing the marker (ordered)"
     );
 
+    let loose_marker = Options {
+        parse: ParseOptions {
+            loose_list_marker_change: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("- a\n* b"),
+        "<ul>\n<li>a</li>\n</ul>\n<ul>\n<li>b</li>\n</ul>",
+        "should support a new list by changing the bullet"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n* b", &loose_marker)?,
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>",
+        "should continue a list when changing the bullet w/ `loose_list_marker_change`"
+    );
+
+    assert_eq!(
+        to_html_with_options("- foo\n- bar\n+ baz", &loose_marker)?,
+        "<ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n</ul>",
+        "should continue a list when changing the marker (unordered) w/ `loose_list_marker_change`"
+    );
+
+    assert_eq!(
+        to_html_with_options("1. foo\n2. bar\n3) baz", &loose_marker)?,
+        "<ol>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n</ol>",
+        "should continue a list when changing the marker (ordered) w/ `loose_list_marker_change`"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n1. b", &loose_marker)?,
+        "<ul>\n<li>a</li>\n</ul>\n<ol>\n<li>b</li>\n</ol>",
+        "should still support a new list when switching between ordered and unordered w/ `loose_list_marker_change`"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n\n* b", &loose_marker)?,
+        "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ul>",
+        "should continue a loose list when changing the bullet w/ `loose_list_marker_change`"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  * b\n- c", &loose_marker)?,
+        "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n<li>c</li>\n</ul>",
+        "should not merge nested lists w/ `loose_list_marker_change`"
+    );
+
     assert_eq!(
         to_html("Foo\n- bar\n- baz"),
         "<p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>",
@@ -571,6 +663,42 @@ fn list() -> Result<(), String> {
         "should support the common list breaking comment method"
     );
 
+    assert_eq!(
+        to_html("100. item\n     continuation"),
+        "<ol start=\"100\">\n<li>item\ncontinuation</li>\n</ol>",
+        "should support continuation lines indented to the content of wide markers"
+    );
+
+    assert_eq!(
+        to_html("100. item\n\n     continuation"),
+        "<ol start=\"100\">\n<li>\n<p>item</p>\n<p>continuation</p>\n</li>\n</ol>",
+        "should support continuation paragraphs indented to the content of wide markers"
+    );
+
+    assert_eq!(
+        to_html("100. item\n\n    continuation"),
+        "<ol start=\"100\">\n<li>item</li>\n</ol>\n<pre><code>continuation\n</code></pre>",
+        "should not support continuation paragraphs indented less than the content of wide markers"
+    );
+
+    assert_eq!(
+        to_html("1. item\n\n   continuation"),
+        "<ol>\n<li>\n<p>item</p>\n<p>continuation</p>\n</li>\n</ol>",
+        "should support continuation paragraphs indented to the content of narrow markers"
+    );
+
+    assert_eq!(
+        to_html("100.  item\n\n      continuation"),
+        "<ol start=\"100\">\n<li>\n<p>item</p>\n<p>continuation</p>\n</li>\n</ol>",
+        "should include the whitespace after wide markers in the content indent"
+    );
+
+    assert_eq!(
+        to_html("100. a\n     - b\n\n       c"),
+        "<ol start=\"100\">\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n</ol>",
+        "should support nested lists in wide markers"
+    );
+
     assert_eq!(
         to_html_with_options(
             "- one\n\n two",
@@ -0,0 +1,183 @@
+use markdown::{
+    extract_media, mdast::ReferenceKind, tokenize, unist::Position, ContentType,
+    DefinitionConflict, EventKind, EventName, Media, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+fn extract(value: &str, options: &ParseOptions) -> Result<Vec<Media>, String> {
+    let events = tokenize(value, &ContentType::Flow, options)?;
+    Ok(extract_media(value, &events, options))
+}
+
+#[test]
+fn media() -> Result<(), String> {
+    let value = "[a](b 'c') ![d](<e f>)";
+    let events = tokenize(value, &ContentType::Flow, &ParseOptions::default())?;
+    let result = extract_media(value, &events, &ParseOptions::default());
+
+    assert_eq!(
+        result,
+        vec![
+            Media {
+                image: false,
+                start: 1,
+                end: 42,
+                position: Position::new(1, 1, 0, 1, 11, 10),
+                reference_kind: None,
+                identifier: None,
+                destination: "b".into(),
+                title: Some("c".into())
+            },
+            Media {
+                image: true,
+                start: 45,
+                end: 80,
+                position: Position::new(1, 12, 11, 1, 23, 22),
+                reference_kind: None,
+                identifier: None,
+                destination: "e f".into(),
+                title: None
+            }
+        ],
+        "should support resources"
+    );
+
+    assert_eq!(
+        (
+            &events[result[1].start].kind,
+            &events[result[1].start].name,
+            &events[result[1].end].kind,
+            &events[result[1].end].name
+        ),
+        (
+            &EventKind::Enter,
+            &EventName::Image,
+            &EventKind::Exit,
+            &EventName::Image
+        ),
+        "should refer to the enter and exit events"
+    );
+
+    assert_eq!(
+        extract(
+            "[a][B] [b][] [B]\n\n[b]: c 'd'\n[b]: e",
+            &ParseOptions::default()
+        )?
+        .iter()
+        .map(|d| (
+            d.reference_kind,
+            d.identifier.clone(),
+            d.destination.clone(),
+            d.title.clone()
+        ))
+        .collect::<Vec<_>>(),
+        vec![
+            (
+                Some(ReferenceKind::Full),
+                Some("b".into()),
+                "c".into(),
+                Some("d".into())
+            ),
+            (
+                Some(ReferenceKind::Collapsed),
+                Some("b".into()),
+                "c".into(),
+                Some("d".into())
+            ),
+            (
+                Some(ReferenceKind::Shortcut),
+                Some("b".into()),
+                "c".into(),
+                Some("d".into())
+            ),
+        ],
+        "should support references (using the first definition)"
+    );
+
+    assert_eq!(
+        extract(
+            "[a]\n\n[a]: b\n[a]: c 'd'",
+            &ParseOptions {
+                definition_conflict: DefinitionConflict::KeepLast,
+                ..ParseOptions::default()
+            }
+        )?
+        .iter()
+        .map(|d| (d.destination.clone(), d.title.clone()))
+        .collect::<Vec<_>>(),
+        vec![("c".into(), Some("d".into()))],
+        "should support `DefinitionConflict::KeepLast`"
+    );
+
+    assert_eq!(
+        extract("[a](b\\*&amp;c \"d\ne\")", &ParseOptions::default())?[0],
+        Media {
+            image: false,
+            start: 1,
+            end: 62,
+            position: Position::new(1, 1, 0, 2, 4, 20),
+            reference_kind: None,
+            identifier: None,
+            destination: "b*&c".into(),
+            title: Some("d\ne".into())
+        },
+        "should decode character escapes and references"
+    );
+
+    assert_eq!(
+        extract("[![a](b)](c)", &ParseOptions::default())?
+            .iter()
+            .map(|d| (d.image, d.destination.clone()))
+            .collect::<Vec<_>>(),
+        vec![(false, "c".into()), (true, "b".into())],
+        "should support nested media, in the order they start"
+    );
+
+    assert_eq!(
+        extract("[a] [b](c)", &ParseOptions::default())?
+            .iter()
+            .map(|d| d.destination.clone())
+            .collect::<Vec<_>>(),
+        vec!["c"],
+        "should not include things that are not links"
+    );
+
+    let mut parse = ParseOptions::default();
+    parse
+        .external_definitions
+        .insert("A".into(), ("b".into(), Some("c".into())));
+    parse
+        .external_definitions
+        .insert("d".into(), ("e".into(), None));
+
+    assert_eq!(
+        extract("[a] [d]\n\n[d]: f", &parse)?
+            .iter()
+            .map(|d| (d.destination.clone(), d.title.clone()))
+            .collect::<Vec<_>>(),
+        vec![("b".into(), Some("c".into())), ("f".into(), None)],
+        "should support external definitions (after definitions in the document)"
+    );
+
+    let parse = ParseOptions {
+        reference_resolver: Some(Box::new(|label| {
+            if label == "a" {
+                Some(("b".into(), None))
+            } else {
+                None
+            }
+        })),
+        ..ParseOptions::default()
+    };
+
+    assert_eq!(
+        extract("[a] [c]", &parse)?
+            .iter()
+            .map(|d| (d.identifier.clone(), d.destination.clone()))
+            .collect::<Vec<_>>(),
+        vec![(Some("a".into()), "b".into())],
+        "should support `reference_resolver`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\'', "&#x27;")
+        .replace('`', "&#x60;")
+}
+
+#[test]
+fn attribute_escaper() -> Result<(), String> {
+    let backticks = Options {
+        compile: CompileOptions {
+            attribute_escaper: Some(Arc::new(escape)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[a](b \"`c` & <d>\")"),
+        "<p><a href=\"b\" title=\"`c` &amp; &lt;d&gt;\">a</a></p>",
+        "should encode titles by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"`c` & <d>\")", &backticks)?,
+        "<p><a href=\"b\" title=\"&#x60;c&#x60; &amp; &lt;d&gt;\">a</a></p>",
+        "should support `attribute_escaper` for titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"\\` &#x60; &quot; &amp;amp;\")", &backticks)?,
+        "<p><a href=\"b\" title=\"&#x60; &#x60; &quot; &amp;amp;\">a</a></p>",
+        "should pass decoded titles to `attribute_escaper`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][]\n\n[a]: b '`c`'", &backticks)?,
+        "<p><a href=\"b\" title=\"&#x60;c&#x60;\">a</a></p>\n",
+        "should support `attribute_escaper` for titles in definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a` *b* c&d \\`", &backticks)?,
+        "<p><code>a</code> <em>b</em> c&amp;d `</p>",
+        "should not affect text"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a\\`](b \"`c`\")", &backticks)?,
+        "<p><img src=\"b\" alt=\"a`\" title=\"&#x60;c&#x60;\" /></p>",
+        "should not affect the alt of images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](<b'c`d e&f>) ![g](h'i) <https://j'k>", &backticks)?,
+        "<p><a href=\"b&#x27;c%60d%20e&amp;f\">a</a> <img src=\"h&#x27;i\" alt=\"g\" /> <a href=\"https://j&#x27;k\">https://j'k</a></p>",
+        "should support `attribute_escaper` for urls (after percent-encoding)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](javascript:alert(1)) ![b](mailto:c)", &backticks)?,
+        "<p><a href=\"\">a</a> <img src=\"\" alt=\"b\" /></p>",
+        "should not call `attribute_escaper` for dropped urls"
+    );
+
+    let mut parse = ParseOptions::default();
+    parse
+        .external_definitions
+        .insert("a".into(), ("b".into(), Some("`c`".into())));
+
+    assert_eq!(
+        to_html_with_options(
+            "[a]",
+            &Options {
+                parse,
+                compile: CompileOptions {
+                    attribute_escaper: Some(Arc::new(escape)),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p><a href=\"b\" title=\"&#x60;c&#x60;\">a</a></p>",
+        "should support `attribute_escaper` for titles in external definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b \"`c`\")\n\n[d](https://e)",
+            &Options {
+                compile: CompileOptions {
+                    attribute_escaper: Some(Arc::new(escape)),
+                    image_figure: true,
+                    link_rel: Some("`f`".into()),
+                    image_attributes: Some(Arc::new(|_| vec![("loading".into(), "`g`".into())])),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<figure><img src=\"b\" alt=\"a\" title=\"&#x60;c&#x60;\" loading=\"&#x60;g&#x60;\" /><figcaption>`c`</figcaption></figure>\n<p><a href=\"https://e\" rel=\"&#x60;f&#x60;\">d</a></p>",
+        "should support `attribute_escaper` for `rel` and image attributes, but not captions"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "cache")]
+
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html_with_options, to_mdast,
+    unist::Position,
+    HtmlCache, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::{cell::Cell, rc::Rc};
+
+/// Get options that count how often documents are parsed.
+fn counting() -> (Options, Rc<Cell<usize>>) {
+    let count = Rc::new(Cell::new(0));
+    let counter = count.clone();
+    let options = Options {
+        parse: ParseOptions {
+            resolvers: vec![Box::new(move |_events, _bytes| {
+                counter.set(counter.get() + 1);
+                Ok(())
+            })],
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+    (options, count)
+}
+
+#[test]
+fn cache() -> Result<(), String> {
+    let (options, count) = counting();
+    let mut cache = HtmlCache::new(options, 2);
+
+    assert_eq!(
+        cache.get_or_render("# a *b*")?,
+        to_html_with_options("# a *b*", &Options::default())?,
+        "should compile like `to_html_with_options`"
+    );
+    assert_eq!(count.get(), 1, "should parse a new document");
+
+    assert_eq!(
+        cache.get_or_render("# a *b*")?,
+        "<h1>a <em>b</em></h1>",
+        "should get the same HTML for a cached document"
+    );
+    assert_eq!(count.get(), 1, "should not parse a cached document");
+
+    assert_eq!(
+        cache.get_or_mdast("# a *b*")?,
+        to_mdast("# a *b*", &ParseOptions::default())?,
+        "should get a syntax tree like `to_mdast`"
+    );
+    assert_eq!(
+        count.get(),
+        1,
+        "should not parse a cached document to get a syntax tree"
+    );
+
+    assert_eq!(
+        cache.get_or_mdast("c")?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "c".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 2, 1))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 2, 1))
+        }),
+        "should support getting a syntax tree of a new document"
+    );
+    assert_eq!(count.get(), 2, "should parse a new document (2)");
+
+    assert_eq!(
+        cache.get_or_render("c")?,
+        "<p>c</p>",
+        "should compile HTML from cached events"
+    );
+    assert_eq!(
+        count.get(),
+        2,
+        "should not parse a cached document to get HTML"
+    );
+    assert_eq!(cache.len(), 2, "should keep documents");
+
+    // Use `# a *b*`, so that `c` is the least recently used.
+    cache.get_or_render("# a *b*")?;
+    cache.get_or_render("d")?;
+    assert_eq!(count.get(), 3, "should parse a new document (3)");
+    assert_eq!(
+        cache.len(),
+        2,
+        "should not keep more documents than allowed"
+    );
+
+    cache.get_or_render("# a *b*")?;
+    assert_eq!(
+        count.get(),
+        3,
+        "should keep the most recently used documents"
+    );
+
+    cache.get_or_render("c")?;
+    assert_eq!(
+        count.get(),
+        4,
+        "should drop the least recently used document"
+    );
+
+    cache.clear();
+    assert!(cache.is_empty(), "should support clearing the cache");
+    cache.get_or_render("c")?;
+    assert_eq!(count.get(), 5, "should parse again after clearing");
+
+    let (options, count) = counting();
+    let mut cache = HtmlCache::new(options, 0);
+    cache.get_or_render("a")?;
+    cache.get_or_render("a")?;
+    assert_eq!(
+        count.get(),
+        2,
+        "should not keep anything w/ a capacity of 0"
+    );
+    assert!(cache.is_empty(), "should be empty w/ a capacity of 0");
+
+    let mut cache = HtmlCache::new(
+        Options {
+            parse: ParseOptions::mdx(),
+            ..Options::default()
+        },
+        2,
+    );
+    assert_eq!(
+        cache.get_or_render("<a").unwrap_err(),
+        to_html_with_options(
+            "<a",
+            &Options {
+                parse: ParseOptions::mdx(),
+                ..Options::default()
+            }
+        )
+        .unwrap_err(),
+        "should fail like `to_html_with_options`"
+    );
+    assert!(cache.is_empty(), "should not keep errors");
+
+    Ok(())
+}
+
+#[test]
+fn cache_order() -> Result<(), String> {
+    let (options, count) = counting();
+    let mut cache = HtmlCache::new(options, 3);
+
+    for value in ["a", "b", "c", "a", "d"] {
+        cache.get_or_render(value)?;
+    }
+    assert_eq!(count.get(), 4, "should parse each new document once");
+
+    // `b` was dropped for `d`, so it is parsed again, which drops `c`.
+    cache.get_or_render("b")?;
+    assert_eq!(
+        count.get(),
+        5,
+        "should drop the least recently used document"
+    );
+    cache.get_or_render("a")?;
+    cache.get_or_render("d")?;
+    assert_eq!(count.get(), 5, "should keep recently used documents");
+    cache.get_or_render("c")?;
+    assert_eq!(count.get(), 6, "should drop documents in order of use");
+    assert_eq!(cache.len(), 3, "should keep as many documents as allowed");
+
+    let mut cache = HtmlCache::new(
+        Options {
+            parse: ParseOptions {
+                reference_resolver: Some(Box::new(|label| Some((format!("/{}", label), None)))),
+                ..ParseOptions::default()
+            },
+            ..Options::default()
+        },
+        1,
+    );
+    assert_eq!(
+        cache.get_or_render("[a]")?,
+        "<p><a href=\"/a\">a</a></p>",
+        "should support resolved references"
+    );
+
+    Ok(())
+}
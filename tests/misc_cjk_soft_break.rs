@@ -0,0 +1,95 @@
+use markdown::{to_html, to_html_with_options, CjkSoftBreak, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn cjk_soft_break() -> Result<(), String> {
+    let wbr = Options {
+        compile: CompileOptions {
+            cjk_soft_break: CjkSoftBreak::Wbr,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let remove = Options {
+        compile: CompileOptions {
+            cjk_soft_break: CjkSoftBreak::Remove,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("中文\n文本"),
+        "<p>中文\n文本</p>",
+        "should keep line endings between CJK by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文\n文本", &wbr)?,
+        "<p>中文<wbr />文本</p>",
+        "should support `Wbr` between Chinese characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文\n文本", &remove)?,
+        "<p>中文文本</p>",
+        "should support `Remove` between Chinese characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("これは\nテスト。\n한국어\n문장", &remove)?,
+        "<p>これはテスト。한국어문장</p>",
+        "should support kana, CJK punctuation, and hangul"
+    );
+
+    assert_eq!(
+        to_html_with_options("alpha\nbravo", &wbr)?,
+        "<p>alpha\nbravo</p>",
+        "should keep line endings between Latin characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文\nbravo\n文本", &wbr)?,
+        "<p>中文\nbravo\n文本</p>",
+        "should keep line endings between CJK and Latin characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文  \n文本", &wbr)?,
+        "<p>中文<br />\n文本</p>",
+        "should not affect hard breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文 \n  文本", &wbr)?,
+        "<p>中文<wbr />文本</p>",
+        "should ignore whitespace around the line ending"
+    );
+
+    assert_eq!(
+        to_html_with_options("*中文*\n文本", &wbr)?,
+        "<p><em>中文</em>\n文本</p>",
+        "should only look at the characters directly around the line ending"
+    );
+
+    assert_eq!(
+        to_html_with_options("> 中文\n> 文本\n\n- 中文\n  文本", &wbr)?,
+        "<blockquote>\n<p>中文<wbr />文本</p>\n</blockquote>\n<ul>\n<li>中文<wbr />文本</li>\n</ul>",
+        "should support containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文\n\n文本", &wbr)?,
+        "<p>中文</p>\n<p>文本</p>",
+        "should not affect line endings between blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n中文\n文本\n```", &wbr)?,
+        "<pre><code>中文\n文本\n</code></pre>",
+        "should not affect code"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+#[test]
+fn code_block_renderer() -> Result<(), String> {
+    let mermaid = Options {
+        compile: CompileOptions {
+            code_block_renderer: Some(Arc::new(|language, content| {
+                if language == "mermaid" {
+                    Some(format!("<div class=\"mermaid\">{}</div>", content))
+                } else {
+                    None
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let debug = Options {
+        compile: CompileOptions {
+            code_block_renderer: Some(Arc::new(|language, content| {
+                Some(format!("[{:?} {:?}]", language, content))
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("```mermaid\na --> b\n```"),
+        "<pre><code class=\"language-mermaid\">a --&gt; b\n</code></pre>",
+        "should compile code as normal by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```mermaid\ngraph TD\n  a --> b\n```", &mermaid)?,
+        "<div class=\"mermaid\">graph TD\n  a --> b</div>",
+        "should support rendering code with a function"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust\nlet a = 1 > 0;\n```", &mermaid)?,
+        "<pre><code class=\"language-rust\">let a = 1 &gt; 0;\n</code></pre>",
+        "should compile code as normal if the function returns `None`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n```mermaid\nb\n```\nc", &mermaid)?,
+        "<p>a</p>\n<div class=\"mermaid\">b</div>\n<p>c</p>",
+        "should support rendered code between other blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ```mermaid\n> b\n\nc", &mermaid)?,
+        "<blockquote>\n<div class=\"mermaid\">b</div>\n</blockquote>\n<p>c</p>",
+        "should support rendered code in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n```", &debug)?,
+        "[\"\" \"a\"]",
+        "should pass an empty language if there is no info"
+    );
+
+    assert_eq!(
+        to_html_with_options("```a&amp;b\\!c d e\n```", &debug)?,
+        "[\"a&b!c\" \"\"]",
+        "should pass the info (w/o meta), w/ character escapes and references"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n\n  a\n\n\tb\n\n```", &debug)?,
+        "[\"\" \"\\n  a\\n\\n\\tb\\n\"]",
+        "should pass the content, w/o the final line ending"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a", &debug)?,
+        "<pre><code>a\n</code></pre>",
+        "should not pass code (indented)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+#[test]
+fn code_highlighter() -> Result<(), String> {
+    let highlight = Options {
+        compile: CompileOptions {
+            code_highlighter: Some(Arc::new(|language, content| {
+                if language == "rust" {
+                    Some(
+                        content
+                            .replace('&', "&amp;")
+                            .replace('<', "&lt;")
+                            .replace('>', "&gt;")
+                            .replace("let", "<span class=\"k\">let</span>"),
+                    )
+                } else {
+                    None
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let debug = Options {
+        compile: CompileOptions {
+            code_highlighter: Some(Arc::new(|language, content| {
+                Some(format!("[{:?} {:?}]", language, content))
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("```rust\nlet a = 1 > 0;\n```"),
+        "<pre><code class=\"language-rust\">let a = 1 &gt; 0;\n</code></pre>",
+        "should encode code as normal by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust\nlet a = 1 > 0;\nlet b;\n```", &highlight)?,
+        "<pre><code class=\"language-rust\"><span class=\"k\">let</span> a = 1 &gt; 0;\n<span class=\"k\">let</span> b;\n</code></pre>",
+        "should support highlighting code with a function"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\nlet a = 1 > 0;\n```", &highlight)?,
+        "<pre><code class=\"language-js\">let a = 1 &gt; 0;\n</code></pre>",
+        "should encode code as normal if the function returns `None`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```a&amp;b c\n<d> &amp;\n```", &debug)?,
+        "<pre><code class=\"language-a&amp;b\">[\"a&b\" \"<d> &amp;\"]\n</code></pre>",
+        "should pass the language (decoded) and content (not encoded), and not encode the result"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n\n```", &debug)?,
+        "<pre><code>[\"\" \"a\\n\"]\n</code></pre>",
+        "should pass an empty language, and keep trailing blank lines in the content"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust\n```", &debug)?,
+        "<pre><code class=\"language-rust\">[\"rust\" \"\"]</code></pre>",
+        "should support empty code"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust\r\nlet a;\r\n```\r\nb", &highlight)?,
+        "<pre><code class=\"language-rust\"><span class=\"k\">let</span> a;\r\n</code></pre>\r\n<p>b</p>",
+        "should keep the final line ending (CRLF)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ```rust\n> let a;\n\nb", &highlight)?,
+        "<blockquote>\n<pre><code class=\"language-rust\"><span class=\"k\">let</span> a;\n</code></pre>\n</blockquote>\n<p>b</p>",
+        "should support highlighted code in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("    let a;", &highlight)?,
+        "<pre><code>let a;\n</code></pre>",
+        "should not highlight code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$$\na\n$$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: markdown::Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    code_highlighter: Some(Arc::new(|_, _| Some("b".into()))),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<pre><code class=\"language-math math-display\">a\n</code></pre>",
+        "should not highlight math (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```rust\nlet a;\nlet b;\n```",
+            &Options {
+                compile: CompileOptions {
+                    code_line_numbers: true,
+                    code_highlighter: Some(Arc::new(|_, content| {
+                        Some(content.replace("let", "<b>let</b>"))
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre><code class=\"language-rust\"><span class=\"line\"><b>let</b> a;</span>\n<span class=\"line\"><b>let</b> b;</span>\n</code></pre>",
+        "should support `code_line_numbers` w/ highlighted code"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```mermaid\na\n```\n```rust\nlet b;\n```",
+            &Options {
+                compile: CompileOptions {
+                    code_block_renderer: Some(Arc::new(|language, content| {
+                        if language == "mermaid" {
+                            Some(format!("<div class=\"mermaid\">{}</div>", content))
+                        } else {
+                            None
+                        }
+                    })),
+                    code_highlighter: Some(Arc::new(|_, content| {
+                        Some(content.replace("let", "<b>let</b>"))
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div class=\"mermaid\">a</div>\n<pre><code class=\"language-rust\"><b>let</b> b;\n</code></pre>",
+        "should support `code_block_renderer` w/ highlighted code"
+    );
+
+    Ok(())
+}
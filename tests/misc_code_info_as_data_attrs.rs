@@ -0,0 +1,81 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+#[test]
+fn code_info_as_data_attrs() -> Result<(), String> {
+    let attrs = Options {
+        compile: CompileOptions {
+            code_info_as_data_attrs: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("```rust,no_run,edition2021\na\n```"),
+        "<pre><code class=\"language-rust,no_run,edition2021\">a\n</code></pre>",
+        "should use the whole info as the language by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust,no_run,edition2021\na\n```", &attrs)?,
+        "<pre><code class=\"language-rust\" data-no_run=\"\" data-edition2021=\"\">a\n</code></pre>",
+        "should turn comma-separated items after the language into `data-*` attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust\na\n```", &attrs)?,
+        "<pre><code class=\"language-rust\">a\n</code></pre>",
+        "should support a language without further items"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust,no_run title=\"a,b\"\na\n```", &attrs)?,
+        "<pre><code class=\"language-rust\" data-no_run=\"\">a\n</code></pre>",
+        "should ignore the meta"
+    );
+
+    assert_eq!(
+        to_html_with_options("```,no_run\na\n```", &attrs)?,
+        "<pre><code data-no_run=\"\">a\n</code></pre>",
+        "should support items without a language"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust,,a-b,,\na\n```", &attrs)?,
+        "<pre><code class=\"language-rust\" data-a-b=\"\">a\n</code></pre>",
+        "should ignore empty items"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust,a&amp;b,c=d,e\\\"f,g\na\n```", &attrs)?,
+        "<pre><code class=\"language-rust\" data-g=\"\">a\n</code></pre>",
+        "should ignore items with other characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~~js,a\nb\n~~~\n\n    c", &attrs)?,
+        "<pre><code class=\"language-js\" data-a=\"\">b\n</code></pre>\n<pre><code>c\n</code></pre>",
+        "should support tildes, and not affect indented code"
+    );
+
+    let highlight = Options {
+        compile: CompileOptions {
+            code_info_as_data_attrs: true,
+            code_highlighter: Some(Arc::new(|language, value| {
+                Some(format!("<b>{}</b>:{}", language, value))
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("```rust,no_run\na\n```", &highlight)?,
+        "<pre><code class=\"language-rust\" data-no_run=\"\"><b>rust</b>:a\n</code></pre>",
+        "should pass the language without further items to a code highlighter"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn code_line_numbers() -> Result<(), String> {
+    let lines = Options {
+        compile: CompileOptions {
+            code_line_numbers: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("```js\na\n\nb\n```"),
+        "<pre><code class=\"language-js\">a\n\nb\n</code></pre>",
+        "should not wrap lines of code by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\na\n\nb\n```", &lines)?,
+        "<pre><code class=\"language-js\"><span class=\"line\">a</span>\n<span class=\"line\"></span>\n<span class=\"line\">b</span>\n</code></pre>",
+        "should wrap lines of code (fenced) w/ `code_line_numbers`"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a\n     b\n\n    c", &lines)?,
+        "<pre><code><span class=\"line\">a</span>\n<span class=\"line\"> b</span>\n<span class=\"line\"></span>\n<span class=\"line\">c</span>\n</code></pre>",
+        "should wrap lines of code (indented) w/ `code_line_numbers`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n```", &lines)?,
+        "<pre><code><span class=\"line\">a</span>\n</code></pre>",
+        "should wrap a single line of code w/ `code_line_numbers`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n```", &lines)?,
+        "<pre><code></code></pre>",
+        "should not wrap empty code w/ `code_line_numbers`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\r\nb\r\n```", &lines)?,
+        "<pre><code><span class=\"line\">a</span>\r\n<span class=\"line\">b</span>\r\n</code></pre>",
+        "should support CRLF line endings w/ `code_line_numbers`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ```\n> a\n\nb", &lines)?,
+        "<blockquote>\n<pre><code><span class=\"line\">a</span>\n</code></pre>\n</blockquote>\n<p>b</p>",
+        "should support unclosed code in containers w/ `code_line_numbers`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$$\na\n$$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    code_line_numbers: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<pre><code class=\"language-math math-display\">a\n</code></pre>",
+        "should not wrap lines of math (flow) w/ `code_line_numbers`"
+    );
+
+    Ok(())
+}
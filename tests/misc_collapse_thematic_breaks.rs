@@ -0,0 +1,57 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn collapse_thematic_breaks() -> Result<(), String> {
+    let collapse = Options {
+        compile: CompileOptions {
+            collapse_thematic_breaks: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("***\n***\n***"),
+        "<hr />\n<hr />\n<hr />",
+        "should not collapse thematic breaks by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n***\n---\n___\n\nb", &collapse)?,
+        "<p>a</p>\n<hr />\n<p>b</p>",
+        "should collapse three consecutive thematic breaks into one"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n***\n\n\n   * * *\n\nb", &collapse)?,
+        "<p>a</p>\n<hr />\n<p>b</p>",
+        "should collapse thematic breaks separated by blank lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("***\na\n***", &collapse)?,
+        "<hr />\n<p>a</p>\n<hr />",
+        "should not collapse thematic breaks separated by content"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ***\n> ***\n>\n> ***", &collapse)?,
+        "<blockquote>\n<hr />\n</blockquote>",
+        "should collapse thematic breaks in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ***\n\n***", &collapse)?,
+        "<blockquote>\n<hr />\n</blockquote>\n<hr />",
+        "should not collapse thematic breaks in different containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("- ***\n- ***", &collapse)?,
+        "<ul>\n<li>\n<hr />\n</li>\n<li>\n<hr />\n</li>\n</ul>",
+        "should not collapse thematic breaks in different list items"
+    );
+
+    Ok(())
+}
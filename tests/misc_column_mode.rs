@@ -0,0 +1,171 @@
+use markdown::{
+    mdast::Node, to_html_with_options, to_mdast, unist::Position, ColumnMode, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the position of the first emphasis, in the first paragraph, which
+/// may be in containers.
+fn emphasis(value: &str, column_mode: ColumnMode) -> Result<Position, String> {
+    let tree = to_mdast(
+        value,
+        &ParseOptions {
+            column_mode,
+            ..Default::default()
+        },
+    )?;
+    let mut node = &tree;
+
+    loop {
+        let children = node.children().expect("expected parent");
+
+        if let Some(emphasis) = children
+            .iter()
+            .find(|node| matches!(node, Node::Emphasis(_)))
+        {
+            return Ok(emphasis.position().unwrap().clone());
+        }
+
+        node = &children[0];
+    }
+}
+
+#[test]
+fn column_mode() -> Result<(), String> {
+    assert_eq!(
+        emphasis("a *b*", ColumnMode::default())?,
+        Position::new(1, 3, 2, 1, 6, 5),
+        "should count bytes by default (ascii)"
+    );
+
+    assert_eq!(
+        emphasis("😀 *b*", ColumnMode::Utf8CodeUnit)?,
+        Position::new(1, 6, 5, 1, 9, 8),
+        "should count bytes (astral)"
+    );
+
+    assert_eq!(
+        emphasis("😀 *b*", ColumnMode::Utf16CodeUnit)?,
+        Position::new(1, 4, 5, 1, 7, 8),
+        "should count UTF-16 code units (astral)"
+    );
+
+    assert_eq!(
+        emphasis("😀 *b*", ColumnMode::Grapheme)?,
+        Position::new(1, 3, 5, 1, 6, 8),
+        "should count graphemes (astral)"
+    );
+
+    assert_eq!(
+        emphasis("e\u{301} *b*", ColumnMode::Utf8CodeUnit)?,
+        Position::new(1, 5, 4, 1, 8, 7),
+        "should count bytes (combining mark)"
+    );
+
+    assert_eq!(
+        emphasis("e\u{301} *b*", ColumnMode::Utf16CodeUnit)?,
+        Position::new(1, 4, 4, 1, 7, 7),
+        "should count UTF-16 code units (combining mark)"
+    );
+
+    assert_eq!(
+        emphasis("e\u{301} *b*", ColumnMode::Grapheme)?,
+        Position::new(1, 3, 4, 1, 6, 7),
+        "should count graphemes (combining mark)"
+    );
+
+    assert_eq!(
+        emphasis("👍🏽 🇳🇱 👨\u{200D}👩\u{200D}👧 *b*", ColumnMode::Grapheme)?,
+        Position::new(1, 7, 37, 1, 10, 40),
+        "should count emoji modifiers, flags, and zero-width joiner sequences as one grapheme"
+    );
+
+    assert_eq!(
+        emphasis("🇳🇱🇧🇪 *b*", ColumnMode::Grapheme)?,
+        Position::new(1, 4, 17, 1, 7, 20),
+        "should count each pair of regional indicators as one grapheme"
+    );
+
+    assert_eq!(
+        emphasis("a\n😀 *b*", ColumnMode::Utf16CodeUnit)?,
+        Position::new(2, 4, 7, 2, 7, 10),
+        "should count from the start of each line"
+    );
+
+    assert_eq!(
+        emphasis("> 😀 *b*", ColumnMode::Utf16CodeUnit)?,
+        Position::new(1, 6, 7, 1, 9, 10),
+        "should count in containers"
+    );
+
+    assert_eq!(
+        to_mdast(
+            "\t😀",
+            &ParseOptions {
+                column_mode: ColumnMode::Utf16CodeUnit,
+                ..Default::default()
+            }
+        )?
+        .position()
+        .unwrap()
+        .clone(),
+        Position::new(1, 1, 0, 1, 4, 5),
+        "should count tabs as 1"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn column_mode_html() -> Result<(), String> {
+    for value in [
+        " > \t\t-**",
+        "> 😀\n>\t\ta",
+        "- e\u{301}\n\n\t\tb",
+        " ```😀\n\t\tc\n```",
+        "😀 | a\n- | -\n\t\tb | c",
+    ] {
+        let html = |column_mode| {
+            to_html_with_options(
+                value,
+                &Options {
+                    parse: ParseOptions {
+                        column_mode,
+                        ..ParseOptions::gfm()
+                    },
+                    ..Options::gfm()
+                },
+            )
+        };
+
+        assert_eq!(
+            html(ColumnMode::Utf16CodeUnit)?,
+            html(ColumnMode::Utf8CodeUnit)?,
+            "should compile the same in all modes (utf-16, `{:?}`)",
+            value
+        );
+
+        assert_eq!(
+            html(ColumnMode::Grapheme)?,
+            html(ColumnMode::Utf8CodeUnit)?,
+            "should compile the same in all modes (graphemes, `{:?}`)",
+            value
+        );
+    }
+
+    assert_eq!(
+        to_html_with_options(
+            " > \t\t-**",
+            &Options {
+                parse: ParseOptions {
+                    column_mode: ColumnMode::Utf16CodeUnit,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<blockquote>\n<pre><code> -**\n</code></pre>\n</blockquote>",
+        "should count virtual spaces of tabs in bytes"
+    );
+
+    Ok(())
+}
@@ -30,5 +30,41 @@ fn dangerous_html() -> Result<(), String> {
         "should be unsafe w/ `allowDangerousHtml`"
     );
 
+    let remove = &Options {
+        compile: CompileOptions {
+            remove_dangerous_html: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("<div>\na\n</div>\n\nb <b>c</b> d", remove)?,
+        "<!-- raw html removed -->\n<p>b <!-- raw html removed -->c<!-- raw html removed --> d</p>",
+        "should replace HTML (flow, text) w/ a placeholder w/ `remove_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> <!-- a -->\n\n- a <x> b\n\n[c](d \"<e>\") `<f>`", remove)?,
+        "<blockquote>\n<!-- raw html removed -->\n</blockquote>\n<ul>\n<li>a <!-- raw html removed --> b</li>\n</ul>\n<p><a href=\"d\" title=\"&lt;e&gt;\">c</a> <code>&lt;f&gt;</code></p>",
+        "should replace HTML in containers, but not things that look like HTML elsewhere"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<x>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    remove_dangerous_html: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<x>",
+        "should prefer `allow_dangerous_html` over `remove_dangerous_html`"
+    );
+
     Ok(())
 }
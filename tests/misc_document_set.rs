@@ -0,0 +1,93 @@
+use markdown::{DefinitionConflict, DocumentSet, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn document_set() -> Result<(), String> {
+    let mut set = DocumentSet::new(Options::default());
+    set.add("# One\n\n[a]: https://a.com \"A\"\n\nSee [b].")
+        .add("# Two\n\n[b]: https://b.com\n\nSee [a] and [A][].");
+
+    assert_eq!(
+        set.to_html()?,
+        vec![
+            "<h1>One</h1>\n<p>See <a href=\"https://b.com\">b</a>.</p>",
+            "<h1>Two</h1>\n<p>See <a href=\"https://a.com\" title=\"A\">a</a> and <a href=\"https://a.com\" title=\"A\">A</a>.</p>"
+        ],
+        "should resolve references to definitions in other documents"
+    );
+
+    let mut set = DocumentSet::new(Options::default());
+    set.add("[a]: https://one.com\n\n[a]")
+        .add("[a]: https://two.com\n\n[a]")
+        .add("[a]");
+
+    assert_eq!(
+        set.to_html()?,
+        vec![
+            "<p><a href=\"https://one.com\">a</a></p>",
+            "<p><a href=\"https://two.com\">a</a></p>",
+            "<p><a href=\"https://one.com\">a</a></p>"
+        ],
+        "should prefer definitions in the same document, then the first other document"
+    );
+
+    let mut set = DocumentSet::new(Options {
+        parse: ParseOptions {
+            definition_conflict: DefinitionConflict::KeepLast,
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    });
+    set.add("[a]: https://one.com\n[a]: https://two.com\n\n[a]")
+        .add("[a]: https://three.com")
+        .add("[a]");
+
+    assert_eq!(
+        set.to_html()?,
+        vec![
+            "<p><a href=\"https://two.com\">a</a></p>",
+            "",
+            "<p><a href=\"https://three.com\">a</a></p>"
+        ],
+        "should prefer the last definitions w/ `DefinitionConflict::KeepLast`"
+    );
+
+    let mut parse = ParseOptions::default();
+    parse
+        .external_definitions
+        .insert("a".into(), ("https://external.com".into(), None));
+    parse
+        .external_definitions
+        .insert("b".into(), ("https://external.com".into(), None));
+    let mut set = DocumentSet::new(Options {
+        parse,
+        ..Default::default()
+    });
+    set.add("[a]: https://document.com").add("[a] [b] [c]");
+
+    assert_eq!(
+        set.to_html()?,
+        vec![
+            "",
+            "<p><a href=\"https://document.com\">a</a> <a href=\"https://external.com\">b</a> [c]</p>"
+        ],
+        "should prefer definitions in documents over external definitions"
+    );
+
+    assert_eq!(
+        set.to_html()?,
+        vec![
+            "",
+            "<p><a href=\"https://document.com\">a</a> <a href=\"https://external.com\">b</a> [c]</p>"
+        ],
+        "should support compiling several times"
+    );
+
+    assert_eq!(
+        DocumentSet::new(Options::default()).to_html()?,
+        Vec::<String>::new(),
+        "should support no documents"
+    );
+
+    Ok(())
+}
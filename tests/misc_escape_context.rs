@@ -0,0 +1,72 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, EscapeContext, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn escape_context() -> Result<(), String> {
+    let attribute = Options {
+        compile: CompileOptions {
+            escape_context: EscapeContext::Attribute,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let attribute_danger = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            escape_context: EscapeContext::Attribute,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a \"b\" 'c'"),
+        "<p>a &quot;b&quot; 'c'</p>",
+        "should not escape apostrophes in content context (default)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \"b\" 'c'", &attribute)?,
+        "<p>a &quot;b&quot; &#x27;c&#x27;</p>",
+        "should escape quotes and apostrophes in attribute context"
+    );
+
+    assert_eq!(
+        to_html_with_options("a &apos; &#39; \\'", &attribute)?,
+        "<p>a &#x27; &#x27; &#x27;</p>",
+        "should escape apostrophes from character references and escapes in attribute context"
+    );
+
+    assert_eq!(
+        to_html_with_options("`'`\n\n    '", &attribute)?,
+        "<p><code>&#x27;</code></p>\n<pre><code>&#x27;\n</code></pre>",
+        "should escape apostrophes in code in attribute context"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a'](b 'c')", &attribute)?,
+        "<p><a href=\"b\" title=\"c\">a&#x27;</a></p>",
+        "should escape apostrophes in links in attribute context"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.b/'c'>", &attribute)?,
+        "<p><a href=\"https://a.b/'c'\">https://a.b/&#x27;c&#x27;</a></p>",
+        "should escape apostrophes in autolinks in attribute context"
+    );
+
+    assert_eq!(
+        to_html_with_options("<b title='c'>", &attribute)?,
+        "&lt;b title=&#x27;c&#x27;&gt;",
+        "should escape apostrophes in unsafe HTML in attribute context"
+    );
+
+    assert_eq!(
+        to_html_with_options("<b title='c'>", &attribute_danger)?,
+        "<b title='c'>",
+        "should not change dangerous HTML in attribute context"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn escape_gt() -> Result<(), String> {
+    let keep_gt = Options {
+        compile: CompileOptions {
+            escape_gt: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a > b"),
+        "<p>a &gt; b</p>",
+        "should escape `>` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a > b", &keep_gt)?,
+        "<p>a > b</p>",
+        "should support not escaping `>`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a < b & c \" d", &keep_gt)?,
+        "<p>a &lt; b &amp; c &quot; d</p>",
+        "should still escape `<`, `&`, and `\"`"
+    );
+
+    assert_eq!(
+        to_html_with_options("&gt; \\> &amp;gt;", &keep_gt)?,
+        "<p>> > &amp;gt;</p>",
+        "should not escape `>` from character references and escapes"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a > b\n\n*c > d*", &keep_gt)?,
+        "<h1>a > b</h1>\n<p><em>c > d</em></p>",
+        "should not escape `>` in other text"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a > b\n\n```\nc > d\n```", &keep_gt)?,
+        "<pre><code>a &gt; b\n</code></pre>\n<pre><code>c &gt; d\n</code></pre>",
+        "should still escape `>` in code (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a > b`", &keep_gt)?,
+        "<p><code>a &gt; b</code></p>",
+        "should still escape `>` in code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a > b", &keep_gt)?,
+        "<blockquote>\n<p>a > b</p>\n</blockquote>",
+        "should not affect block quotes"
+    );
+
+    Ok(())
+}
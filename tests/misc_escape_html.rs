@@ -0,0 +1,57 @@
+use markdown::{escape_html, escape_html_attribute, to_html};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn escape_html_text() {
+    assert_eq!(
+        escape_html("a < b > c & d \" e"),
+        "a &lt; b &gt; c &amp; d &quot; e",
+        "should escape `<`, `>`, `&`, and `\"`"
+    );
+
+    assert_eq!(
+        escape_html("a ' b 🦀"),
+        "a ' b 🦀",
+        "should not escape other characters"
+    );
+
+    assert_eq!(escape_html("a\0b"), "a�b", "should replace NUL");
+
+    assert_eq!(
+        escape_html("&amp;"),
+        "&amp;amp;",
+        "should escape character references"
+    );
+
+    let value = "<a> & \"b\"";
+    assert_eq!(
+        format!("<p>{}</p>", escape_html(value)),
+        to_html("\\<a\\> &amp; \"b\""),
+        "should escape like text in `to_html`"
+    );
+}
+
+#[test]
+fn escape_html_attribute_value() {
+    assert_eq!(
+        escape_html_attribute("a < b > c & d \" e"),
+        "a &lt; b &gt; c &amp; d &quot; e",
+        "should escape `<`, `>`, `&`, and `\"`"
+    );
+
+    assert_eq!(
+        escape_html_attribute("a ' b"),
+        "a ' b",
+        "should not escape `'`"
+    );
+
+    let value = "<a> & \"b\"";
+    assert_eq!(
+        format!(
+            "<p><a href=\"c\" title=\"{}\">d</a></p>",
+            escape_html_attribute(value)
+        ),
+        to_html("[d](c \"<a> & \\\"b\\\"\")"),
+        "should escape like attributes in `to_html`"
+    );
+}
@@ -0,0 +1,72 @@
+use markdown::{to_html_with_options, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn github_comments() -> Result<(), String> {
+    // Snippets, and how GitHub renders them in comments (apart from things
+    // that GitHub does after compiling, such as `dir="auto"` and mentions),
+    // serialized as `markdown-rs` does.
+    let fixtures = [
+        (
+            "Thanks!\nI’ll look at it.",
+            "<p>Thanks!<br />\nI’ll look at it.</p>",
+            "line endings",
+        ),
+        (
+            "See https://example.com and www.example.com, or mail a@b.com.",
+            "<p>See <a href=\"https://example.com\">https://example.com</a> and <a href=\"http://www.example.com\">www.example.com</a>, or mail <a href=\"mailto:a@b.com\">a@b.com</a>.</p>",
+            "autolink literals",
+        ),
+        (
+            "~~wontfix~~ fixed",
+            "<p><del>wontfix</del> fixed</p>",
+            "strikethrough",
+        ),
+        (
+            "| a | b |\n| :- | -: |\n| c | d |",
+            "<table>\n<thead>\n<tr>\n<th align=\"left\">a</th>\n<th align=\"right\">b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td align=\"left\">c</td>\n<td align=\"right\">d</td>\n</tr>\n</tbody>\n</table>",
+            "tables",
+        ),
+        (
+            "- [x] done\n- [ ] todo",
+            "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> done</li>\n<li><input type=\"checkbox\" disabled=\"\" /> todo</li>\n</ul>",
+            "tasklists",
+        ),
+        (
+            "<script>alert(1)</script>\n\na <b>b</b>",
+            "&lt;script&gt;alert(1)&lt;/script&gt;\n<p>a &lt;b&gt;b&lt;/b&gt;</p>",
+            "raw HTML (not allowed)",
+        ),
+        (
+            "```js\nconst a = 1\nconst b = 2\n```",
+            "<pre><code class=\"language-js\">const a = 1\nconst b = 2\n</code></pre>",
+            "line endings in code",
+        ),
+        (
+            "a  \nb\\\nc",
+            "<p>a<br />\nb<br />\nc</p>",
+            "hard breaks",
+        ),
+        (
+            "> quote\n> more\n\n1. one\n   two",
+            "<blockquote>\n<p>quote<br />\nmore</p>\n</blockquote>\n<ol>\n<li>one<br />\ntwo</li>\n</ol>",
+            "line endings in containers",
+        ),
+        (
+            "a[^1]\n\n[^1]: b",
+            "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<p>b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+            "footnotes",
+        ),
+    ];
+
+    for (input, expected, name) in fixtures {
+        assert_eq!(
+            to_html_with_options(input, &Options::gfm_github_comments())?,
+            expected,
+            "should match GitHub comments: {}",
+            name
+        );
+    }
+
+    Ok(())
+}
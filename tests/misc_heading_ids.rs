@@ -0,0 +1,130 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, HeadingAnchor, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn heading_ids() -> Result<(), String> {
+    let ids = Options {
+        compile: CompileOptions {
+            heading_ids: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("# a"),
+        "<h1>a</h1>",
+        "should not add `id`s by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Hello, World!", &ids)?,
+        "<h1 id=\"hello-world\">Hello, World!</h1>",
+        "should add an `id` to headings (atx)"
+    );
+
+    assert_eq!(
+        to_html_with_options("Hello,\nWorld!\n===", &ids)?,
+        "<h1 id=\"hello-world\">Hello,\nWorld!</h1>",
+        "should add an `id` to headings (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options("## *a* `b` [c](d \"e\") [f][] &amp; g\\_h\n\n[f]: i", &ids)?,
+        "<h2 id=\"a-b-c-f--g_h\"><em>a</em> <code>b</code> <a href=\"d\" title=\"e\">c</a> <a href=\"i\">f</a> &amp; g_h</h2>\n",
+        "should make `id`s from the text of headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Ünïcödé 日本", &ids)?,
+        "<h1 id=\"ünïcödé-日本\">Ünïcödé 日本</h1>",
+        "should keep non-ASCII letters in `id`s"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n# a\n# a-1\n# a", &ids)?,
+        "<h1 id=\"a\">a</h1>\n<h1 id=\"a-1\">a</h1>\n<h1 id=\"a-1-1\">a-1</h1>\n<h1 id=\"a-2\">a</h1>",
+        "should make `id`s unique"
+    );
+
+    assert_eq!(
+        to_html_with_options("#\n\n# !", &ids)?,
+        "<h1></h1>\n<h1>!</h1>",
+        "should not add empty `id`s"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn heading_anchor_links() -> Result<(), String> {
+    let anchors = Options {
+        compile: CompileOptions {
+            heading_ids: true,
+            heading_anchor_links: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "# a",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor_links: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1>a</h1>",
+        "should not add anchors w/o `heading_ids`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Hello, World!", &anchors)?,
+        "<h1 id=\"hello-world\"><a class=\"anchor\" aria-hidden=\"true\" href=\"#hello-world\">#</a>Hello, World!</h1>",
+        "should add an anchor before the text of headings (atx)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a *b*\n---", &anchors)?,
+        "<h2 id=\"a-b\"><a class=\"anchor\" aria-hidden=\"true\" href=\"#a-b\">#</a>a <em>b</em></h2>",
+        "should add an anchor before the text of headings (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n# a", &anchors)?,
+        "<h1 id=\"a\"><a class=\"anchor\" aria-hidden=\"true\" href=\"#a\">#</a>a</h1>\n<h1 id=\"a-1\"><a class=\"anchor\" aria-hidden=\"true\" href=\"#a-1\">#</a>a</h1>",
+        "should link anchors to unique `id`s"
+    );
+
+    assert_eq!(
+        to_html_with_options("#", &anchors)?,
+        "<h1></h1>",
+        "should not add anchors to empty headings"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a",
+            &Options {
+                compile: CompileOptions {
+                    heading_ids: true,
+                    heading_anchor_links: true,
+                    heading_anchor: HeadingAnchor {
+                        class: "x\"y".into(),
+                        content: "<svg></svg>".into(),
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"a\"><a class=\"x&quot;y\" aria-hidden=\"true\" href=\"#a\"><svg></svg></a>a</h1>",
+        "should support a custom anchor"
+    );
+
+    Ok(())
+}
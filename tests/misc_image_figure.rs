@@ -0,0 +1,87 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn image_figure() -> Result<(), String> {
+    let figure = Options {
+        compile: CompileOptions {
+            image_figure: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("![a](b)"),
+        "<p><img src=\"b\" alt=\"a\" /></p>",
+        "should not compile figures by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b)", &figure)?,
+        "<figure><img src=\"b\" alt=\"a\" /></figure>",
+        "should compile a paragraph with only an image as a figure"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b 'c & d')", &figure)?,
+        "<figure><img src=\"b\" alt=\"a\" title=\"c &amp; d\" /><figcaption>c &amp; d</figcaption></figure>",
+        "should use the title of the image as the caption"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a]\n\n[a]: b 'c'", &figure)?,
+        "<figure><img src=\"b\" alt=\"a\" title=\"c\" /><figcaption>c</figcaption></figure>\n",
+        "should support image references"
+    );
+
+    assert_eq!(
+        to_html_with_options("![![a](b)](c 'd')", &figure)?,
+        "<figure><img src=\"c\" alt=\"a\" title=\"d\" /><figcaption>d</figcaption></figure>",
+        "should support images in the alt of images"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ![b](c)", &figure)?,
+        "<p>a <img src=\"c\" alt=\"b\" /></p>",
+        "should not compile figures for images after text"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b 'c') d", &figure)?,
+        "<p><img src=\"b\" alt=\"a\" title=\"c\" /> d</p>",
+        "should not compile figures for images before text"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b)\n![c](d)", &figure)?,
+        "<p><img src=\"b\" alt=\"a\" />\n<img src=\"d\" alt=\"c\" /></p>",
+        "should not compile figures for several images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![a](b)](c)", &figure)?,
+        "<p><a href=\"c\"><img src=\"b\" alt=\"a\" /></a></p>",
+        "should not compile figures for images in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ![a](b)", &figure)?,
+        "<blockquote>\n<figure><img src=\"b\" alt=\"a\" /></figure>\n</blockquote>",
+        "should compile figures in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("- ![a](b)", &figure)?,
+        "<ul>\n<li><img src=\"b\" alt=\"a\" /></li>\n</ul>",
+        "should not compile figures in tight lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("- ![a](b)\n\n- c", &figure)?,
+        "<ul>\n<li>\n<figure><img src=\"b\" alt=\"a\" /></figure>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>",
+        "should compile figures in loose lists"
+    );
+
+    Ok(())
+}
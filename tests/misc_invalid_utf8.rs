@@ -0,0 +1,57 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html_with_options_lossy, to_mdast_lossy,
+    unist::Position,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn invalid_utf8() -> Result<(), String> {
+    assert_eq!(
+        to_html_with_options_lossy(b"# a", &Options::default())?,
+        "<h1>a</h1>",
+        "should support valid UTF-8"
+    );
+
+    assert_eq!(
+        to_html_with_options_lossy(b"a\xFFb", &Options::default())?,
+        "<p>a�b</p>",
+        "should replace an invalid byte w/ a replacement character (`�`)"
+    );
+
+    assert_eq!(
+        to_html_with_options_lossy(b"*a\xE2\x82*", &Options::default())?,
+        "<p><em>a�</em></p>",
+        "should replace a truncated sequence w/ a replacement character (`�`)"
+    );
+
+    assert_eq!(
+        to_html_with_options_lossy(b"[\xC0](\xFF \"\x80\")", &Options::default())?,
+        "<p><a href=\"%EF%BF%BD\" title=\"�\">�</a></p>",
+        "should replace invalid UTF-8 in labels, destinations, and titles"
+    );
+
+    assert_eq!(
+        to_html_with_options_lossy(b"```\xFF\n\xFE\n```", &Options::default())?,
+        "<pre><code class=\"language-�\">�\n</code></pre>",
+        "should replace invalid UTF-8 in code"
+    );
+
+    assert_eq!(
+        to_mdast_lossy(b"a\xFFb", &ParseOptions::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a�b".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 6, 5))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 6, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+        }),
+        "should replace invalid UTF-8 in mdast (positions are in the replaced value)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use markdown::{is_empty, Constructs, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn is_empty_document() -> Result<(), String> {
+    let options = ParseOptions::default();
+
+    assert_eq!(
+        is_empty("", &options)?,
+        true,
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        is_empty("  \n\t\n\n   ", &options)?,
+        true,
+        "should support a whitespace-only document"
+    );
+
+    assert_eq!(
+        is_empty("\r\n\r\r\n", &options)?,
+        true,
+        "should support a document of line endings"
+    );
+
+    assert_eq!(
+        is_empty("[a]: b\n\n  [c]: <d> \"e\"\n", &options)?,
+        true,
+        "should support a definition-only document"
+    );
+
+    assert_eq!(
+        is_empty("<!-- a -->", &options)?,
+        true,
+        "should support a comment-only document"
+    );
+
+    assert_eq!(
+        is_empty("<!--\na\n-->\n\n<!-- b --> <!-- c -->\n[d]: e", &options)?,
+        true,
+        "should support several comments and definitions"
+    );
+
+    assert_eq!(
+        is_empty("<!-->", &options)?,
+        true,
+        "should support an empty comment"
+    );
+
+    assert_eq!(
+        is_empty("a", &options)?,
+        false,
+        "should not support a paragraph"
+    );
+
+    assert_eq!(
+        is_empty("[a]: b\n[a]", &options)?,
+        false,
+        "should not support a reference"
+    );
+
+    assert_eq!(
+        is_empty("<!-- a --> b", &options)?,
+        false,
+        "should not support text after a comment"
+    );
+
+    assert_eq!(
+        is_empty("<!-- a", &options)?,
+        false,
+        "should not support an unclosed comment"
+    );
+
+    assert_eq!(
+        is_empty("<div>", &options)?,
+        false,
+        "should not support other HTML"
+    );
+
+    assert_eq!(
+        is_empty("***", &options)?,
+        false,
+        "should not support a thematic break"
+    );
+
+    assert_eq!(
+        is_empty(">", &options)?,
+        false,
+        "should not support an empty block quote"
+    );
+
+    assert_eq!(
+        is_empty("> [a]: b", &options)?,
+        false,
+        "should not support a definition in a block quote"
+    );
+
+    assert_eq!(
+        is_empty(
+            "---\na: b\n---\n\n[^c]: d",
+            &ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    gfm_footnote_definition: true,
+                    ..Constructs::default()
+                },
+                ..ParseOptions::default()
+            }
+        )?,
+        true,
+        "should support frontmatter and footnote definitions"
+    );
+
+    assert_eq!(
+        is_empty("[^a]: b\n\nc[^a]", &ParseOptions::gfm())?,
+        false,
+        "should not support a footnote reference"
+    );
+
+    Ok(())
+}
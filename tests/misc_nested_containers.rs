@@ -0,0 +1,107 @@
+use markdown::to_html;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn nested_containers() {
+    assert_eq!(
+        to_html("> - a\n> - b"),
+        "<blockquote>\n<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n</blockquote>",
+        "should support a tight list in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> - a\n>\n> - b"),
+        "<blockquote>\n<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ul>\n</blockquote>",
+        "should support a loose list in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> - a\n> - b\nc"),
+        "<blockquote>\n<ul>\n<li>a</li>\n<li>b\nc</li>\n</ul>\n</blockquote>",
+        "should support a lazy line in a tight list in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> - a\n>\n>   b\n> - c"),
+        "<blockquote>\n<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>\n</blockquote>",
+        "should support a loose item in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> - a\n>\n> b"),
+        "<blockquote>\n<ul>\n<li>a</li>\n</ul>\n<p>b</p>\n</blockquote>",
+        "should support a paragraph after a list in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> - a\n>   - b\n> - c"),
+        "<blockquote>\n<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n<li>c</li>\n</ul>\n</blockquote>",
+        "should support a nested tight list in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> - a\n>   - b\n>\n>     c\n> - d"),
+        "<blockquote>\n<ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>\n</blockquote>",
+        "should not make an outer list loose if a blank line is in an inner list, in a block quote"
+    );
+
+    assert_eq!(
+        to_html("- a\n  > - b\n  > - c\n- d"),
+        "<ul>\n<li>a\n<blockquote>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</blockquote>\n</li>\n<li>d</li>\n</ul>",
+        "should support a list in a block quote in a list"
+    );
+
+    assert_eq!(
+        to_html("- a\n  > - b\n  > - c\n\n- d"),
+        "<ul>\n<li>\n<p>a</p>\n<blockquote>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</blockquote>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>",
+        "should support a tight list in a block quote in a loose list"
+    );
+
+    assert_eq!(
+        to_html("- a\n  > - b\n  >\n  >   c\n- d"),
+        "<ul>\n<li>a\n<blockquote>\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</blockquote>\n</li>\n<li>d</li>\n</ul>",
+        "should support a loose list in a block quote in a tight list"
+    );
+
+    assert_eq!(
+        to_html("- > - a\n  >   - b\n- c"),
+        "<ul>\n<li>\n<blockquote>\n<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>\n</blockquote>\n</li>\n<li>c</li>\n</ul>",
+        "should support a nested list in a block quote directly in a list"
+    );
+
+    assert_eq!(
+        to_html("- > a\n  >\n  > b\n- c"),
+        "<ul>\n<li>\n<blockquote>\n<p>a</p>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>",
+        "should not make a list loose if a blank line is in a block quote in it"
+    );
+
+    assert_eq!(
+        to_html("- > a\n- > b"),
+        "<ul>\n<li>\n<blockquote>\n<p>a</p>\n</blockquote>\n</li>\n<li>\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n</ul>",
+        "should support block quotes directly in list items"
+    );
+
+    assert_eq!(
+        to_html("> 1. a\n>    > b\n> 2. c"),
+        "<blockquote>\n<ol>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ol>\n</blockquote>",
+        "should support a block quote in a list in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> - a\n>   > b\n>   > - c\n>   >   - d"),
+        "<blockquote>\n<ul>\n<li>a\n<blockquote>\n<p>b</p>\n<ul>\n<li>c\n<ul>\n<li>d</li>\n</ul>\n</li>\n</ul>\n</blockquote>\n</li>\n</ul>\n</blockquote>",
+        "should support deeply nested lists and block quotes"
+    );
+
+    assert_eq!(
+        to_html("1. a\n   > - b\n   >   > c\n2. d"),
+        "<ol>\n<li>a\n<blockquote>\n<ul>\n<li>b\n<blockquote>\n<p>c</p>\n</blockquote>\n</li>\n</ul>\n</blockquote>\n</li>\n<li>d</li>\n</ol>",
+        "should support a block quote in a list in a block quote in a list"
+    );
+
+    assert_eq!(
+        to_html("> > - a\n> > - b\n> c"),
+        "<blockquote>\n<blockquote>\n<ul>\n<li>a</li>\n<li>b\nc</li>\n</ul>\n</blockquote>\n</blockquote>",
+        "should support a lazy line in a list in nested block quotes"
+    );
+}
@@ -0,0 +1,60 @@
+use markdown::{to_html, to_html_with_options, BlockKind, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+#[test]
+fn on_block() -> Result<(), String> {
+    let hr_before_paragraphs = Options {
+        compile: CompileOptions {
+            on_block: Some(Arc::new(|kind, _index| {
+                if kind == BlockKind::Paragraph {
+                    Some("<hr/>".into())
+                } else {
+                    None
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let index_before_blocks = Options {
+        compile: CompileOptions {
+            on_block: Some(Arc::new(|_kind, index| Some(format!("<!-- {} -->", index)))),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\n\nb"),
+        "<p>a</p>\n<p>b</p>",
+        "should not add anything by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n# b\n\nc", &hr_before_paragraphs)?,
+        "<hr/>\n<p>a</p>\n<h1>b</h1>\n<hr/>\n<p>c</p>",
+        "should add HTML before paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n\n- b\n\nc", &hr_before_paragraphs)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>\n<ul>\n<li>b</li>\n</ul>\n<hr/>\n<p>c</p>",
+        "should only call the function for top-level blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n***\n[b]: c\n\n```\nd\n```", &index_before_blocks)?,
+        "<!-- 0 -->\n<p>a</p>\n<!-- 1 -->\n<hr />\n<!-- 2 -->\n<!-- 3 -->\n<pre><code>d\n</code></pre>",
+        "should call the function in document order, with the index of each block (including definitions)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<b>&amp;</b> *c*", &hr_before_paragraphs)?,
+        "<hr/>\n<p>&lt;b&gt;&amp;&lt;/b&gt; <em>c</em></p>",
+        "should not interfere with the encoding of blocks"
+    );
+
+    Ok(())
+}
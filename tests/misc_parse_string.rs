@@ -0,0 +1,90 @@
+use markdown::{
+    mdast::{Definition, Node},
+    parse_string, to_mdast, Constructs, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn string_content() -> Result<(), String> {
+    assert_eq!(
+        parse_string("a", &ParseOptions::default())?,
+        "a",
+        "should support data"
+    );
+
+    assert_eq!(
+        parse_string("", &ParseOptions::default())?,
+        "",
+        "should support an empty string"
+    );
+
+    assert_eq!(
+        parse_string("a &amp; &#35; &#x2A; &copy; b", &ParseOptions::default())?,
+        "a & # * © b",
+        "should decode character references"
+    );
+
+    assert_eq!(
+        parse_string("a &unknown; &#xZZ; & b", &ParseOptions::default())?,
+        "a &unknown; &#xZZ; & b",
+        "should not decode things that are not character references"
+    );
+
+    assert_eq!(
+        parse_string("a \\\" \\* \\a \\\\ b", &ParseOptions::default())?,
+        "a \" * \\a \\ b",
+        "should resolve character escapes"
+    );
+
+    assert_eq!(
+        parse_string("a \\&amp; b", &ParseOptions::default())?,
+        "a &amp; b",
+        "should not decode an escaped character reference"
+    );
+
+    assert_eq!(
+        parse_string("*a* `b` [c](d) <e> \\\n", &ParseOptions::default())?,
+        "*a* `b` [c](d) <e> \\\n",
+        "should not interpret other constructs"
+    );
+
+    assert_eq!(
+        parse_string("a\nb\r\nc", &ParseOptions::default())?,
+        "a\nb\r\nc",
+        "should keep line endings"
+    );
+
+    assert_eq!(
+        parse_string(
+            "a \\* &amp; b",
+            &ParseOptions {
+                constructs: Constructs {
+                    character_escape: false,
+                    character_reference: false,
+                    ..Constructs::default()
+                },
+                ..ParseOptions::default()
+            }
+        )?,
+        "a \\* &amp; b",
+        "should not decode when turned off"
+    );
+
+    let title = "a \\\" &quot; &#x1F44D; \\&copy;";
+    let tree = to_mdast(&format!("[x]: y \"{}\"", title), &ParseOptions::default())?;
+
+    if let Node::Definition(Definition {
+        title: Some(value), ..
+    }) = &tree.children().unwrap()[0]
+    {
+        assert_eq!(
+            &parse_string(title, &ParseOptions::default())?,
+            value,
+            "should match how titles are parsed"
+        );
+    } else {
+        unreachable!("expected definition");
+    }
+
+    Ok(())
+}
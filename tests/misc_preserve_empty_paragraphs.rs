@@ -0,0 +1,81 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn preserve_empty_paragraphs() -> Result<(), String> {
+    let preserve = Options {
+        compile: CompileOptions {
+            preserve_empty_paragraphs: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\n\n\n\nb"),
+        "<p>a</p>\n<p>b</p>",
+        "should drop extra blank lines by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\nb", &preserve)?,
+        "<p>a</p>\n<p>b</p>",
+        "should not add empty paragraphs for one blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n\nb", &preserve)?,
+        "<p>a</p>\n<p></p>\n<p>b</p>",
+        "should add an empty paragraph for a second blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n\n\n\nb", &preserve)?,
+        "<p>a</p>\n<p></p>\n<p></p>\n<p></p>\n<p>b</p>",
+        "should add an empty paragraph for each consecutive blank line after the first"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n   \n\nb", &preserve)?,
+        "<p>a</p>\n<p></p>\n<p></p>\n<p>b</p>",
+        "should add empty paragraphs for lines of only spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n\t\n \nb", &preserve)?,
+        "<p>a</p>\n<p></p>\n<p></p>\n<p>b</p>",
+        "should add empty paragraphs for lines of only whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("\n\na", &preserve)?,
+        "<p></p>\n<p>a</p>",
+        "should add empty paragraphs at the start"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\r\n\r\n\r\nb", &preserve)?,
+        "<p>a</p>\r\n<p></p>\r\n<p>b</p>",
+        "should use the line ending style of the document"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n>\n>\n> b", &preserve)?,
+        "<blockquote>\n<p>a</p>\n<p></p>\n<p>b</p>\n</blockquote>",
+        "should add empty paragraphs in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n\n\n  b", &preserve)?,
+        "<ul>\n<li>\n<p>a</p>\n<p></p>\n<p>b</p>\n</li>\n</ul>",
+        "should add empty paragraphs in list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n\n\nb\n```", &preserve)?,
+        "<pre><code>a\n\n\nb\n</code></pre>",
+        "should not add empty paragraphs in code"
+    );
+
+    Ok(())
+}
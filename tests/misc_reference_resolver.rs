@@ -0,0 +1,116 @@
+use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+use std::{cell::Cell, rc::Rc};
+
+#[test]
+fn reference_resolver() -> Result<(), String> {
+    let wiki = Options {
+        parse: ParseOptions {
+            reference_resolver: Some(Box::new(|label| {
+                if label.starts_with('!') {
+                    None
+                } else if label == "titled" {
+                    Some(("/t".into(), Some("a & b".into())))
+                } else {
+                    Some((format!("/wiki/{}", label), None))
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[PageName]"),
+        "<p>[PageName]</p>",
+        "should not resolve undefined references by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[PageName]", &wiki)?,
+        "<p><a href=\"/wiki/PageName\">PageName</a></p>",
+        "should resolve shortcut references"
+    );
+
+    assert_eq!(
+        to_html_with_options("[PageName][]", &wiki)?,
+        "<p><a href=\"/wiki/PageName\">PageName</a></p>",
+        "should resolve collapsed references"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][PageName]", &wiki)?,
+        "<p><a href=\"/wiki/PageName\">a</a></p>",
+        "should resolve full references (w/ the reference, not the label)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![PageName]", &wiki)?,
+        "<p><img src=\"/wiki/PageName\" alt=\"PageName\" /></p>",
+        "should resolve images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[titled]", &wiki)?,
+        "<p><a href=\"/t\" title=\"a &amp; b\">titled</a></p>",
+        "should support titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("[!a] [b][!c]", &wiki)?,
+        "<p>[!a] [b][!c]</p>",
+        "should keep references as text if the resolver returns `None`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[PageName]\n\n[pagename]: /here", &wiki)?,
+        "<p><a href=\"/here\">PageName</a></p>\n",
+        "should prefer definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](/b) [c](<>)", &wiki)?,
+        "<p><a href=\"/b\">a</a> <a href=\"\">c</a></p>",
+        "should not affect resources"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reference_resolver_once() -> Result<(), String> {
+    let calls = Rc::new(Cell::new(0));
+    let count = calls.clone();
+    // Returns something different each time, and nothing after the first
+    // time.
+    let once = Options {
+        parse: ParseOptions {
+            reference_resolver: Some(Box::new(move |_label| {
+                count.set(count.get() + 1);
+
+                if count.get() == 1 {
+                    Some((format!("/{}", count.get()), None))
+                } else {
+                    None
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a] [A][] [b][a]", &once)?,
+        "<p><a href=\"/1\">a</a> <a href=\"/1\">A</a> <a href=\"/1\">b</a></p>",
+        "should use what the resolver returned while parsing"
+    );
+
+    // Once for `a`, and once for the label `b`, which is not defined.
+    assert_eq!(
+        calls.get(),
+        2,
+        "should call the resolver once per identifier"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use markdown::{
+    to_html, to_html_with_options, to_mdast, tokenize, ContentType, EditMap, Event, EventKind,
+    EventName, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Sentinel value to look for.
+const SENTINEL: &[u8] = b"@@";
+
+/// Example resolver: wrap sentinels in data in a custom span.
+fn sentinel(events: &mut Vec<Event>, bytes: &[u8]) -> Result<(), String> {
+    let mut map = EditMap::new();
+    let mut index = 0;
+
+    while index < events.len() {
+        if events[index].kind == EventKind::Exit && events[index].name == EventName::Data {
+            let enter = &events[index - 1];
+            let exit = &events[index];
+            let start = enter.point.index;
+            let end = exit.point.index;
+
+            if let Some(offset) = bytes[start..end]
+                .windows(SENTINEL.len())
+                .position(|window| window == SENTINEL)
+            {
+                let sentinel_start = if offset == 0 {
+                    enter.point.clone()
+                } else {
+                    enter.point.shift_to(bytes, start + offset)
+                };
+                let sentinel_end = sentinel_start.shift_to(bytes, start + offset + SENTINEL.len());
+                let mut add = vec![];
+
+                if offset > 0 {
+                    add.push(enter.clone());
+                    add.push(Event {
+                        kind: EventKind::Exit,
+                        name: EventName::Data,
+                        point: sentinel_start.clone(),
+                        link: None,
+                    });
+                }
+
+                for (kind, name, point) in [
+                    (
+                        EventKind::Enter,
+                        EventName::Custom("sentinel"),
+                        &sentinel_start,
+                    ),
+                    (EventKind::Enter, EventName::Data, &sentinel_start),
+                    (EventKind::Exit, EventName::Data, &sentinel_end),
+                    (
+                        EventKind::Exit,
+                        EventName::Custom("sentinel"),
+                        &sentinel_end,
+                    ),
+                ] {
+                    add.push(Event {
+                        kind,
+                        name,
+                        point: point.clone(),
+                        link: None,
+                    });
+                }
+
+                if sentinel_end.index < end {
+                    add.push(Event {
+                        kind: EventKind::Enter,
+                        name: EventName::Data,
+                        point: sentinel_end.clone(),
+                        link: None,
+                    });
+                    add.push(exit.clone());
+                }
+
+                map.add(index - 1, 2, add);
+            }
+        }
+
+        index += 1;
+    }
+
+    map.consume(events);
+    Ok(())
+}
+
+/// Serialize events as `kind:name` for comparison.
+fn names(events: &[Event]) -> Vec<String> {
+    events
+        .iter()
+        .map(|event| {
+            format!(
+                "{}:{:?}",
+                if event.kind == EventKind::Enter {
+                    "enter"
+                } else {
+                    "exit"
+                },
+                event.name
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn resolve() -> Result<(), String> {
+    let custom = ParseOptions {
+        resolvers: vec![Box::new(sentinel)],
+        ..ParseOptions::default()
+    };
+
+    assert_eq!(
+        names(&tokenize(
+            "a @@ b",
+            &ContentType::Text,
+            &ParseOptions::default()
+        )?),
+        vec!["enter:Data", "exit:Data"],
+        "should not resolve custom spans by default"
+    );
+
+    assert_eq!(
+        names(&tokenize("a @@ b", &ContentType::Text, &custom)?),
+        vec![
+            "enter:Data",
+            "exit:Data",
+            "enter:Custom(\"sentinel\")",
+            "enter:Data",
+            "exit:Data",
+            "exit:Custom(\"sentinel\")",
+            "enter:Data",
+            "exit:Data"
+        ],
+        "should support a resolver that adds custom spans"
+    );
+
+    assert_eq!(
+        names(&tokenize("@@", &ContentType::Text, &custom)?),
+        vec![
+            "enter:Custom(\"sentinel\")",
+            "enter:Data",
+            "exit:Data",
+            "exit:Custom(\"sentinel\")"
+        ],
+        "should support a custom span without surrounding data"
+    );
+
+    let events = tokenize("ab@@cd", &ContentType::Text, &custom)?;
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| event.point.index)
+            .collect::<Vec<_>>(),
+        vec![0, 2, 2, 2, 4, 4, 4, 6],
+        "should support positional info in custom spans"
+    );
+
+    assert_eq!(
+        names(&tokenize("*a* `@@`", &ContentType::Text, &custom)?)
+            .iter()
+            .filter(|name| name.contains("Custom"))
+            .count(),
+        0,
+        "should not add custom spans outside of data"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a @@ *b*\n\n# c@@",
+            &Options {
+                parse: ParseOptions {
+                    resolvers: vec![Box::new(sentinel)],
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        to_html("a @@ *b*\n\n# c@@"),
+        "should compile what is in custom spans"
+    );
+
+    assert_eq!(
+        to_mdast("a @@ *b*", &custom)?,
+        to_mdast("a @@ *b*", &ParseOptions::default())?,
+        "should compile what is in custom spans (mdast)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a",
+            &Options {
+                parse: ParseOptions {
+                    resolvers: vec![Box::new(|_events, _bytes| Err("1:1: stop".into()))],
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        ),
+        Err("1:1: stop".into()),
+        "should support errors from resolvers"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn link_protocols() -> Result<(), String> {
+    let http = Options {
+        compile: CompileOptions {
+            link_protocols: Some(vec!["http".into(), "https".into()]),
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("[a](irc://b) [c](xmpp:d)"),
+        "<p><a href=\"irc://b\">a</a> <a href=\"xmpp:d\">c</a></p>",
+        "should allow safe protocols by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](irc://b) [c](xmpp:d)", &http)?,
+        "<p><a href=\"\">a</a> <a href=\"\">c</a></p>",
+        "should drop protocols not in `link_protocols`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://b) [c](HTTP://d) [e](/f) [g](#h)", &http)?,
+        "<p><a href=\"https://b\">a</a> <a href=\"HTTP://d\">c</a> <a href=\"/f\">e</a> <a href=\"#h\">g</a></p>",
+        "should allow protocols in `link_protocols`, and relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("<mailto:a@b.c> <a@b.c>", &http)?,
+        "<p><a href=\"\">mailto:a@b.c</a> <a href=\"\">a@b.c</a></p>",
+        "should apply `link_protocols` to autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](https://b) ![c](data:d)", &http)?,
+        "<p><img src=\"https://b\" alt=\"a\" /> <img src=\"\" alt=\"c\" /></p>",
+        "should not apply `link_protocols` to images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](irc://b)",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    link_protocols: Some(vec!["http".into()]),
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"irc://b\">a</a></p>",
+        "should ignore `link_protocols` w/ `allow_dangerous_protocol`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn link_rel() -> Result<(), String> {
+    let rel = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            link_rel: Some("nofollow".into()),
+            ..CompileOptions::default()
+        },
+    };
+
+    assert_eq!(
+        to_html("[a](https://b)"),
+        "<p><a href=\"https://b\">a</a></p>",
+        "should not add `rel` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://b 'c')", &rel)?,
+        "<p><a href=\"https://b\" title=\"c\" rel=\"nofollow\">a</a></p>",
+        "should add `rel` to external links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](/b) [c](d) [e](#f) [g](?h)", &rel)?,
+        "<p><a href=\"/b\">a</a> <a href=\"d\">c</a> <a href=\"#f\">e</a> <a href=\"?h\">g</a></p>",
+        "should not add `rel` to relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: https://b", &rel)?,
+        "<p><a href=\"https://b\" rel=\"nofollow\">a</a></p>\n",
+        "should add `rel` to references"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a> <b@c.d> www.e.f", &rel)?,
+        "<p><a href=\"https://a\" rel=\"nofollow\">https://a</a> <a href=\"mailto:b@c.d\" rel=\"nofollow\">b@c.d</a> <a href=\"http://www.e.f\" rel=\"nofollow\">www.e.f</a></p>",
+        "should add `rel` to autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](https://b)", &rel)?,
+        "<p><img src=\"https://b\" alt=\"a\" /></p>",
+        "should not add `rel` to images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b)",
+            &Options {
+                compile: CompileOptions {
+                    link_rel: Some("a\"b".into()),
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"https://b\" rel=\"a&quot;b\">a</a></p>",
+        "should encode `rel`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auto_noopener() -> Result<(), String> {
+    let attributes = || ParseOptions {
+        constructs: Constructs {
+            attributes: true,
+            ..Constructs::default()
+        },
+        ..ParseOptions::default()
+    };
+    let noopener = Options {
+        parse: attributes(),
+        compile: CompileOptions {
+            auto_noopener: true,
+            ..CompileOptions::default()
+        },
+    };
+    let noopener_rel = |rel: &str| Options {
+        parse: attributes(),
+        compile: CompileOptions {
+            auto_noopener: true,
+            link_rel: Some(rel.into()),
+            ..CompileOptions::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b){target=_blank}",
+            &Options {
+                parse: attributes(),
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"https://b\" target=\"_blank\">a</a></p>",
+        "should not add `rel` to links w/ `target=\"_blank\"` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://b){target=_blank}", &noopener)?,
+        "<p><a href=\"https://b\" rel=\"noopener noreferrer\" target=\"_blank\">a</a></p>",
+        "should add `rel` to links w/ `target=\"_blank\"`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](/b){target=\"_BLANK\" .c}", &noopener)?,
+        "<p><a href=\"/b\" rel=\"noopener noreferrer\" class=\"c\" target=\"_BLANK\">a</a></p>",
+        "should add `rel` to relative links, and compare `_blank` case-insensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://b){target=c} [d](https://e) <https://f>", &noopener)?,
+        "<p><a href=\"https://b\" target=\"c\">a</a> <a href=\"https://e\">d</a> <a href=\"https://f\">https://f</a></p>",
+        "should not add `rel` to other links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://b){target=_blank}", &noopener_rel("nofollow"))?,
+        "<p><a href=\"https://b\" rel=\"nofollow noopener noreferrer\" target=\"_blank\">a</a></p>",
+        "should merge into `link_rel`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b){target=_blank}",
+            &noopener_rel("NoOpener nofollow")
+        )?,
+        "<p><a href=\"https://b\" rel=\"NoOpener nofollow noreferrer\" target=\"_blank\">a</a></p>",
+        "should not duplicate tokens in `link_rel`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](/b){target=_blank rel=me} [c](/d){rel=\"me noopener\" target=_blank}",
+            &noopener
+        )?,
+        "<p><a href=\"/b\" rel=\"me noopener noreferrer\" target=\"_blank\">a</a> <a href=\"/d\" rel=\"me noopener noreferrer\" target=\"_blank\">c</a></p>",
+        "should merge into `rel` from attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://b){target=_blank rel=me}", &noopener_rel("nofollow"))?,
+        "<p><a href=\"https://b\" rel=\"me nofollow noopener noreferrer\" target=\"_blank\">a</a></p>",
+        "should merge `link_rel` and `auto_noopener` into `rel` from attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://b) [c](/d){target=_blank}", &noopener_rel("nofollow"))?,
+        "<p><a href=\"https://b\" rel=\"nofollow\">a</a> <a href=\"/d\" rel=\"noopener noreferrer\" target=\"_blank\">c</a></p>",
+        "should not merge `link_rel` into relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](https://b){target=_blank}", &noopener)?,
+        "<p><img src=\"https://b\" alt=\"a\" target=\"_blank\" /></p>",
+        "should not add `rel` to images"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn safe() -> Result<(), String> {
+    let safe = Options::safe();
+
+    assert_eq!(
+        to_html_with_options("<script>alert(1)</script>", &safe)?,
+        "&lt;script&gt;alert(1)&lt;/script&gt;",
+        "should escape HTML (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <img src=x onerror=alert(1)> b", &safe)?,
+        "<p>a &lt;img src=x onerror=alert(1)&gt; b</p>",
+        "should escape HTML (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](javascript:alert(1)) <javascript:alert(1)> ![b](javascript:alert(1))",
+            &safe
+        )?,
+        "<p><a href=\"\">a</a> <a href=\"\">javascript:alert(1)</a> <img src=\"\" alt=\"b\" /></p>",
+        "should drop `javascript:` destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](irc://b) [c](mailto:d@e.f) [g](https://h)", &safe)?,
+        "<p><a href=\"\">a</a> <a href=\"mailto:d@e.f\" rel=\"nofollow noopener\">c</a> <a href=\"https://h\" rel=\"nofollow noopener\">g</a></p>",
+        "should only allow `http`, `https`, and `mailto`, and add `rel`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](//b) [c](\\\\\\\\d) [e](/\\\\f) [g](/h)", &safe)?,
+        "<p><a href=\"//b\" rel=\"nofollow noopener\">a</a> <a href=\"%5C%5Cd\" rel=\"nofollow noopener\">c</a> <a href=\"/%5Cf\" rel=\"nofollow noopener\">e</a> <a href=\"/h\">g</a></p>",
+        "should add `rel` to protocol-relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<script>alert(1)</script>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..CompileOptions::safe()
+                },
+                ..Options::safe()
+            }
+        )?,
+        "&lt;script>alert(1)&lt;/script>",
+        "should turn on the tag filter"
+    );
+
+    Ok(())
+}
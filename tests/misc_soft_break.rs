@@ -1,8 +1,8 @@
-use markdown::to_html;
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
 use pretty_assertions::assert_eq;
 
 #[test]
-fn soft_break() {
+fn soft_break() -> Result<(), String> {
     assert_eq!(
         to_html("foo\nbaz"),
         "<p>foo\nbaz</p>",
@@ -14,4 +14,84 @@ fn soft_break() {
         "<p>foo\nbaz</p>",
         "should trim spaces around line endings"
     );
+
+    let hard = Options {
+        compile: CompileOptions {
+            soft_break_as_hard_break: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\nb\nc", &hard)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should support `soft_break_as_hard_break`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \n b", &hard)?,
+        "<p>a<br />\nb</p>",
+        "should support `soft_break_as_hard_break` w/ whitespace around line endings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb\\\nc", &hard)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should not add another hard break after hard breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a\nb* **c\nd** [e\nf](g)", &hard)?,
+        "<p><em>a<br />\nb</em> <strong>c<br />\nd</strong> <a href=\"g\">e<br />\nf</a></p>",
+        "should support `soft_break_as_hard_break` in phrasing"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> b\n\n- c\n  d", &hard)?,
+        "<blockquote>\n<p>a<br />\nb</p>\n</blockquote>\n<ul>\n<li>c<br />\nd</li>\n</ul>",
+        "should support `soft_break_as_hard_break` in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n===", &hard)?,
+        "<h1>a<br />\nb</h1>",
+        "should support `soft_break_as_hard_break` in setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b\n\"c\nd\") ![e\nf](g)", &hard)?,
+        "<p><a href=\"b\" title=\"c\nd\">a</a> <img src=\"g\" alt=\"e\nf\" /></p>",
+        "should not support `soft_break_as_hard_break` in titles or alt text"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a\nb`\n\n```\nc\nd\n```", &hard)?,
+        "<p><code>a b</code></p>\n<pre><code>c\nd\n</code></pre>",
+        "should not support `soft_break_as_hard_break` in code"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<a\nb>\n\n<div>\nc\n</div>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    soft_break_as_hard_break: true,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a\nb></p>\n<div>\nc\n</div>",
+        "should not support `soft_break_as_hard_break` in HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\nb\n", &hard)?,
+        "<p>a</p>\n<p>b</p>\n",
+        "should not support `soft_break_as_hard_break` between blocks"
+    );
+
+    Ok(())
 }
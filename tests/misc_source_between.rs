@@ -0,0 +1,82 @@
+use markdown::{source_between, tokenize, ContentType, Event, EventKind, EventName, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Get the indices of the first enter and exit of `name`.
+fn find(events: &[Event], name: &EventName) -> (usize, usize) {
+    let enter = events
+        .iter()
+        .position(|d| d.kind == EventKind::Enter && d.name == *name)
+        .unwrap();
+    let exit = enter
+        + events[enter..]
+            .iter()
+            .position(|d| d.kind == EventKind::Exit && d.name == *name)
+            .unwrap();
+    (enter, exit)
+}
+
+#[test]
+fn source_between_events() -> Result<(), String> {
+    let options = ParseOptions::default();
+
+    let value = "a\n\n~~~js eval\n\tb\n\n  c\n~~~~\n\nd";
+    let events = tokenize(value, &ContentType::Flow, &options)?;
+    let (enter, exit) = find(&events, &EventName::CodeFenced);
+
+    assert_eq!(
+        source_between(value, &events, enter, exit),
+        "~~~js eval\n\tb\n\n  c\n~~~~",
+        "should get the source of fenced code, including its fences"
+    );
+
+    let (enter, exit) = find(&events, &EventName::CodeFencedFenceInfo);
+    let (_, meta_exit) = find(&events, &EventName::CodeFencedFenceMeta);
+
+    assert_eq!(
+        source_between(value, &events, enter, meta_exit),
+        "js eval",
+        "should get the source between different events"
+    );
+
+    assert_eq!(
+        source_between(value, &events, exit, enter),
+        "",
+        "should be empty if the second point is before the first"
+    );
+
+    assert_eq!(
+        source_between(value, &events, enter, enter),
+        "",
+        "should be empty for the same event"
+    );
+
+    let value = "  ```\r\n  é\r\n  ```";
+    let events = tokenize(value, &ContentType::Flow, &options)?;
+    let (enter, exit) = find(&events, &EventName::CodeFenced);
+
+    assert_eq!(
+        source_between(value, &events, enter, exit),
+        "```\r\n  é\r\n  ```",
+        "should include line endings, indents inside the block, and multibyte characters"
+    );
+
+    let (enter, exit) = find(&events, &EventName::CodeFlowChunk);
+
+    assert_eq!(
+        source_between(value, &events, enter, exit),
+        "é",
+        "should get the source of a part of a block"
+    );
+
+    let value = "*a* `b`";
+    let events = tokenize(value, &ContentType::Text, &options)?;
+    let (enter, exit) = find(&events, &EventName::CodeText);
+
+    assert_eq!(
+        source_between(value, &events, enter, exit),
+        "`b`",
+        "should support text content"
+    );
+
+    Ok(())
+}
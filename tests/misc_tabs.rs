@@ -284,9 +284,209 @@ fn tabs_virtual_spaces() {
 
     assert_eq!(
         to_html("-\ta\n\n\tb"),
-        "<ul>\n<li>\n<p>a</p>\n<p>\tb</p>\n</li>\n</ul>",
-        // To do: CM.js does not output the tab before `b`. See if that makes sense?
-        // "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
         "should support a part of a tab as a container, and the rest of a tab as flow"
     );
 }
+
+#[test]
+fn tabs_block_quote() {
+    assert_eq!(
+        to_html(">\tx"),
+        "<blockquote>\n<p>x</p>\n</blockquote>",
+        "should support a tab after a block quote marker (as one space, and the rest as flow)"
+    );
+
+    assert_eq!(
+        to_html(">\t\tx"),
+        "<blockquote>\n<pre><code>  x\n</code></pre>\n</blockquote>",
+        "should support two tabs after a block quote marker as indented code"
+    );
+
+    assert_eq!(
+        to_html(">\t\t\tx"),
+        "<blockquote>\n<pre><code>  \tx\n</code></pre>\n</blockquote>",
+        "should support three tabs after a block quote marker as indented code with a tab"
+    );
+
+    assert_eq!(
+        to_html(" >\tx"),
+        "<blockquote>\n<p>x</p>\n</blockquote>",
+        "should support a tab after an indented block quote marker"
+    );
+
+    assert_eq!(
+        to_html("   >\t\tx"),
+        "<blockquote>\n<pre><code>   x\n</code></pre>\n</blockquote>",
+        "should measure tabs after a block quote marker from its column"
+    );
+
+    assert_eq!(
+        to_html(">   \tx"),
+        "<blockquote>\n<pre><code>  x\n</code></pre>\n</blockquote>",
+        "should support spaces and then a tab after a block quote marker"
+    );
+
+    assert_eq!(
+        to_html(">\t\ta\n>\t\tb"),
+        "<blockquote>\n<pre><code>  a\n  b\n</code></pre>\n</blockquote>",
+        "should support tabs after block quote markers to continue indented code"
+    );
+
+    assert_eq!(
+        to_html(">\t\ta\n> \t\tb\n>  \t\tc\n>   \t\td"),
+        "<blockquote>\n<pre><code>  a\n  b\n  c\n  \td\n</code></pre>\n</blockquote>",
+        "should support mixed spaces and tabs after block quote markers to continue indented code"
+    );
+
+    assert_eq!(
+        to_html(">     a\n>\t\tb"),
+        "<blockquote>\n<pre><code>a\n  b\n</code></pre>\n</blockquote>",
+        "should support spaces, then tabs, after block quote markers in indented code"
+    );
+
+    assert_eq!(
+        to_html(">\ta\n> \tb"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should support mixed spaces and tabs after block quote markers in a paragraph"
+    );
+
+    assert_eq!(
+        to_html(">\ta\n\tb"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should support a tab after a block quote marker, and a lazy line"
+    );
+
+    assert_eq!(
+        to_html(">\t>\tx"),
+        "<blockquote>\n<blockquote>\n<p>x</p>\n</blockquote>\n</blockquote>",
+        "should support tabs after nested block quote markers"
+    );
+
+    assert_eq!(
+        to_html(">\t-\t\tx"),
+        "<blockquote>\n<ul>\n<li>\n<pre><code>  x\n</code></pre>\n</li>\n</ul>\n</blockquote>",
+        "should support tabs after a block quote marker and a list item marker"
+    );
+
+    assert_eq!(
+        to_html(">\t```\n>\t\tx\n>\t```"),
+        "<blockquote>\n<pre><code>\tx\n</code></pre>\n</blockquote>",
+        "should strip the rest of a tab after a block quote marker as the indent of fenced code"
+    );
+
+    assert_eq!(
+        to_html("> ```\n>\t x\n> ```"),
+        "<blockquote>\n<pre><code>   x\n</code></pre>\n</blockquote>",
+        "should keep the rest of a tab after a block quote marker in fenced code"
+    );
+}
+
+#[test]
+fn tabs_expand() -> Result<(), String> {
+    let expand = |size| Options {
+        compile: CompileOptions {
+            expand_tabs: Some(size),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\tb"),
+        "<p>a\tb</p>",
+        "should keep tabs in text by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\tb", &expand(4))?,
+        "<p>a   b</p>",
+        "should expand tabs in a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\tbcde\tf\t\tg", &expand(4))?,
+        "<p>a   bcde    f       g</p>",
+        "should expand tabs to the next tab stop"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\tb\nab\tc", &expand(4))?,
+        "<p>a   b\nab  c</p>",
+        "should count tab stops from the start of each line"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*\tb", &expand(4))?,
+        "<p><em>a</em>   b</p>",
+        "should count tab stops in text, not in markdown syntax (emphasis)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\tb", &expand(4))?,
+        "<blockquote>\n<p>a   b</p>\n</blockquote>",
+        "should count tab stops in text, not in markdown syntax (block quote)"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\tb\n  cd\te", &expand(4))?,
+        "<ul>\n<li>a   b\ncd  e</li>\n</ul>",
+        "should count tab stops in text, not in markdown syntax (list item)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b)\tc &amp;\td \\*\te", &expand(4))?,
+        "<p><a href=\"b\">a</a>   c &amp; d * e</p>",
+        "should count tab stops in text, not in markdown syntax (links, references, escapes)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\t*b*\tc", &expand(4))?,
+        "<p>a   <em>b</em>   c</p>",
+        "should count expanded tabs before"
+    );
+
+    assert_eq!(
+        to_html_with_options("é\tb", &expand(4))?,
+        "<p>é   b</p>",
+        "should count tab stops in characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\tb", &expand(8))?,
+        "<p>a       b</p>",
+        "should support other tab sizes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\tb", &expand(0))?,
+        "<p>ab</p>",
+        "should remove tabs with a tab size of `0`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\tb", &expand(4))?,
+        "<h1>a   b</h1>",
+        "should expand tabs in headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("\ta\tb", &expand(4))?,
+        "<pre><code>a\tb\n</code></pre>",
+        "should keep tabs in indented code"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\tb\n```", &expand(4))?,
+        "<pre><code>a\tb\n</code></pre>",
+        "should keep tabs in fenced code"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a\tb`", &expand(4))?,
+        "<p><code>a\tb</code></p>",
+        "should keep tabs in code (text)"
+    );
+
+    Ok(())
+}
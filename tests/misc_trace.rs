@@ -0,0 +1,48 @@
+#![cfg(feature = "trace")]
+
+use markdown::{to_html_with_options, Options, ParseOptions, StateName};
+use pretty_assertions::assert_eq;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn trace() -> Result<(), String> {
+    let trace = Rc::new(RefCell::new(vec![]));
+    let sink = Rc::clone(&trace);
+
+    let options = Options {
+        parse: ParseOptions {
+            trace: Some(Box::new(move |name, point| {
+                sink.borrow_mut().push((name, point.index));
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("*a*", &options)?,
+        "<p><em>a</em></p>",
+        "should not change the result"
+    );
+
+    let trace = trace.borrow();
+
+    assert_eq!(
+        trace.first(),
+        Some(&(StateName::DocumentStart, 0)),
+        "should start in the document"
+    );
+
+    assert!(
+        trace.contains(&(StateName::AttentionStart, 0)),
+        "should trace states in other content types"
+    );
+
+    assert!(
+        trace.contains(&(StateName::AttentionInside, 1)),
+        "should trace points"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+#[test]
+fn trim_output() -> Result<(), String> {
+    let trim = Options {
+        compile: CompileOptions {
+            trim_output: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\n"),
+        "<p>a</p>\n",
+        "should keep the final line ending by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n", &trim)?,
+        "<p>a</p>",
+        "should support `trim_output` to remove the final line ending"
+    );
+
+    assert_eq!(
+        to_html_with_options("a", &trim)?,
+        "<p>a</p>",
+        "should not change output without line endings"
+    );
+
+    assert_eq!(
+        to_html_with_options("\n\na\n\n", &trim)?,
+        "<p>a</p>",
+        "should support blank lines around the document"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\r\n", &trim)?,
+        "<p>a</p>",
+        "should support a carriage return + line feed"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\r", &trim)?,
+        "<p>a</p>",
+        "should support a carriage return"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\nb\n", &trim)?,
+        "<p>a</p>\n<p>b</p>",
+        "should keep line endings between blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a\n", &trim)?,
+        "<pre><code>a\n</code></pre>",
+        "should keep line endings in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("", &trim)?,
+        "",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n",
+            &Options {
+                compile: CompileOptions {
+                    trim_output: true,
+                    on_block: Some(Arc::new(|_kind, _index| Some("\n<hr />".into()))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<hr />\n<p>a</p>",
+        "should remove a line ending at the start"
+    );
+
+    Ok(())
+}
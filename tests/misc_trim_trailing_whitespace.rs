@@ -0,0 +1,84 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn trim_trailing_whitespace() -> Result<(), String> {
+    let trim = Options {
+        compile: CompileOptions {
+            trim_trailing_whitespace: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let trim_danger = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            trim_trailing_whitespace: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a&#32;\nb"),
+        "<p>a \nb</p>",
+        "should keep trailing whitespace by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a&#32;\nb", &trim)?,
+        "<p>a\nb</p>",
+        "should trim trailing whitespace before line endings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a&#9;&#32;\r\nb", &trim)?,
+        "<p>a\r\nb</p>",
+        "should trim trailing tabs and spaces before other line endings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb", &trim)?,
+        "<p>a<br />\nb</p>",
+        "should support a hard break (trailing)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a&#32;\\\nb", &trim)?,
+        "<p>a <br />\nb</p>",
+        "should support a hard break (escape)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a  \n    b\t\n", &trim)?,
+        "<pre><code>a  \nb\t\n</code></pre>\n",
+        "should not trim in code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na  \nb\t\n```", &trim)?,
+        "<pre><code>a  \nb\t\n</code></pre>",
+        "should not trim in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na  ", &trim)?,
+        "<pre><code>a  \n</code></pre>\n",
+        "should not trim in unclosed code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<pre>  \n  a  \n</pre>", &trim_danger)?,
+        "<pre>  \n  a  \n</pre>",
+        "should not trim in dangerous HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a&#32;\n> b", &trim)?,
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should trim in containers"
+    );
+
+    Ok(())
+}
@@ -27,6 +27,66 @@ fn url() {
         "should support incorrect percentage encoded values (3)"
     );
 
+    assert_eq!(
+        to_html("[](a%20b)"),
+        "<p><a href=\"a%20b\"></a></p>",
+        "should not double encode percentage encoded values"
+    );
+
+    assert_eq!(
+        to_html("[](<a b>)"),
+        "<p><a href=\"a%20b\"></a></p>",
+        "should encode spaces"
+    );
+
+    assert_eq!(
+        to_html("[](a%zz)"),
+        "<p><a href=\"a%zz\"></a></p>",
+        "should not encode percentage signs followed by two alphanumericals (like `micromark`)"
+    );
+
+    assert_eq!(
+        to_html("<http://example.com/%E2%9C%93>"),
+        "<p><a href=\"http://example.com/%E2%9C%93\">http://example.com/%E2%9C%93</a></p>",
+        "should keep uppercase percent-encoded non-ASCII in autolinks"
+    );
+
+    assert_eq!(
+        to_html("<http://example.com/%e2%9c%93>"),
+        "<p><a href=\"http://example.com/%e2%9c%93\">http://example.com/%e2%9c%93</a></p>",
+        "should keep lowercase percent-encoded non-ASCII in autolinks"
+    );
+
+    assert_eq!(
+        to_html("<http://example.com/✓>"),
+        "<p><a href=\"http://example.com/%E2%9C%93\">http://example.com/✓</a></p>",
+        "should encode non-ASCII in autolinks as uppercase hex"
+    );
+
+    assert_eq!(
+        to_html("[a](http://example.com/%E2%9C%93)"),
+        "<p><a href=\"http://example.com/%E2%9C%93\">a</a></p>",
+        "should keep uppercase percent-encoded non-ASCII in resources"
+    );
+
+    assert_eq!(
+        to_html("[a](<http://example.com/✓>)"),
+        "<p><a href=\"http://example.com/%E2%9C%93\">a</a></p>",
+        "should encode non-ASCII in resources as uppercase hex"
+    );
+
+    assert_eq!(
+        to_html("![a](http://example.com/%E2%9C%93)"),
+        "<p><img src=\"http://example.com/%E2%9C%93\" alt=\"a\" /></p>",
+        "should keep uppercase percent-encoded non-ASCII in images"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n[a]: http://example.com/%E2%9C%93"),
+        "<p><a href=\"http://example.com/%E2%9C%93\">a</a></p>\n",
+        "should keep uppercase percent-encoded non-ASCII in definitions"
+    );
+
     // Note: Surrogate handling not needed in Rust.
     //   assert_eq!(
     //     to_html("[](<foo\u{D800}bar>)"),
@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use markdown::{to_html_into_writer, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+use std::io;
+
+#[test]
+fn writer() -> Result<(), String> {
+    let to_vec = |value: &str, options: &Options| {
+        let mut out = vec![];
+        to_html_into_writer(value, &mut out, options).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+
+    let doc = "# a\n\n* b\n\n  ```c\n  d  \n  ```\n\n> e[^f]  \n> g\n\n[^f]: h\n\n<div>\n";
+
+    assert_eq!(
+        to_vec(doc, &Options::gfm()),
+        to_html_with_options(doc, &Options::gfm())?,
+        "should write the same HTML as `to_html_with_options`"
+    );
+
+    let trim = Options {
+        compile: CompileOptions {
+            trim_output: true,
+            trim_trailing_whitespace: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_vec("\n\n<!-- a -->\n\nb  \nc  \n\n    d  \n", &trim),
+        to_html_with_options("\n\n<!-- a -->\n\nb  \nc  \n\n    d  \n", &trim)?,
+        "should write the same HTML w/ `trim_output`, `trim_trailing_whitespace`"
+    );
+
+    assert_eq!(
+        to_vec("", &Options::default()),
+        "",
+        "should support an empty document"
+    );
+
+    /// Writer that fails after some writes.
+    struct Failing(usize);
+
+    impl io::Write for Failing {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.0 == 0 {
+                Err(io::Error::new(io::ErrorKind::Other, "full"))
+            } else {
+                self.0 -= 1;
+                Ok(buf.len())
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    assert_eq!(
+        to_html_into_writer("a\n\nb\n\nc", Failing(1), &Options::default())
+            .unwrap_err()
+            .to_string(),
+        "full",
+        "should stop at errors from the writer"
+    );
+
+    assert_eq!(
+        to_html_into_writer(
+            "{",
+            Failing(1),
+            &Options {
+                parse: ParseOptions::mdx(),
+                ..Options::default()
+            }
+        )
+        .unwrap_err()
+        .kind(),
+        io::ErrorKind::InvalidData,
+        "should turn syntax errors into `InvalidData` errors"
+    );
+
+    Ok(())
+}
@@ -188,6 +188,64 @@ fn thematic_break() -> Result<(), String> {
         "should support turning off thematic breaks"
     );
 
+    let no_underscore = Options {
+        parse: ParseOptions {
+            thematic_break_markers: vec!['*', '-'],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let no_dash = Options {
+        parse: ParseOptions {
+            thematic_break_markers: vec!['*', '_'],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("___", &no_underscore)?,
+        "<p>___</p>",
+        "should support turning off underscore thematic breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("_a_\n___\n_b_", &no_underscore)?,
+        "<p><em>a</em>\n___\n<em>b</em></p>",
+        "should continue a paragraph w/ underscores if underscore thematic breaks are turned off"
+    );
+
+    assert_eq!(
+        to_html_with_options("___a___", &no_underscore)?,
+        "<p><em><strong>a</strong></em></p>",
+        "should support emphasis w/ underscores if underscore thematic breaks are turned off"
+    );
+
+    assert_eq!(
+        to_html_with_options("***\n\n---\n\n_ _ _", &no_underscore)?,
+        "<hr />\n<hr />\n<p>_ _ _</p>",
+        "should support other markers if underscore thematic breaks are turned off"
+    );
+
+    assert_eq!(
+        to_html_with_options("---", &no_dash)?,
+        "<p>---</p>",
+        "should support turning off dash thematic breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n---", &no_dash)?,
+        "<h2>a</h2>",
+        "should support setext headings if dash thematic breaks are turned off"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n---", &no_dash)?,
+        "<p>---</p>",
+        "should not support dash thematic breaks after definitions if they are turned off"
+    );
+
     assert_eq!(
         to_mdast("***", &Default::default())?,
         Node::Root(Root {
@@ -0,0 +1,431 @@
+use markdown::{mdast::Node, to_markdown, to_mdast, Constructs, ParseOptions, SerializeOptions};
+use pretty_assertions::assert_eq;
+
+/// Turn markdown into a tree, and back into markdown.
+fn roundtrip(value: &str, parse: &ParseOptions, serialize: &SerializeOptions) -> String {
+    to_markdown(&to_mdast(value, parse).unwrap(), serialize)
+}
+
+/// Turn markdown into a tree without positional info.
+fn tree(value: &str, parse: &ParseOptions) -> Node {
+    fn strip(node: &mut Node) {
+        node.position_set(None);
+        if let Some(children) = node.children_mut() {
+            for child in children {
+                strip(child);
+            }
+        }
+    }
+
+    let mut node = to_mdast(value, parse).unwrap();
+    strip(&mut node);
+    node
+}
+
+#[test]
+fn to_markdown_flow() {
+    let parse = ParseOptions::default();
+    let serialize = SerializeOptions::default();
+
+    assert_eq!(
+        roundtrip("", &parse, &serialize),
+        "",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        roundtrip("a\nb", &parse, &serialize),
+        "a\nb\n",
+        "should support a paragraph"
+    );
+
+    assert_eq!(
+        roundtrip("a\n\nb", &parse, &serialize),
+        "a\n\nb\n",
+        "should separate paragraphs with a blank line"
+    );
+
+    assert_eq!(
+        roundtrip("Alpha\n=====\n\n## Bravo ##", &parse, &serialize),
+        "# Alpha\n\n## Bravo\n",
+        "should support headings (atx)"
+    );
+
+    assert_eq!(
+        roundtrip("a\\\nb\n===", &parse, &serialize),
+        "a\\\nb\n===\n",
+        "should support headings with line endings (setext)"
+    );
+
+    assert_eq!(
+        roundtrip("# a \\#", &parse, &serialize),
+        "# a \\#\n",
+        "should escape a closing sequence in headings"
+    );
+
+    assert_eq!(
+        roundtrip("***", &parse, &serialize),
+        "***\n",
+        "should support thematic breaks"
+    );
+
+    assert_eq!(
+        roundtrip("> a\n> b\n>\n> > c", &parse, &serialize),
+        "> a\n> b\n>\n> > c\n",
+        "should support block quotes"
+    );
+
+    assert_eq!(
+        roundtrip("    a\n\n    b", &parse, &serialize),
+        "```\na\n\nb\n```\n",
+        "should support indented code as fenced code"
+    );
+
+    assert_eq!(
+        roundtrip("````js\n```\n````", &parse, &serialize),
+        "````js\n```\n````\n",
+        "should support fenced code with fences in it"
+    );
+
+    assert_eq!(
+        roundtrip("<div>\n*a*\n</div>", &parse, &serialize),
+        "<div>\n*a*\n</div>\n",
+        "should support html"
+    );
+
+    assert_eq!(
+        roundtrip("[A]: <b c> 'd'\n\n[a]", &parse, &serialize),
+        "[A]: <b c> \"d\"\n\n[a]\n",
+        "should support definitions and references"
+    );
+}
+
+#[test]
+fn to_markdown_list() {
+    let parse = ParseOptions::default();
+    let serialize = SerializeOptions::default();
+
+    assert_eq!(
+        roundtrip("- a\n- b", &parse, &serialize),
+        "* a\n* b\n",
+        "should support tight lists"
+    );
+
+    assert_eq!(
+        roundtrip("- a\n\n- b", &parse, &serialize),
+        "* a\n\n* b\n",
+        "should support spread lists"
+    );
+
+    assert_eq!(
+        roundtrip("- a\n\n\n- b", &parse, &serialize),
+        "* a\n\n\n* b\n",
+        "should support spread list items with one child"
+    );
+
+    assert_eq!(
+        roundtrip("3) a\n4) b\n   c", &parse, &serialize),
+        "3. a\n4. b\n   c\n",
+        "should support ordered lists"
+    );
+
+    assert_eq!(
+        roundtrip("- a\n  - b\n\n    c", &parse, &serialize),
+        "* a\n  * b\n\n    c\n",
+        "should support nested lists"
+    );
+
+    assert_eq!(
+        roundtrip("- a\n\n\n+ b", &parse, &serialize),
+        "* a\n\n- b\n",
+        "should use another bullet for adjacent lists"
+    );
+
+    assert_eq!(
+        roundtrip("1. a\n\n\n1) b", &parse, &serialize),
+        "1. a\n\n1) b\n",
+        "should use another delimiter for adjacent ordered lists"
+    );
+
+    assert_eq!(
+        roundtrip("- ***", &parse, &serialize),
+        "* ---\n",
+        "should not serialize a thematic break in a list item as the same marker"
+    );
+
+    assert_eq!(
+        roundtrip(
+            "* [x] a\n* [ ] b",
+            &ParseOptions {
+                constructs: Constructs::gfm(),
+                ..ParseOptions::default()
+            },
+            &serialize
+        ),
+        "* [x] a\n* [ ] b\n",
+        "should support task list items"
+    );
+}
+
+#[test]
+fn to_markdown_definition_list() {
+    let parse = ParseOptions {
+        constructs: Constructs {
+            definition_list: true,
+            ..Constructs::default()
+        },
+        ..ParseOptions::default()
+    };
+    let serialize = SerializeOptions::default();
+
+    assert_eq!(
+        roundtrip("a\n:   b\n: c", &parse, &serialize),
+        "a\n: b\n: c\n",
+        "should support tight definition lists"
+    );
+
+    assert_eq!(
+        roundtrip("a\n: b\n\n: c", &parse, &serialize),
+        "a\n: b\n\n: c\n",
+        "should support spread definition lists"
+    );
+
+    assert_eq!(
+        roundtrip("a\nb\n: c\nd\n\ne\n: f", &parse, &serialize),
+        "a\nb\n: c\n    d\n\ne\n: f\n",
+        "should support multiple terms, and indent lazy lines"
+    );
+
+    assert_eq!(
+        roundtrip("a\n: b\n\n    ```\n    c\n    ```", &parse, &serialize),
+        "a\n: b\n\n    ```\n    c\n    ```\n",
+        "should support flow in details"
+    );
+}
+
+#[test]
+fn to_markdown_phrasing() {
+    let parse = ParseOptions::default();
+    let serialize = SerializeOptions::default();
+
+    assert_eq!(
+        roundtrip("_a_ __b__", &parse, &serialize),
+        "*a* **b**\n",
+        "should support emphasis and strong"
+    );
+
+    assert_eq!(
+        roundtrip("*_a_*", &parse, &serialize),
+        "_*a*_\n",
+        "should use another marker for emphasis directly in emphasis"
+    );
+
+    assert_eq!(
+        roundtrip("**_a_**", &parse, &serialize),
+        "__*a*__\n",
+        "should use another marker for emphasis directly in strong"
+    );
+
+    assert_eq!(
+        roundtrip("***a***", &parse, &serialize),
+        "***a***\n",
+        "should use the same marker for strong directly in emphasis"
+    );
+
+    assert_eq!(
+        roundtrip("foo******bar*********baz", &parse, &serialize),
+        "foo******bar******\\*\\*\\*baz\n",
+        "should use the same marker for strong directly in strong"
+    );
+
+    assert_eq!(
+        roundtrip("`` a`b ``", &parse, &serialize),
+        "`` a`b ``\n",
+        "should support code (text)"
+    );
+
+    assert_eq!(
+        roundtrip("a  \nb\\\nc", &parse, &serialize),
+        "a  \nb\\\nc\n",
+        "should support hard breaks"
+    );
+
+    assert_eq!(
+        roundtrip("[a](b 'c') ![d](<e f>)", &parse, &serialize),
+        "[a](b \"c\") ![d](<e f>)\n",
+        "should support links and images"
+    );
+
+    assert_eq!(
+        roundtrip("<https://a.b> <c@d.e>", &parse, &serialize),
+        "<https://a.b> <c@d.e>\n",
+        "should support autolinks"
+    );
+
+    assert_eq!(
+        roundtrip("<https://a.b/\\*&amp;c>", &parse, &serialize),
+        "<https://a.b/\\*&amp;c>\n",
+        "should support autolinks w/ backslashes and ampersands"
+    );
+
+    assert_eq!(
+        roundtrip("https://a.b c@d.e", &ParseOptions::gfm(), &serialize),
+        "https://a.b c@d.e\n",
+        "should support GFM autolink literals"
+    );
+
+    assert_eq!(
+        roundtrip("\\*a\\* \\[b] \\<c> \\# d &amp;e;", &parse, &serialize),
+        "\\*a\\* \\[b\\] \\<c> # d \\&e;\n",
+        "should escape characters that would otherwise form syntax"
+    );
+
+    assert_eq!(
+        roundtrip("&#x20;a&#x20;", &parse, &serialize),
+        "&#x20;a&#x20;\n",
+        "should encode initial and final whitespace"
+    );
+
+    assert_eq!(
+        roundtrip("[a][B]\n\n[b]: c", &parse, &serialize),
+        "[a][B]\n\n[b]: c\n",
+        "should support full references"
+    );
+
+    assert_eq!(
+        roundtrip("[a][]\n\n[a]: b", &parse, &serialize),
+        "[a][]\n\n[a]: b\n",
+        "should support collapsed references"
+    );
+
+    assert_eq!(
+        roundtrip("[a](b c)\n\n[a]: d", &parse, &serialize),
+        "[a]\\(b c)\n\n[a]: d\n",
+        "should escape a parenthesis after a shortcut reference"
+    );
+
+    assert_eq!(
+        roundtrip("[a*b\\]]\n\n[a*b\\]]: c", &parse, &serialize),
+        "[a*b\\]]\n\n[a*b\\]]: c\n",
+        "should use the label of a reference if escaped content does not match"
+    );
+
+    assert_eq!(
+        roundtrip("![a *b*][]\n\n[a *b*]: c", &parse, &serialize),
+        "![a *b*][]\n\n[a *b*]: c\n",
+        "should use the label of a definition if content does not match"
+    );
+
+    assert_eq!(
+        roundtrip("![*A*][]\n\n[*a*]: b", &parse, &serialize),
+        "![A][*a*]\n\n[*a*]: b\n",
+        "should not use the label of a definition if it is seen differently"
+    );
+}
+
+#[test]
+fn to_markdown_options() {
+    let parse = ParseOptions::default();
+
+    assert_eq!(
+        roundtrip(
+            "- *a* **b**\n\n  ```\n  c\n  ```",
+            &parse,
+            &SerializeOptions {
+                bullet: '-',
+                emphasis: '_',
+                strong: '_',
+                fence: '~',
+                ..SerializeOptions::default()
+            }
+        ),
+        "- _a_ __b__\n\n  ~~~\n  c\n  ~~~\n",
+        "should support `bullet`, `emphasis`, `strong`, and `fence`"
+    );
+
+    assert_eq!(
+        roundtrip(
+            "[a](b) [c](d 'e') [f](b)",
+            &parse,
+            &SerializeOptions {
+                reference_links: true,
+                ..SerializeOptions::default()
+            }
+        ),
+        "[a][1] [c][2] [f][1]\n\n[1]: b\n[2]: d \"e\"\n",
+        "should support `reference_links`"
+    );
+
+    assert_eq!(
+        roundtrip(
+            "[a](b)\n\n[1]: c",
+            &parse,
+            &SerializeOptions {
+                reference_links: true,
+                ..SerializeOptions::default()
+            }
+        ),
+        "[a][2]\n\n[1]: c\n\n[2]: b\n",
+        "should not reuse existing identifiers with `reference_links`"
+    );
+}
+
+#[test]
+fn to_markdown_roundtrip() {
+    let parse = ParseOptions::gfm();
+    let serialize = SerializeOptions::default();
+    let inputs = [
+        "# a\n\n> b *c* **d**\n>\n> * e\n>   * f\n\n1. g\n2. h\n\n***\n",
+        "a\n\n```js\nb\n```\n\n    c\n\n<div>\n\nd\n\n</div>\n",
+        "a *b\\*c* _d_e_ `f` [g](h) ![i](j) <k@l.m>\n",
+        "| a | b |\n| :- | -: |\n| c | d \\| e |\n",
+        "a[^b]\n\n[^b]: c\n\n    d\n",
+        "~~a~~ https://b.c\n",
+        "* [ ] a\n* [x] b\n\n  c\n",
+        "  a  \n\tb\n\n- \n-\n",
+        "a\n&#xA;b\n",
+        "[a]\n\n[a]: <> \"b\\\"c\"\n",
+    ];
+
+    for input in inputs {
+        let markdown = to_markdown(&tree(input, &parse), &serialize);
+        assert_eq!(
+            tree(&markdown, &parse),
+            tree(input, &parse),
+            "should roundtrip `{:?}` (as `{:?}`)",
+            input,
+            markdown
+        );
+    }
+}
+
+#[test]
+fn to_markdown_commonmark() {
+    let parse = ParseOptions::default();
+    let serialize = SerializeOptions::default();
+    // Take the input of each example from the generated `CommonMark` tests.
+    let source = include_str!("commonmark.rs");
+    let start = "to_html_with_options(\n            r###\"";
+    let mut rest = source;
+    let mut count = 0;
+
+    while let Some(index) = rest.find(start) {
+        rest = &rest[index + start.len()..];
+        let end = rest.find("\"###").expect("expected end of example");
+        let input = &rest[..end];
+        rest = &rest[end..];
+        count += 1;
+
+        let markdown = to_markdown(&tree(input, &parse), &serialize);
+        assert_eq!(
+            tree(&markdown, &parse),
+            tree(input, &parse),
+            "should roundtrip example {} `{:?}` (as `{:?}`)",
+            count,
+            input,
+            markdown
+        );
+    }
+
+    assert_eq!(count, 652, "should roundtrip all examples");
+}